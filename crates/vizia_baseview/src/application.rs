@@ -214,6 +214,9 @@ impl ApplicationRunner {
             cx.send_event(event);
         }
 
+        // Scheduled events are checked once per frame.
+        cx.process_timers();
+
         // Events
         cx.process_events();
 
@@ -247,7 +250,7 @@ impl ApplicationRunner {
         }
 
         // Force restyle on every frame for baseview backend to avoid style inheritance issues
-        cx.style().needs_restyle();
+        cx.style().needs_full_restyle();
         cx.process_data_updates();
 
         let context = window.gl_context().expect("Window was created without OpenGL support");
@@ -2,7 +2,8 @@ use crate::prelude::*;
 // use instant::Duration;
 use morphorm::Units;
 use vizia_style::{
-    BackgroundSize, BorderWidth, BoxShadow, FontSize, Position, Property, Scale, Translate,
+    BackgroundSize, BorderWidth, BorderWidthValue, BoxShadow, FontSize, Position, Property, Scale,
+    Translate,
 };
 
 // pub struct AnimationDescription {
@@ -108,12 +109,60 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
-    pub fn border_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn border_top_width(mut self, val: impl Into<BorderWidthValue>) -> Self {
+        self.properties.push(Property::BorderTopWidth(val.into()));
+
+        self
+    }
+
+    pub fn border_right_width(mut self, val: impl Into<BorderWidthValue>) -> Self {
+        self.properties.push(Property::BorderRightWidth(val.into()));
+
+        self
+    }
+
+    pub fn border_bottom_width(mut self, val: impl Into<BorderWidthValue>) -> Self {
+        self.properties.push(Property::BorderBottomWidth(val.into()));
+
+        self
+    }
+
+    pub fn border_left_width(mut self, val: impl Into<BorderWidthValue>) -> Self {
+        self.properties.push(Property::BorderLeftWidth(val.into()));
+
+        self
+    }
+
+    pub fn border_color(mut self, val: impl Into<BorderColor>) -> Self {
         self.properties.push(Property::BorderColor(val.into()));
 
         self
     }
 
+    pub fn border_top_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderTopColor(val.into()));
+
+        self
+    }
+
+    pub fn border_right_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderRightColor(val.into()));
+
+        self
+    }
+
+    pub fn border_bottom_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderBottomColor(val.into()));
+
+        self
+    }
+
+    pub fn border_left_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderLeftColor(val.into()));
+
+        self
+    }
+
     pub fn border_top_left_radius(mut self, val: impl Into<LengthOrPercentage>) -> Self {
         self.properties.push(Property::BorderTopLeftRadius(val.into()));
 
@@ -186,6 +235,20 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    // FILTERS
+
+    pub fn filter(mut self, val: impl Into<Vec<Filter>>) -> Self {
+        self.properties.push(Property::Filter(val.into()));
+
+        self
+    }
+
+    pub fn backdrop_filter(mut self, val: impl Into<Vec<Filter>>) -> Self {
+        self.properties.push(Property::BackdropFilter(val.into()));
+
+        self
+    }
+
     // TEXT
 
     pub fn color(mut self, val: impl Into<Color>) -> Self {
@@ -1,8 +1,9 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, BoxShadow, ClipPath, Color, ColorStop, Display, Filter, FontSize,
-    Gradient, Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection,
-    LinearGradient, Opacity, PercentageOrNumber, Rect, Scale, Transform, Translate, RGBA,
+    Angle, BackgroundSize, BoxShadow, ClipPath, Color, ColorStop, Display, DropShadow, Filter,
+    FontSize, Gradient, Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue,
+    LineDirection, LinearGradient, Opacity, PercentageOrNumber, Rect, Scale, Transform, Translate,
+    RGBA,
 };
 
 use femtovg::Transform2D;
@@ -13,6 +14,15 @@ use crate::style::ImageOrGradient;
 /// TODO: Make this crate private when `AnimatableSet` can be made crate private.
 pub(crate) trait Interpolator {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self;
+
+    /// Returns a value of the same kind as the given value which has no effect, if there is one,
+    /// which stands in for the missing items when interpolating lists of different lengths.
+    fn identity(_value: &Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 impl Interpolator for f32 {
@@ -85,12 +95,72 @@ impl Interpolator for RGBA {
 
 impl Interpolator for Filter {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        // Filter amounts are interpolated as factors so that a percentage can animate to a number.
+        let amount = |start: &PercentageOrNumber, end: &PercentageOrNumber| {
+            PercentageOrNumber::Number(f32::interpolate(&start.to_factor(), &end.to_factor(), t))
+        };
+
         match (start, end) {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+
+            (Filter::Brightness(start), Filter::Brightness(end)) => {
+                Filter::Brightness(amount(start, end))
+            }
+
+            (Filter::Contrast(start), Filter::Contrast(end)) => {
+                Filter::Contrast(amount(start, end))
+            }
+
+            (Filter::Grayscale(start), Filter::Grayscale(end)) => {
+                Filter::Grayscale(amount(start, end))
+            }
+
+            (Filter::Saturate(start), Filter::Saturate(end)) => {
+                Filter::Saturate(amount(start, end))
+            }
+
+            (Filter::Invert(start), Filter::Invert(end)) => Filter::Invert(amount(start, end)),
+
+            (Filter::HueRotate(start), Filter::HueRotate(end)) => {
+                Filter::HueRotate(Angle::interpolate(start, end, t))
+            }
+
+            (Filter::Opacity(start), Filter::Opacity(end)) => Filter::Opacity(amount(start, end)),
+
+            (Filter::DropShadow(start), Filter::DropShadow(end)) => {
+                Filter::DropShadow(DropShadow::interpolate(start, end, t))
+            }
+
+            _ => end.clone(),
         }
     }
+
+    fn identity(value: &Self) -> Option<Self> {
+        let none = PercentageOrNumber::Number(0.0);
+        let one = PercentageOrNumber::Number(1.0);
+
+        Some(match value {
+            Filter::Blur(_) => Filter::Blur(Length::default()),
+            Filter::Brightness(_) => Filter::Brightness(one),
+            Filter::Contrast(_) => Filter::Contrast(one),
+            Filter::Grayscale(_) => Filter::Grayscale(none),
+            Filter::Saturate(_) => Filter::Saturate(one),
+            Filter::Invert(_) => Filter::Invert(none),
+            Filter::HueRotate(_) => Filter::HueRotate(Angle::Deg(0.0)),
+            Filter::Opacity(_) => Filter::Opacity(one),
+            // A transparent shadow of the same color, so that only its opacity changes.
+            Filter::DropShadow(shadow) => Filter::DropShadow(DropShadow {
+                x_offset: Length::default(),
+                y_offset: Length::default(),
+                blur_radius: shadow.blur_radius.as_ref().map(|_| Length::default()),
+                color: Some(shadow.color.map_or(Color::transparent(), |color| {
+                    Color::rgba(color.r(), color.g(), color.b(), 0)
+                })),
+            }),
+        })
+    }
 }
 
 impl Interpolator for LengthValue {
@@ -211,10 +281,15 @@ impl Interpolator for Transform2D {
 
 impl<T: Interpolator> Interpolator for Vec<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        start
-            .iter()
-            .zip(end.iter())
-            .map(|(start, end)| T::interpolate(start, end, t))
+        // The shorter list is padded with the identity values of the items of the longer list, so
+        // that `none` animates smoothly to `grayscale(1)`. Items without one are dropped.
+        (0..start.len().max(end.len()))
+            .filter_map(|index| match (start.get(index), end.get(index)) {
+                (Some(start), Some(end)) => Some(T::interpolate(start, end, t)),
+                (Some(start), None) => T::identity(start).map(|end| T::interpolate(start, &end, t)),
+                (None, Some(end)) => T::identity(end).map(|start| T::interpolate(&start, end, t)),
+                (None, None) => None,
+            })
             .collect::<Vec<T>>()
     }
 }
@@ -320,6 +395,17 @@ impl Interpolator for BoxShadow {
     }
 }
 
+impl Interpolator for DropShadow {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        DropShadow {
+            x_offset: Length::interpolate(&start.x_offset, &end.x_offset, t),
+            y_offset: Length::interpolate(&start.y_offset, &end.y_offset, t),
+            blur_radius: Option::interpolate(&start.blur_radius, &end.blur_radius, t),
+            color: Option::interpolate(&start.color, &end.color, t),
+        }
+    }
+}
+
 impl<T: Interpolator + Clone + Default> Interpolator for Option<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
@@ -356,3 +442,30 @@ impl Interpolator for ClipPath {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_lists_are_padded() {
+        let none = Vec::new();
+        let grayscale = vec![Filter::Grayscale(PercentageOrNumber::Number(1.0))];
+        let half = vec![Filter::Grayscale(PercentageOrNumber::Number(0.5))];
+
+        assert_eq!(Vec::interpolate(&none, &grayscale, 0.5), half);
+        assert_eq!(Vec::interpolate(&grayscale, &none, 0.5), half);
+
+        let filters = vec![
+            Filter::Grayscale(PercentageOrNumber::Number(1.0)),
+            Filter::Brightness(PercentageOrNumber::Number(2.0)),
+        ];
+        assert_eq!(
+            Vec::interpolate(&grayscale, &filters, 0.5),
+            vec![
+                Filter::Grayscale(PercentageOrNumber::Number(1.0)),
+                Filter::Brightness(PercentageOrNumber::Number(1.5)),
+            ]
+        );
+    }
+}
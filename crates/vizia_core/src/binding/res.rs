@@ -95,6 +95,7 @@ impl_res_clone!(Translate);
 impl_res_clone!(Scale);
 impl_res_clone!(Position);
 impl_res_simple!(PointerEvents);
+impl_res_simple!(Direction);
 
 impl<L> Res<L::Target> for L
 where
@@ -11,6 +11,61 @@ pub(crate) struct Pos {
     pub(crate) y: f32,
 }
 
+/// The offscreen images used to draw a view with filters and its children.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FilterLayer {
+    /// The size of the layer images in pixels.
+    pub(crate) size: (usize, usize),
+    /// The layer image, followed by two images used as targets by the filters.
+    pub(crate) images: [ImageId; 3],
+    /// An image holding the pixels of the layer after a color filter is applied on the CPU.
+    pub(crate) pixels: Option<ImageId>,
+    /// The region of the render target which the filtered contents in the layer image were drawn
+    /// for, or `None` if the view must be drawn and filtered again.
+    pub(crate) region: Option<BoundingBox>,
+    /// The opacity with which the layer image is composited.
+    pub(crate) alpha: f32,
+}
+
+impl FilterLayer {
+    /// Creates the images of a layer, or returns `None` if the canvas fails to create them.
+    pub(crate) fn new(canvas: &mut Canvas, size: (usize, usize)) -> Option<Self> {
+        let mut images = Vec::with_capacity(3);
+        for _ in 0..3 {
+            match canvas.create_image_empty(
+                size.0,
+                size.1,
+                femtovg::PixelFormat::Rgba8,
+                femtovg::ImageFlags::FLIP_Y | femtovg::ImageFlags::PREMULTIPLIED,
+            ) {
+                Ok(image) => images.push(image),
+                Err(_) => {
+                    for image in images {
+                        canvas.delete_image(image);
+                    }
+
+                    return None;
+                }
+            }
+        }
+
+        Some(FilterLayer {
+            size,
+            images: [images[0], images[1], images[2]],
+            pixels: None,
+            region: None,
+            alpha: 1.0,
+        })
+    }
+
+    /// Deletes the images of the layer from the canvas.
+    pub(crate) fn delete(self, canvas: &mut Canvas) {
+        for image in self.images.into_iter().chain(self.pixels) {
+            canvas.delete_image(image);
+        }
+    }
+}
+
 /// Stores data which can be cached between system runs.
 ///
 /// When an event occurs or style data is changed systems run to determine the new state of the UI.
@@ -22,6 +77,7 @@ pub struct CachedData {
     pub(crate) shadow_images: SparseSet<Vec<Option<(ImageId, ImageId)>>>,
     pub(crate) filter_image: SparseSet<Option<(ImageId, ImageId)>>,
     pub(crate) screenshot_image: SparseSet<Option<ImageId>>,
+    pub(crate) filter_layer: SparseSet<FilterLayer>,
     pub(crate) geo_changed: SparseSet<GeoChanged>,
 }
 
@@ -32,15 +88,40 @@ impl CachedData {
         self.geo_changed.insert(entity, GeoChanged::empty());
     }
 
+    /// Removes the data cached for the entity. The images cached for it are only dropped, so they
+    /// must first be deleted from its canvas with [`delete_images`](Self::delete_images).
     pub(crate) fn remove(&mut self, entity: Entity) {
         self.bounds.remove(entity);
         self.relative_position.remove(entity);
         self.filter_image.remove(entity);
         self.screenshot_image.remove(entity);
+        self.filter_layer.remove(entity);
         self.shadow_images.remove(entity);
         self.geo_changed.remove(entity);
     }
 
+    /// Deletes the images cached for the entity from the canvas they were created on, so that
+    /// they are created again the next time the entity is drawn.
+    pub(crate) fn delete_images(&mut self, entity: Entity, canvas: &mut Canvas) {
+        if let Some((source, target)) = self.filter_image.remove(entity).flatten() {
+            canvas.delete_image(source);
+            canvas.delete_image(target);
+        }
+
+        if let Some(layer) = self.filter_layer.remove(entity) {
+            layer.delete(canvas);
+        }
+
+        if let Some(image) = self.screenshot_image.remove(entity).flatten() {
+            canvas.delete_image(image);
+        }
+
+        for (source, target) in self.shadow_images.remove(entity).into_iter().flatten().flatten() {
+            canvas.delete_image(source);
+            canvas.delete_image(target);
+        }
+    }
+
     /// Returns the bounding box of the entity, determined by the layout system.
     pub fn get_bounds(&self, entity: Entity) -> BoundingBox {
         self.bounds.get(entity).cloned().unwrap()
@@ -0,0 +1,244 @@
+//! Exchanges clipboard data with other applications as MIME types, as on X11 and Wayland.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use image::{DynamicImage, ImageFormat, ImageOutputFormat};
+
+use super::*;
+
+/// The MIME types under which plain text is offered, in order of preference when reading.
+const TEXT_MIME_TYPES: [&str; 3] = ["text/plain;charset=utf-8", "UTF8_STRING", MIME_TEXT];
+
+/// Data offered on the clipboard under a MIME type.
+pub(crate) type Offer = (String, Arc<[u8]>);
+
+/// A clipboard which exchanges data with other applications as MIME types.
+pub(crate) trait MimeBackend {
+    /// Returns the MIME types in which the contents of the clipboard can be read.
+    fn mime_types(&mut self) -> Result<Vec<String>, ClipboardError>;
+
+    /// Reads the contents of the clipboard as the given MIME type.
+    fn read(&mut self, mime_type: &str) -> Result<Vec<u8>, ClipboardError>;
+
+    /// Replaces the contents of the clipboard with the given offers.
+    fn write(&mut self, offers: Vec<Offer>) -> Result<(), ClipboardError>;
+}
+
+/// Converts between [`ClipboardData`] and the MIME types of a [`MimeBackend`].
+pub(crate) struct MimeClipboard<B>(pub B);
+
+impl<B: MimeBackend> ClipboardProvider for MimeClipboard<B> {
+    fn get_contents(&mut self, format: &ClipboardFormat) -> Result<ClipboardData, ClipboardError> {
+        let mime_types = self.0.mime_types()?;
+        let mime_type = match format {
+            ClipboardFormat::Text => TEXT_MIME_TYPES
+                .iter()
+                .copied()
+                .find(|mime_type| mime_types.iter().any(|offered| offered == mime_type)),
+            _ => mime_types
+                .iter()
+                .map(String::as_str)
+                .find(|mime_type| *mime_type == format.mime_type()),
+        };
+
+        let mime_type = match mime_type {
+            Some(mime_type) => mime_type.to_owned(),
+            None => return Err(Box::new(FormatUnavailable(format.clone()))),
+        };
+
+        let data = self.0.read(&mime_type)?;
+        Ok(match format {
+            ClipboardFormat::Text => ClipboardData::Text(String::from_utf8(data)?),
+            ClipboardFormat::Html => {
+                ClipboardData::Html { html: String::from_utf8(data)?, alt_text: None }
+            }
+            ClipboardFormat::Image => {
+                ClipboardData::Image(image::load_from_memory_with_format(&data, ImageFormat::Png)?)
+            }
+            ClipboardFormat::Custom(_) => ClipboardData::Custom { mime_type, data },
+        })
+    }
+
+    fn set_contents(&mut self, contents: Vec<ClipboardData>) -> Result<(), ClipboardError> {
+        let offers = offers(&contents)?;
+
+        // Copying empty text leaves the clipboard untouched.
+        if offers.is_empty() {
+            return Ok(());
+        }
+
+        self.0.write(offers)
+    }
+
+    fn formats(&mut self) -> Vec<ClipboardFormat> {
+        let mut formats = Vec::new();
+        for mime_type in self.0.mime_types().unwrap_or_default() {
+            // X11 also advertises targets such as `TARGETS` and `TIMESTAMP`, which are not data.
+            let format = if TEXT_MIME_TYPES.contains(&mime_type.as_str()) {
+                ClipboardFormat::Text
+            } else if mime_type.contains('/') {
+                ClipboardFormat::from_mime_type(&mime_type)
+            } else {
+                continue;
+            };
+
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+
+        formats
+    }
+}
+
+/// Converts clipboard contents into data tagged with the MIME types other applications request.
+fn offers(contents: &[ClipboardData]) -> Result<Vec<Offer>, ClipboardError> {
+    fn push(offers: &mut Vec<Offer>, mime_type: &str, data: Arc<[u8]>) {
+        if !offers.iter().any(|(offered, _)| offered == mime_type) {
+            offers.push((mime_type.to_owned(), data));
+        }
+    }
+
+    let mut offers = Vec::new();
+    for data in contents {
+        match data {
+            ClipboardData::Text(_) => {}
+            ClipboardData::Html { html, .. } => {
+                push(&mut offers, MIME_HTML, html.as_bytes().into())
+            }
+            ClipboardData::Image(image) => push(&mut offers, MIME_IMAGE, encode_png(image)?.into()),
+            ClipboardData::Custom { mime_type, data } => {
+                push(&mut offers, mime_type, data.as_slice().into())
+            }
+        }
+
+        if let Some(text) = data.text().filter(|text| !text.is_empty()) {
+            let text: Arc<[u8]> = text.as_bytes().into();
+            for mime_type in TEXT_MIME_TYPES {
+                push(&mut offers, mime_type, text.clone());
+            }
+        }
+    }
+
+    Ok(offers)
+}
+
+fn encode_png(image: &DynamicImage) -> Result<Vec<u8>, ClipboardError> {
+    let mut data = Vec::new();
+    image.write_to(&mut Cursor::new(&mut data), ImageOutputFormat::Png)?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A clipboard which holds the offers written to it, with the targets X11 adds to them.
+    #[derive(Default)]
+    struct FakeBackend {
+        offers: Vec<Offer>,
+        writes: usize,
+    }
+
+    impl MimeBackend for FakeBackend {
+        fn mime_types(&mut self) -> Result<Vec<String>, ClipboardError> {
+            let mut mime_types = vec![String::from("TARGETS"), String::from("TIMESTAMP")];
+            mime_types.extend(self.offers.iter().map(|(mime_type, _)| mime_type.clone()));
+            Ok(mime_types)
+        }
+
+        fn read(&mut self, mime_type: &str) -> Result<Vec<u8>, ClipboardError> {
+            self.offers
+                .iter()
+                .find(|(offered, _)| offered == mime_type)
+                .map(|(_, data)| data.to_vec())
+                .ok_or_else(|| "not offered".into())
+        }
+
+        fn write(&mut self, offers: Vec<Offer>) -> Result<(), ClipboardError> {
+            self.offers = offers;
+            self.writes += 1;
+            Ok(())
+        }
+    }
+
+    fn mime_types(clipboard: &MimeClipboard<FakeBackend>) -> Vec<&str> {
+        clipboard.0.offers.iter().map(|(mime_type, _)| mime_type.as_str()).collect()
+    }
+
+    #[test]
+    fn custom_data_is_offered_with_its_alternatives() {
+        let mut clipboard = MimeClipboard(FakeBackend::default());
+        clipboard
+            .set_contents(vec![
+                ClipboardData::Custom {
+                    mime_type: String::from("application/x-shapes"),
+                    data: vec![1, 2, 3],
+                },
+                ClipboardData::Image(DynamicImage::new_rgba8(2, 2)),
+                ClipboardData::Text(String::from("shapes")),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            mime_types(&clipboard),
+            [
+                "application/x-shapes",
+                "image/png",
+                "text/plain;charset=utf-8",
+                "UTF8_STRING",
+                "text/plain"
+            ]
+        );
+
+        let custom = ClipboardFormat::Custom(String::from("application/x-shapes"));
+        assert_eq!(
+            clipboard.formats(),
+            [custom.clone(), ClipboardFormat::Image, ClipboardFormat::Text]
+        );
+        assert!(matches!(
+            clipboard.get_contents(&custom).unwrap(),
+            ClipboardData::Custom { data, .. } if data == vec![1, 2, 3]
+        ));
+        assert!(matches!(
+            clipboard.get_contents(&ClipboardFormat::Image).unwrap(),
+            ClipboardData::Image(image) if image.width() == 2 && image.height() == 2
+        ));
+        assert_eq!(clipboard.get_contents(&ClipboardFormat::Text).unwrap().text(), Some("shapes"));
+    }
+
+    #[test]
+    fn html_is_offered_with_alt_text() {
+        let mut clipboard = MimeClipboard(FakeBackend::default());
+        clipboard
+            .set_contents(vec![ClipboardData::Html {
+                html: String::from("<b>bold</b>"),
+                alt_text: Some(String::from("bold")),
+            }])
+            .unwrap();
+
+        assert!(matches!(
+            clipboard.get_contents(&ClipboardFormat::Html).unwrap(),
+            ClipboardData::Html { html, .. } if html == "<b>bold</b>"
+        ));
+        assert_eq!(clipboard.get_contents(&ClipboardFormat::Text).unwrap().text(), Some("bold"));
+    }
+
+    #[test]
+    fn text_is_read_from_other_mime_types() {
+        let mut clipboard = MimeClipboard(FakeBackend::default());
+        clipboard.0.offers = vec![(String::from("UTF8_STRING"), b"other".as_slice().into())];
+
+        assert_eq!(clipboard.formats(), [ClipboardFormat::Text]);
+        assert_eq!(clipboard.get_contents(&ClipboardFormat::Text).unwrap().text(), Some("other"));
+        assert!(clipboard.get_contents(&ClipboardFormat::Html).is_err());
+    }
+
+    #[test]
+    fn empty_text_leaves_the_clipboard_untouched() {
+        let mut clipboard = MimeClipboard(FakeBackend::default());
+        clipboard.set_contents(vec![ClipboardData::Text(String::new())]).unwrap();
+        assert_eq!(clipboard.0.writes, 0);
+    }
+}
@@ -0,0 +1,321 @@
+//! Clipboard formats and providers.
+//!
+//! Views access the clipboard through methods on [`EventContext`](crate::context::EventContext),
+//! such as [`get_clipboard`](crate::context::EventContext::get_clipboard) for text and
+//! [`get_clipboard_image`](crate::context::EventContext::get_clipboard_image) for images. The
+//! underlying storage is a [`ClipboardProvider`], which windowing backends install with
+//! [`BackendContext::set_clipboard_provider`](crate::backend::BackendContext::set_clipboard_provider).
+//! A [`MemoryClipboard`] can be installed instead to exercise copy and paste in tests without
+//! touching the system clipboard.
+
+use std::error::Error;
+use std::fmt;
+
+use image::DynamicImage;
+
+#[cfg(any(
+    test,
+    all(
+        any(feature = "x11", feature = "wayland"),
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    )
+))]
+mod mime;
+#[cfg(any(windows, target_os = "macos"))]
+mod native;
+#[cfg(all(
+    feature = "wayland",
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+mod wayland;
+#[cfg(all(
+    feature = "x11",
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+mod x11;
+
+/// The error type returned by clipboard operations.
+pub type ClipboardError = Box<dyn Error + Send + Sync + 'static>;
+
+/// The MIME type used for plain text.
+pub const MIME_TEXT: &str = "text/plain";
+/// The MIME type used for HTML.
+pub const MIME_HTML: &str = "text/html";
+/// The MIME type used for images.
+pub const MIME_IMAGE: &str = "image/png";
+
+/// A format in which data can be read from the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    /// Plain text.
+    Text,
+    /// HTML markup.
+    Html,
+    /// An image.
+    Image,
+    /// Application-defined data identified by a MIME type, e.g. `application/x-my-app-shapes`.
+    Custom(String),
+}
+
+impl ClipboardFormat {
+    /// Returns the MIME type of the format.
+    pub fn mime_type(&self) -> &str {
+        match self {
+            ClipboardFormat::Text => MIME_TEXT,
+            ClipboardFormat::Html => MIME_HTML,
+            ClipboardFormat::Image => MIME_IMAGE,
+            ClipboardFormat::Custom(mime_type) => mime_type,
+        }
+    }
+
+    /// Returns the format corresponding to the given MIME type.
+    pub fn from_mime_type(mime_type: &str) -> Self {
+        match mime_type {
+            MIME_TEXT => ClipboardFormat::Text,
+            MIME_HTML => ClipboardFormat::Html,
+            MIME_IMAGE => ClipboardFormat::Image,
+            _ => ClipboardFormat::Custom(mime_type.to_owned()),
+        }
+    }
+}
+
+/// Data which can be placed on the clipboard.
+#[derive(Debug, Clone)]
+pub enum ClipboardData {
+    /// Plain text.
+    Text(String),
+    /// HTML markup with an optional plain text alternative for applications which cannot read HTML.
+    Html { html: String, alt_text: Option<String> },
+    /// An image.
+    Image(DynamicImage),
+    /// Application-defined data identified by a MIME type.
+    Custom { mime_type: String, data: Vec<u8> },
+}
+
+impl ClipboardData {
+    /// Returns the format of the data.
+    pub fn format(&self) -> ClipboardFormat {
+        match self {
+            ClipboardData::Text(_) => ClipboardFormat::Text,
+            ClipboardData::Html { .. } => ClipboardFormat::Html,
+            ClipboardData::Image(_) => ClipboardFormat::Image,
+            ClipboardData::Custom { mime_type, .. } => ClipboardFormat::from_mime_type(mime_type),
+        }
+    }
+
+    /// Returns the plain text representation of the data, if there is one.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            ClipboardData::Text(text) => Some(text),
+            ClipboardData::Html { alt_text, .. } => alt_text.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// The error returned when the clipboard does not contain data in the requested format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatUnavailable(pub ClipboardFormat);
+
+impl fmt::Display for FormatUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "clipboard does not contain data of type {}", self.0.mime_type())
+    }
+}
+
+impl Error for FormatUnavailable {}
+
+/// A source and destination for clipboard data.
+///
+/// Setting the contents replaces everything on the clipboard. Multiple representations of the same
+/// content, for example a custom format alongside an image for applications which do not
+/// understand it, can be placed on the clipboard at once with
+/// [`set_contents`](ClipboardProvider::set_contents).
+pub trait ClipboardProvider {
+    /// Returns the contents of the clipboard in the given format.
+    fn get_contents(&mut self, format: &ClipboardFormat) -> Result<ClipboardData, ClipboardError>;
+
+    /// Replaces the contents of the clipboard with the given representations.
+    fn set_contents(&mut self, contents: Vec<ClipboardData>) -> Result<(), ClipboardError>;
+
+    /// Returns the formats which are currently available on the clipboard.
+    fn formats(&mut self) -> Vec<ClipboardFormat>;
+}
+
+/// A clipboard which stores its contents in memory.
+///
+/// The contents are only visible to the application which owns the clipboard, which makes this
+/// provider useful for testing copy and paste behaviour.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryClipboard {
+    contents: Vec<ClipboardData>,
+}
+
+impl MemoryClipboard {
+    /// Creates a new, empty, in-memory clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all data from the clipboard.
+    pub fn clear(&mut self) {
+        self.contents.clear();
+    }
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get_contents(&mut self, format: &ClipboardFormat) -> Result<ClipboardData, ClipboardError> {
+        if let Some(data) = self.contents.iter().find(|data| data.format() == *format) {
+            return Ok(data.clone());
+        }
+
+        // Fall back to the plain text alternative of rich text.
+        if *format == ClipboardFormat::Text {
+            if let Some(text) = self.contents.iter().find_map(|data| data.text()) {
+                return Ok(ClipboardData::Text(text.to_owned()));
+            }
+        }
+
+        Err(Box::new(FormatUnavailable(format.clone())))
+    }
+
+    fn set_contents(&mut self, contents: Vec<ClipboardData>) -> Result<(), ClipboardError> {
+        self.contents = contents;
+        Ok(())
+    }
+
+    fn formats(&mut self) -> Vec<ClipboardFormat> {
+        let mut formats: Vec<ClipboardFormat> = Vec::new();
+        for data in self.contents.iter() {
+            let format = data.format();
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+
+        if !formats.contains(&ClipboardFormat::Text)
+            && self.contents.iter().any(|data| data.text().is_some())
+        {
+            formats.push(ClipboardFormat::Text);
+        }
+
+        formats
+    }
+}
+
+/// A clipboard backed by the clipboard of the operating system.
+///
+/// Every representation passed to [`set_contents`](ClipboardProvider::set_contents) is offered to
+/// other applications: plain text, HTML and images in the formats they expect, and custom data
+/// under its MIME type, so that it can be pasted into another instance of the application.
+///
+/// On Linux the clipboard is reached through the X server when the `x11` feature is enabled. With
+/// the `wayland` feature, the windowing backend replaces it with a clipboard which shares the
+/// Wayland connection of the window, see [`SystemClipboard::from_wayland_display`]. On Windows
+/// custom data is stored as a registered clipboard format, and on macOS as a pasteboard type,
+/// named after its MIME type.
+pub struct SystemClipboard {
+    inner: Box<dyn ClipboardProvider>,
+}
+
+impl SystemClipboard {
+    /// Connects to the clipboard of the operating system.
+    pub fn new() -> Result<Self, ClipboardError> {
+        Ok(Self { inner: connect()? })
+    }
+
+    /// Creates a clipboard which shares the Wayland connection of a window.
+    ///
+    /// # Safety
+    ///
+    /// `display` must be a valid `wl_display` pointer which outlives the clipboard.
+    #[cfg(all(
+        feature = "wayland",
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    ))]
+    pub unsafe fn from_wayland_display(display: *mut std::ffi::c_void) -> Self {
+        Self { inner: Box::new(mime::MimeClipboard(wayland::WaylandClipboard::new(display))) }
+    }
+}
+
+#[cfg(any(windows, target_os = "macos"))]
+fn connect() -> Result<Box<dyn ClipboardProvider>, ClipboardError> {
+    Ok(Box::new(native::NativeClipboard::new()?))
+}
+
+#[cfg(all(
+    feature = "x11",
+    unix,
+    not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+))]
+fn connect() -> Result<Box<dyn ClipboardProvider>, ClipboardError> {
+    Ok(Box::new(mime::MimeClipboard(x11::X11Clipboard::new()?)))
+}
+
+#[cfg(not(any(
+    windows,
+    target_os = "macos",
+    all(
+        feature = "x11",
+        unix,
+        not(any(target_os = "macos", target_os = "ios", target_os = "android"))
+    )
+)))]
+fn connect() -> Result<Box<dyn ClipboardProvider>, ClipboardError> {
+    Err("no system clipboard is available on this platform".into())
+}
+
+impl fmt::Debug for SystemClipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemClipboard").finish_non_exhaustive()
+    }
+}
+
+impl ClipboardProvider for SystemClipboard {
+    fn get_contents(&mut self, format: &ClipboardFormat) -> Result<ClipboardData, ClipboardError> {
+        self.inner.get_contents(format)
+    }
+
+    fn set_contents(&mut self, contents: Vec<ClipboardData>) -> Result<(), ClipboardError> {
+        self.inner.set_contents(contents)
+    }
+
+    fn formats(&mut self) -> Vec<ClipboardFormat> {
+        self.inner.formats()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_clipboard_round_trip() {
+        let mut clipboard = MemoryClipboard::new();
+        clipboard
+            .set_contents(vec![
+                ClipboardData::Custom {
+                    mime_type: String::from("application/x-shapes"),
+                    data: vec![1, 2, 3],
+                },
+                ClipboardData::Html {
+                    html: String::from("<b>shape</b>"),
+                    alt_text: Some(String::from("shape")),
+                },
+            ])
+            .unwrap();
+
+        let custom = ClipboardFormat::Custom(String::from("application/x-shapes"));
+        assert!(matches!(
+            clipboard.get_contents(&custom).unwrap(),
+            ClipboardData::Custom { data, .. } if data == vec![1, 2, 3]
+        ));
+        assert_eq!(clipboard.get_contents(&ClipboardFormat::Text).unwrap().text(), Some("shape"));
+        assert!(clipboard.get_contents(&ClipboardFormat::Image).is_err());
+        assert_eq!(clipboard.formats(), vec![custom, ClipboardFormat::Html, ClipboardFormat::Text]);
+    }
+}
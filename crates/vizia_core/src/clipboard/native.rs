@@ -0,0 +1,271 @@
+//! The clipboards of Windows and macOS.
+//!
+//! Text, HTML and images are written with `arboard`, which converts them to the formats other
+//! applications expect. Custom data is stored next to them in a clipboard format (Windows) or a
+//! pasteboard type (macOS) named after its MIME type.
+
+use super::*;
+
+/// The clipboard of Windows or macOS.
+pub(crate) struct NativeClipboard {
+    clipboard: arboard::Clipboard,
+}
+
+impl NativeClipboard {
+    pub fn new() -> Result<Self, ClipboardError> {
+        Ok(Self { clipboard: arboard::Clipboard::new()? })
+    }
+}
+
+impl ClipboardProvider for NativeClipboard {
+    fn get_contents(&mut self, format: &ClipboardFormat) -> Result<ClipboardData, ClipboardError> {
+        match format {
+            ClipboardFormat::Text => Ok(ClipboardData::Text(self.clipboard.get_text()?)),
+            ClipboardFormat::Html => {
+                Ok(ClipboardData::Html { html: platform::read_html()?, alt_text: None })
+            }
+            ClipboardFormat::Image => {
+                let image = self.clipboard.get_image()?;
+                image::RgbaImage::from_raw(
+                    image.width as u32,
+                    image.height as u32,
+                    image.bytes.into_owned(),
+                )
+                .map(|image| ClipboardData::Image(DynamicImage::ImageRgba8(image)))
+                .ok_or_else(|| Box::new(FormatUnavailable(format.clone())) as ClipboardError)
+            }
+            ClipboardFormat::Custom(mime_type) => Ok(ClipboardData::Custom {
+                mime_type: mime_type.clone(),
+                data: platform::read(mime_type)?,
+            }),
+        }
+    }
+
+    fn set_contents(&mut self, contents: Vec<ClipboardData>) -> Result<(), ClipboardError> {
+        let text = contents.iter().find_map(|data| data.text()).filter(|text| !text.is_empty());
+        let html = contents.iter().find_map(|data| match data {
+            ClipboardData::Html { html, .. } => Some(html.as_str()),
+            _ => None,
+        });
+        let image = contents.iter().find_map(|data| match data {
+            ClipboardData::Image(image) => Some(image),
+            _ => None,
+        });
+        let custom = contents
+            .iter()
+            .filter_map(|data| match data {
+                ClipboardData::Custom { mime_type, data } => Some((mime_type.as_str(), &data[..])),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // arboard writes one of the standard representations, replacing the previous contents.
+        if let Some(html) = html {
+            self.clipboard.set_html(html, text)?;
+        } else if let Some(image) = image {
+            let image = image.to_rgba8();
+            self.clipboard.set_image(arboard::ImageData {
+                width: image.width() as usize,
+                height: image.height() as usize,
+                bytes: image.into_raw().into(),
+            })?;
+        } else if let Some(text) = text {
+            self.clipboard.set_text(text)?;
+        } else if custom.is_empty() {
+            // Copying empty text leaves the clipboard untouched.
+            return Ok(());
+        } else {
+            platform::clear()?;
+        }
+
+        platform::write(&custom)
+    }
+
+    fn formats(&mut self) -> Vec<ClipboardFormat> {
+        let names = platform::names();
+        let mut formats = Vec::new();
+        if self.clipboard.get_text().map(|text| !text.is_empty()).unwrap_or_default() {
+            formats.push(ClipboardFormat::Text);
+        }
+
+        if names.iter().any(|name| name == platform::HTML) {
+            formats.push(ClipboardFormat::Html);
+        }
+
+        if self.clipboard.get_image().is_ok() {
+            formats.push(ClipboardFormat::Image);
+        }
+
+        for name in names.iter().filter(|name| name.contains('/')) {
+            let format = ClipboardFormat::from_mime_type(name);
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+
+        formats
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use clipboard_win::{raw, Clipboard};
+
+    use super::ClipboardError;
+
+    /// The name of the clipboard format which holds HTML.
+    pub const HTML: &str = "HTML Format";
+
+    fn open() -> Result<Clipboard, ClipboardError> {
+        // Another application may have the clipboard open for a moment.
+        Ok(Clipboard::new_attempts(10)?)
+    }
+
+    fn format(name: &str) -> Result<u32, ClipboardError> {
+        raw::register_format(name)
+            .map(|format| format.get())
+            .ok_or_else(|| format!("failed to register the clipboard format {}", name).into())
+    }
+
+    pub fn names() -> Vec<String> {
+        match open() {
+            Ok(_clipboard) => raw::EnumFormats::new().filter_map(raw::format_name_big).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    pub fn read(name: &str) -> Result<Vec<u8>, ClipboardError> {
+        let format = format(name)?;
+        let _clipboard = open()?;
+        let mut data = Vec::new();
+        raw::get_vec(format, &mut data)?;
+        Ok(data)
+    }
+
+    pub fn read_html() -> Result<String, ClipboardError> {
+        let data = String::from_utf8(read(HTML)?)?;
+
+        // The markup is preceded by a header with the byte offsets of the copied fragment.
+        let offset = |key: &str| {
+            data.lines()
+                .find_map(|line| line.strip_prefix(key))
+                .and_then(|offset| offset.trim().parse::<usize>().ok())
+        };
+
+        offset("StartFragment:")
+            .zip(offset("EndFragment:"))
+            .and_then(|(start, end)| data.get(start..end))
+            .map(str::to_owned)
+            .ok_or_else(|| "the clipboard holds malformed HTML".into())
+    }
+
+    pub fn clear() -> Result<(), ClipboardError> {
+        let _clipboard = open()?;
+        Ok(raw::empty()?)
+    }
+
+    /// Adds data to the current contents of the clipboard.
+    pub fn write(offers: &[(&str, &[u8])]) -> Result<(), ClipboardError> {
+        if offers.is_empty() {
+            return Ok(());
+        }
+
+        let _clipboard = open()?;
+        for (name, data) in offers {
+            raw::set_without_clear(format(name)?, data)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::{CStr, CString};
+    use std::os::raw::{c_char, c_void};
+
+    use objc::runtime::{Object, NO};
+    use objc::{class, msg_send, sel, sel_impl};
+
+    use super::ClipboardError;
+
+    /// The pasteboard type which holds HTML.
+    pub const HTML: &str = "public.html";
+
+    fn pasteboard() -> *mut Object {
+        unsafe { msg_send![class!(NSPasteboard), generalPasteboard] }
+    }
+
+    fn string(value: &str) -> Result<*mut Object, ClipboardError> {
+        let value = CString::new(value)?;
+        Ok(unsafe { msg_send![class!(NSString), stringWithUTF8String: value.as_ptr()] })
+    }
+
+    pub fn names() -> Vec<String> {
+        unsafe {
+            let types: *mut Object = msg_send![pasteboard(), types];
+            if types.is_null() {
+                return Vec::new();
+            }
+
+            let count: usize = msg_send![types, count];
+            (0..count)
+                .filter_map(|index| {
+                    let name: *mut Object = msg_send![types, objectAtIndex: index];
+                    let name: *const c_char = msg_send![name, UTF8String];
+                    (!name.is_null()).then(|| CStr::from_ptr(name).to_string_lossy().into_owned())
+                })
+                .collect()
+        }
+    }
+
+    pub fn read(name: &str) -> Result<Vec<u8>, ClipboardError> {
+        let name = string(name)?;
+        unsafe {
+            let data: *mut Object = msg_send![pasteboard(), dataForType: name];
+            if data.is_null() {
+                return Err("the pasteboard does not contain data of this type".into());
+            }
+
+            let length: usize = msg_send![data, length];
+            if length == 0 {
+                return Ok(Vec::new());
+            }
+
+            let bytes: *const u8 = msg_send![data, bytes];
+            Ok(std::slice::from_raw_parts(bytes, length).to_vec())
+        }
+    }
+
+    pub fn read_html() -> Result<String, ClipboardError> {
+        Ok(String::from_utf8(read(HTML)?)?)
+    }
+
+    pub fn clear() -> Result<(), ClipboardError> {
+        unsafe {
+            let _: isize = msg_send![pasteboard(), clearContents];
+        }
+        Ok(())
+    }
+
+    /// Adds data to the current contents of the pasteboard.
+    pub fn write(offers: &[(&str, &[u8])]) -> Result<(), ClipboardError> {
+        for (name, data) in offers {
+            let name = string(name)?;
+            unsafe {
+                let data: *mut Object = msg_send![
+                    class!(NSData),
+                    dataWithBytes: data.as_ptr() as *const c_void
+                    length: data.len()
+                ];
+                let written: objc::runtime::BOOL =
+                    msg_send![pasteboard(), setData: data forType: name];
+                if written == NO {
+                    return Err("failed to write to the pasteboard".into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+//! The clipboard of a Wayland compositor, shared with the connection of a window.
+
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::io::{Read, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use sctk::data_device::{
+    DataDevice, DataDeviceHandler, DataDeviceHandling, DataSourceEvent, DndEvent,
+};
+use sctk::environment::Environment;
+use sctk::reexports::client::protocol::wl_data_device_manager::WlDataDeviceManager;
+use sctk::reexports::client::protocol::wl_keyboard::{self, WlKeyboard};
+use sctk::reexports::client::protocol::wl_pointer::{self, WlPointer};
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
+use sctk::reexports::client::{Attached, DispatchData, Display, EventQueue};
+use sctk::seat::{SeatData, SeatHandler, SeatHandling, SeatListener};
+use sctk::MissingGlobal;
+
+use super::mime::{MimeBackend, Offer};
+use super::ClipboardError;
+
+/// How often the clipboard thread answers the requests of other applications.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+enum Command {
+    MimeTypes,
+    Read(String),
+    Write(Vec<Offer>),
+    Exit,
+}
+
+enum Reply {
+    MimeTypes(Vec<String>),
+    Data(Vec<u8>),
+    Written,
+}
+
+/// The clipboard of a Wayland compositor.
+///
+/// Requests are handled on a background thread with its own event queue on the connection of the
+/// window, which also answers the requests of other applications for the contents written by this
+/// one.
+pub(crate) struct WaylandClipboard {
+    commands: Sender<Command>,
+    replies: Receiver<Result<Reply, ClipboardError>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl WaylandClipboard {
+    /// # Safety
+    ///
+    /// `display` must be a valid `wl_display` pointer which outlives the clipboard.
+    pub unsafe fn new(display: *mut c_void) -> Self {
+        let display = Display::from_external_display(display as *mut _);
+        let (commands, command_receiver) = mpsc::channel();
+        let (reply_sender, replies) = mpsc::channel();
+
+        let thread = std::thread::Builder::new()
+            .name(String::from("vizia-clipboard"))
+            .spawn(move || run(display, command_receiver, reply_sender))
+            .ok();
+
+        Self { commands, replies, thread }
+    }
+
+    fn request(&self, command: Command) -> Result<Reply, ClipboardError> {
+        self.commands.send(command).map_err(|_| "the clipboard thread has stopped")?;
+        self.replies.recv().map_err(|_| "the clipboard thread has stopped")?
+    }
+}
+
+impl MimeBackend for WaylandClipboard {
+    fn mime_types(&mut self) -> Result<Vec<String>, ClipboardError> {
+        match self.request(Command::MimeTypes)? {
+            Reply::MimeTypes(mime_types) => Ok(mime_types),
+            _ => Err("unexpected reply from the clipboard thread".into()),
+        }
+    }
+
+    fn read(&mut self, mime_type: &str) -> Result<Vec<u8>, ClipboardError> {
+        match self.request(Command::Read(mime_type.to_owned()))? {
+            Reply::Data(data) => Ok(data),
+            _ => Err("unexpected reply from the clipboard thread".into()),
+        }
+    }
+
+    fn write(&mut self, offers: Vec<Offer>) -> Result<(), ClipboardError> {
+        match self.request(Command::Write(offers))? {
+            Reply::Written => Ok(()),
+            _ => Err("unexpected reply from the clipboard thread".into()),
+        }
+    }
+}
+
+impl Drop for WaylandClipboard {
+    fn drop(&mut self) {
+        let _ = self.commands.send(Command::Exit);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The globals used by the clipboard thread.
+struct ClipboardEnvironment {
+    seats: SeatHandler,
+    data_device_manager: DataDeviceHandler,
+}
+
+impl SeatHandling for ClipboardEnvironment {
+    fn listen<F: FnMut(Attached<WlSeat>, &SeatData, DispatchData) + 'static>(
+        &mut self,
+        f: F,
+    ) -> SeatListener {
+        self.seats.listen(f)
+    }
+}
+
+impl DataDeviceHandling for ClipboardEnvironment {
+    fn set_callback<F: FnMut(WlSeat, DndEvent, DispatchData) + 'static>(
+        &mut self,
+        callback: F,
+    ) -> Result<(), MissingGlobal> {
+        self.data_device_manager.set_callback(callback)
+    }
+
+    fn with_device<F: FnOnce(&DataDevice)>(
+        &self,
+        seat: &WlSeat,
+        f: F,
+    ) -> Result<(), MissingGlobal> {
+        self.data_device_manager.with_device(seat, f)
+    }
+}
+
+sctk::environment!(ClipboardEnvironment,
+    singles = [
+        WlDataDeviceManager => data_device_manager,
+    ],
+    multis = [
+        WlSeat => seats,
+    ]
+);
+
+/// The input devices of a seat, whose events tell which seat was used last.
+struct Seat {
+    seat: WlSeat,
+    keyboard: Option<WlKeyboard>,
+    pointer: Option<WlPointer>,
+}
+
+impl Seat {
+    fn release(&mut self) {
+        if let Some(keyboard) = self.keyboard.take() {
+            if keyboard.as_ref().version() >= 3 {
+                keyboard.release();
+            }
+        }
+
+        if let Some(pointer) = self.pointer.take() {
+            if pointer.as_ref().version() >= 3 {
+                pointer.release();
+            }
+        }
+    }
+}
+
+/// The state of the clipboard thread which is passed to the event handlers.
+#[derive(Default)]
+struct State {
+    seats: Vec<Seat>,
+    // The seats which have focus with the serial of their last input event, most recent first.
+    observed: VecDeque<(WlSeat, u32)>,
+}
+
+impl State {
+    fn seat(&mut self, seat: WlSeat) -> &mut Seat {
+        let index = match self.seats.iter().position(|tracked| tracked.seat == seat) {
+            Some(index) => index,
+            None => {
+                self.seats.push(Seat { seat, keyboard: None, pointer: None });
+                self.seats.len() - 1
+            }
+        };
+
+        &mut self.seats[index]
+    }
+
+    fn observe(&mut self, seat: WlSeat, serial: u32) {
+        self.forget(&seat);
+        self.observed.push_front((seat, serial));
+    }
+
+    fn forget(&mut self, seat: &WlSeat) {
+        self.observed.retain(|(observed, _)| observed != seat);
+    }
+}
+
+/// Listens to the input devices of a seat for the serials used to set the selection.
+fn track_devices(seat: &Attached<WlSeat>, data: &SeatData, state: &mut Seat) {
+    if data.has_keyboard && !data.defunct {
+        if state.keyboard.is_none() {
+            let keyboard = seat.get_keyboard();
+            let seat = seat.detach();
+            keyboard.quick_assign(move |_, event, mut dispatch_data| {
+                if let Some(state) = dispatch_data.get::<State>() {
+                    match event {
+                        wl_keyboard::Event::Enter { serial, .. }
+                        | wl_keyboard::Event::Key { serial, .. } => {
+                            state.observe(seat.clone(), serial)
+                        }
+                        wl_keyboard::Event::Leave { .. } => state.forget(&seat),
+                        _ => {}
+                    }
+                }
+            });
+            state.keyboard = Some(keyboard.detach());
+        }
+    } else if let Some(keyboard) = state.keyboard.take() {
+        if keyboard.as_ref().version() >= 3 {
+            keyboard.release();
+        }
+    }
+
+    if data.has_pointer && !data.defunct {
+        if state.pointer.is_none() {
+            let pointer = seat.get_pointer();
+            let seat = seat.detach();
+            pointer.quick_assign(move |_, event, mut dispatch_data| {
+                if let Some(state) = dispatch_data.get::<State>() {
+                    match event {
+                        wl_pointer::Event::Enter { serial, .. }
+                        | wl_pointer::Event::Button { serial, .. } => {
+                            state.observe(seat.clone(), serial)
+                        }
+                        _ => {}
+                    }
+                }
+            });
+            state.pointer = Some(pointer.detach());
+        }
+    } else if let Some(pointer) = state.pointer.take() {
+        if pointer.as_ref().version() >= 3 {
+            pointer.release();
+        }
+    }
+}
+
+fn run(
+    display: Display,
+    commands: Receiver<Command>,
+    replies: Sender<Result<Reply, ClipboardError>>,
+) {
+    let mut queue = display.create_event_queue();
+    let display_proxy = display.attach(queue.token());
+    let mut seats = SeatHandler::new();
+    let data_device_manager = DataDeviceHandler::init(&mut seats);
+    let environment = ClipboardEnvironment { seats, data_device_manager };
+    let environment = Environment::new(&display_proxy, &mut queue, environment)
+        .ok()
+        .filter(|environment| environment.get_global::<WlDataDeviceManager>().is_some());
+
+    let environment = match environment {
+        Some(environment) => environment,
+        None => {
+            // Keep answering so that the application does not wait forever.
+            while let Ok(command) = commands.recv() {
+                if let Command::Exit = command {
+                    return;
+                }
+                let _ = replies.send(Err("the compositor has no clipboard".into()));
+            }
+            return;
+        }
+    };
+
+    let mut state = State::default();
+    for seat in environment.get_all_seats() {
+        if let Some(data) = sctk::seat::clone_seat_data(&seat) {
+            track_devices(&seat, &data, state.seat(seat.detach()));
+        }
+    }
+
+    let listener = environment.listen_for_seats(|seat, data, mut dispatch_data| {
+        if let Some(state) = dispatch_data.get::<State>() {
+            if data.defunct {
+                state.forget(&seat);
+            }
+            track_devices(&seat, data, state.seat(seat.detach()));
+        }
+    });
+
+    loop {
+        match commands.recv_timeout(POLL_INTERVAL) {
+            Ok(Command::Exit) | Err(RecvTimeoutError::Disconnected) => break,
+            Ok(command) => {
+                let reply = handle(&environment, &mut queue, &mut state, command);
+                let _ = replies.send(reply);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if queue.dispatch_pending(&mut state, |_, _, _| {}).is_err() {
+            break;
+        }
+        let _ = queue.display().flush();
+    }
+
+    // Every proxy on the queue must be destroyed on this thread, see
+    // https://gitlab.freedesktop.org/wayland/wayland/-/issues/13.
+    for seat in state.seats.iter_mut() {
+        seat.release();
+    }
+    drop(listener);
+    let _ = queue.sync_roundtrip(&mut state, |_, _, _| {});
+    let _ = queue.display().flush();
+}
+
+fn handle(
+    environment: &Environment<ClipboardEnvironment>,
+    queue: &mut EventQueue,
+    state: &mut State,
+    command: Command,
+) -> Result<Reply, ClipboardError> {
+    queue.sync_roundtrip(state, |_, _, _| {})?;
+
+    let (seat, serial) = match state.observed.front() {
+        Some((seat, serial)) => (seat.clone(), *serial),
+        None => return Err("no seat has focus".into()),
+    };
+
+    match command {
+        Command::MimeTypes => {
+            let mut mime_types = Vec::new();
+            environment.with_data_device(&seat, |device| {
+                device.with_selection(|offer| {
+                    if let Some(offer) = offer {
+                        mime_types = offer.with_mime_types(<[String]>::to_vec);
+                    }
+                })
+            })?;
+            Ok(Reply::MimeTypes(mime_types))
+        }
+
+        Command::Read(mime_type) => {
+            let mut pipe = None;
+            environment.with_data_device(&seat, |device| {
+                pipe = device.with_selection(|offer| offer.map(|offer| offer.receive(mime_type)))
+            })?;
+
+            let mut pipe = match pipe {
+                Some(pipe) => pipe?,
+                None => return Err("the clipboard is empty".into()),
+            };
+
+            // The roundtrip also delivers the request if this application owns the selection.
+            queue.sync_roundtrip(state, |_, _, _| {})?;
+            let mut data = Vec::new();
+            pipe.read_to_end(&mut data)?;
+            Ok(Reply::Data(data))
+        }
+
+        Command::Write(offers) => {
+            let mime_types = offers.iter().map(|(mime_type, _)| mime_type.clone()).collect();
+            let source = environment.new_data_source(mime_types, move |event, _| {
+                if let DataSourceEvent::Send { mime_type, mut pipe } = event {
+                    if let Some((_, data)) =
+                        offers.iter().find(|(offered, _)| *offered == mime_type)
+                    {
+                        // Writing blocks until the other application has read all of the data.
+                        let data = data.clone();
+                        std::thread::spawn(move || pipe.write_all(&data));
+                    }
+                }
+            });
+
+            environment
+                .with_data_device(&seat, |device| device.set_selection(&Some(source), serial))?;
+            queue.sync_roundtrip(state, |_, _, _| {})?;
+            Ok(Reply::Written)
+        }
+
+        Command::Exit => unreachable!("the clipboard thread stops before handling exit"),
+    }
+}
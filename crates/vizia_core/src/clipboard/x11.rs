@@ -0,0 +1,413 @@
+//! The clipboard of the X server.
+
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{
+    Atom, AtomEnum, ChangeWindowAttributesAux, ClientMessageEvent, ConnectionExt as _,
+    CreateWindowAux, EventMask, PropMode, Property, SelectionNotifyEvent, SelectionRequestEvent,
+    Window, WindowClass, SELECTION_NOTIFY_EVENT,
+};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+use x11rb::{COPY_DEPTH_FROM_PARENT, CURRENT_TIME, NONE};
+
+use super::mime::{MimeBackend, Offer};
+use super::ClipboardError;
+
+/// How long to wait for the owner of the clipboard to answer a request.
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Data larger than this is sent to other applications in chunks of this size.
+const INCR_CHUNK_SIZE: usize = 64 * 1024;
+
+x11rb::atom_manager! {
+    Atoms: AtomsCookie {
+        CLIPBOARD,
+        TARGETS,
+        INCR,
+        VIZIA_CLIPBOARD,
+        VIZIA_EXIT,
+    }
+}
+
+/// A connection to the X server with a hidden window for exchanging selections.
+struct Client {
+    connection: RustConnection,
+    window: Window,
+    atoms: Atoms,
+}
+
+impl Client {
+    fn new() -> Result<Self, ClipboardError> {
+        let (connection, screen) = x11rb::connect(None)?;
+        let window = connection.generate_id()?;
+        let screen = &connection.setup().roots[screen];
+        connection
+            .create_window(
+                COPY_DEPTH_FROM_PARENT,
+                window,
+                screen.root,
+                0,
+                0,
+                1,
+                1,
+                0,
+                WindowClass::INPUT_OUTPUT,
+                screen.root_visual,
+                &CreateWindowAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+            )?
+            .check()?;
+        let atoms = Atoms::new(&connection)?.reply()?;
+
+        Ok(Self { connection, window, atoms })
+    }
+
+    fn is_owner(&self) -> Result<bool, ClipboardError> {
+        let owner = self.connection.get_selection_owner(self.atoms.CLIPBOARD)?.reply()?.owner;
+        Ok(owner == self.window)
+    }
+}
+
+/// Answers the requests of other applications for the contents written by this one.
+struct Server {
+    client: Client,
+    offers: Mutex<Vec<(Atom, Offer)>>,
+}
+
+/// Data which is sent to another application in chunks.
+struct Transfer {
+    requestor: Window,
+    property: Atom,
+    target: Atom,
+    data: Arc<[u8]>,
+    offset: usize,
+}
+
+impl Server {
+    fn run(&self) {
+        let mut transfers = Vec::new();
+        while let Ok(event) = self.client.connection.wait_for_event() {
+            let result = match event {
+                Event::ClientMessage(event) if event.type_ == self.client.atoms.VIZIA_EXIT => {
+                    return;
+                }
+                Event::SelectionRequest(request) => self.answer(&request, &mut transfers),
+                Event::SelectionClear(event) if event.selection == self.client.atoms.CLIPBOARD => {
+                    // The clipboard may have been taken back since the event was sent.
+                    self.client.is_owner().map(|owner| {
+                        if !owner {
+                            self.offers.lock().unwrap().clear();
+                        }
+                    })
+                }
+                Event::PropertyNotify(event) if event.state == Property::DELETE => {
+                    self.continue_transfer(event.window, event.atom, &mut transfers)
+                }
+                _ => Ok(()),
+            };
+
+            if result.and_then(|_| Ok(self.client.connection.flush()?)).is_err() {
+                transfers.clear();
+            }
+        }
+    }
+
+    fn answer(
+        &self,
+        request: &SelectionRequestEvent,
+        transfers: &mut Vec<Transfer>,
+    ) -> Result<(), ClipboardError> {
+        let connection = &self.client.connection;
+        let atoms = &self.client.atoms;
+
+        // Obsolete clients leave the property unset, in which case the target is used instead.
+        let property = if request.property == NONE { request.target } else { request.property };
+        let offers = self.offers.lock().unwrap();
+        let data = offers.iter().find(|(atom, _)| *atom == request.target);
+
+        let reply = if request.selection != atoms.CLIPBOARD {
+            NONE
+        } else if request.target == atoms.TARGETS {
+            let mut targets = vec![atoms.TARGETS];
+            targets.extend(offers.iter().map(|(atom, _)| *atom));
+            connection.change_property32(
+                PropMode::REPLACE,
+                request.requestor,
+                property,
+                AtomEnum::ATOM,
+                &targets,
+            )?;
+            property
+        } else if let Some((_, (_, data))) = data {
+            if data.len() > INCR_CHUNK_SIZE {
+                // Large data is sent in chunks, each one after the requestor deleted the last.
+                connection.change_window_attributes(
+                    request.requestor,
+                    &ChangeWindowAttributesAux::new().event_mask(EventMask::PROPERTY_CHANGE),
+                )?;
+                connection.change_property32(
+                    PropMode::REPLACE,
+                    request.requestor,
+                    property,
+                    atoms.INCR,
+                    &[data.len() as u32],
+                )?;
+                transfers.push(Transfer {
+                    requestor: request.requestor,
+                    property,
+                    target: request.target,
+                    data: data.clone(),
+                    offset: 0,
+                });
+            } else {
+                connection.change_property8(
+                    PropMode::REPLACE,
+                    request.requestor,
+                    property,
+                    request.target,
+                    data,
+                )?;
+            }
+            property
+        } else {
+            NONE
+        };
+
+        connection.send_event(
+            false,
+            request.requestor,
+            EventMask::NO_EVENT,
+            SelectionNotifyEvent {
+                response_type: SELECTION_NOTIFY_EVENT,
+                sequence: 0,
+                time: request.time,
+                requestor: request.requestor,
+                selection: request.selection,
+                target: request.target,
+                property: reply,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Sends the next chunk of a transfer after the requestor has read the last one.
+    fn continue_transfer(
+        &self,
+        window: Window,
+        property: Atom,
+        transfers: &mut Vec<Transfer>,
+    ) -> Result<(), ClipboardError> {
+        let index = match transfers
+            .iter()
+            .position(|transfer| transfer.requestor == window && transfer.property == property)
+        {
+            Some(index) => index,
+            None => return Ok(()),
+        };
+
+        let transfer = &mut transfers[index];
+        let end = transfer.data.len().min(transfer.offset + INCR_CHUNK_SIZE);
+        let chunk = &transfer.data[transfer.offset..end];
+        self.client.connection.change_property8(
+            PropMode::REPLACE,
+            window,
+            property,
+            transfer.target,
+            chunk,
+        )?;
+
+        // An empty chunk ends the transfer.
+        if chunk.is_empty() {
+            transfers.remove(index);
+        } else {
+            transfer.offset = end;
+        }
+
+        Ok(())
+    }
+}
+
+/// The clipboard of the X server.
+///
+/// The contents written by the application are served from a background thread, which answers
+/// the requests of other applications until another application takes over the clipboard.
+pub(crate) struct X11Clipboard {
+    server: Arc<Server>,
+    reader: Client,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl X11Clipboard {
+    pub fn new() -> Result<Self, ClipboardError> {
+        let server = Arc::new(Server { client: Client::new()?, offers: Mutex::default() });
+        let reader = Client::new()?;
+
+        let thread = {
+            let server = server.clone();
+            std::thread::Builder::new()
+                .name(String::from("vizia-clipboard"))
+                .spawn(move || server.run())?
+        };
+
+        Ok(Self { server, reader, thread: Some(thread) })
+    }
+
+    /// Asks the owner of the clipboard to convert its contents to the given target.
+    fn convert(&self, target: Atom) -> Result<Vec<u8>, ClipboardError> {
+        let Client { connection, window, atoms } = &self.reader;
+        let property = atoms.VIZIA_CLIPBOARD;
+        connection.convert_selection(*window, atoms.CLIPBOARD, target, property, CURRENT_TIME)?;
+        connection.flush()?;
+
+        let mut data = Vec::new();
+        let mut incremental = false;
+        let mut deadline = Instant::now() + TIMEOUT;
+        loop {
+            let event = match connection.poll_for_event()? {
+                Some(event) => event,
+                None if Instant::now() > deadline => {
+                    return Err("the owner of the clipboard did not respond".into())
+                }
+                None => {
+                    std::thread::sleep(Duration::from_millis(1));
+                    continue;
+                }
+            };
+
+            match event {
+                Event::SelectionNotify(event)
+                    if event.requestor == *window && event.target == target =>
+                {
+                    if event.property == NONE {
+                        return Err("the owner of the clipboard refused the request".into());
+                    }
+
+                    let reply = connection
+                        .get_property(false, *window, property, AtomEnum::ANY, 0, u32::MAX)?
+                        .reply()?;
+                    connection.delete_property(*window, property)?;
+                    connection.flush()?;
+
+                    if reply.type_ != atoms.INCR {
+                        return Ok(reply.value);
+                    }
+
+                    // Deleting the property asks the owner for the first chunk.
+                    incremental = true;
+                }
+
+                Event::PropertyNotify(event)
+                    if incremental
+                        && event.window == *window
+                        && event.atom == property
+                        && event.state == Property::NEW_VALUE =>
+                {
+                    let reply = connection
+                        .get_property(true, *window, property, AtomEnum::ANY, 0, u32::MAX)?
+                        .reply()?;
+                    connection.flush()?;
+
+                    if reply.value.is_empty() {
+                        return Ok(data);
+                    }
+
+                    data.extend(reply.value);
+                    deadline = Instant::now() + TIMEOUT;
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    fn atom_names(&self, atoms: &[Atom]) -> Result<Vec<String>, ClipboardError> {
+        let connection = &self.reader.connection;
+        let cookies = atoms
+            .iter()
+            .map(|atom| connection.get_atom_name(*atom))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut names = Vec::new();
+        for cookie in cookies {
+            names.push(String::from_utf8_lossy(&cookie.reply()?.name).into_owned());
+        }
+
+        Ok(names)
+    }
+}
+
+impl MimeBackend for X11Clipboard {
+    fn mime_types(&mut self) -> Result<Vec<String>, ClipboardError> {
+        if self.server.client.is_owner()? {
+            let offers = self.server.offers.lock().unwrap();
+            return Ok(offers.iter().map(|(_, (mime_type, _))| mime_type.clone()).collect());
+        }
+
+        let targets = self
+            .convert(self.reader.atoms.TARGETS)?
+            .chunks_exact(4)
+            .map(|bytes| Atom::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect::<Vec<_>>();
+        self.atom_names(&targets)
+    }
+
+    fn read(&mut self, mime_type: &str) -> Result<Vec<u8>, ClipboardError> {
+        if self.server.client.is_owner()? {
+            let offers = self.server.offers.lock().unwrap();
+            if let Some((_, (_, data))) =
+                offers.iter().find(|(_, (offered, _))| offered == mime_type)
+            {
+                return Ok(data.to_vec());
+            }
+        }
+
+        let connection = &self.reader.connection;
+        let target = connection.intern_atom(false, mime_type.as_bytes())?.reply()?.atom;
+        self.convert(target)
+    }
+
+    fn write(&mut self, offers: Vec<Offer>) -> Result<(), ClipboardError> {
+        let Client { connection, window, atoms } = &self.server.client;
+        let cookies = offers
+            .iter()
+            .map(|(mime_type, _)| connection.intern_atom(false, mime_type.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut targets = Vec::new();
+        for (cookie, offer) in cookies.into_iter().zip(offers) {
+            targets.push((cookie.reply()?.atom, offer));
+        }
+
+        *self.server.offers.lock().unwrap() = targets;
+        connection.set_selection_owner(*window, atoms.CLIPBOARD, CURRENT_TIME)?;
+        connection.flush()?;
+
+        if self.server.client.is_owner()? {
+            Ok(())
+        } else {
+            Err("failed to take over the clipboard".into())
+        }
+    }
+}
+
+impl Drop for X11Clipboard {
+    fn drop(&mut self) {
+        // Events sent without an event mask reach the client which created the window.
+        let Client { window, atoms, .. } = &self.server.client;
+        let event = ClientMessageEvent::new(32, *window, atoms.VIZIA_EXIT, [0u32; 5]);
+        let sent = self
+            .reader
+            .connection
+            .send_event(false, *window, EventMask::NO_EVENT, event)
+            .map(|_| self.reader.connection.flush());
+
+        if let (Ok(Ok(())), Some(thread)) = (sent, self.thread.take()) {
+            let _ = thread.join();
+        }
+    }
+}
@@ -12,7 +12,7 @@ use vizia_id::GenerationalId;
 pub use crate::text::cosmic::TextConfig;
 
 #[cfg(feature = "clipboard")]
-use copypasta::ClipboardProvider;
+use crate::clipboard::ClipboardProvider;
 
 /// Context used to integrate vizia with windowing backends such as winit and baseview.
 pub struct BackendContext<'a>(pub &'a mut Context, Option<EventManager>);
@@ -119,6 +119,98 @@ impl<'a> BackendContext<'a> {
         self.0.canvases.insert(Entity::root(), canvas);
     }
 
+    /// Adds the canvas of an opened secondary window, along with the scale factor of the display
+    /// showing it, to the context.
+    pub fn add_secondary_window(
+        &mut self,
+        window: Entity,
+        mut canvas: Canvas<OpenGl>,
+        scale_factor: f64,
+    ) {
+        if let Some(window_state) = self.0.windows.get_mut(&window) {
+            window_state.scale_factor = Some(scale_factor);
+        }
+
+        let ratio = self.0.window_scale_ratio(window);
+        let bounds = self.0.cache.get_bounds(window);
+        canvas.set_size((bounds.w * ratio) as u32, (bounds.h * ratio) as u32, 1.0);
+        self.0.canvases.insert(window, canvas);
+        self.0.style.needs_redraw();
+    }
+
+    /// Returns the secondary windows which have been built since the last call.
+    pub fn take_pending_windows(&mut self) -> Vec<Entity> {
+        std::mem::take(&mut self.0.pending_windows)
+    }
+
+    /// Returns the secondary windows which have been removed since the last call. The canvas of
+    /// each window is returned so that it can be dropped with the correct rendering context.
+    pub fn take_closed_windows(&mut self) -> Vec<(Entity, Option<Canvas<OpenGl>>)> {
+        let closed_windows = std::mem::take(&mut self.0.closed_windows);
+
+        // Images uploaded to the canvas of a closed window are dropped along with it.
+        for image in self.0.resource_manager.images.values_mut() {
+            image.ids.retain(|window, _| !closed_windows.contains(window));
+        }
+
+        closed_windows.into_iter().map(|window| (window, self.0.canvases.remove(&window))).collect()
+    }
+
+    /// Returns the description of a secondary window.
+    pub fn window_description(&self, window: Entity) -> Option<&WindowDescription> {
+        self.0.windows.get(&window).map(|window_state| &window_state.window_description)
+    }
+
+    /// Returns the commands, such as setting the title or cursor, which have been sent to a
+    /// secondary window since the last call.
+    pub fn take_window_commands(&mut self, window: Entity) -> Vec<WindowEvent> {
+        self.0
+            .views
+            .get_mut(&window)
+            .and_then(|view| view.downcast_mut::<crate::views::Window>())
+            .map(|window| window.take_commands())
+            .unwrap_or_default()
+    }
+
+    /// Sends a window event originating from the OS window of the given window entity. Cursor
+    /// positions are in physical pixels relative to the window and are translated into the
+    /// coordinate space of the tree.
+    pub fn emit_window_event(&mut self, window: Entity, window_event: WindowEvent) {
+        let (bounds, ratio) = if window == Entity::root() {
+            (BoundingBox::default(), 1.0)
+        } else {
+            (self.0.cache.get_bounds(window), self.0.window_scale_ratio(window))
+        };
+        let position = |x: f32, y: f32| (x / ratio + bounds.x, y / ratio + bounds.y);
+
+        let window_event = match window_event {
+            WindowEvent::MouseMove(x, y) => {
+                let (x, y) = position(x, y);
+                WindowEvent::MouseMove(x, y)
+            }
+            WindowEvent::TouchStart(id, x, y) => {
+                let (x, y) = position(x, y);
+                WindowEvent::TouchStart(id, x, y)
+            }
+            WindowEvent::TouchMove(id, x, y) => {
+                let (x, y) = position(x, y);
+                WindowEvent::TouchMove(id, x, y)
+            }
+            WindowEvent::TouchEnd(id, x, y) => {
+                let (x, y) = position(x, y);
+                WindowEvent::TouchEnd(id, x, y)
+            }
+            window_event => window_event,
+        };
+
+        self.0.event_queue.push_back(
+            Event::new(window_event)
+                .target(window)
+                .origin(Entity::root())
+                .propagate(Propagation::Up),
+        );
+    }
+
     /// Returns a reference to the [`Environment`] model.
     pub fn environment(&self) -> &Environment {
         self.0.data::<Environment>().unwrap()
@@ -131,7 +223,12 @@ impl<'a> BackendContext<'a> {
 
     /// Calls the draw system.
     pub fn draw(&mut self) {
-        draw_system(self.0);
+        draw_system(self.0, Entity::root());
+    }
+
+    /// Calls the draw system for a secondary window.
+    pub fn draw_window(&mut self, window: Entity) {
+        draw_system(self.0, window);
     }
 
     /// Set the current entity. This is useful in user code when you're performing black magic and
@@ -146,11 +243,19 @@ impl<'a> BackendContext<'a> {
         self.0.text_config = text_config;
     }
 
-    /// Sets the scale factor used by the application.
+    /// Sets the scale factor used by the application, which is that of the display showing the
+    /// main window.
     pub fn set_scale_factor(&mut self, scale: f64) {
         self.0.style.dpi_factor = scale;
     }
 
+    /// Sets the scale factor of the display showing a secondary window.
+    pub fn set_window_scale_factor(&mut self, window: Entity, scale: f64) {
+        if let Some(window_state) = self.0.windows.get_mut(&window) {
+            window_state.scale_factor = Some(scale);
+        }
+    }
+
     /// Sets the size of the root window.
     pub fn set_window_size(&mut self, physical_width: f32, physical_height: f32) {
         self.0.cache.set_width(Entity::root(), physical_width);
@@ -162,6 +267,34 @@ impl<'a> BackendContext<'a> {
         self.0.style.height.insert(Entity::root(), Units::Pixels(logical_height));
     }
 
+    /// Sets the size of a secondary window from the size of its OS window in physical pixels,
+    /// which are converted to logical pixels with the scale factor of the window.
+    pub fn set_secondary_window_size(
+        &mut self,
+        window: Entity,
+        physical_width: f32,
+        physical_height: f32,
+    ) {
+        let scale_factor = self
+            .0
+            .windows
+            .get(&window)
+            .and_then(|window_state| window_state.scale_factor)
+            .unwrap_or(self.0.style.dpi_factor) as f32;
+        let logical_width = physical_width / scale_factor;
+        let logical_height = physical_height / scale_factor;
+        self.0.style.width.insert(window, Units::Pixels(logical_width));
+        self.0.style.height.insert(window, Units::Pixels(logical_height));
+
+        if let Some(window_state) = self.0.windows.get_mut(&window) {
+            window_state.window_description.inner_size =
+                WindowSize::new(logical_width as u32, logical_height as u32);
+        }
+
+        self.0.style.needs_relayout();
+        self.0.style.needs_redraw();
+    }
+
     /// Temporarily sets the current entity, calls the provided closure, and then resets the current entity back to previous.
     pub fn with_current(&mut self, e: Entity, f: impl FnOnce(&mut Context)) {
         let prev = self.0.current;
@@ -180,8 +313,11 @@ impl<'a> BackendContext<'a> {
         self.0.event_proxy = Some(proxy);
     }
 
-    /// You should not call this method unless you are writing a windowing backend, in which case
-    /// you should consult the existing windowing backends for usage information.
+    /// Replaces the clipboard used by the application.
+    ///
+    /// Windowing backends use this to connect the system clipboard. Tests can install a
+    /// [`MemoryClipboard`](crate::clipboard::MemoryClipboard) to exercise copy and paste without
+    /// touching the system clipboard.
     #[cfg(feature = "clipboard")]
     pub fn set_clipboard_provider(&mut self, clipboard: Box<dyn ClipboardProvider>) {
         self.0.clipboard = clipboard;
@@ -197,6 +333,18 @@ impl<'a> BackendContext<'a> {
         !self.0.event_queue.is_empty()
     }
 
+    /// Moves any scheduled events whose time has passed into the event queue.
+    pub fn process_timers(&mut self) {
+        let due = self.0.timers.take_due(Instant::now());
+        self.0.event_queue.extend(due);
+    }
+
+    /// Returns the time at which the next scheduled event is due, so that the backend can wake up
+    /// to send it.
+    pub fn next_timer(&self) -> Option<Instant> {
+        self.0.timers.next_time()
+    }
+
     pub fn renegotiate_language(&mut self) {
         self.0.resource_manager.renegotiate_language();
     }
@@ -237,6 +385,9 @@ impl<'a> BackendContext<'a> {
 
         shared_inheritance_system(self.0);
 
+        // Return focus from views which have been hidden, such as a closed popup.
+        self.0.restore_hidden_focus();
+
         // Load any unloaded images and remove unused images.
         image_system(self.0);
     }
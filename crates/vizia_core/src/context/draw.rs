@@ -10,8 +10,8 @@ use crate::cache::CachedData;
 use crate::events::ViewHandler;
 use crate::model::ModelDataStore;
 use crate::prelude::*;
-use crate::resource::{ImageOrId, ResourceManager};
-use crate::style::{ImageOrGradient, IntoTransform, Style};
+use crate::resource::ResourceManager;
+use crate::style::{BorderShape, ColorMatrix, ImageOrGradient, IntoTransform, Side, Style};
 use crate::text::{TextConfig, TextContext};
 use crate::vg::{Paint, Path};
 use vizia_input::{Modifiers, MouseState};
@@ -64,6 +64,8 @@ pub struct DrawContext<'a> {
     pub(crate) modifiers: &'a Modifiers,
     pub(crate) mouse: &'a MouseState<Entity>,
     pub(crate) opacity: f32,
+    // The target being drawn to, which is an offscreen image while drawing a view with filters.
+    pub(crate) render_target: femtovg::RenderTarget,
 }
 
 macro_rules! get_units_property {
@@ -119,6 +121,25 @@ macro_rules! get_length_property {
     };
 }
 
+macro_rules! get_border_width_property {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $side:expr
+    ) => {
+        $(#[$meta])*
+        pub fn $name(&self) -> f32 {
+            if let Some(length) = self.style.border_width(self.current, $side) {
+                let bounds = self.bounds();
+
+                let px = length.to_pixels(bounds.w.min(bounds.h), self.scale_factor());
+                return px.round();
+            }
+
+            0.0
+        }
+    };
+}
+
 impl<'a> DrawContext<'a> {
     /// Returns the bounds of the current view.
     pub fn bounds(&self) -> BoundingBox {
@@ -288,16 +309,67 @@ impl<'a> DrawContext<'a> {
         self.style.physical_to_logical(physical)
     }
 
-    get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+    get_border_width_property!(
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width,
+        Side::Top
+    );
+
+    get_border_width_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width,
+        Side::Right
+    );
+
+    get_border_width_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width,
+        Side::Bottom
+    );
+
+    get_border_width_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width,
+        Side::Left
     );
 
+    /// Returns the width of the top border of the current view in physical pixels.
+    #[deprecated(note = "borders can differ on each side, use `border_top_width` and the getters \
+                         for the other sides instead")]
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
+    /// Returns the style of the top border of the current view.
+    pub fn border_top_style(&self) -> BorderStyleKeyword {
+        self.style.border_top_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the right border of the current view.
+    pub fn border_right_style(&self) -> BorderStyleKeyword {
+        self.style.border_right_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the bottom border of the current view.
+    pub fn border_bottom_style(&self) -> BorderStyleKeyword {
+        self.style.border_bottom_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the left border of the current view.
+    pub fn border_left_style(&self) -> BorderStyleKeyword {
+        self.style.border_left_style.get(self.current).copied().unwrap_or_default()
+    }
+
     get_color_property!(
         /// Returns the outline color of the current view.
         outline_color
     );
 
+    /// Returns the outline style of the current view.
+    pub fn outline_style(&self) -> BorderStyleKeyword {
+        self.style.outline_style.get(self.current).copied().unwrap_or_default()
+    }
+
     get_length_property!(
         /// Returns the outline width of the current view in physical pixels.
         outline_width
@@ -369,7 +441,17 @@ impl<'a> DrawContext<'a> {
     );
 
     get_color_property!(background_color);
-    get_color_property!(border_color);
+    get_color_property!(border_top_color);
+    get_color_property!(border_right_color);
+    get_color_property!(border_bottom_color);
+    get_color_property!(border_left_color);
+
+    /// Returns the color of the top border of the current view.
+    #[deprecated(note = "borders can differ on each side, use `border_top_color` and the getters \
+                         for the other sides instead")]
+    pub fn border_color(&self) -> Color {
+        self.border_top_color()
+    }
 
     get_color_property!(selection_color);
     get_color_property!(caret_color);
@@ -388,7 +470,13 @@ impl<'a> DrawContext<'a> {
         self.style.box_shadow.get(self.current)
     }
 
-    pub fn backdrop_filter(&self) -> Option<&Filter> {
+    /// Returns the filters applied to the rendered content of the current view.
+    pub fn filter(&self) -> Option<&Vec<Filter>> {
+        self.style.filter.get(self.current)
+    }
+
+    /// Returns the filters applied to the content behind the current view.
+    pub fn backdrop_filter(&self) -> Option<&Vec<Filter>> {
         self.style.backdrop_filter.get(self.current)
     }
 
@@ -407,7 +495,10 @@ impl<'a> DrawContext<'a> {
 
         let bounds = self.bounds();
 
-        let border_width = self.border_width();
+        let border_top = self.border_top_width();
+        let border_right = self.border_right_width();
+        let border_bottom = self.border_bottom_width();
+        let border_left = self.border_left_width();
 
         let border_top_left_radius = self.border_top_left_radius();
         let border_top_right_radius = self.border_top_right_radius();
@@ -422,18 +513,22 @@ impl<'a> DrawContext<'a> {
         //TODO: Cache the path and regenerate if the bounds change
         let mut path = Path::new();
 
-        if bounds.w == bounds.h
+        let uniform_border =
+            border_top == border_right && border_top == border_bottom && border_top == border_left;
+
+        if uniform_border
+            && bounds.w == bounds.h
             && border_bottom_left_radius == bounds.w / 2.0
             && border_bottom_right_radius == bounds.w / 2.0
             && border_top_left_radius == bounds.h / 2.0
             && border_top_right_radius == bounds.h / 2.0
         {
-            path.circle(bounds.center().0, bounds.center().1, bounds.w / 2.0 - border_width / 2.0);
+            path.circle(bounds.center().0, bounds.center().1, bounds.w / 2.0 - border_top / 2.0);
         } else {
-            let x = bounds.x + border_width / 2.0;
-            let y = bounds.y + border_width / 2.0;
-            let w = bounds.w - border_width;
-            let h = bounds.h - border_width;
+            let x = bounds.x + border_left / 2.0;
+            let y = bounds.y + border_top / 2.0;
+            let w = bounds.w - (border_left + border_right) / 2.0;
+            let h = bounds.h - (border_top + border_bottom) / 2.0;
             let halfw = w.abs() * 0.5;
             let halfh = h.abs() * 0.5;
 
@@ -529,119 +624,141 @@ impl<'a> DrawContext<'a> {
         let window_height = self.cache.get_height(Entity::root());
         let bounds = self.bounds();
 
-        let blur_radius = self.backdrop_filter().map(|filter| match filter {
-            Filter::Blur(r) => r.to_px().unwrap_or_default(),
-        });
-
-        if let Some(blur_radius) = blur_radius {
-            let sigma = blur_radius / 2.0;
-
-            let filter_image =
-                self.cache.filter_image.get(self.current).cloned().unwrap_or_default();
-
-            fn create_images(canvas: &mut Canvas, w: usize, h: usize) -> (ImageId, ImageId) {
-                (
-                    canvas
-                        .create_image_empty(
-                            w,
-                            h,
-                            femtovg::PixelFormat::Rgba8,
-                            femtovg::ImageFlags::FLIP_Y | femtovg::ImageFlags::PREMULTIPLIED,
-                        )
-                        .unwrap(),
-                    canvas
-                        .create_image_empty(
-                            w,
-                            h,
-                            femtovg::PixelFormat::Rgba8,
-                            femtovg::ImageFlags::FLIP_Y | femtovg::ImageFlags::PREMULTIPLIED,
-                        )
-                        .unwrap(),
-                )
-            }
-
-            let (source, target) = match filter_image {
-                Some((s, t)) => {
-                    let image_size = canvas.image_size(s).unwrap();
-                    if image_size.0 != bounds.w as usize || image_size.1 != bounds.h as usize {
-                        canvas.delete_image(s);
-                        canvas.delete_image(t);
-
-                        create_images(canvas, bounds.w as usize, bounds.h as usize)
-                    } else {
-                        (s, t)
-                    }
+        let filters = match self.backdrop_filter() {
+            Some(filters) if !filters.is_empty() => filters,
+            _ => return,
+        };
+
+        // Successive blurs combine into a single blur, and the color filters into a single color
+        // matrix applied to the backdrop before it is blurred. Drop shadows have no effect on the
+        // backdrop.
+        let sigma = filters
+            .iter()
+            .map(|filter| match filter {
+                Filter::Blur(r) => (r.to_px().unwrap_or_default() / 2.0).powi(2),
+                _ => 0.0,
+            })
+            .sum::<f32>()
+            .sqrt();
+
+        let color_matrix = filters
+            .iter()
+            .filter_map(ColorMatrix::from_filter)
+            .reduce(|matrix, next| matrix.then(&next));
+
+        let filter_image = self.cache.filter_image.get(self.current).cloned().unwrap_or_default();
+
+        fn create_images(canvas: &mut Canvas, w: usize, h: usize) -> Option<(ImageId, ImageId)> {
+            let flags = femtovg::ImageFlags::FLIP_Y | femtovg::ImageFlags::PREMULTIPLIED;
+            let source =
+                canvas.create_image_empty(w, h, femtovg::PixelFormat::Rgba8, flags).ok()?;
+            match canvas.create_image_empty(w, h, femtovg::PixelFormat::Rgba8, flags) {
+                Ok(target) => Some((source, target)),
+                Err(_) => {
+                    canvas.delete_image(source);
+                    None
                 }
+            }
+        }
 
-                None => create_images(canvas, bounds.w as usize, bounds.h as usize),
-            };
+        let size = (bounds.w as usize, bounds.h as usize);
+        let images = match filter_image {
+            Some((s, t)) if canvas.image_size(s).ok() == Some(size) => Some((s, t)),
+            Some((s, t)) => {
+                canvas.delete_image(s);
+                canvas.delete_image(t);
 
-            self.cache.filter_image.insert(self.current, Some((source, target)));
+                create_images(canvas, size.0, size.1)
+            }
 
-            // TODO: Cache these
-            let screenshot = canvas.screenshot().unwrap();
+            None => create_images(canvas, size.0, size.1),
+        };
+
+        self.cache.filter_image.insert(self.current, images);
+
+        // The backdrop is left unfiltered if the images for filtering it cannot be created.
+        let (source, target) = match images {
+            Some(images) => images,
+            None => return,
+        };
+
+        // TODO: Cache these
+        let mut screenshot = match canvas.screenshot() {
+            Ok(screenshot) => screenshot,
+            Err(_) => return,
+        };
+
+        if let Some(color_matrix) = color_matrix {
+            let x = (bounds.x.max(0.0) as usize).min(screenshot.width());
+            let y = (bounds.y.max(0.0) as usize).min(screenshot.height());
+            let right = (bounds.right().ceil().max(0.0) as usize).min(screenshot.width());
+            for row in screenshot.rows_mut().skip(y).take(bounds.h.ceil() as usize) {
+                for pixel in &mut row[x..right.max(x)] {
+                    color_matrix.apply(pixel);
+                }
+            }
+        }
 
-            let screenshot_image =
-                self.cache.screenshot_image.get(self.current).cloned().unwrap_or_default();
+        let screenshot_size = (screenshot.width(), screenshot.height());
+        let screenshot_image = self.cache.screenshot_image.get(self.current).cloned().flatten();
+        let screenshot_image = match screenshot_image {
+            Some(s)
+                if canvas.image_size(s).ok() == Some(screenshot_size)
+                    && canvas.update_image(s, screenshot.as_ref(), 0, 0).is_ok() =>
+            {
+                Some(s)
+            }
 
-            let screenshot_image_id = if let Some(s) = screenshot_image {
-                let image_size = canvas.image_size(s).unwrap();
-                if image_size.0 != screenshot.width() || image_size.1 != screenshot.height() {
+            image => {
+                if let Some(s) = image {
                     canvas.delete_image(s);
-                    canvas.create_image(screenshot.as_ref(), femtovg::ImageFlags::empty()).unwrap()
-                } else {
-                    canvas
-                        .update_image(s, screenshot.as_ref(), 0, 0)
-                        .expect("Failed to update image");
-                    s
                 }
-            } else {
-                canvas.create_image(screenshot.as_ref(), femtovg::ImageFlags::empty()).unwrap()
-            };
 
-            self.cache.screenshot_image.insert(self.current, Some(screenshot_image_id));
-
-            // Draw canvas to source image
-            canvas.save();
-            canvas.set_render_target(femtovg::RenderTarget::Image(source));
-            canvas.reset_scissor();
-            canvas.reset_transform();
-            canvas.clear_rect(
-                0,
-                0,
-                bounds.w as u32,
-                bounds.h as u32,
-                femtovg::Color::rgba(0, 0, 0, 0),
-            );
-            let mut p = femtovg::Path::new();
-            p.rect(0.0, 0.0, bounds.w, bounds.h);
-            canvas.fill_path(
-                &p,
-                &Paint::image(
-                    screenshot_image_id,
-                    -bounds.x,
-                    -bounds.y,
-                    window_width,
-                    window_height,
-                    0.0,
-                    1.0,
-                ),
-            );
-
-            let blurred_image = if blur_radius > 0.0 {
-                canvas.filter_image(target, femtovg::ImageFilter::GaussianBlur { sigma }, source);
-                target
-            } else {
-                source
-            };
-            canvas.restore();
-            canvas.set_render_target(femtovg::RenderTarget::Screen);
+                canvas.create_image(screenshot.as_ref(), femtovg::ImageFlags::empty()).ok()
+            }
+        };
+
+        self.cache.screenshot_image.insert(self.current, screenshot_image);
+
+        let screenshot_image_id = match screenshot_image {
+            Some(image) => image,
+            None => return,
+        };
+
+        // Draw canvas to source image
+        canvas.save();
+        canvas.set_render_target(femtovg::RenderTarget::Image(source));
+        canvas.reset_scissor();
+        canvas.reset_transform();
+        canvas.clear_rect(0, 0, bounds.w as u32, bounds.h as u32, femtovg::Color::rgba(0, 0, 0, 0));
+        let mut p = femtovg::Path::new();
+        p.rect(0.0, 0.0, bounds.w, bounds.h);
+        canvas.fill_path(
+            &p,
+            &Paint::image(
+                screenshot_image_id,
+                -bounds.x,
+                -bounds.y,
+                window_width,
+                window_height,
+                0.0,
+                1.0,
+            ),
+        );
 
-            canvas.fill_path(
-                path,
-                &Paint::image(blurred_image, bounds.x, bounds.y, bounds.w, bounds.h, 0.0, 1.0),
-            );
-        }
+        let blurred_image = if sigma > 0.0 {
+            canvas.filter_image(target, femtovg::ImageFilter::GaussianBlur { sigma }, source);
+            target
+        } else {
+            source
+        };
+        canvas.restore();
+        canvas.set_render_target(self.render_target);
+
+        canvas.fill_path(
+            path,
+            &Paint::image(blurred_image, bounds.x, bounds.y, bounds.w, bounds.h, 0.0, 1.0),
+        );
     }
 
     /// Draw background color or background image (including gradients) for the current view.
@@ -656,14 +773,17 @@ impl<'a> DrawContext<'a> {
     pub fn draw_text_and_selection(&mut self, canvas: &mut Canvas) {
         if self.text_context.has_buffer(self.current) {
             let mut bounds = self.bounds();
-            let border_width = self.border_width();
+            let border_top = self.border_top_width();
+            let border_right = self.border_right_width();
+            let border_bottom = self.border_bottom_width();
+            let border_left = self.border_left_width();
 
             // let mut box_x = bounds.x + border_width;
             // let mut box_y = bounds.y + border_width;
             // let mut box_w = bounds.w - border_width * 2.0;
             // let mut box_h = bounds.h - border_width * 2.0;
 
-            bounds = bounds.shrink(border_width);
+            bounds = bounds.shrink_sides(border_left, border_top, border_right, border_bottom);
 
             let child_left = self.child_left();
             let child_right = self.child_right();
@@ -731,42 +851,100 @@ impl<'a> DrawContext<'a> {
 
     /// Draw the border of the current view.
     pub fn draw_border(&mut self, canvas: &mut Canvas, path: &mut Path) {
-        let border_color = self.border_color();
-        let border_width = self.border_width();
+        let widths = [
+            self.border_top_width(),
+            self.border_right_width(),
+            self.border_bottom_width(),
+            self.border_left_width(),
+        ];
+        let colors = [
+            self.border_top_color(),
+            self.border_right_color(),
+            self.border_bottom_color(),
+            self.border_left_color(),
+        ];
+        let styles = [
+            self.border_top_style(),
+            self.border_right_style(),
+            self.border_bottom_style(),
+            self.border_left_style(),
+        ];
+
+        // A uniform solid border is stroked along the path of the view.
+        if widths.iter().all(|width| *width == widths[0])
+            && colors.iter().all(|color| *color == colors[0])
+            && styles.iter().all(|style| *style == BorderStyleKeyword::Solid)
+        {
+            let mut paint = Paint::color(colors[0].into());
+            paint.set_line_width(widths[0]);
+            canvas.stroke_path(path, &paint);
+            return;
+        }
 
-        let mut paint = Paint::color(border_color.into());
-        paint.set_line_width(border_width);
-        canvas.stroke_path(path, &paint);
+        let shape = BorderShape {
+            bounds: self.bounds(),
+            widths,
+            radii: [
+                self.border_top_left_radius(),
+                self.border_top_right_radius(),
+                self.border_bottom_right_radius(),
+                self.border_bottom_left_radius(),
+            ],
+            shapes: [
+                self.border_top_left_shape(),
+                self.border_top_right_shape(),
+                self.border_bottom_right_shape(),
+                self.border_bottom_left_shape(),
+            ],
+        };
+
+        shape.draw(canvas, styles, colors);
     }
 
     /// Draw the outline of the current view.
     pub fn draw_outline(&mut self, canvas: &mut Canvas) {
-        let bounds = self.bounds();
-
-        let border_top_left_radius = self.border_top_left_radius();
-        let border_top_right_radius = self.border_top_left_radius();
-        let border_bottom_right_radius = self.border_top_left_radius();
-        let border_bottom_left_radius = self.border_top_left_radius();
-
         let outline_width = self.outline_width();
         let outline_offset = self.outline_offset();
         let outline_color = self.outline_color();
+        let outline_style = self.outline_style();
 
-        let mut outline_path = Path::new();
-        let half_outline_width = outline_width / 2.0;
-        outline_path.rounded_rect_varying(
-            bounds.x - half_outline_width - outline_offset,
-            bounds.y - half_outline_width - outline_offset,
-            bounds.w + outline_width + 2.0 * outline_offset,
-            bounds.h + outline_width + 2.0 * outline_offset,
-            border_top_left_radius * 1.5,
-            border_top_right_radius * 1.5,
-            border_bottom_right_radius * 1.5,
-            border_bottom_left_radius * 1.5,
-        );
-        let mut outline_paint = Paint::color(outline_color.into());
-        outline_paint.set_line_width(outline_width);
-        canvas.stroke_path(&outline_path, &outline_paint);
+        if outline_width <= 0.0 {
+            return;
+        }
+
+        let border_top = self.border_top_width();
+        let border_right = self.border_right_width();
+        let border_bottom = self.border_bottom_width();
+        let border_left = self.border_left_width();
+
+        // The outline follows the rounded corners of the outer edge of the border.
+        let radius = |radius: f32, horizontal: f32, vertical: f32| {
+            if radius > 0.0 {
+                (radius + (horizontal + vertical) / 4.0 + outline_offset + outline_width / 2.0)
+                    .max(0.0)
+            } else {
+                0.0
+            }
+        };
+
+        let shape = BorderShape {
+            bounds: self.bounds().expand(outline_offset + outline_width),
+            widths: [outline_width; 4],
+            radii: [
+                radius(self.border_top_left_radius(), border_left, border_top),
+                radius(self.border_top_right_radius(), border_right, border_top),
+                radius(self.border_bottom_right_radius(), border_right, border_bottom),
+                radius(self.border_bottom_left_radius(), border_left, border_bottom),
+            ],
+            shapes: [
+                self.border_top_left_shape(),
+                self.border_top_right_shape(),
+                self.border_bottom_right_shape(),
+                self.border_bottom_left_shape(),
+            ],
+        };
+
+        shape.draw(canvas, [outline_style; 4], [outline_color; 4]);
     }
 
     /// Draw inset box shadows for the current view.
@@ -898,7 +1076,7 @@ impl<'a> DrawContext<'a> {
                     source
                 };
 
-                canvas.set_render_target(femtovg::RenderTarget::Screen);
+                canvas.set_render_target(self.render_target);
                 canvas.save();
 
                 let paint = Paint::image(
@@ -1050,7 +1228,7 @@ impl<'a> DrawContext<'a> {
                     source
                 };
 
-                canvas.set_render_target(femtovg::RenderTarget::Screen);
+                canvas.set_render_target(self.render_target);
                 canvas.save();
                 canvas.translate(x_offset, y_offset);
                 let mut shadow_path = Path::new();
@@ -1238,14 +1416,15 @@ impl<'a> DrawContext<'a> {
                     },
 
                     ImageOrGradient::Image(image_name) => {
+                        let window = self.tree.get_window(self.current);
                         if let Some(image) = self.resource_manager.images.get(image_name) {
-                            match image.image {
-                                ImageOrId::Id(id, dim) => {
-                                    let (width, height) =
-                                        if let Some(background_size) = image_sizes.get(index) {
-                                            match background_size {
-                                                BackgroundSize::Explicit { width, height } => {
-                                                    let w = match width {
+                            if let Some(&id) = image.ids.get(&window) {
+                                let dim = image.dimensions();
+                                let (width, height) =
+                                    if let Some(background_size) = image_sizes.get(index) {
+                                        match background_size {
+                                            BackgroundSize::Explicit { width, height } => {
+                                                let w = match width {
                                                     LengthPercentageOrAuto::LengthPercentage(
                                                         length,
                                                     ) => length
@@ -1253,7 +1432,7 @@ impl<'a> DrawContext<'a> {
                                                     LengthPercentageOrAuto::Auto => dim.0 as f32,
                                                 };
 
-                                                    let h = match height {
+                                                let h = match height {
                                                     LengthPercentageOrAuto::LengthPercentage(
                                                         length,
                                                     ) => length
@@ -1261,47 +1440,43 @@ impl<'a> DrawContext<'a> {
                                                     LengthPercentageOrAuto::Auto => dim.1 as f32,
                                                 };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
+                                            }
 
-                                                BackgroundSize::Contain => {
-                                                    let image_ratio = dim.0 as f32 / dim.1 as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
+                                            BackgroundSize::Contain => {
+                                                let image_ratio = dim.0 as f32 / dim.1 as f32;
+                                                let container_ratio = bounds.w / bounds.h;
 
-                                                    let (w, h) = if image_ratio > container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
+                                                let (w, h) = if image_ratio > container_ratio {
+                                                    (bounds.w, bounds.w / image_ratio)
+                                                } else {
+                                                    (bounds.h * image_ratio, bounds.h)
+                                                };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
+                                            }
 
-                                                BackgroundSize::Cover => {
-                                                    let image_ratio = dim.0 as f32 / dim.1 as f32;
-                                                    let container_ratio = bounds.w / bounds.h;
+                                            BackgroundSize::Cover => {
+                                                let image_ratio = dim.0 as f32 / dim.1 as f32;
+                                                let container_ratio = bounds.w / bounds.h;
 
-                                                    let (w, h) = if image_ratio < container_ratio {
-                                                        (bounds.w, bounds.w / image_ratio)
-                                                    } else {
-                                                        (bounds.h * image_ratio, bounds.h)
-                                                    };
+                                                let (w, h) = if image_ratio < container_ratio {
+                                                    (bounds.w, bounds.w / image_ratio)
+                                                } else {
+                                                    (bounds.h * image_ratio, bounds.h)
+                                                };
 
-                                                    (w, h)
-                                                }
+                                                (w, h)
                                             }
-                                        } else {
-                                            (dim.0 as f32, dim.1 as f32)
-                                        };
-
-                                    let paint = Paint::image(
-                                        id, bounds.x, bounds.y, width, height, 0.0, 1.0,
-                                    );
+                                        }
+                                    } else {
+                                        (dim.0 as f32, dim.1 as f32)
+                                    };
 
-                                    canvas.fill_path(path, &paint);
-                                }
+                                let paint =
+                                    Paint::image(id, bounds.x, bounds.y, width, height, 0.0, 1.0);
 
-                                _ => {}
+                                canvas.fill_path(path, &paint);
                             }
                         }
                     }
@@ -5,17 +5,19 @@ use std::error::Error;
 
 use femtovg::Transform2D;
 use fnv::FnvHashMap;
-use instant::Duration;
+use instant::{Duration, Instant};
 use vizia_style::{ClipPath, Filter, Scale, Translate};
 
 use crate::animation::{AnimId, Interpolator};
 use crate::cache::CachedData;
+use crate::context::InternalEvent;
 use crate::environment::ThemeMode;
-use crate::events::ViewHandler;
+use crate::events::{Timers, ViewHandler};
+use crate::input::{keymap_conflicts, KeymapRegistry};
 use crate::model::ModelDataStore;
 use crate::prelude::*;
 use crate::resource::ResourceManager;
-use crate::style::{Abilities, IntoTransform, PseudoClassFlags, Style, SystemFlags};
+use crate::style::{Abilities, IntoTransform, PseudoClassFlags, Side, Style, SystemFlags};
 use crate::window::DropData;
 use vizia_id::GenerationalId;
 use vizia_input::{Modifiers, MouseState};
@@ -23,8 +25,11 @@ use vizia_storage::SparseSet;
 
 use crate::context::EmitContext;
 use crate::text::TextContext;
+use crate::tree::{focus_scope_members, is_focus_scope_member, update_focus_scope, FocusScope};
 #[cfg(feature = "clipboard")]
-use copypasta::ClipboardProvider;
+use crate::clipboard::{ClipboardData, ClipboardFormat, ClipboardProvider, FormatUnavailable};
+#[cfg(feature = "clipboard")]
+use crate::image::DynamicImage;
 
 use super::{DARK_THEME, LIGHT_THEME};
 
@@ -64,6 +69,8 @@ pub struct EventContext<'a> {
     pub(crate) current: Entity,
     pub(crate) captured: &'a mut Entity,
     pub(crate) focused: &'a mut Entity,
+    pub(crate) focus_scopes: &'a mut FnvHashMap<Entity, FocusScope>,
+    pub(crate) keymaps: &'a mut KeymapRegistry,
     pub(crate) hovered: &'a Entity,
     pub(crate) style: &'a mut Style,
     pub(crate) entity_identifiers: &'a HashMap<String, Entity>,
@@ -78,6 +85,7 @@ pub struct EventContext<'a> {
     pub(crate) modifiers: &'a Modifiers,
     pub(crate) mouse: &'a MouseState<Entity>,
     pub(crate) event_queue: &'a mut VecDeque<Event>,
+    pub(crate) timers: &'a mut Timers,
     cursor_icon_locked: &'a mut bool,
     window_size: &'a mut WindowSize,
     user_scale_factor: &'a mut f64,
@@ -86,16 +94,18 @@ pub struct EventContext<'a> {
     event_proxy: &'a mut Option<Box<dyn crate::context::EventProxy>>,
     pub(crate) ignore_default_theme: &'a bool,
     pub(crate) drop_data: &'a mut Option<DropData>,
+    pub(crate) drop_target: &'a mut Entity,
+    pub(crate) drag_preview: &'a mut Entity,
 }
 
-macro_rules! get_length_property {
+macro_rules! get_border_width_property {
     (
         $(#[$meta:meta])*
-        $name:ident
+        $name:ident, $side:expr
     ) => {
         $(#[$meta])*
         pub fn $name(&self) -> f32 {
-            if let Some(length) = self.style.$name.get(self.current) {
+            if let Some(length) = self.style.border_width(self.current, $side) {
                 let bounds = self.bounds();
 
                 let px = length.to_pixels(bounds.w.min(bounds.h), self.scale_factor());
@@ -113,6 +123,8 @@ impl<'a> EventContext<'a> {
             current: cx.current,
             captured: &mut cx.captured,
             focused: &mut cx.focused,
+            focus_scopes: &mut cx.focus_scopes,
+            keymaps: &mut cx.keymaps,
             hovered: &cx.hovered,
             entity_identifiers: &cx.entity_identifiers,
             style: &mut cx.style,
@@ -126,6 +138,7 @@ impl<'a> EventContext<'a> {
             modifiers: &cx.modifiers,
             mouse: &cx.mouse,
             event_queue: &mut cx.event_queue,
+            timers: &mut cx.timers,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
             window_size: &mut cx.window_size,
             user_scale_factor: &mut cx.user_scale_factor,
@@ -134,6 +147,8 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            drop_target: &mut cx.drop_target,
+            drag_preview: &mut cx.drag_preview,
         }
     }
 
@@ -142,6 +157,8 @@ impl<'a> EventContext<'a> {
             current,
             captured: &mut cx.captured,
             focused: &mut cx.focused,
+            focus_scopes: &mut cx.focus_scopes,
+            keymaps: &mut cx.keymaps,
             hovered: &cx.hovered,
             entity_identifiers: &cx.entity_identifiers,
             style: &mut cx.style,
@@ -155,6 +172,7 @@ impl<'a> EventContext<'a> {
             modifiers: &cx.modifiers,
             mouse: &cx.mouse,
             event_queue: &mut cx.event_queue,
+            timers: &mut cx.timers,
             cursor_icon_locked: &mut cx.cursor_icon_locked,
             window_size: &mut cx.window_size,
             user_scale_factor: &mut cx.user_scale_factor,
@@ -163,6 +181,8 @@ impl<'a> EventContext<'a> {
             event_proxy: &mut cx.event_proxy,
             ignore_default_theme: &cx.ignore_default_theme,
             drop_data: &mut cx.drop_data,
+            drop_target: &mut cx.drop_target,
+            drag_preview: &mut cx.drag_preview,
         }
     }
 
@@ -402,6 +422,14 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Moves an already built view, along with its descendants, to be the child of `parent` at the
+    /// given index once the current event has been handled.
+    ///
+    /// See [`Context::reparent`].
+    pub fn reparent(&mut self, entity: Entity, parent: Entity, index: usize) {
+        self.event_queue.push_back(Event::new(InternalEvent::Reparent { entity, parent, index }));
+    }
+
     /// Capture mouse input for the current view.
     pub fn capture(&mut self) {
         *self.captured = self.current;
@@ -435,15 +463,19 @@ impl<'a> EventContext<'a> {
     pub fn focus_with_visibility(&mut self, focus_visible: bool) {
         let old_focus = self.focused();
         let new_focus = self.current();
+        let snapshot = self.style.snapshot_pseudo_classes(
+            old_focus.parent_iter(self.tree).chain(new_focus.parent_iter(self.tree)),
+        );
         self.set_focus_pseudo_classes(old_focus, false, focus_visible);
         if self.current() != self.focused() {
             self.emit_to(old_focus, WindowEvent::FocusOut);
             self.emit_to(new_focus, WindowEvent::FocusIn);
             *self.focused = self.current();
+            update_focus_scope(self.tree, self.focus_scopes, new_focus);
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
-        self.style.needs_restyle();
+        self.style.restyle_changed_pseudo_classes(snapshot);
     }
 
     /// Sets application focus to the current view using the previous focus visibility.
@@ -460,6 +492,54 @@ impl<'a> EventContext<'a> {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Moves focus into the focus scope with the given name, added with the
+    /// [`focus_scope`](crate::modifiers::AbilityModifiers::focus_scope) modifier.
+    ///
+    /// Focus is given to the member of the scope which was most recently focused, or to the first
+    /// member if none of its members have been focused.
+    pub fn focus_scope(&mut self, name: &str) {
+        let scope = match self
+            .focus_scopes
+            .iter()
+            .find(|(_, focus_scope)| focus_scope.name == name)
+            .map(|(entity, _)| *entity)
+        {
+            Some(scope) => scope,
+            None => return,
+        };
+
+        let active = self.focus_scopes[&scope].active;
+        let new_focus =
+            if is_focus_scope_member(self.tree, self.style, self.focus_scopes, scope, active) {
+                Some(active)
+            } else {
+                focus_scope_members(self.tree, self.style, self.focus_scopes, scope)
+                    .first()
+                    .copied()
+            };
+
+        if let Some(new_focus) = new_focus {
+            self.with_current(new_focus, |cx| cx.focus_with_visibility(true));
+        }
+    }
+
+    /// Returns the key sequences which are bound in keymaps that can be active at the same time
+    /// and which cannot all be triggered as expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let mut context = Context::default();
+    /// # let mut cx = EventContext::new(&mut context);
+    /// for conflict in cx.keymap_conflicts() {
+    ///     println!("{:?} conflicts with {:?}", conflict.sequence, conflict.other_sequence);
+    /// }
+    /// ```
+    pub fn keymap_conflicts(&self) -> Vec<KeymapConflict> {
+        keymap_conflicts(self.tree, self.keymaps)
+    }
+
     /// Returns the currently hovered view.
     pub fn hovered(&self) -> Entity {
         *self.hovered
@@ -555,12 +635,62 @@ impl<'a> EventContext<'a> {
         *self.drop_data = Some(data.into())
     }
 
+    /// Returns the payload of the current drag-and-drop operation, if any.
+    pub fn drop_data(&self) -> Option<&DropData> {
+        self.drop_data.as_ref()
+    }
+
+    /// Accepts the current drag-and-drop operation, making the current view the drop target.
+    ///
+    /// This should be called in response to a [`WindowEvent::DragEnter`] event. The drop target
+    /// receives [`WindowEvent::DragOver`] events while the drag moves over it, and a
+    /// [`WindowEvent::Drop`] event when the drag ends over it. While accepting a drop, the view can
+    /// be selected with the `:drop-target` CSS pseudo-class selector.
+    pub fn accept_drop(&mut self) {
+        if self.drop_data.is_none() {
+            return;
+        }
+
+        if *self.drop_target != self.current {
+            if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(*self.drop_target) {
+                pseudo_classes.set(PseudoClassFlags::DROP_TARGET, false);
+            }
+            self.style.needs_restyle(*self.drop_target);
+        }
+
+        *self.drop_target = self.current;
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(self.current) {
+            pseudo_classes.set(PseudoClassFlags::DROP_TARGET, true);
+        }
+
+        self.style.needs_restyle(self.current);
+    }
+
+    /// Returns true if the current view has accepted the current drag-and-drop operation.
+    pub fn is_drop_target(&self) -> bool {
+        *self.drop_target == self.current
+    }
+
+    /// Sets the view which follows the cursor while the current drag-and-drop operation is active.
+    /// The view is shown when the drag starts and hidden again when it ends.
+    pub fn set_drag_preview(&mut self, preview: Entity) {
+        *self.drag_preview = preview;
+        self.style.display.insert(preview, Display::Flex);
+        self.style.needs_relayout();
+        self.style.needs_redraw();
+    }
+
     /// Get the contents of the system clipboard.
     ///
     /// This may fail for a variety of backend-specific reasons.
     #[cfg(feature = "clipboard")]
     pub fn get_clipboard(&mut self) -> Result<String, Box<dyn Error + Send + Sync + 'static>> {
-        self.clipboard.get_contents()
+        match self.clipboard.get_contents(&ClipboardFormat::Text)? {
+            ClipboardData::Text(text) => Ok(text),
+            data => {
+                data.text().map(str::to_owned).ok_or_else(|| unavailable(ClipboardFormat::Text))
+            }
+        }
     }
 
     /// Set the contents of the system clipboard.
@@ -571,7 +701,117 @@ impl<'a> EventContext<'a> {
         &mut self,
         text: String,
     ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-        self.clipboard.set_contents(text)
+        self.clipboard.set_contents(vec![ClipboardData::Text(text)])
+    }
+
+    /// Get an image from the system clipboard.
+    ///
+    /// This fails if the clipboard does not contain an image.
+    #[cfg(feature = "clipboard")]
+    pub fn get_clipboard_image(
+        &mut self,
+    ) -> Result<DynamicImage, Box<dyn Error + Send + Sync + 'static>> {
+        match self.clipboard.get_contents(&ClipboardFormat::Image)? {
+            ClipboardData::Image(image) => Ok(image),
+            _ => Err(unavailable(ClipboardFormat::Image)),
+        }
+    }
+
+    /// Place an image on the system clipboard.
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard_image(
+        &mut self,
+        image: DynamicImage,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.set_contents(vec![ClipboardData::Image(image)])
+    }
+
+    /// Get HTML markup from the system clipboard.
+    ///
+    /// This fails if the clipboard does not contain HTML.
+    #[cfg(feature = "clipboard")]
+    pub fn get_clipboard_html(&mut self) -> Result<String, Box<dyn Error + Send + Sync + 'static>> {
+        match self.clipboard.get_contents(&ClipboardFormat::Html)? {
+            ClipboardData::Html { html, .. } => Ok(html),
+            _ => Err(unavailable(ClipboardFormat::Html)),
+        }
+    }
+
+    /// Place HTML markup on the system clipboard, along with an optional plain text alternative for
+    /// applications which cannot paste HTML.
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard_html(
+        &mut self,
+        html: String,
+        alt_text: Option<String>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.set_contents(vec![ClipboardData::Html { html, alt_text }])
+    }
+
+    /// Get data with the given MIME type from the system clipboard.
+    ///
+    /// This fails if the clipboard does not contain data of that type.
+    #[cfg(feature = "clipboard")]
+    pub fn get_clipboard_data(
+        &mut self,
+        mime_type: &str,
+    ) -> Result<Vec<u8>, Box<dyn Error + Send + Sync + 'static>> {
+        let format = ClipboardFormat::from_mime_type(mime_type);
+        match self.clipboard.get_contents(&format)? {
+            ClipboardData::Custom { data, .. } => Ok(data),
+            ClipboardData::Text(text) | ClipboardData::Html { html: text, .. } => {
+                Ok(text.into_bytes())
+            }
+            _ => Err(unavailable(format)),
+        }
+    }
+
+    /// Place data with the given MIME type on the system clipboard.
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard_data(
+        &mut self,
+        mime_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        let mime_type = mime_type.to_owned();
+        self.clipboard.set_contents(vec![ClipboardData::Custom { mime_type, data }])
+    }
+
+    /// Get the contents of the system clipboard in the given format.
+    #[cfg(feature = "clipboard")]
+    pub fn get_clipboard_contents(
+        &mut self,
+        format: &ClipboardFormat,
+    ) -> Result<ClipboardData, Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.get_contents(format)
+    }
+
+    /// Replace the contents of the system clipboard with several representations of the same data.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # use vizia_core::image::DynamicImage;
+    /// # fn copy(cx: &mut EventContext, shapes: Vec<u8>, preview: DynamicImage) {
+    /// cx.set_clipboard_contents(vec![
+    ///     ClipboardData::Custom { mime_type: String::from("application/x-shapes"), data: shapes },
+    ///     ClipboardData::Image(preview),
+    /// ])
+    /// .expect("Failed to copy shapes");
+    /// # }
+    /// ```
+    #[cfg(feature = "clipboard")]
+    pub fn set_clipboard_contents(
+        &mut self,
+        contents: Vec<ClipboardData>,
+    ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
+        self.clipboard.set_contents(contents)
+    }
+
+    /// Returns the formats which are currently available on the system clipboard.
+    #[cfg(feature = "clipboard")]
+    pub fn clipboard_formats(&mut self) -> Vec<ClipboardFormat> {
+        self.clipboard.formats()
     }
 
     /// Toggles the addition/removal of a class name for the current view.
@@ -597,7 +837,32 @@ impl<'a> EventContext<'a> {
             self.style.classes.insert(current, class_list);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
+    }
+
+    /// Sets an attribute of the current view with the given name and value, which can be
+    /// referenced by CSS attribute selectors.
+    pub fn set_attr(&mut self, name: &str, value: impl ToString) {
+        let current = self.current();
+        if let Some(attributes) = self.style.attributes.get_mut(current) {
+            attributes.insert(name.to_owned(), value.to_string());
+        } else {
+            let mut attributes = HashMap::new();
+            attributes.insert(name.to_owned(), value.to_string());
+            self.style.attributes.insert(current, attributes);
+        }
+
+        self.style.needs_restyle(current);
+    }
+
+    /// Removes the attribute with the given name from the current view.
+    pub fn remove_attr(&mut self, name: &str) {
+        let current = self.current();
+        if let Some(attributes) = self.style.attributes.get_mut(current) {
+            if attributes.remove(name).is_some() {
+                self.style.needs_restyle(current);
+            }
+        }
     }
 
     /// Returns a reference to the [Environment] model.
@@ -622,7 +887,7 @@ impl<'a> EventContext<'a> {
 
     /// Marks the current view as needing to be redrawn.
     pub fn needs_redraw(&mut self) {
-        self.style.needs_redraw();
+        self.style.needs_view_redraw(self.current);
     }
 
     /// Marks the current view as needing a layout computation.
@@ -631,39 +896,147 @@ impl<'a> EventContext<'a> {
         self.style.needs_redraw();
     }
 
+    /// Marks the current view as needing to recompute its styles.
     pub fn needs_restyle(&mut self) {
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     /// Reloads the stylesheets linked to the application.
     pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
+        self.apply_styles(true);
+
+        Ok(())
+    }
+
+    /// Parses the themes and stylesheets of the application and adds their rules, reading the
+    /// stylesheets again first if `read` is true.
+    pub(crate) fn apply_styles(&mut self, read: bool) {
         if self.resource_manager.themes.is_empty() && self.resource_manager.styles.is_empty() {
-            return Ok(());
+            return;
         }
 
         self.style.remove_rules();
 
         self.style.clear_style_rules();
 
-        let mut overall_theme = String::new();
+        let mut diagnostics = Vec::new();
 
         // Reload built-in themes
         for theme in self.resource_manager.themes.iter() {
-            overall_theme += theme;
+            diagnostics
+                .extend(self.style.parse_theme("<theme>", None, theme, &mut |path| {
+                    std::fs::read_to_string(path)
+                }));
         }
+        diagnostics.extend(self.load_font_faces(0));
+
+        let mut styles = std::mem::take(&mut self.resource_manager.styles);
+        for style in styles.iter_mut() {
+            let name = style.style.source_name();
+            let path = style.style.source_path();
+
+            #[cfg(feature = "hot_reload")]
+            if let Some(path) = &path {
+                crate::hot_reload::watch_stylesheet(
+                    &mut self.resource_manager.style_watcher,
+                    self.event_proxy,
+                    path,
+                );
+            }
 
-        for style_string in self.resource_manager.styles.iter().flat_map(|style| style.get_style())
-        {
-            overall_theme += &style_string;
+            let mut rejected = Vec::new();
+            let source = read.then(|| style.style.get_style().ok()).flatten();
+            let source = match accept_source(&name, source, &mut style.source, &mut rejected) {
+                Some(source) => source,
+                None => {
+                    style.diagnostics.clear();
+                    continue;
+                }
+            };
+
+            // Imported files are kept the same way, so an import which fails to parse doesn't
+            // replace the last good version of that file either.
+            let font_faces = self.style.font_faces.len();
+            let mut previous_imports = std::mem::take(&mut style.imports);
+            let imports = &mut style.imports;
+            let applied = self.style.parse_theme(&name, path.as_deref(), &source, &mut |path| {
+                let mut last_good = previous_imports.remove(path);
+                let import = if read || last_good.is_none() {
+                    match std::fs::read_to_string(path) {
+                        Ok(import) => Some(import),
+                        Err(err) if last_good.is_none() => return Err(err),
+                        Err(_) => None,
+                    }
+                } else {
+                    None
+                };
+
+                let name = path.display().to_string();
+                let import = accept_source(&name, import, &mut last_good, &mut rejected);
+                if let Some(good_import) = last_good {
+                    imports.insert(path.to_owned(), good_import);
+                }
+
+                Ok(import.unwrap_or_default())
+            });
+
+            style.diagnostics = if rejected.is_empty() { applied } else { rejected };
+            style.diagnostics.extend(self.load_font_faces(font_faces));
+            diagnostics.extend(style.diagnostics.iter().cloned());
+        }
+        self.resource_manager.styles = styles;
+
+        // Imported stylesheets are watched along with the stylesheets which import them.
+        #[cfg(feature = "hot_reload")]
+        for path in self.style.imports.iter() {
+            crate::hot_reload::watch_stylesheet(
+                &mut self.resource_manager.style_watcher,
+                self.event_proxy,
+                path,
+            );
         }
 
-        self.style.parse_theme(&overall_theme);
+        if let Some(handler) = self.resource_manager.style_diagnostic_handler.take() {
+            for diagnostic in diagnostics.iter() {
+                (handler)(self, diagnostic);
+            }
+
+            self.resource_manager.style_diagnostic_handler = Some(handler);
+        }
 
-        self.style.needs_restyle();
+        self.style.needs_full_restyle();
         self.style.needs_relayout();
         self.style.needs_redraw();
+    }
 
-        Ok(())
+    /// Loads the font files of the `@font-face` rules after the given number of rules, returning
+    /// the problems found while loading them.
+    fn load_font_faces(&mut self, from: usize) -> Vec<StyleDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for font_face in self.style.font_faces[from..].iter() {
+            let db = self.text_context.font_system().db_mut();
+            match self.resource_manager.load_font_face(db, &font_face.path) {
+                // The family name declared by the rule selects the fonts of the file.
+                Ok(families) => {
+                    if let (Some(family), Some(font_family)) = (&font_face.family, families.first())
+                    {
+                        self.style.font_aliases.insert(family.clone(), font_family.clone());
+                    }
+                }
+
+                Err(err) => diagnostics.push(StyleDiagnostic {
+                    severity: DiagnosticSeverity::Error,
+                    location: font_face.location.clone(),
+                    line: font_face.location.line + 1,
+                    property: Some(String::from("src")),
+                    expected: None,
+                    message: format!("Failed to load font `{}`: {}", font_face.path.display(), err),
+                }),
+            }
+        }
+
+        diagnostics
     }
 
     /// Spawns a thread and provides a [ContextProxy] for sending events back to the main thread.
@@ -679,6 +1052,39 @@ impl<'a> EventContext<'a> {
         std::thread::spawn(move || target(&mut cxp));
     }
 
+    /// Sends an event containing the provided message directly to the current view once the
+    /// delay has passed, without blocking or spawning a thread.
+    ///
+    /// Returns a [`TimerId`] which can be passed to
+    /// [`cancel_scheduled`](Self::cancel_scheduled) to drop the event before it is sent. Events
+    /// scheduled for a view are dropped when the view is removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// # let cx = &mut EventContext::new(cx);
+    /// pub struct Timeout;
+    ///
+    /// let timer = cx.schedule_emit(Duration::from_millis(500), Timeout);
+    /// cx.cancel_scheduled(timer);
+    /// ```
+    pub fn schedule_emit<M: Any + Send>(&mut self, delay: Duration, message: M) -> TimerId {
+        self.timers.schedule(
+            Instant::now() + delay,
+            Event::new(message)
+                .target(self.current)
+                .origin(self.current)
+                .propagate(Propagation::Direct),
+        )
+    }
+
+    /// Drops an event scheduled with [`schedule_emit`](Self::schedule_emit) if it has not yet
+    /// been sent.
+    pub fn cancel_scheduled(&mut self, timer: TimerId) {
+        self.timers.cancel(timer);
+    }
+
     pub fn modify<V: View>(&mut self, f: impl FnOnce(&mut V)) {
         if let Some(view) = self
             .views
@@ -734,7 +1140,8 @@ impl<'a> EventContext<'a> {
     // Setters
 
     pub fn set_id(&mut self, id: &str) {
-        self.style.ids.insert(self.current, id.to_string())
+        self.style.ids.insert(self.current, id.to_string());
+        self.style.needs_restyle(self.current);
     }
 
     // Pseudoclass Setters
@@ -754,7 +1161,7 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::HOVER, flag);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     /// Set the active state for the current view.
@@ -770,7 +1177,7 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::ACTIVE, active);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     pub fn set_read_only(&mut self, flag: bool) {
@@ -779,7 +1186,7 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::READ_ONLY, flag);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     pub fn set_read_write(&mut self, flag: bool) {
@@ -788,7 +1195,20 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::READ_WRITE, flag);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
+    }
+
+    /// Sets whether the current view is showing placeholder text.
+    ///
+    /// The placeholder text can be styled with the `::placeholder` CSS pseudo-element, and views
+    /// showing it can be selected with the `:placeholder-shown` CSS pseudo-class.
+    pub fn set_placeholder_shown(&mut self, flag: bool) {
+        let current = self.current();
+        if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(current) {
+            pseudo_classes.set(PseudoClassFlags::PLACEHOLDER_SHOWN, flag);
+        }
+
+        self.style.needs_restyle(self.current);
     }
 
     /// Sets the checked state of the current view.
@@ -805,7 +1225,7 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::CHECKED, flag);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     /// Sets the valid state of the current view.
@@ -823,7 +1243,32 @@ impl<'a> EventContext<'a> {
             pseudo_classes.set(PseudoClassFlags::INVALID, !flag);
         }
 
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
+    }
+
+    /// Sets whether the current view has the custom pseudo-class with the given name.
+    ///
+    /// Custom pseudo-classes can be selected with a CSS pseudo-class selector of the same name:
+    /// ```css
+    /// element:dragging {
+    ///     background-color: red;
+    /// }
+    /// ```
+    pub fn set_pseudo_class(&mut self, name: &str, flag: bool) {
+        let current = self.current();
+        if let Some(pseudo_classes) = self.style.custom_pseudo_classes.get_mut(current) {
+            if flag {
+                pseudo_classes.insert(name.to_owned());
+            } else {
+                pseudo_classes.remove(name);
+            }
+        } else if flag {
+            let mut pseudo_classes = HashSet::new();
+            pseudo_classes.insert(name.to_owned());
+            self.style.custom_pseudo_classes.insert(current, pseudo_classes);
+        }
+
+        self.style.needs_restyle(self.current);
     }
 
     // TODO: Move me
@@ -951,9 +1396,14 @@ impl<'a> EventContext<'a> {
 
     // FILTER
 
-    /// Sets the backdrop filter of the current view.
-    pub fn set_backdrop_filter(&mut self, filter: Filter) {
-        self.style.backdrop_filter.insert(self.current, filter);
+    /// Sets the filters applied to the rendered content of the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.filter.insert(self.current, filter.into());
+    }
+
+    /// Sets the filters applied to the content behind the current view.
+    pub fn set_backdrop_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.backdrop_filter.insert(self.current, filter.into());
     }
 
     // BOX SHADOW
@@ -993,6 +1443,20 @@ impl<'a> EventContext<'a> {
         self.needs_redraw();
     }
 
+    // SIZE
+
+    pub fn set_width(&mut self, width: Units) {
+        self.style.width.insert(self.current, width);
+        self.needs_relayout();
+        self.needs_redraw();
+    }
+
+    pub fn set_height(&mut self, height: Units) {
+        self.style.height.insert(self.current, height);
+        self.needs_relayout();
+        self.needs_redraw();
+    }
+
     // TEXT
 
     /// Sets the text of the current view.
@@ -1009,11 +1473,37 @@ impl<'a> EventContext<'a> {
     }
 
     // GETTERS
-    get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+    get_border_width_property!(
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width,
+        Side::Top
     );
 
+    get_border_width_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width,
+        Side::Right
+    );
+
+    get_border_width_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width,
+        Side::Bottom
+    );
+
+    get_border_width_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width,
+        Side::Left
+    );
+
+    /// Returns the width of the top border of the current view in physical pixels.
+    #[deprecated(note = "borders can differ on each side, use `border_top_width` and the getters \
+                         for the other sides instead")]
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
     /// Returns the font-size of the current view in physical pixels.
     pub fn font_size(&self) -> f32 {
         self.logical_to_physical(
@@ -1087,3 +1577,131 @@ impl<'a> TreeProps for EventContext<'a> {
         self.tree.get_layout_first_child(self.current).unwrap()
     }
 }
+
+/// Returns the version of a stylesheet to apply, given the text just read from it, if any.
+///
+/// A changed stylesheet which fails to parse is rejected in favour of its last good version, so
+/// that a half-finished edit doesn't break the styling of the application.
+fn accept_source(
+    name: &str,
+    source: Option<String>,
+    last_good: &mut Option<String>,
+    rejected: &mut Vec<StyleDiagnostic>,
+) -> Option<String> {
+    if let Some(source) = source {
+        match last_good {
+            Some(good_source) if *good_source == source => {}
+
+            Some(good_source) => {
+                let diagnostics = Style::check_stylesheet(name, &source);
+                if diagnostics
+                    .iter()
+                    .any(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+                {
+                    rejected.extend(diagnostics);
+                } else {
+                    *good_source = source;
+                }
+            }
+
+            None => *last_good = Some(source),
+        }
+    }
+
+    last_good.clone()
+}
+
+/// Returns the error for a clipboard which does not contain data in the requested format.
+#[cfg(feature = "clipboard")]
+fn unavailable(format: ClipboardFormat) -> Box<dyn Error + Send + Sync + 'static> {
+    Box::new(FormatUnavailable(format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::style_system;
+
+    #[test]
+    fn invalid_import_keeps_last_good_version() {
+        let directory =
+            std::env::temp_dir().join(format!("vizia-invalid-import-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let stylesheet = directory.join("main.css");
+        let import = directory.join("sizes.css");
+        std::fs::write(&stylesheet, "@import \"sizes.css\";\n").unwrap();
+        std::fs::write(&import, "element { width: 5px; }\n").unwrap();
+
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).entity();
+        cx.add_stylesheet(stylesheet).unwrap();
+        style_system(cx);
+        assert_eq!(cx.style.width.get(entity), Some(&Units::Pixels(5.0)));
+
+        std::fs::write(&import, "element { width: 10px; }\n#: {}\n").unwrap();
+        EventContext::new(cx).reload_styles().unwrap();
+        style_system(cx);
+        assert_eq!(cx.style.width.get(entity), Some(&Units::Pixels(5.0)));
+
+        let diagnostics = &cx.resource_manager.styles[0].diagnostics;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].location.filename, import.display().to_string());
+
+        std::fs::write(&import, "element { width: 10px; }\n").unwrap();
+        EventContext::new(cx).reload_styles().unwrap();
+        style_system(cx);
+        assert_eq!(cx.style.width.get(entity), Some(&Units::Pixels(10.0)));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn font_face_family_and_load_failures() {
+        let directory =
+            std::env::temp_dir().join(format!("vizia-font-face-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let font = directory.join("icons.ttf");
+        std::fs::write(&font, crate::fonts::TABLER_ICONS).unwrap();
+        let stylesheet = directory.join("fonts.css");
+        std::fs::write(
+            &stylesheet,
+            "@font-face { font-family: \"Icons\"; src: url(\"icons.ttf\"); }\n\
+             @font-face { font-family: \"Missing\"; src: url(\"missing.otf\"); }\n",
+        )
+        .unwrap();
+
+        let cx = &mut Context::default();
+        let diagnostics = cx.add_stylesheet_with_diagnostics(stylesheet).unwrap();
+
+        let families = &cx.resource_manager.font_faces[&font];
+        assert!(!families.is_empty());
+        assert_eq!(cx.style.font_aliases.get("Icons"), families.first());
+        assert_eq!(cx.style.font_aliases.get("Missing"), None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[0].line, 2);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn redraws_are_attributed_to_views() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).entity();
+        cx.style.should_redraw(|| {});
+        cx.style.redraw.clear();
+
+        EventContext::new_with_current(cx, entity).needs_redraw();
+        let mut redrawn = false;
+        cx.style.should_redraw(|| redrawn = true);
+        assert!(redrawn);
+        assert!(cx.style.redraw.contains(&entity));
+        assert!(!cx.style.redraw.contains(&Entity::root()));
+
+        cx.needs_redraw();
+        cx.style.should_redraw(|| {});
+        assert!(cx.style.redraw.contains(&Entity::root()));
+    }
+}
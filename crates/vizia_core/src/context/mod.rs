@@ -8,16 +8,13 @@ mod event;
 mod proxy;
 mod resource;
 
-use instant::Instant;
+use accesskit::TreeUpdate;
+use instant::{Duration, Instant};
 use std::any::{Any, TypeId};
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, VecDeque};
 use std::sync::Mutex;
 
-#[cfg(all(feature = "clipboard", feature = "x11"))]
-use copypasta::ClipboardContext;
-#[cfg(feature = "clipboard")]
-use copypasta::{nop_clipboard::NopClipboardContext, ClipboardProvider};
 use cosmic_text::{fontdb::Database, FamilyOwned};
 use fnv::FnvHashMap;
 
@@ -31,21 +28,27 @@ pub use resource::*;
 
 use crate::binding::BindingHandler;
 use crate::cache::CachedData;
+#[cfg(feature = "clipboard")]
+use crate::clipboard::{ClipboardProvider, MemoryClipboard, SystemClipboard};
 use crate::environment::{Environment, ThemeMode};
-use crate::events::ViewHandler;
+use crate::events::{Timers, ViewHandler};
 #[cfg(feature = "embedded_fonts")]
 use crate::fonts;
 
 use crate::fonts::TABLER_ICONS;
+use crate::input::{keymap_conflicts, KeymapRegistry};
 use crate::model::ModelDataStore;
 use crate::prelude::*;
-use crate::resource::{ImageOrId, ImageRetentionPolicy, ResourceManager, StoredImage};
+use crate::resource::{ImageRetentionPolicy, ResourceManager, StoredImage, StyleSource};
 use crate::style::{PseudoClassFlags, Style};
+use crate::systems::get_access_node;
 use crate::text::{TextConfig, TextContext};
+use crate::tree::{update_focus_scope, FocusScope};
+use crate::window::WindowState;
 use vizia_id::{GenerationalId, IdManager};
 use vizia_input::{Modifiers, MouseState};
 use vizia_storage::TreeExt;
-use vizia_storage::{ChildIterator, SparseSet};
+use vizia_storage::{ChildIterator, LayoutChildIterator, SparseSet};
 
 static DEFAULT_LAYOUT: &str = include_str!("../../resources/themes/default_layout.css");
 static DARK_THEME: &str = include_str!("../../resources/themes/dark_theme.css");
@@ -65,6 +68,7 @@ pub struct Context {
     pub(crate) data: Models,
     pub(crate) bindings: Bindings,
     pub(crate) event_queue: VecDeque<Event>,
+    pub(crate) timers: Timers,
     pub(crate) tree_updates: Vec<accesskit::TreeUpdate>,
     pub(crate) listeners:
         HashMap<Entity, Box<dyn Fn(&mut dyn ViewHandler, &mut EventContext, &mut Event)>>,
@@ -80,7 +84,11 @@ pub struct Context {
     pub(crate) triggered: Entity,
     pub(crate) hovered: Entity,
     pub(crate) focused: Entity,
-    pub(crate) focus_stack: Vec<Entity>,
+    /// Pairs of views which lock focus within themselves and the view which was focused before
+    /// they took focus, used to restore focus when they are closed.
+    pub(crate) focus_stack: Vec<(Entity, Entity)>,
+    pub(crate) focus_scopes: FnvHashMap<Entity, FocusScope>,
+    pub(crate) keymaps: KeymapRegistry,
     pub(crate) cursor_icon_locked: bool,
 
     pub(crate) resource_manager: ResourceManager,
@@ -112,6 +120,21 @@ pub struct Context {
     pub window_has_focus: bool,
 
     pub(crate) drop_data: Option<DropData>,
+    /// The view which accepted the current drag-and-drop operation.
+    pub(crate) drop_target: Entity,
+    /// The hovered view when the current drag-and-drop operation last moved.
+    pub(crate) drag_hovered: Entity,
+    /// The view which follows the cursor during the current drag-and-drop operation.
+    pub(crate) drag_preview: Entity,
+
+    /// The stored state of each secondary window which is not currently active.
+    pub(crate) windows: FnvHashMap<Entity, WindowState>,
+    /// The window which currently owns the hover, focus, and mouse state of the context.
+    pub(crate) active_window: Entity,
+    /// Secondary windows which have been built but not yet opened by the backend.
+    pub(crate) pending_windows: Vec<Entity>,
+    /// Secondary windows which have been removed but not yet closed by the backend.
+    pub(crate) closed_windows: Vec<Entity>,
 }
 
 impl Default for Context {
@@ -152,6 +175,7 @@ impl Context {
             cache,
             canvases: HashMap::new(),
             event_queue: VecDeque::new(),
+            timers: Timers::default(),
             tree_updates: Vec::new(),
             listeners: HashMap::default(),
             global_listeners: vec![],
@@ -162,6 +186,8 @@ impl Context {
             hovered: Entity::root(),
             focused: Entity::root(),
             focus_stack: Vec::new(),
+            focus_scopes: FnvHashMap::default(),
+            keymaps: KeymapRegistry::default(),
             cursor_icon_locked: false,
             resource_manager: ResourceManager::new(),
             text_context: TextContext::new_from_locale_and_db(
@@ -177,15 +203,9 @@ impl Context {
             user_scale_factor,
 
             #[cfg(feature = "clipboard")]
-            clipboard: {
-                #[cfg(feature = "x11")]
-                if let Ok(context) = ClipboardContext::new() {
-                    Box::new(context)
-                } else {
-                    Box::new(NopClipboardContext::new().unwrap())
-                }
-                #[cfg(not(feature = "x11"))]
-                Box::new(NopClipboardContext::new().unwrap())
+            clipboard: match SystemClipboard::new() {
+                Ok(clipboard) => Box::new(clipboard),
+                Err(_) => Box::new(MemoryClipboard::new()),
             },
             click_time: Instant::now(),
             clicks: 0,
@@ -195,9 +215,17 @@ impl Context {
             window_has_focus: true,
 
             drop_data: None,
+            drop_target: Entity::null(),
+            drag_hovered: Entity::null(),
+            drag_preview: Entity::null(),
+
+            windows: FnvHashMap::default(),
+            active_window: Entity::root(),
+            pending_windows: Vec::new(),
+            closed_windows: Vec::new(),
         };
 
-        result.style.needs_restyle();
+        result.style.needs_full_restyle();
         result.style.needs_relayout();
         result.style.needs_redraw();
 
@@ -266,9 +294,9 @@ impl Context {
         self.style.needs_redraw();
     }
 
-    /// Mark the application as needing to recompute view styles
+    /// Mark the current view as needing to recompute its styles
     pub fn needs_restyle(&mut self) {
-        self.style.needs_restyle();
+        self.style.needs_restyle(self.current);
     }
 
     /// Mark the application as needing to rerun layout computations
@@ -318,15 +346,19 @@ impl Context {
     pub fn focus_with_visibility(&mut self, focus_visible: bool) {
         let old_focus = self.focused;
         let new_focus = self.current;
+        let snapshot = self.style.snapshot_pseudo_classes(
+            old_focus.parent_iter(&self.tree).chain(new_focus.parent_iter(&self.tree)),
+        );
         self.set_focus_pseudo_classes(old_focus, false, focus_visible);
         if self.current != self.focused {
             self.emit_to(old_focus, WindowEvent::FocusOut);
             self.emit_to(new_focus, WindowEvent::FocusIn);
             self.focused = self.current;
+            update_focus_scope(&self.tree, &mut self.focus_scopes, new_focus);
         }
         self.set_focus_pseudo_classes(new_focus, true, focus_visible);
 
-        self.style.needs_restyle();
+        self.style.restyle_changed_pseudo_classes(snapshot);
     }
 
     /// Sets application focus to the current entity using the previous focus visibility
@@ -341,6 +373,82 @@ impl Context {
         self.focus_with_visibility(old_focus_visible)
     }
 
+    /// Returns the key sequences which are bound in keymaps that can be active at the same time
+    /// and which cannot all be triggered as expected.
+    pub fn keymap_conflicts(&self) -> Vec<KeymapConflict> {
+        keymap_conflicts(&self.tree, &self.keymaps)
+    }
+
+    /// Makes the provided window the active window, swapping the hover, focus, and mouse state of
+    /// the previously active window into storage.
+    pub(crate) fn switch_window(&mut self, window: Entity) {
+        if window == self.active_window {
+            return;
+        }
+
+        let previous = self.active_window;
+        let mut previous_state = self
+            .windows
+            .remove(&previous)
+            .unwrap_or_else(|| WindowState::new(previous, WindowDescription::new()));
+        std::mem::swap(&mut previous_state.hovered, &mut self.hovered);
+        std::mem::swap(&mut previous_state.focused, &mut self.focused);
+        std::mem::swap(&mut previous_state.captured, &mut self.captured);
+        std::mem::swap(&mut previous_state.triggered, &mut self.triggered);
+        std::mem::swap(&mut previous_state.mouse, &mut self.mouse);
+        self.windows.insert(previous, previous_state);
+
+        let mut next_state = self
+            .windows
+            .remove(&window)
+            .unwrap_or_else(|| WindowState::new(window, WindowDescription::new()));
+        std::mem::swap(&mut next_state.hovered, &mut self.hovered);
+        std::mem::swap(&mut next_state.focused, &mut self.focused);
+        std::mem::swap(&mut next_state.captured, &mut self.captured);
+        std::mem::swap(&mut next_state.triggered, &mut self.triggered);
+        std::mem::swap(&mut next_state.mouse, &mut self.mouse);
+        self.windows.insert(window, next_state);
+
+        self.active_window = window;
+    }
+
+    /// Returns the ratio between the scale factor of the display showing a window and the scale
+    /// factor used for layout, which is that of the main window. The contents of a window are
+    /// scaled by this ratio when drawn to its canvas.
+    pub(crate) fn window_scale_ratio(&self, window: Entity) -> f32 {
+        self.windows
+            .get(&window)
+            .and_then(|window_state| window_state.scale_factor)
+            .map_or(1.0, |scale_factor| (scale_factor / self.style.dpi_factor) as f32)
+    }
+
+    /// Returns focus to the view which was focused before a view which locks focus, such as a
+    /// popup or dialog, was opened if the focused view has since been hidden with `display: none`.
+    pub(crate) fn restore_hidden_focus(&mut self) {
+        let is_displayed = |cx: &Self, entity: Entity| {
+            entity.parent_iter(&cx.tree).all(|ancestor| {
+                cx.style.display.get(ancestor).copied().unwrap_or_default() != Display::None
+            })
+        };
+
+        if is_displayed(self, self.focused) {
+            return;
+        }
+
+        while let Some((scope, previous)) = self.focus_stack.last().copied() {
+            if !self.focused.is_descendant_of(&self.tree, scope) || is_displayed(self, scope) {
+                break;
+            }
+
+            self.focus_stack.pop();
+
+            if is_displayed(self, previous) {
+                self.with_current(previous, |cx| cx.focus());
+                break;
+            }
+        }
+    }
+
     /// Removes the children of the provided entity from the application.
     pub(crate) fn remove_children(&mut self, entity: Entity) {
         let child_iter = ChildIterator::new(&self.tree, entity);
@@ -355,11 +463,31 @@ impl Context {
         let delete_list = entity.branch_iter(&self.tree).collect::<Vec<_>>();
 
         if !delete_list.is_empty() {
-            self.style.needs_restyle();
+            // Generated content is skipped by selectors, so removing it does not affect the styles
+            // of its neighbours.
+            if !self.style.generated.contains(entity) {
+                self.style.restyle_neighbours(entity, &self.tree);
+            }
             self.style.needs_relayout();
             self.style.needs_redraw();
         }
 
+        // If focus is within a removed view which locks focus, such as a popup or dialog, return
+        // focus to the view which was focused before it was opened.
+        let restore_focus = if self.focused.is_descendant_of(&self.tree, entity) {
+            self.focus_stack
+                .iter()
+                .find(|(scope, _)| delete_list.contains(scope))
+                .map(|(_, previous)| *previous)
+                .filter(|previous| !delete_list.contains(previous))
+        } else {
+            None
+        };
+
+        self.focus_stack.retain(|(scope, previous)| {
+            !delete_list.contains(scope) && !delete_list.contains(previous)
+        });
+
         for entity in delete_list.iter().rev() {
             if let Some(binding) = self.bindings.remove(entity) {
                 binding.remove(self);
@@ -376,45 +504,60 @@ impl Context {
                 self.entity_identifiers.remove(identifier);
             }
 
-            if let Some(index) = self.focus_stack.iter().position(|r| r == entity) {
-                self.focus_stack.remove(index);
-            }
+            self.focus_scopes.remove(entity);
+            self.keymaps.remove(entity);
+            self.timers.remove(*entity);
 
             if self.focused == *entity {
-                if let Some(new_focus) = self.focus_stack.pop() {
-                    self.with_current(new_focus, |cx| cx.focus());
-                } else {
-                    self.with_current(Entity::root(), |cx| cx.focus());
-                }
+                let new_focus = restore_focus.unwrap_or(Entity::root());
+                self.with_current(new_focus, |cx| cx.focus());
             }
 
             if self.captured == *entity {
                 self.captured = Entity::null();
             }
 
-            // Remove any cached filter images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some((s, t)) = self.cache.filter_image.get(*entity).cloned().flatten() {
-                    canvas.delete_image(s);
-                    canvas.delete_image(t);
-                }
+            if self.drop_target == *entity {
+                self.drop_target = Entity::null();
             }
 
-            // Remove any cached screenshot images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some(s) = self.cache.screenshot_image.get(*entity).cloned().flatten() {
-                    canvas.delete_image(s);
+            if self.drag_hovered == *entity {
+                self.drag_hovered = Entity::null();
+            }
+
+            if self.drag_preview == *entity {
+                self.drag_preview = Entity::null();
+            }
+
+            // Reset any state of inactive windows which refers to the entity.
+            for (window, window_state) in self.windows.iter_mut() {
+                if window_state.hovered == *entity {
+                    window_state.hovered = *window;
+                }
+                if window_state.focused == *entity {
+                    window_state.focused = *window;
+                }
+                if window_state.captured == *entity {
+                    window_state.captured = Entity::null();
+                }
+                if window_state.triggered == *entity {
+                    window_state.triggered = Entity::null();
                 }
             }
 
-            // Remove any cached shadow images associated with the entity.
-            if let Some(canvas) = self.canvases.get_mut(&Entity::root()) {
-                if let Some(shadows) = self.cache.shadow_images.get(*entity).cloned() {
-                    for (s, t) in shadows.into_iter().flatten() {
-                        canvas.delete_image(s);
-                        canvas.delete_image(t);
-                    }
+            if *entity != Entity::root() && self.tree.is_window(*entity) {
+                if self.active_window == *entity {
+                    self.switch_window(Entity::root());
                 }
+                self.windows.remove(entity);
+                self.pending_windows.retain(|window| window != entity);
+                self.closed_windows.push(*entity);
+            }
+
+            // Remove any cached images associated with the entity.
+            let window = self.tree.get_window(*entity);
+            if let Some(canvas) = self.canvases.get_mut(&window) {
+                self.cache.delete_images(*entity, canvas);
             }
 
             self.tree.remove(*entity).expect("");
@@ -427,6 +570,145 @@ impl Context {
         }
     }
 
+    /// Moves an already built view, along with its descendants, to be the child of `parent` at the
+    /// given index. An index past the last child of the parent appends the view to its children.
+    ///
+    /// The view keeps its state, models and bindings, so any models or view data which the bindings
+    /// within it depend on should be provided by an ancestor common to the old and new parents.
+    /// Moving a view into its own subtree, or moving the root, does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// #
+    /// let first = VStack::new(cx, |_| {}).entity();
+    /// let second = VStack::new(cx, |_| {}).entity();
+    /// let label = cx.with_current(first, |cx| Label::new(cx, "Hello").entity());
+    ///
+    /// cx.reparent(label, second, 0);
+    /// ```
+    pub fn reparent(&mut self, entity: Entity, parent: Entity, index: usize) {
+        let old_parent = self.tree.get_layout_parent(entity);
+        let old_window = self.tree.get_window(entity);
+        let old_ancestors = entity.parent_iter(&self.tree).skip(1).collect::<Vec<_>>();
+        let old_siblings = old_parent.map_or_else(Vec::new, |old_parent| {
+            LayoutChildIterator::new(&self.tree, old_parent).collect::<Vec<_>>()
+        });
+
+        if self.tree.reparent(entity, parent, index).is_err() {
+            return;
+        }
+
+        // Only the moved views and the siblings at their old and new positions can match different
+        // rules, and the descendants of the moved view are restyled along with it.
+        let new_parent = self.tree.get_layout_parent(entity);
+        let new_siblings = new_parent.map_or_else(Vec::new, |new_parent| {
+            LayoutChildIterator::new(&self.tree, new_parent).collect::<Vec<_>>()
+        });
+        for sibling in old_siblings.into_iter().chain(new_siblings) {
+            self.style.needs_restyle(sibling);
+        }
+        self.style.needs_restyle(entity);
+        self.style.needs_relayout();
+        self.style.needs_redraw();
+
+        // The focus-within state of the old ancestors of a focused view moves to its new ancestors.
+        if self.focused.is_descendant_of(&self.tree, entity) {
+            let snapshot = self.style.snapshot_pseudo_classes(
+                old_ancestors.iter().copied().chain(entity.parent_iter(&self.tree).skip(1)),
+            );
+            for ancestor in old_ancestors {
+                if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(ancestor) {
+                    pseudo_classes.set(PseudoClassFlags::FOCUS_WITHIN, false);
+                }
+            }
+            for ancestor in entity.parent_iter(&self.tree).skip(1) {
+                if let Some(pseudo_classes) = self.style.pseudo_classes.get_mut(ancestor) {
+                    pseudo_classes.set(PseudoClassFlags::FOCUS_WITHIN, true);
+                }
+            }
+            self.style.restyle_changed_pseudo_classes(snapshot);
+            update_focus_scope(&self.tree, &mut self.focus_scopes, self.focused);
+        }
+
+        if self.tree.get_window(entity) != old_window {
+            self.moved_between_windows(entity, old_window);
+        }
+
+        // Update the children of the old and new parents in the accessibility tree.
+        self.update_access_children(old_parent);
+        if new_parent != old_parent {
+            self.update_access_children(new_parent);
+        }
+    }
+
+    // Resets the state of the window a view was moved out of which refers to the view or its
+    // descendants, and deletes the images cached for them on the canvas of that window.
+    fn moved_between_windows(&mut self, entity: Entity, old_window: Entity) {
+        let moved = entity.branch_iter(&self.tree).collect::<Vec<_>>();
+
+        if self.focused.is_descendant_of(&self.tree, entity) && self.active_window == old_window {
+            self.with_current(old_window, |cx| cx.focus());
+        }
+
+        if self.captured.is_descendant_of(&self.tree, entity) && self.active_window == old_window {
+            self.captured = Entity::null();
+        }
+
+        if let Some(window_state) = self.windows.get_mut(&old_window) {
+            if window_state.hovered.is_descendant_of(&self.tree, entity) {
+                window_state.hovered = old_window;
+            }
+            if window_state.focused.is_descendant_of(&self.tree, entity) {
+                window_state.focused = old_window;
+            }
+            if window_state.captured.is_descendant_of(&self.tree, entity) {
+                window_state.captured = Entity::null();
+            }
+            if window_state.triggered.is_descendant_of(&self.tree, entity) {
+                window_state.triggered = Entity::null();
+            }
+        }
+
+        if let Some(canvas) = self.canvases.get_mut(&old_window) {
+            for entity in moved {
+                self.cache.delete_images(entity, canvas);
+            }
+        }
+    }
+
+    fn update_access_children(&mut self, parent: Option<Entity>) {
+        let parent = match parent {
+            Some(parent) => parent,
+            None => return,
+        };
+
+        let children =
+            parent.child_iter(&self.tree).map(|entity| entity.accesskit_id()).collect::<Vec<_>>();
+
+        let mut access_context = AccessContext {
+            current: parent,
+            tree: &self.tree,
+            cache: &self.cache,
+            style: &self.style,
+            text_context: &mut self.text_context,
+        };
+
+        if let Some(mut parent_node) = get_access_node(&mut access_context, &mut self.views, parent)
+        {
+            parent_node.node_builder.set_children(children);
+            let parent_node =
+                parent_node.node_builder.build(&mut self.style.accesskit_node_classes);
+            self.tree_updates.push(TreeUpdate {
+                nodes: vec![(parent.accesskit_id(), parent_node)],
+                tree: None,
+                focus: None,
+            });
+        }
+    }
+
     /// Add a listener to an entity.
     ///
     /// A listener can be used to handle events which would not normally propagate to the entity.
@@ -491,12 +773,61 @@ impl Context {
         EventContext::new(self).reload_styles().expect("Failed to reload styles");
     }
 
+    /// Adds a stylesheet to the application.
+    ///
+    /// Returns an error if the stylesheet could not be read. With the `hot_reload` feature enabled,
+    /// stylesheets read from files are reloaded automatically when the files change.
     pub fn add_stylesheet(&mut self, style: impl IntoCssStr) -> Result<(), std::io::Error> {
-        self.resource_manager.styles.push(Box::new(style));
+        self.add_stylesheet_with_diagnostics(style).map(|_| ())
+    }
 
-        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+    /// Adds a stylesheet to the application like [`add_stylesheet`](Self::add_stylesheet),
+    /// returning the problems found while parsing it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let diagnostics = cx.add_stylesheet_with_diagnostics("button { width: red; }").unwrap();
+    /// for diagnostic in diagnostics.iter() {
+    ///     eprintln!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn add_stylesheet_with_diagnostics(
+        &mut self,
+        style: impl IntoCssStr,
+    ) -> Result<Vec<StyleDiagnostic>, std::io::Error> {
+        let source = style.get_style()?;
 
-        Ok(())
+        self.resource_manager.styles.push(StyleSource::new(Box::new(style), source));
+
+        // The other stylesheets are applied as they were last read.
+        EventContext::new(self).apply_styles(false);
+
+        Ok(self
+            .resource_manager
+            .styles
+            .last()
+            .map(|style| style.diagnostics.clone())
+            .unwrap_or_default())
+    }
+
+    /// Sets a callback which is called with each problem found while parsing the stylesheets of
+    /// the application, whenever they are reloaded.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.on_style_diagnostic(|_, diagnostic| {
+    ///     eprintln!("{}", diagnostic);
+    /// });
+    /// ```
+    pub fn on_style_diagnostic<F: 'static + Fn(&mut EventContext, &StyleDiagnostic)>(
+        &mut self,
+        handler: F,
+    ) {
+        self.resource_manager.style_diagnostic_handler = Some(Box::new(handler));
     }
 
     /// Remove all user themes from the application.
@@ -533,24 +864,11 @@ impl Context {
     ) {
         match self.resource_manager.images.entry(path.to_string()) {
             Entry::Occupied(mut occ) => {
-                occ.get_mut().image = ImageOrId::Image(
-                    image,
-                    femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
-                );
-                occ.get_mut().dirty = true;
+                occ.get_mut().replace(image, &mut self.canvases);
                 occ.get_mut().retention_policy = policy;
             }
             Entry::Vacant(vac) => {
-                vac.insert(StoredImage {
-                    image: ImageOrId::Image(
-                        image,
-                        femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
-                    ),
-                    retention_policy: policy,
-                    used: true,
-                    dirty: false,
-                    observers: HashSet::new(),
-                });
+                vac.insert(StoredImage::new(image, policy));
             }
         }
         self.style.needs_relayout();
@@ -568,6 +886,38 @@ impl Context {
         std::thread::spawn(move || target(&mut cxp));
     }
 
+    /// Sends an event containing the provided message directly to the current view once the
+    /// delay has passed, without blocking or spawning a thread.
+    ///
+    /// Returns a [`TimerId`] which can be passed to
+    /// [`cancel_scheduled`](Self::cancel_scheduled) to drop the event before it is sent. Events
+    /// scheduled for a view are dropped when the view is removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// pub struct Timeout;
+    ///
+    /// let timer = cx.schedule_emit(Duration::from_millis(500), Timeout);
+    /// cx.cancel_scheduled(timer);
+    /// ```
+    pub fn schedule_emit<M: Any + Send>(&mut self, delay: Duration, message: M) -> TimerId {
+        self.timers.schedule(
+            Instant::now() + delay,
+            Event::new(message)
+                .target(self.current)
+                .origin(self.current)
+                .propagate(Propagation::Direct),
+        )
+    }
+
+    /// Drops an event scheduled with [`schedule_emit`](Self::schedule_emit) if it has not yet
+    /// been sent.
+    pub fn cancel_scheduled(&mut self, timer: TimerId) {
+        self.timers.cancel(timer);
+    }
+
     pub fn get_proxy(&self) -> ContextProxy {
         ContextProxy {
             current: self.current,
@@ -583,11 +933,19 @@ impl Context {
 
 pub(crate) enum InternalEvent {
     Redraw,
+    /// Ends the current drag-and-drop operation, dropping onto the drop target if there is one.
+    EndDrag,
     LoadImage {
         path: String,
         image: Mutex<Option<image::DynamicImage>>,
         policy: ImageRetentionPolicy,
     },
+    /// Moves an entity to be the child of a parent at an index.
+    Reparent {
+        entity: Entity,
+        parent: Entity,
+        index: usize,
+    },
 }
 
 /// A trait for any Context-like object that lets you access stored model data.
@@ -1,10 +1,10 @@
-use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::collections::{hash_map::Entry, HashMap};
 
 use vizia_storage::Tree;
 
 use crate::{
     entity::Entity,
-    resource::{ImageOrId, ImageRetentionPolicy, ResourceManager, StoredImage},
+    resource::{ImageRetentionPolicy, ResourceManager, StoredImage},
     style::Style,
 };
 
@@ -52,24 +52,11 @@ impl<'a> ResourceContext<'a> {
     ) {
         match self.resource_manager.images.entry(path) {
             Entry::Occupied(mut occ) => {
-                occ.get_mut().image = ImageOrId::Image(
-                    image,
-                    femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
-                );
-                occ.get_mut().dirty = true;
+                occ.get_mut().replace(image, self.canvases);
                 occ.get_mut().retention_policy = policy;
             }
             Entry::Vacant(vac) => {
-                vac.insert(StoredImage {
-                    image: ImageOrId::Image(
-                        image,
-                        femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
-                    ),
-                    retention_policy: policy,
-                    used: true,
-                    dirty: false,
-                    observers: HashSet::new(),
-                });
+                vac.insert(StoredImage::new(image, policy));
             }
         }
         self.style.needs_relayout();
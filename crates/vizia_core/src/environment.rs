@@ -51,6 +51,8 @@ impl Model for Environment {
         event.map(|event, _| match event {
             EnvironmentEvent::SetLocale(locale) => {
                 self.locale = locale.clone();
+                // Views may be styled with the `:lang()` pseudo-class.
+                cx.style.needs_full_restyle();
             }
 
             EnvironmentEvent::SetThemeMode(theme_mode) => {
@@ -62,6 +64,7 @@ impl Model for Environment {
             EnvironmentEvent::UseSystemLocale => {
                 self.locale =
                     sys_locale::get_locale().map(|l| l.parse().unwrap()).unwrap_or_default();
+                cx.style.needs_full_restyle();
             }
 
             EnvironmentEvent::ToggleThemeMode => {
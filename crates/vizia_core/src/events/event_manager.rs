@@ -3,7 +3,8 @@ use crate::events::EventMeta;
 use crate::prelude::*;
 use crate::style::{Abilities, PseudoClassFlags};
 use crate::systems::{compute_matched_rules, hover_system};
-use crate::tree::{focus_backward, focus_forward, is_navigatable};
+use crate::tree::{focus_backward, focus_forward, focus_order, update_focus_scope};
+use crate::views::{DialogHost, DialogHostEvent, ToastHost};
 use instant::{Duration, Instant};
 use std::any::Any;
 use vizia_id::GenerationalId;
@@ -41,11 +42,36 @@ impl EventManager {
             // Handle internal events
             event.map(|internal_event, _| match internal_event {
                 InternalEvent::Redraw => cx.needs_redraw(),
+                InternalEvent::EndDrag => end_drag(cx, true),
                 InternalEvent::LoadImage { path, image, policy } => {
                     if let Some(image) = image.lock().unwrap().take() {
                         ResourceContext::new(cx).load_image(path.clone(), image, *policy);
                     }
                 }
+                InternalEvent::Reparent { entity, parent, index } => {
+                    cx.reparent(*entity, *parent, *index)
+                }
+            });
+
+            // Stylesheets are reloaded here rather than by the window view so that reloading also
+            // works for windows created by backends without one.
+            event.map(|window_event, _| {
+                if let WindowEvent::ReloadStyles = window_event {
+                    EventContext::new(cx).reload_styles().unwrap();
+                }
+            });
+
+            // Build the hosts of dialogs and toasts once the first one is opened.
+            event.map(|dialog_event, _| {
+                if let DialogHostEvent::Open(_) = dialog_event {
+                    DialogHost::build_host(cx);
+                }
+            });
+
+            event.map(|toast_event, _| {
+                if let ToastEvent::Show { .. } = toast_event {
+                    ToastHost::build_host(cx);
+                }
             });
 
             // Send events to any global listeners
@@ -79,6 +105,10 @@ impl EventManager {
             // Handle state updates for window events
             event.map(|window_event, meta| {
                 if meta.origin == Entity::root() {
+                    // Backend window events target the window they originated from.
+                    if cx.tree.is_window(meta.target) {
+                        cx.switch_window(meta.target);
+                    }
                     internal_state_updates(cx, window_event, meta);
                 }
             });
@@ -180,7 +210,11 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
 fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, meta: &mut EventMeta) {
     match window_event {
         WindowEvent::Drop(drop_data) => {
+            // Files dropped from the operating system are dropped onto the hovered view once it has
+            // had the chance to accept them. The window event itself still reaches its target.
             context.drop_data = Some(drop_data.clone());
+            update_drag(context);
+            context.event_queue.push_back(Event::new(InternalEvent::EndDrag));
         }
 
         WindowEvent::MouseMove(x, y) => {
@@ -192,6 +226,10 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             hover_system(context);
             mutate_direct_or_up(meta, context.captured, context.hovered, false);
 
+            if context.drop_data.is_some() {
+                update_drag(context);
+            }
+
             // if let Some(dropped_file) = context.dropped_file.take() {
             //     emit_direct_or_up(
             //         context,
@@ -202,6 +240,19 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             //     );
             // }
         }
+        WindowEvent::TouchStart(_, x, y) => {
+            // A new touch point is hit tested in the same way as the mouse cursor.
+            context.mouse.previous_cursorx = context.mouse.cursorx;
+            context.mouse.previous_cursory = context.mouse.cursory;
+            context.mouse.cursorx = *x;
+            context.mouse.cursory = *y;
+
+            hover_system(context);
+            mutate_direct_or_up(meta, context.captured, context.hovered, true);
+        }
+        WindowEvent::TouchMove(..) | WindowEvent::TouchEnd(..) | WindowEvent::TouchCancel(_) => {
+            mutate_direct_or_up(meta, context.captured, context.hovered, true);
+        }
         WindowEvent::MouseDown(button) => {
             // do direct state-updates
             match button {
@@ -222,6 +273,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                             pseudo_classes.set(PseudoClassFlags::ACTIVE, true);
                         }
                     }
+                    context.style.needs_restyle(context.triggered);
                     let focusable = context
                         .style
                         .abilities
@@ -230,7 +282,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         .is_some();
 
                     // Reset drag data
-                    context.drop_data = None;
+                    end_drag(context, false);
 
                     context.with_current(
                         if focusable { context.hovered } else { context.focused },
@@ -305,6 +357,10 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             }
 
             if matches!(button, MouseButton::Left) {
+                if context.drop_data.is_some() {
+                    end_drag(context, true);
+                }
+
                 if context.hovered == context.triggered {
                     emit_direct_or_up(
                         context,
@@ -320,7 +376,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                 }
-                context.needs_restyle();
+                context.style.needs_restyle(context.triggered);
 
                 context.triggered = Entity::null();
             }
@@ -333,6 +389,10 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
         WindowEvent::KeyDown(code, _) => {
             meta.target = context.focused;
 
+            if *code == Code::Escape && context.drop_data.is_some() {
+                end_drag(context, false);
+            }
+
             #[cfg(debug_assertions)]
             if *code == Code::KeyH {
                 for entity in context.tree.into_iter() {
@@ -424,7 +484,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 && context.modifiers == Modifiers::CTRL | Modifiers::SHIFT | Modifiers::ALT
             {
                 let mut result = vec![];
-                compute_matched_rules(context, context.hovered, &mut result);
+                compute_matched_rules(context, context.hovered, None, &mut result);
 
                 let entity = context.hovered;
                 println!("/* Matched rules for Entity: {} Parent: {:?} View: {} posx: {} posy: {} width: {} height: {}",
@@ -470,7 +530,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 EventContext::new(context).reload_styles().unwrap();
             }
 
-            if *code == Code::Tab {
+            // Ctrl+Tab is left to views, such as for switching between tabs.
+            if *code == Code::Tab && !context.modifiers.contains(Modifiers::CTRL) {
                 let lock_focus_to = context.tree.lock_focus_within(context.focused);
                 if context.modifiers.contains(Modifiers::SHIFT) {
                     let prev_focused = if let Some(prev_focused) =
@@ -478,10 +539,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     {
                         prev_focused
                     } else {
-                        TreeIterator::full(&context.tree)
-                            .filter(|node| is_navigatable(context, *node, lock_focus_to))
-                            .next_back()
-                            .unwrap_or(Entity::root())
+                        let order = focus_order(context, lock_focus_to);
+                        order.last().copied().unwrap_or(Entity::root())
                     };
 
                     if prev_focused != context.focused {
@@ -501,7 +560,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         {
                             pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                         }
-                        context.needs_restyle();
+                        context.style.needs_restyle(context.triggered);
                         context.triggered = Entity::null();
                     }
                 } else {
@@ -510,9 +569,8 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                     {
                         next_focused
                     } else {
-                        TreeIterator::full(&context.tree)
-                            .find(|node| is_navigatable(context, *node, lock_focus_to))
-                            .unwrap_or(Entity::root())
+                        let order = focus_order(context, lock_focus_to);
+                        order.first().copied().unwrap_or(Entity::root())
                     };
 
                     if next_focused != context.focused {
@@ -532,7 +590,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                         {
                             pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                         }
-                        context.needs_restyle();
+                        context.style.needs_restyle(context.triggered);
                         context.triggered = Entity::null();
                     }
                 }
@@ -545,6 +603,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClassFlags::ACTIVE, true);
                 }
+                context.style.needs_restyle(context.triggered);
                 context.with_current(context.focused, |cx| {
                     cx.emit(WindowEvent::PressDown { mouse: false })
                 });
@@ -563,7 +622,7 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
                 {
                     pseudo_classes.set(PseudoClassFlags::ACTIVE, false);
                 }
-                context.needs_restyle();
+                context.style.needs_restyle(context.triggered);
                 context.triggered = Entity::null();
             }
         }
@@ -571,28 +630,41 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
             meta.target = context.focused;
         }
         WindowEvent::FocusOut => {
+            let snapshot =
+                context.style.snapshot_pseudo_classes(context.focused.parent_iter(&context.tree));
             context.set_focus_pseudo_classes(context.focused, false, true);
+            context.style.restyle_changed_pseudo_classes(snapshot);
             context.focused = Entity::null();
         }
         WindowEvent::FocusIn => {
             context.focused = meta.target;
+            update_focus_scope(&context.tree, &mut context.focus_scopes, context.focused);
+            let snapshot =
+                context.style.snapshot_pseudo_classes(context.focused.parent_iter(&context.tree));
             context.set_focus_pseudo_classes(context.focused, true, true);
+            context.style.restyle_changed_pseudo_classes(snapshot);
         }
         WindowEvent::MouseEnter => {
-            if let Some(pseudo_class) = context.style.pseudo_classes.get_mut(Entity::root()) {
+            if let Some(pseudo_class) = context.style.pseudo_classes.get_mut(context.active_window)
+            {
                 pseudo_class.set(PseudoClassFlags::OVER, true);
             }
+            context.style.needs_restyle(context.active_window);
         }
         WindowEvent::MouseLeave => {
-            if let Some(pseudo_class) = context.style.pseudo_classes.get_mut(Entity::root()) {
+            if let Some(pseudo_class) = context.style.pseudo_classes.get_mut(context.active_window)
+            {
                 pseudo_class.set(PseudoClassFlags::OVER, false);
             }
+            context.style.needs_restyle(context.active_window);
 
             let parent_iter = LayoutParentIterator::new(&context.tree, Some(context.hovered));
             for ancestor in parent_iter {
                 if let Some(pseudo_classes) = context.style.pseudo_classes.get_mut(ancestor) {
-                    pseudo_classes.set(PseudoClassFlags::HOVER, false);
-                    context.style.needs_restyle();
+                    if pseudo_classes.contains(PseudoClassFlags::HOVER) {
+                        pseudo_classes.set(PseudoClassFlags::HOVER, false);
+                        context.style.needs_restyle(ancestor);
+                    }
                 }
             }
         }
@@ -600,6 +672,90 @@ fn internal_state_updates(context: &mut Context, window_event: &WindowEvent, met
     }
 }
 
+/// Moves the drag preview to the cursor and sends drag events to the views under the cursor.
+fn update_drag(context: &mut Context) {
+    let cursorx = context.mouse.cursorx;
+    let cursory = context.mouse.cursory;
+
+    let preview = context.drag_preview;
+    if preview != Entity::null() {
+        let parent = context.tree.get_layout_parent(preview).unwrap_or(Entity::root());
+        let left = context.style.physical_to_logical(cursorx - context.cache.get_posx(parent));
+        let top = context.style.physical_to_logical(cursory - context.cache.get_posy(parent));
+        context.style.left.insert(preview, Units::Pixels(left));
+        context.style.top.insert(preview, Units::Pixels(top));
+        context.style.needs_relayout();
+        context.style.needs_redraw();
+    }
+
+    if context.hovered != context.drag_hovered {
+        context.drag_hovered = context.hovered;
+
+        // Moving between the descendants of the drop target keeps the drop target.
+        let drop_target = context.drop_target;
+        let within_target = drop_target != Entity::null()
+            && (context.hovered == drop_target
+                || context.hovered.is_descendant_of(&context.tree, drop_target));
+
+        if !within_target {
+            clear_drop_target(context);
+            context.event_queue.push_back(
+                Event::new(WindowEvent::DragEnter)
+                    .target(context.hovered)
+                    .propagate(Propagation::Up),
+            );
+        }
+    }
+
+    if context.drop_target != Entity::null() {
+        context.event_queue.push_back(
+            Event::new(WindowEvent::DragOver(cursorx, cursory)).direct(context.drop_target),
+        );
+    }
+}
+
+/// Sends a drag leave event to the drop target, if there is one, and clears the drop target.
+fn clear_drop_target(context: &mut Context) {
+    let drop_target = context.drop_target;
+    if drop_target == Entity::null() {
+        return;
+    }
+
+    if let Some(pseudo_classes) = context.style.pseudo_classes.get_mut(drop_target) {
+        pseudo_classes.set(PseudoClassFlags::DROP_TARGET, false);
+    }
+    context.style.needs_restyle(drop_target);
+
+    context.event_queue.push_back(Event::new(WindowEvent::DragLeave).direct(drop_target));
+    context.drop_target = Entity::null();
+}
+
+/// Ends the current drag-and-drop operation. If `drop` is true then the drop data is sent to the
+/// drop target, otherwise the operation is cancelled.
+fn end_drag(context: &mut Context, drop: bool) {
+    if let Some(drop_data) = context.drop_data.take() {
+        if drop && context.drop_target != Entity::null() {
+            context.event_queue.push_back(
+                Event::new(WindowEvent::Drop(drop_data))
+                    .target(context.drop_target)
+                    .origin(context.drop_target)
+                    .propagate(Propagation::Direct),
+            );
+        }
+    }
+
+    clear_drop_target(context);
+    context.drag_hovered = Entity::null();
+
+    let preview = context.drag_preview;
+    if preview != Entity::null() {
+        context.style.display.insert(preview, Display::None);
+        context.style.needs_relayout();
+        context.style.needs_redraw();
+        context.drag_preview = Entity::null();
+    }
+}
+
 fn mutate_direct_or_up(meta: &mut EventMeta, direct: Entity, up: Entity, root: bool) {
     if direct != Entity::null() {
         meta.target = direct;
@@ -623,3 +779,122 @@ fn emit_direct_or_up<M: Any + Send>(
     mutate_direct_or_up(&mut event.meta, direct, up, root);
     context.emit_custom(event);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::backend::BackendContext;
+    use std::sync::{Arc, Mutex};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    struct WindowDropLog(Log);
+
+    impl Model for WindowDropLog {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|window_event, _| {
+                if let WindowEvent::Drop(_) = window_event {
+                    self.0.lock().unwrap().push(String::from("window drop"));
+                }
+            });
+        }
+    }
+
+    fn process(cx: &mut Context) {
+        BackendContext::new_with_event_manager(cx).process_events();
+    }
+
+    fn drop_target(cx: &mut Context, log: &Log) -> Entity {
+        let (enter, leave, drop) = (log.clone(), log.clone(), log.clone());
+        VStack::new(cx, |cx| {
+            Element::new(cx);
+            Element::new(cx);
+        })
+        .on_drag_enter(move |_, _| {
+            enter.lock().unwrap().push(String::from("enter"));
+            true
+        })
+        .on_drag_leave(move |_| leave.lock().unwrap().push(String::from("leave")))
+        .on_drop(move |_, _| drop.lock().unwrap().push(String::from("drop")))
+        .entity()
+    }
+
+    fn hover(cx: &mut Context, entity: Entity) {
+        cx.hovered = entity;
+        update_drag(cx);
+        process(cx);
+    }
+
+    fn take(log: &Log) -> Vec<String> {
+        std::mem::take(&mut *log.lock().unwrap())
+    }
+
+    #[test]
+    fn drop_target_is_kept_within_its_subtree() {
+        let mut cx = Context::default();
+        let log = Log::default();
+        let target = drop_target(&mut cx, &log);
+        let other = Element::new(&mut cx).entity();
+        process(&mut cx);
+        let children = target.child_iter(&cx.tree).collect::<Vec<_>>();
+
+        cx.drop_data = Some(DropData::Id(other));
+        hover(&mut cx, children[0]);
+        assert_eq!(take(&log), ["enter"]);
+        assert_eq!(cx.drop_target, target);
+
+        hover(&mut cx, children[1]);
+        hover(&mut cx, target);
+        assert!(take(&log).is_empty());
+        assert_eq!(cx.drop_target, target);
+
+        hover(&mut cx, other);
+        assert_eq!(take(&log), ["leave"]);
+        assert_eq!(cx.drop_target, Entity::null());
+    }
+
+    #[test]
+    fn dropped_files_reach_the_window() {
+        let mut cx = Context::default();
+        let log = Log::default();
+        WindowDropLog(log.clone()).build(&mut cx);
+        let target = drop_target(&mut cx, &log);
+        process(&mut cx);
+
+        cx.hovered = target;
+        cx.emit_custom(
+            Event::new(WindowEvent::Drop(DropData::File("file.txt".into())))
+                .target(Entity::root())
+                .origin(Entity::root()),
+        );
+        process(&mut cx);
+
+        assert_eq!(take(&log), ["window drop", "enter", "drop"]);
+        assert_eq!(cx.drop_target, Entity::null());
+        assert!(cx.drop_data.is_none());
+    }
+
+    #[test]
+    fn styles_are_reloaded_without_a_window_view() {
+        let directory =
+            std::env::temp_dir().join(format!("vizia-reload-styles-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let stylesheet = directory.join("main.css");
+        std::fs::write(&stylesheet, "element { width: 5px; }\n").unwrap();
+
+        let mut cx = Context::default();
+        let entity = Element::new(&mut cx).entity();
+        cx.add_stylesheet(stylesheet.clone()).unwrap();
+        crate::systems::style_system(&mut cx);
+        assert_eq!(cx.style.width.get(entity), Some(&Units::Pixels(5.0)));
+
+        // Event proxies send the event without an origin.
+        std::fs::write(&stylesheet, "element { width: 10px; }\n").unwrap();
+        cx.emit_custom(Event::new(WindowEvent::ReloadStyles).target(Entity::root()));
+        process(&mut cx);
+        crate::systems::style_system(&mut cx);
+        assert_eq!(cx.style.width.get(entity), Some(&Units::Pixels(10.0)));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -95,4 +95,8 @@ pub use event::{Event, EventMeta, Propagation};
 mod event_handler;
 pub(crate) use event_handler::ViewHandler;
 
+mod timer;
+pub use timer::TimerId;
+pub(crate) use timer::Timers;
+
 pub use crate::window::WindowEvent;
@@ -0,0 +1,57 @@
+use instant::Instant;
+
+use crate::prelude::*;
+
+/// Identifies an event scheduled with [`EventContext::schedule_emit`], which can be used to
+/// cancel it before it is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimerId(usize);
+
+struct ScheduledEvent {
+    id: TimerId,
+    time: Instant,
+    event: Event,
+}
+
+/// Events which are held back until a point in time.
+#[derive(Default)]
+pub(crate) struct Timers {
+    next_id: usize,
+    scheduled: Vec<ScheduledEvent>,
+}
+
+impl Timers {
+    /// Holds back an event until the given time.
+    pub fn schedule(&mut self, time: Instant, event: Event) -> TimerId {
+        let id = TimerId(self.next_id);
+        self.next_id += 1;
+        self.scheduled.push(ScheduledEvent { id, time, event });
+        id
+    }
+
+    /// Drops a scheduled event which has not yet been sent.
+    pub fn cancel(&mut self, id: TimerId) {
+        self.scheduled.retain(|scheduled| scheduled.id != id);
+    }
+
+    /// Drops the scheduled events targeting a view.
+    pub fn remove(&mut self, entity: Entity) {
+        self.scheduled.retain(|scheduled| scheduled.event.meta.target != entity);
+    }
+
+    /// Returns the scheduled events whose time has passed, in the order they are due.
+    pub fn take_due(&mut self, now: Instant) -> Vec<Event> {
+        let (mut due, pending) = std::mem::take(&mut self.scheduled)
+            .into_iter()
+            .partition::<Vec<_>, _>(|scheduled| scheduled.time <= now);
+        self.scheduled = pending;
+
+        due.sort_by_key(|scheduled| (scheduled.time, scheduled.id.0));
+        due.into_iter().map(|scheduled| scheduled.event).collect()
+    }
+
+    /// Returns the time at which the next scheduled event is due.
+    pub fn next_time(&self) -> Option<Instant> {
+        self.scheduled.iter().map(|scheduled| scheduled.time).min()
+    }
+}
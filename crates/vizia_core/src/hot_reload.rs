@@ -0,0 +1,77 @@
+//! Automatic reloading of stylesheet files when they change on disk.
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::context::EventProxy;
+use crate::prelude::*;
+
+/// Watches the files of the stylesheets of an application and sends a
+/// [`WindowEvent::ReloadStyles`] event when one of them changes.
+pub(crate) struct StyleWatcher {
+    watcher: RecommendedWatcher,
+    // The stylesheet files being watched.
+    files: Arc<Mutex<HashSet<PathBuf>>>,
+    // Files are watched through their directories so that changes are still detected when an
+    // editor saves a file by replacing it.
+    directories: HashSet<PathBuf>,
+}
+
+impl StyleWatcher {
+    /// Creates a watcher which sends reload events through the given proxy.
+    pub fn new(proxy: Box<dyn EventProxy>) -> notify::Result<Self> {
+        let files = Arc::new(Mutex::new(HashSet::<PathBuf>::new()));
+        let watched_files = files.clone();
+
+        let watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+            if let Ok(event) = result {
+                if !event.kind.is_modify() && !event.kind.is_create() {
+                    return;
+                }
+
+                let files = watched_files.lock().unwrap();
+                if event.paths.iter().any(|path| files.contains(path)) {
+                    proxy.send(Event::new(WindowEvent::ReloadStyles).target(Entity::root())).ok();
+                }
+            }
+        })?;
+
+        Ok(StyleWatcher { watcher, files, directories: HashSet::new() })
+    }
+
+    /// Starts watching a stylesheet file, if it is not already being watched.
+    pub fn watch(&mut self, path: &Path) {
+        let path = match std::fs::canonicalize(path) {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+
+        if let Some(directory) = path.parent() {
+            if !self.directories.contains(directory)
+                && self.watcher.watch(directory, RecursiveMode::NonRecursive).is_ok()
+            {
+                self.directories.insert(directory.to_owned());
+            }
+        }
+
+        self.files.lock().unwrap().insert(path);
+    }
+}
+
+/// Watches a stylesheet file, creating the watcher if needed. Does nothing if the backend has no
+/// event proxy to send reload events through.
+pub(crate) fn watch_stylesheet(
+    watcher: &mut Option<StyleWatcher>,
+    proxy: &Option<Box<dyn EventProxy>>,
+    path: &Path,
+) {
+    if watcher.is_none() {
+        *watcher = proxy.as_ref().and_then(|proxy| StyleWatcher::new(proxy.make_clone()).ok());
+    }
+
+    if let Some(watcher) = watcher {
+        watcher.watch(path);
+    }
+}
@@ -0,0 +1,107 @@
+use vizia_input::KeySequence;
+
+/// The key sequences bound to each action of a [`Keymap`](crate::prelude::Keymap), in a form which
+/// can be saved to and loaded from a file.
+///
+/// A config only contains actions and key sequences, since the callbacks of keymap entries cannot
+/// be stored. Applying a config to a keymap rebinds the actions it contains to the given key
+/// sequences, while actions which are not part of the config keep their current key sequences.
+///
+/// With the `serde` feature the config can be serialized with any serde format, and with the
+/// `keymap_toml` and `keymap_json` features it can be converted to and from TOML and JSON
+/// directly. Key sequences are stored as strings such as `"Ctrl+K, Ctrl+S"`.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Debug, PartialEq, Copy, Clone)]
+/// # enum Action {
+/// #     Save,
+/// # }
+/// #
+/// let keymap = Keymap::from(vec![(
+///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+///     KeymapEntry::new(Action::Save, |_| println!("Save")),
+/// )]);
+///
+/// let mut config = keymap.config();
+/// config.bindings[0].keys = vec!["Ctrl+Shift+S".parse().unwrap()];
+///
+/// let keymap = keymap.with_config(&config);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeymapConfig<T> {
+    /// The key sequences bound to each action.
+    pub bindings: Vec<KeyBinding<T>>,
+}
+
+/// The key sequences bound to an action in a [`KeymapConfig`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyBinding<T> {
+    /// The action triggered by the key sequences.
+    pub action: T,
+    /// The key sequences which trigger the action. An empty list unbinds the action.
+    pub keys: Vec<KeySequence>,
+}
+
+impl<T> Default for KeymapConfig<T> {
+    fn default() -> Self {
+        Self { bindings: Vec::new() }
+    }
+}
+
+impl<T> KeymapConfig<T> {
+    /// Returns the key sequences bound to the given action.
+    pub fn keys(&self, action: &T) -> &[KeySequence]
+    where
+        T: PartialEq,
+    {
+        self.bindings
+            .iter()
+            .find(|binding| binding.action == *action)
+            .map(|binding| binding.keys.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "keymap_json")]
+impl<T> KeymapConfig<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Parses a config from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the config to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(feature = "keymap_toml")]
+impl<T> KeymapConfig<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Parses a config from TOML.
+    ///
+    /// ```toml
+    /// [[bindings]]
+    /// action = "Save"
+    /// keys = ["Ctrl+S"]
+    /// ```
+    pub fn from_toml(toml: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml)
+    }
+
+    /// Serializes the config to TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+}
@@ -11,6 +11,7 @@ where
 {
     action: T,
     on_action: fn(&mut EventContext),
+    priority: i32,
 }
 
 impl<T> KeymapEntry<T>
@@ -32,7 +33,30 @@ where
     /// KeymapEntry::new(Action::One, |_| println!("Action One"));
     /// ```
     pub fn new(action: T, on_action: fn(&mut EventContext)) -> Self {
-        Self { action, on_action }
+        Self { action, on_action, priority: 0 }
+    }
+
+    /// Sets the priority of the keymap entry. The default priority is zero.
+    ///
+    /// When a key sequence is bound by several entries, only the entries with the highest priority
+    /// are triggered. This also applies to entries in the keymaps of ancestor views, which would
+    /// otherwise be shadowed by the keymap closest to the focused view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Copy, Clone, PartialEq)]
+    /// # enum Action {
+    /// #     Save,
+    /// # }
+    /// #
+    /// KeymapEntry::new(Action::Save, |_| println!("Save")).with_priority(10);
+    /// ```
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
     }
 
     /// Returns the action of the keymap entry.
@@ -44,6 +68,11 @@ where
     pub fn on_action(&self) -> &fn(&mut EventContext) {
         &self.on_action
     }
+
+    /// Returns the priority of the keymap entry.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
 }
 
 impl<T> PartialEq for KeymapEntry<T>
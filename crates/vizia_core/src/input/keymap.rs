@@ -1,12 +1,33 @@
+use crate::model::insert_model;
 use crate::prelude::*;
+use crate::tree::FocusScope;
+use fnv::FnvHashMap;
+use instant::{Duration, Instant};
+use std::any::TypeId;
 use std::collections::HashMap;
+use vizia_input::KeySequence;
 
-/// A keymap that associates key chords with actions.
+/// The default time allowed between the key presses of a key sequence.
+const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// A keymap that associates key chords and key sequences with actions.
 ///
 /// This is useful if you have an application that lets the user configure their key chords.
 /// It allows you to check if a particular action is pressed rather than the actual keys.
 /// The relationship between a key chord and an action is a many-to-many relationship.
 ///
+/// A keymap handles the key presses which reach the view it is built into, which are those made
+/// while focus is within the subtree of the view. When several keymaps bind the same key chord, the
+/// keymap closest to the focused view handles it, unless an ancestor keymap binds it with a higher
+/// [priority](KeymapEntry::with_priority). This allows the same key chord to mean different things
+/// in different panels. Overlapping bindings can be listed with
+/// [`keymap_conflicts`](crate::context::EventContext::keymap_conflicts).
+///
+/// A key sequence, such as `CTRL+K, CTRL+S`, is triggered when its key chords are pressed one after
+/// another, with no more than the [timeout](Keymap::with_timeout) between them. If a sequence is
+/// also the beginning of a longer sequence, it is triggered once the next key press does not
+/// continue the longer sequence, or once the timeout passes without a further key press.
+///
 /// # Examples
 ///
 /// First we need to create something that represents an action in our application.
@@ -42,12 +63,39 @@ use std::collections::HashMap;
 ///     (KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyC), KeymapEntry::new(Action::Three, |_| println!("Action Three"))),
 /// ]);
 /// ```
-#[derive(Default)]
+///
+/// Key sequences are bound in the same way.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(PartialEq, Copy, Clone)]
+/// # enum Action {
+/// #     SaveAll,
+/// # }
+/// #
+/// let keymap = Keymap::from(vec![(
+///     KeySequence::from([
+///         KeyChord::new(Modifiers::CTRL, Code::KeyK),
+///         KeyChord::new(Modifiers::CTRL, Code::KeyS),
+///     ]),
+///     KeymapEntry::new(Action::SaveAll, |_| println!("Save All")),
+/// )]);
+/// ```
 pub struct Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
-    entries: HashMap<KeyChord, Vec<KeymapEntry<T>>>,
+    entries: HashMap<KeySequence, Vec<KeymapEntry<T>>>,
+    // Entries whose action was unbound by a config, kept so that the action can be bound again.
+    unbound: Vec<KeymapEntry<T>>,
+    scope: KeymapScope,
+    timeout: Duration,
+    pending: Vec<KeyChord>,
+    last_press: Option<Instant>,
+    timer: Option<TimerId>,
+    // Incremented whenever the pending key chords change, so that outdated timeouts are ignored.
+    generation: usize,
 }
 
 impl<T> Keymap<T>
@@ -71,20 +119,73 @@ where
     /// let keymap = Keymap::<Action>::new();
     /// ```
     pub fn new() -> Self {
-        Self { entries: HashMap::new() }
+        Self {
+            entries: HashMap::new(),
+            unbound: Vec::new(),
+            scope: KeymapScope::default(),
+            timeout: DEFAULT_SEQUENCE_TIMEOUT,
+            pending: Vec::new(),
+            last_press: None,
+            timer: None,
+            generation: 0,
+        }
+    }
+
+    /// Sets the scope in which the keymap is active.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// let keymap = Keymap::<Action>::new().with_scope(KeymapScope::FocusScope("editor".into()));
+    /// ```
+    pub fn with_scope(mut self, scope: KeymapScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Sets the maximum time allowed between the key presses of a key sequence.
+    /// The default timeout is one second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # use instant::Duration;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// let keymap = Keymap::<Action>::new().with_timeout(Duration::from_millis(500));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
     }
 
     /// Inserts an entry into the keymap.
     ///
     /// This method is for internal use only.
     /// To insert an entry into the keymap at runtime use the [`KeymapEvent::InsertAction`] event.
-    fn insert(&mut self, chord: KeyChord, keymap_entry: KeymapEntry<T>) {
-        if let Some(actions) = self.entries.get_mut(&chord) {
+    fn insert(&mut self, sequence: KeySequence, keymap_entry: KeymapEntry<T>) {
+        if sequence.is_empty() {
+            return;
+        }
+
+        if let Some(actions) = self.entries.get_mut(&sequence) {
             if !actions.contains(&keymap_entry) {
                 actions.push(keymap_entry);
             }
         } else {
-            self.entries.insert(chord, vec![keymap_entry]);
+            self.entries.insert(sequence, vec![keymap_entry]);
         }
     }
 
@@ -92,11 +193,11 @@ where
     ///
     /// This method is for internal use only.
     /// To remove an entry of the keymap at runtime use the [`KeymapEvent::RemoveAction`] event.
-    fn remove(&mut self, chord: &KeyChord, action: &T) {
-        if let Some(actions) = self.entries.get_mut(chord) {
+    fn remove(&mut self, sequence: &KeySequence, action: &T) {
+        if let Some(actions) = self.entries.get_mut(sequence) {
             if let Some(index) = actions.iter().position(|x| x == action) {
                 if actions.len() == 1 {
-                    self.entries.remove(chord);
+                    self.entries.remove(sequence);
                 } else {
                     actions.swap_remove(index);
                 }
@@ -104,6 +205,212 @@ where
         }
     }
 
+    /// Returns the key sequences bound to each action of the keymap, which can be saved to a file
+    /// and applied to the keymap again with [`with_config`](Keymap::with_config).
+    ///
+    /// Key sequences are sorted by their string representation, so that saving an unchanged keymap
+    /// produces the same file.
+    pub fn config(&self) -> KeymapConfig<T> {
+        let mut sequences = self.entries.keys().collect::<Vec<_>>();
+        sequences.sort_by_key(|sequence| sequence.to_string());
+
+        let mut config = KeymapConfig::default();
+        for sequence in sequences {
+            for entry in self.entries[sequence].iter() {
+                match config.bindings.iter_mut().find(|binding| binding.action == *entry.action()) {
+                    Some(binding) => binding.keys.push(sequence.clone()),
+                    None => config.bindings.push(KeyBinding {
+                        action: entry.action().clone(),
+                        keys: vec![sequence.clone()],
+                    }),
+                }
+            }
+        }
+
+        for entry in self.unbound.iter() {
+            config.bindings.push(KeyBinding { action: entry.action().clone(), keys: Vec::new() });
+        }
+
+        config
+    }
+
+    /// Rebinds the actions of the keymap to the key sequences of the given config.
+    ///
+    /// Actions which are not part of the config keep their key sequences, while actions of the
+    /// config which are not part of the keymap are ignored, since they have no callback.
+    /// To apply a config at runtime use the [`KeymapEvent::ApplyConfig`] event.
+    pub fn with_config(mut self, config: &KeymapConfig<T>) -> Self {
+        self.apply_config(config);
+        self
+    }
+
+    fn apply_config(&mut self, config: &KeymapConfig<T>) {
+        for binding in config.bindings.iter() {
+            let template = match self
+                .entries
+                .values()
+                .flat_map(|entries| entries.iter())
+                .chain(self.unbound.iter())
+                .find(|entry| *entry == &binding.action)
+            {
+                Some(template) => template.clone(),
+                None => continue,
+            };
+
+            self.unbound.retain(|entry| *entry != binding.action);
+            if binding.keys.is_empty() {
+                self.unbound.push(template.clone());
+            }
+
+            let sequences = self.entries.keys().cloned().collect::<Vec<_>>();
+            for sequence in sequences {
+                self.remove(&sequence, &binding.action);
+            }
+
+            for sequence in binding.keys.iter() {
+                self.insert(sequence.clone(), template.clone());
+            }
+        }
+
+        self.pending.clear();
+    }
+
+    /// Returns the bound key sequences with the highest priority of their entries.
+    fn bindings(&self) -> KeymapBindings {
+        KeymapBindings {
+            scope: self.scope.clone(),
+            sequences: self
+                .entries
+                .iter()
+                .map(|(sequence, entries)| {
+                    let priority = entries.iter().map(|entry| entry.priority()).max();
+                    (sequence.clone(), priority.unwrap_or_default())
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the highest priority of the sequences which begin with the given key chords.
+    fn priority(&self, chords: &[KeyChord]) -> Option<i32> {
+        self.entries
+            .iter()
+            .filter(|(sequence, _)| sequence.starts_with(chords))
+            .flat_map(|(_, entries)| entries.iter().map(|entry| entry.priority()))
+            .max()
+    }
+
+    /// Handles the given key chords, which either complete a sequence or begin a longer one.
+    ///
+    /// Returns false if no sequence begins with the key chords or if the key chords are left to a
+    /// keymap of an ancestor view which binds them with a higher priority.
+    fn dispatch(&mut self, cx: &mut EventContext, chords: &[KeyChord]) -> bool {
+        let priority = match self.priority(chords) {
+            Some(priority) => priority,
+            None => return false,
+        };
+
+        let deferred = cx.current.parent_iter(cx.tree).skip(1).any(|ancestor| {
+            cx.keymaps.get(&ancestor).map_or(false, |keymaps| {
+                keymaps.values().any(|bindings| {
+                    bindings.scope.is_active(cx.tree, cx.focus_scopes, *cx.focused)
+                        && bindings.sequences.iter().any(|(sequence, other_priority)| {
+                            *other_priority > priority && sequence.starts_with(chords)
+                        })
+                })
+            })
+        });
+
+        if deferred {
+            return false;
+        }
+
+        let is_prefix = self
+            .entries
+            .keys()
+            .any(|sequence| sequence.len() > chords.len() && sequence.starts_with(chords));
+
+        if is_prefix {
+            self.pending = chords.to_vec();
+            self.start_timeout(cx);
+        } else {
+            self.trigger(cx, &KeySequence::new(chords.to_vec()));
+        }
+
+        true
+    }
+
+    /// Calls the callbacks of the entries with the highest priority bound to the key sequence.
+    fn trigger(&self, cx: &mut EventContext, sequence: &KeySequence) {
+        if let Some(entries) = self.entries.get(sequence) {
+            let priority = entries.iter().map(|entry| entry.priority()).max().unwrap_or_default();
+            for entry in entries.iter().filter(|entry| entry.priority() == priority) {
+                (entry.on_action())(cx)
+            }
+        }
+    }
+
+    /// Starts the timeout after which the pending key chords are triggered if no further key is
+    /// pressed.
+    fn start_timeout(&mut self, cx: &mut EventContext) {
+        self.generation += 1;
+
+        if let Some(timer) = self.timer.take() {
+            cx.cancel_scheduled(timer);
+        }
+
+        let expire = SequenceTimeout(TypeId::of::<Self>(), self.generation);
+        self.timer = Some(cx.schedule_emit(self.timeout, expire));
+    }
+
+    /// Triggers the pending key chords, which were held back because they begin a longer sequence.
+    fn expire(&mut self, cx: &mut EventContext) {
+        self.generation += 1;
+
+        let chords = std::mem::take(&mut self.pending);
+        if !chords.is_empty() {
+            self.trigger(cx, &KeySequence::new(chords));
+        }
+    }
+
+    /// Handles a key press, returning true if the key press was used by the keymap.
+    fn key_down(&mut self, cx: &mut EventContext, code: Code) -> bool {
+        // Modifier keys on their own neither form nor interrupt a key sequence.
+        if is_modifier_code(code) {
+            return false;
+        }
+
+        // The timeout usually triggers the pending key chords, but the key press may arrive first.
+        let now = Instant::now();
+        let expired = self.last_press.map_or(false, |last| now.duration_since(last) > self.timeout);
+        if expired {
+            self.expire(cx);
+        }
+        self.last_press = Some(now);
+
+        if !self.scope.is_active(cx.tree, cx.focus_scopes, *cx.focused) {
+            self.pending.clear();
+            return false;
+        }
+
+        let chord = KeyChord::new(*cx.modifiers, code);
+        let mut chords = std::mem::take(&mut self.pending);
+        self.generation += 1;
+        chords.push(chord);
+        if self.dispatch(cx, &chords) {
+            return true;
+        }
+
+        // The key press does not continue the pending sequence. If the pending key chords were
+        // held back because they begin a longer sequence, trigger them before starting over.
+        chords.pop();
+        if chords.is_empty() {
+            return false;
+        }
+
+        self.trigger(cx, &KeySequence::new(chords));
+        self.dispatch(cx, &[chord])
+    }
+
     /// Returns an iterator over every pressed keymap entry.
     ///
     /// # Examples
@@ -127,17 +434,20 @@ where
         cx: &Context,
         code: Code,
     ) -> impl Iterator<Item = &KeymapEntry<T>> {
-        if let Some(actions) = self.entries.get(&KeyChord::new(cx.modifiers, code)) {
+        let sequence = KeySequence::from(KeyChord::new(cx.modifiers, code));
+        if let Some(actions) = self.entries.get(&sequence) {
             actions.iter()
         } else {
             [].iter()
         }
     }
 
-    /// Exports all keymap entries and their associated key chords.
+    /// Exports all keymap entries which are bound to a single key chord and their associated key
+    /// chords.
     ///
-    /// This is useful if you want to have a settings window and need to access every key chord
-    /// and keymap entry of a keymap.
+    /// This is useful if you want to have a settings window and need to access every key chord and
+    /// keymap entry of a keymap. Entries bound to key sequences of several key chords are exported
+    /// by [`export_sequences`](Keymap::export_sequences).
     ///
     /// # Examples
     ///
@@ -159,30 +469,100 @@ where
     /// ```
     pub fn export(&self) -> Vec<(&KeyChord, &KeymapEntry<T>)> {
         let mut vec = Vec::new();
-        for (chord, entries) in self.entries.iter() {
+        for (sequence, entries) in self.entries.iter() {
+            if let [chord] = sequence.chords() {
+                for entry in entries {
+                    vec.push((chord, entry));
+                }
+            }
+        }
+        vec
+    }
+
+    /// Exports all keymap entries and their associated key sequences, including those bound to a
+    /// single key chord.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Debug, PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     One,
+    /// # }
+    /// #
+    /// # let keymap = Keymap::<Action>::new();
+    /// #
+    /// let actions_sequences = keymap.export_sequences();
+    ///
+    /// for (sequence, entry) in actions_sequences {
+    ///     println!("The key sequence {} triggers the action {:?}!", sequence, entry.action());
+    /// }
+    /// ```
+    pub fn export_sequences(&self) -> Vec<(&KeySequence, &KeymapEntry<T>)> {
+        let mut vec = Vec::new();
+        for (sequence, entries) in self.entries.iter() {
             for entry in entries {
-                vec.push((chord, entry));
+                vec.push((sequence, entry));
             }
         }
         vec
     }
 }
 
+impl<T> Default for Keymap<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Model for Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
 {
+    fn build(self, cx: &mut Context) {
+        cx.keymaps.entry(cx.current()).or_default().insert(TypeId::of::<Self>(), self.bindings());
+        insert_model(cx, self);
+    }
+
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|keymap_event, _| match keymap_event {
-            KeymapEvent::InsertAction(chord, entry) => self.insert(*chord, entry.clone()),
-            KeymapEvent::RemoveAction(chord, action) => self.remove(chord, action),
+        event.map(|keymap_event, _| {
+            match keymap_event {
+                KeymapEvent::InsertAction(chord, entry) => {
+                    self.insert(KeySequence::from(*chord), entry.clone())
+                }
+                KeymapEvent::RemoveAction(chord, action) => {
+                    self.remove(&KeySequence::from(*chord), action)
+                }
+                KeymapEvent::InsertSequence(sequence, entry) => {
+                    self.insert(sequence.clone(), entry.clone())
+                }
+                KeymapEvent::RemoveSequence(sequence, action) => self.remove(sequence, action),
+                KeymapEvent::ApplyConfig(config) => self.apply_config(config),
+            }
+
+            cx.keymaps.entry(cx.current).or_default().insert(TypeId::of::<Self>(), self.bindings());
         });
-        event.map(|window_event, _| match window_event {
+
+        event.map(|timeout: &SequenceTimeout, _| {
+            if timeout.0 == TypeId::of::<Self>() && timeout.1 == self.generation {
+                self.expire(cx);
+            }
+        });
+
+        event.map(|window_event, meta| match window_event {
             WindowEvent::KeyDown(code, _) => {
-                if let Some(entries) = self.entries.get(&KeyChord::new(*cx.modifiers, *code)) {
-                    for entry in entries {
-                        (entry.on_action())(cx)
-                    }
+                // Another keymap of the same view has already handled the key press.
+                if meta.consumed {
+                    return;
+                }
+
+                if self.key_down(cx, *code) {
+                    meta.consume();
                 }
             }
             _ => {}
@@ -190,14 +570,15 @@ where
     }
 }
 
-impl<T> From<Vec<(KeyChord, KeymapEntry<T>)>> for Keymap<T>
+impl<T, S> From<Vec<(S, KeymapEntry<T>)>> for Keymap<T>
 where
     T: 'static + Clone + PartialEq + Send + Sync,
+    S: Into<KeySequence>,
 {
-    fn from(vec: Vec<(KeyChord, KeymapEntry<T>)>) -> Self {
+    fn from(vec: Vec<(S, KeymapEntry<T>)>) -> Self {
         let mut keymap = Self::new();
-        for (chord, entry) in vec {
-            keymap.insert(chord, entry);
+        for (sequence, entry) in vec {
+            keymap.insert(sequence.into(), entry);
         }
         keymap
     }
@@ -248,4 +629,464 @@ where
     /// ));
     /// ```
     RemoveAction(KeyChord, T),
+    /// Inserts an entry bound to a key sequence into the [`Keymap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(PartialEq, Copy, Clone)]
+    /// # enum Action {
+    /// #     SaveAll,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// cx.emit(KeymapEvent::InsertSequence(
+    ///     KeySequence::from([
+    ///         KeyChord::new(Modifiers::CTRL, Code::KeyK),
+    ///         KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    ///     ]),
+    ///     KeymapEntry::new(Action::SaveAll, |_| println!("Save All")),
+    /// ));
+    /// ```
+    InsertSequence(KeySequence, KeymapEntry<T>),
+    /// Removes an entry bound to a key sequence from the [`Keymap`].
+    RemoveSequence(KeySequence, T),
+    /// Rebinds the actions of the [`Keymap`] to the key sequences of a config, such as one loaded
+    /// from a settings file. See [`Keymap::with_config`].
+    ApplyConfig(KeymapConfig<T>),
+}
+
+/// Sent once the timeout of the pending key chords of a keymap has passed, with the type of the
+/// keymap and the generation of its pending key chords.
+struct SequenceTimeout(TypeId, usize);
+
+/// Determines when a [`Keymap`] is active.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapScope {
+    /// The keymap is active while focus is within the subtree of the view it is built into.
+    Subtree,
+    /// The keymap is active while focus is within a focus scope with the given name, created with
+    /// [`focus_scope`](crate::modifiers::AbilityModifiers::focus_scope), inside the subtree of the
+    /// view the keymap is built into.
+    FocusScope(String),
+}
+
+impl Default for KeymapScope {
+    fn default() -> Self {
+        KeymapScope::Subtree
+    }
+}
+
+impl KeymapScope {
+    pub(crate) fn is_active(
+        &self,
+        tree: &Tree<Entity>,
+        focus_scopes: &FnvHashMap<Entity, FocusScope>,
+        focused: Entity,
+    ) -> bool {
+        match self {
+            KeymapScope::Subtree => true,
+            KeymapScope::FocusScope(name) => focused.parent_iter(tree).any(|ancestor| {
+                focus_scopes.get(&ancestor).map_or(false, |scope| scope.name == *name)
+            }),
+        }
+    }
+
+    /// Returns true if the scopes can be active at the same time.
+    fn overlaps(&self, other: &KeymapScope) -> bool {
+        match (self, other) {
+            (KeymapScope::FocusScope(name), KeymapScope::FocusScope(other_name)) => {
+                name == other_name
+            }
+            _ => true,
+        }
+    }
+}
+
+/// The key sequences bound by a keymap, which are stored in the context so that keymaps of
+/// different views can take each other into account.
+#[derive(Debug, Clone)]
+pub(crate) struct KeymapBindings {
+    scope: KeymapScope,
+    sequences: Vec<(KeySequence, i32)>,
+}
+
+/// The bindings of every keymap, by the view it is built into and the type of the keymap.
+pub(crate) type KeymapRegistry = FnvHashMap<Entity, HashMap<TypeId, KeymapBindings>>;
+
+/// The reason two key sequences of overlapping keymaps conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapConflictKind {
+    /// Both keymaps bind the same key sequence with the same priority, so the keymap closest to the
+    /// focused view shadows the other.
+    Duplicate,
+    /// One key sequence begins with the other, so the shorter sequence is only triggered once the
+    /// next key press does not continue the longer one, or not at all if it belongs to a keymap
+    /// closer to the focused view.
+    Prefix,
+}
+
+/// Two key sequences, bound in keymaps which can be active at the same time, which cannot both be
+/// triggered as expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeymapConflict {
+    /// The reason the key sequences conflict.
+    pub kind: KeymapConflictKind,
+    /// The view which owns the keymap closest to the focused view.
+    pub entity: Entity,
+    /// The key sequence bound in the keymap closest to the focused view.
+    pub sequence: KeySequence,
+    /// The view which owns the other keymap.
+    pub other_entity: Entity,
+    /// The key sequence bound in the other keymap.
+    pub other_sequence: KeySequence,
+}
+
+/// Returns the conflicts between the bindings of all keymaps.
+pub(crate) fn keymap_conflicts(
+    tree: &Tree<Entity>,
+    keymaps: &KeymapRegistry,
+) -> Vec<KeymapConflict> {
+    let bindings = keymaps
+        .iter()
+        .flat_map(|(entity, keymaps)| {
+            keymaps.iter().flat_map(move |(type_id, bindings)| {
+                bindings.sequences.iter().map(move |(sequence, priority)| {
+                    (*entity, *type_id, &bindings.scope, sequence, *priority)
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut conflicts = Vec::new();
+    for (index, first) in bindings.iter().enumerate() {
+        for second in bindings.iter().skip(index + 1) {
+            // Order the pair so that the first binding belongs to the innermost keymap.
+            let (inner, outer) = if first.0.is_descendant_of(tree, second.0) {
+                (first, second)
+            } else if second.0.is_descendant_of(tree, first.0) {
+                (second, first)
+            } else {
+                continue;
+            };
+
+            if !inner.2.overlaps(outer.2) {
+                continue;
+            }
+
+            // Several actions bound to the same sequence of a single keymap are intentional.
+            let same_keymap = inner.0 == outer.0 && inner.1 == outer.1;
+            let kind = if inner.3 == outer.3 {
+                if same_keymap || inner.4 != outer.4 {
+                    continue;
+                }
+
+                KeymapConflictKind::Duplicate
+            } else if inner.3.starts_with(outer.3.chords()) || outer.3.starts_with(inner.3.chords())
+            {
+                KeymapConflictKind::Prefix
+            } else {
+                continue;
+            };
+
+            conflicts.push(KeymapConflict {
+                kind,
+                entity: inner.0,
+                sequence: inner.3.clone(),
+                other_entity: outer.0,
+                other_sequence: outer.3.clone(),
+            });
+        }
+    }
+
+    conflicts
+}
+
+/// Returns true if the code belongs to a modifier key, which does not form a key chord on its own.
+fn is_modifier_code(code: Code) -> bool {
+    matches!(
+        code,
+        Code::ShiftLeft
+            | Code::ShiftRight
+            | Code::ControlLeft
+            | Code::ControlRight
+            | Code::AltLeft
+            | Code::AltRight
+            | Code::MetaLeft
+            | Code::MetaRight
+            | Code::Hyper
+            | Code::Super
+            | Code::Fn
+            | Code::FnLock
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::backend::BackendContext;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Action {
+        One,
+        Two,
+        Three,
+    }
+
+    fn log(name: &'static str) {
+        LOG.with(|log| log.borrow_mut().push(name));
+    }
+
+    fn take() -> Vec<&'static str> {
+        LOG.with(|log| std::mem::take(&mut *log.borrow_mut()))
+    }
+
+    fn ctrl(code: Code) -> KeyChord {
+        KeyChord::new(Modifiers::CTRL, code)
+    }
+
+    fn sequence(chords: &[KeyChord]) -> KeySequence {
+        KeySequence::new(chords.to_vec())
+    }
+
+    fn keymap(cx: &mut Context, entity: Entity, keymap: Keymap<Action>) {
+        cx.with_current(entity, |cx| keymap.build(cx));
+    }
+
+    fn press(cx: &mut Context, chord: KeyChord) {
+        cx.modifiers = chord.modifiers;
+        let mut cx = BackendContext::new_with_event_manager(cx);
+        cx.emit_window_event(Entity::root(), WindowEvent::KeyDown(chord.code, None));
+        cx.process_events();
+    }
+
+    fn process_timers(cx: &mut Context) {
+        let mut cx = BackendContext::new_with_event_manager(cx);
+        cx.process_timers();
+        cx.process_events();
+    }
+
+    #[test]
+    fn sequences_are_matched() {
+        let cx = &mut Context::default();
+        let view = Element::new(cx).entity();
+        keymap(
+            cx,
+            view,
+            Keymap::from(vec![
+                (sequence(&[ctrl(Code::KeyS)]), KeymapEntry::new(Action::One, |_| log("save"))),
+                (sequence(&[ctrl(Code::KeyK)]), KeymapEntry::new(Action::Two, |_| log("chord"))),
+                (
+                    sequence(&[ctrl(Code::KeyK), ctrl(Code::KeyS)]),
+                    KeymapEntry::new(Action::Three, |_| log("save all")),
+                ),
+            ])
+            .with_timeout(Duration::from_secs(60)),
+        );
+        cx.focused = view;
+
+        press(cx, ctrl(Code::KeyS));
+        assert_eq!(take(), ["save"]);
+
+        // The first key chord begins a longer sequence, so it waits for the next key press.
+        press(cx, ctrl(Code::KeyK));
+        assert!(take().is_empty());
+        press(cx, ctrl(Code::KeyS));
+        assert_eq!(take(), ["save all"]);
+
+        // A key press which does not continue the sequence triggers the pending key chord.
+        press(cx, ctrl(Code::KeyK));
+        press(cx, ctrl(Code::KeyQ));
+        assert_eq!(take(), ["chord"]);
+
+        // Modifier keys on their own do not interrupt a sequence.
+        press(cx, ctrl(Code::KeyK));
+        press(cx, ctrl(Code::ControlLeft));
+        press(cx, ctrl(Code::KeyS));
+        assert_eq!(take(), ["save all"]);
+    }
+
+    #[test]
+    fn pending_sequence_is_triggered_after_timeout() {
+        let cx = &mut Context::default();
+        let view = Element::new(cx).entity();
+        keymap(
+            cx,
+            view,
+            Keymap::from(vec![
+                (sequence(&[ctrl(Code::KeyK)]), KeymapEntry::new(Action::One, |_| log("chord"))),
+                (
+                    sequence(&[ctrl(Code::KeyK), ctrl(Code::KeyS)]),
+                    KeymapEntry::new(Action::Two, |_| log("save all")),
+                ),
+            ])
+            .with_timeout(Duration::ZERO),
+        );
+        cx.focused = view;
+
+        press(cx, ctrl(Code::KeyK));
+        assert!(take().is_empty());
+        assert!(cx.timers.next_time().is_some());
+
+        process_timers(cx);
+        assert_eq!(take(), ["chord"]);
+
+        // The sequence has been abandoned, so the second key chord does nothing on its own.
+        press(cx, ctrl(Code::KeyS));
+        assert!(take().is_empty());
+    }
+
+    #[test]
+    fn keymap_is_active_in_its_focus_scope() {
+        let cx = &mut Context::default();
+        let view = Element::new(cx).entity();
+        let (inside, outside) = cx.with_current(view, |cx| {
+            let scope = Element::new(cx).focus_scope("editor").entity();
+            let inside = cx.with_current(scope, |cx| Element::new(cx).entity());
+            (inside, Element::new(cx).entity())
+        });
+        keymap(
+            cx,
+            view,
+            Keymap::from(vec![(ctrl(Code::KeyS), KeymapEntry::new(Action::One, |_| log("save")))])
+                .with_scope(KeymapScope::FocusScope(String::from("editor"))),
+        );
+
+        cx.focused = inside;
+        press(cx, ctrl(Code::KeyS));
+        assert_eq!(take(), ["save"]);
+
+        cx.focused = outside;
+        press(cx, ctrl(Code::KeyS));
+        assert!(take().is_empty());
+    }
+
+    #[test]
+    fn closest_keymap_handles_key_chord_unless_outranked() {
+        let cx = &mut Context::default();
+        let outer = Element::new(cx).entity();
+        let inner = cx.with_current(outer, |cx| Element::new(cx).entity());
+        let focused = cx.with_current(inner, |cx| Element::new(cx).entity());
+        keymap(
+            cx,
+            outer,
+            Keymap::from(vec![
+                (ctrl(Code::KeyS), KeymapEntry::new(Action::One, |_| log("outer save"))),
+                (
+                    ctrl(Code::KeyW),
+                    KeymapEntry::new(Action::Two, |_| log("outer close")).with_priority(1),
+                ),
+            ]),
+        );
+        keymap(
+            cx,
+            inner,
+            Keymap::from(vec![
+                (ctrl(Code::KeyS), KeymapEntry::new(Action::One, |_| log("inner save"))),
+                (ctrl(Code::KeyW), KeymapEntry::new(Action::Two, |_| log("inner close"))),
+            ]),
+        );
+        cx.focused = focused;
+
+        press(cx, ctrl(Code::KeyS));
+        assert_eq!(take(), ["inner save"]);
+
+        press(cx, ctrl(Code::KeyW));
+        assert_eq!(take(), ["outer close"]);
+    }
+
+    #[test]
+    fn conflicts_between_nested_keymaps() {
+        let cx = &mut Context::default();
+        let outer = Element::new(cx).entity();
+        let inner = cx.with_current(outer, |cx| Element::new(cx).entity());
+        let other = Element::new(cx).entity();
+        keymap(
+            cx,
+            outer,
+            Keymap::from(vec![
+                (sequence(&[ctrl(Code::KeyW)]), KeymapEntry::new(Action::One, |_| {})),
+                (sequence(&[ctrl(Code::KeyK)]), KeymapEntry::new(Action::Two, |_| {})),
+                (
+                    sequence(&[ctrl(Code::KeyQ)]),
+                    KeymapEntry::new(Action::Three, |_| {}).with_priority(1),
+                ),
+            ]),
+        );
+        keymap(
+            cx,
+            inner,
+            Keymap::from(vec![
+                (sequence(&[ctrl(Code::KeyW)]), KeymapEntry::new(Action::One, |_| {})),
+                (
+                    sequence(&[ctrl(Code::KeyK), ctrl(Code::KeyS)]),
+                    KeymapEntry::new(Action::Two, |_| {}),
+                ),
+                (sequence(&[ctrl(Code::KeyQ)]), KeymapEntry::new(Action::Three, |_| {})),
+            ]),
+        );
+        // Keymaps of views which are not nested cannot be active for the same focused view.
+        keymap(
+            cx,
+            other,
+            Keymap::from(vec![(ctrl(Code::KeyW), KeymapEntry::new(Action::One, |_| {}))]),
+        );
+
+        let mut conflicts = cx.keymap_conflicts();
+        conflicts.sort_by_key(|conflict| conflict.sequence.to_string());
+        assert_eq!(
+            conflicts,
+            [
+                KeymapConflict {
+                    kind: KeymapConflictKind::Prefix,
+                    entity: inner,
+                    sequence: sequence(&[ctrl(Code::KeyK), ctrl(Code::KeyS)]),
+                    other_entity: outer,
+                    other_sequence: sequence(&[ctrl(Code::KeyK)]),
+                },
+                KeymapConflict {
+                    kind: KeymapConflictKind::Duplicate,
+                    entity: inner,
+                    sequence: sequence(&[ctrl(Code::KeyW)]),
+                    other_entity: outer,
+                    other_sequence: sequence(&[ctrl(Code::KeyW)]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn export_keeps_key_chords_apart_from_sequences() {
+        let keymap = Keymap::from(vec![
+            (sequence(&[ctrl(Code::KeyS)]), KeymapEntry::new(Action::One, |_| {})),
+            (
+                sequence(&[ctrl(Code::KeyK), ctrl(Code::KeyS)]),
+                KeymapEntry::new(Action::Two, |_| {}),
+            ),
+        ]);
+
+        let exported = keymap.export();
+        assert_eq!(exported.len(), 1);
+        assert_eq!(*exported[0].0, ctrl(Code::KeyS));
+        assert_eq!(*exported[0].1.action(), Action::One);
+
+        let mut sequences = keymap
+            .export_sequences()
+            .into_iter()
+            .map(|(sequence, entry)| (sequence.to_string(), *entry.action()))
+            .collect::<Vec<_>>();
+        sequences.sort_by_key(|(sequence, _)| sequence.clone());
+        assert_eq!(
+            sequences,
+            [(String::from("Ctrl+K, Ctrl+S"), Action::Two), (String::from("Ctrl+S"), Action::One)]
+        );
+    }
 }
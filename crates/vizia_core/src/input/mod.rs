@@ -6,4 +6,7 @@ pub use keymap::*;
 mod entry;
 pub use entry::*;
 
+mod config;
+pub use config::*;
+
 pub use vizia_input::{Code, Key, Modifiers, MouseButton, MouseButtonData, MouseState};
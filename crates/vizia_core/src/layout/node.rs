@@ -2,8 +2,8 @@ use morphorm::{Node, Units};
 use vizia_storage::MorphormChildIter;
 
 use crate::prelude::*;
-use crate::resource::{ImageOrId, ResourceManager};
-use crate::style::{ImageOrGradient, Style};
+use crate::resource::ResourceManager;
+use crate::style::{ImageOrGradient, Side, Style};
 use crate::text::TextContext;
 
 pub struct SubLayout<'a> {
@@ -231,11 +231,12 @@ impl Node for Entity {
             for image in images.iter() {
                 match image {
                     ImageOrGradient::Image(image_name) => {
-                        if let Some(ImageOrId::Id(_, dim)) = sublayout
+                        if let Some(dim) = sublayout
                             .resource_manager
                             .images
                             .get(image_name)
-                            .map(|stored_img| &stored_img.image)
+                            .filter(|stored_img| !stored_img.ids.is_empty())
+                            .map(|stored_img| stored_img.dimensions())
                         {
                             max_width = max_width.max(dim.0 as f32);
                             max_height = max_height.max(dim.1 as f32);
@@ -318,7 +319,7 @@ impl Node for Entity {
     }
 
     fn border_left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_width(*self, Side::Left).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -327,7 +328,7 @@ impl Node for Entity {
     }
 
     fn border_right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_width(*self, Side::Right).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -336,7 +337,7 @@ impl Node for Entity {
     }
 
     fn border_top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_width(*self, Side::Top).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -345,7 +346,7 @@ impl Node for Entity {
     }
 
     fn border_bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_width(*self, Side::Bottom).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -10,11 +10,15 @@ pub(crate) mod animation;
 pub mod binding;
 #[doc(hidden)]
 pub mod cache;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
 pub mod context;
 #[doc(hidden)]
 pub mod entity;
 pub mod environment;
 pub mod events;
+#[cfg(feature = "hot_reload")]
+mod hot_reload;
 pub mod input;
 pub mod layout;
 pub mod localization;
@@ -54,6 +58,8 @@ pub use fonts::*;
 pub mod backend {
     #[cfg(not(target_arch = "wasm32"))]
     pub use super::accessibility::IntoNode;
+    #[cfg(feature = "clipboard")]
+    pub use super::clipboard::{ClipboardProvider, MemoryClipboard, SystemClipboard};
     pub use super::context::backend::BackendContext;
     pub use super::text::cosmic::TextConfig;
     pub use vizia_window::WindowDescription;
@@ -69,20 +75,26 @@ pub mod prelude {
     pub use crate::model::Model;
 
     pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
+    #[cfg(feature = "clipboard")]
+    pub use super::clipboard::{ClipboardData, ClipboardFormat};
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
         EventContext, ProxyEmitError,
     };
     pub use super::entity::Entity;
     pub use super::environment::{Environment, EnvironmentEvent, ThemeMode};
-    pub use super::events::{Event, Propagation};
+    pub use super::events::{Event, Propagation, TimerId};
     pub use super::include_style;
-    pub use super::input::{Keymap, KeymapEntry, KeymapEvent};
+    pub use super::input::{
+        KeyBinding, Keymap, KeymapConfig, KeymapConflict, KeymapConflictKind, KeymapEntry,
+        KeymapEvent, KeymapScope,
+    };
     pub use super::layout::{BoundingBox, GeoChanged};
     pub use super::localization::{Localized, ToStringLocalized};
     pub use super::modifiers::{
-        AbilityModifiers, AccessibilityModifiers, ActionModifiers, BoxShadowBuilder,
-        LayoutModifiers, LinearGradientBuilder, StyleModifiers, TextModifiers,
+        AbilityModifiers, AccessibilityModifiers, ActionModifiers, BoxShadowBuilder, DragGesture,
+        GestureModifiers, LayoutModifiers, LinearGradientBuilder, PointerId, StyleModifiers,
+        TextModifiers,
     };
     pub use super::resource::ImageRetentionPolicy;
     pub use super::util::{IntoCssStr, CSS};
@@ -92,7 +104,10 @@ pub mod prelude {
     pub use accesskit::{Action, DefaultActionVerb, Live, Role};
     pub use vizia_derive::{Data, Lens, Model, Setter};
     pub use vizia_id::GenerationalId;
-    pub use vizia_input::{Code, Key, KeyChord, Modifiers, MouseButton, MouseButtonState};
+    pub use vizia_input::{
+        Code, Key, KeyChord, KeyLabelStyle, KeySequence, Modifiers, MouseButton,
+        MouseButtonState,
+    };
     pub use vizia_storage::{Tree, TreeExt};
     pub use vizia_window::WindowSize;
 
@@ -63,13 +63,7 @@ pub trait Model: 'static + Sized {
     /// }
     /// ```
     fn build(self, cx: &mut Context) {
-        if let Some(model_data_store) = cx.data.get_mut(cx.current()) {
-            model_data_store.models.insert(TypeId::of::<Self>(), Box::new(self));
-        } else {
-            let mut models: HashMap<TypeId, Box<dyn ModelData>> = HashMap::new();
-            models.insert(TypeId::of::<Self>(), Box::new(self));
-            cx.data.insert(cx.current(), ModelDataStore { models, stores: HashMap::default() });
-        }
+        insert_model(cx, self);
     }
 
     /// Respond to events in order to mutate the model data.
@@ -109,6 +103,17 @@ pub trait Model: 'static + Sized {
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
 }
 
+/// Inserts the model into the model data store of the current entity.
+pub(crate) fn insert_model<M: Model>(cx: &mut Context, model: M) {
+    if let Some(model_data_store) = cx.data.get_mut(cx.current()) {
+        model_data_store.models.insert(TypeId::of::<M>(), Box::new(model));
+    } else {
+        let mut models: HashMap<TypeId, Box<dyn ModelData>> = HashMap::new();
+        models.insert(TypeId::of::<M>(), Box::new(model));
+        cx.data.insert(cx.current(), ModelDataStore { models, stores: HashMap::default() });
+    }
+}
+
 pub(crate) trait ModelData: Any {
     #[allow(unused_variables)]
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {}
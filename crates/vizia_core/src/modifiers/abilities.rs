@@ -1,6 +1,38 @@
 use super::internal;
+use crate::tree::{focus_scope_members, FocusScope};
 use crate::{prelude::*, style::Abilities};
 
+// Moves focus between the members of a focus scope with the arrow keys.
+pub(crate) struct FocusScopeModel;
+
+impl Model for FocusScopeModel {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::KeyDown(code, _) => {
+                let members = focus_scope_members(cx.tree, cx.style, cx.focus_scopes, cx.current);
+                let index = match members.iter().position(|member| *member == meta.target) {
+                    Some(index) => index,
+                    None => return,
+                };
+
+                let len = members.len();
+                let new_index = match code {
+                    Code::ArrowRight | Code::ArrowDown => (index + 1) % len,
+                    Code::ArrowLeft | Code::ArrowUp => (index + len - 1) % len,
+                    Code::Home => 0,
+                    Code::End => len - 1,
+                    _ => return,
+                };
+
+                cx.with_current(members[new_index], |cx| cx.focus_with_visibility(true));
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
 /// Modifiers for changing the abilities of a view.
 pub trait AbilityModifiers: internal::Modifiable {
     /// Sets whether the view can be hovered by the mouse.
@@ -103,6 +135,60 @@ pub trait AbilityModifiers: internal::Modifiable {
 
         self
     }
+
+    /// Sets the position of the view in the keyboard navigation order.
+    ///
+    /// Views with a positive tab index are navigated to first, in ascending order, followed by
+    /// views with a tab index of zero, or without a tab index, in tree order. Views with a negative
+    /// tab index can be focused but are skipped during keyboard navigation.
+    /// Setting a tab index also makes the view focusable.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "Second")).tab_index(2);
+    /// Button::new(cx, |_| {}, |cx| Label::new(cx, "First")).tab_index(1);
+    /// ```
+    fn tab_index(mut self, index: impl Res<i32>) -> Self {
+        let entity = self.entity();
+        index.set_or_bind(self.context(), entity, |cx, index| {
+            cx.style.tab_index.insert(cx.current, index);
+            if let Some(abilities) = cx.style.abilities.get_mut(cx.current) {
+                abilities.set(Abilities::FOCUSABLE, true);
+                abilities.set(Abilities::NAVIGABLE, index >= 0);
+            }
+        });
+
+        self
+    }
+
+    /// Makes the view a named focus scope, such as a toolbar or a radio group.
+    ///
+    /// Focus is moved between the navigable views within the scope with the arrow keys, while only
+    /// the most recently focused view within the scope is reached with tab navigation, so that tab
+    /// moves focus out of the scope. Focus can be moved into the scope with
+    /// [`cx.focus_scope()`](crate::prelude::EventContext::focus_scope).
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// HStack::new(cx, |cx| {
+    ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Cut"));
+    ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Copy"));
+    ///     Button::new(cx, |_| {}, |cx| Label::new(cx, "Paste"));
+    /// })
+    /// .focus_scope("toolbar");
+    /// ```
+    fn focus_scope(mut self, name: impl Into<String>) -> Self {
+        let entity = self.entity();
+        let cx = self.context();
+        cx.focus_scopes.insert(entity, FocusScope::new(name.into()));
+        cx.with_current(entity, |cx| FocusScopeModel.build(cx));
+
+        self
+    }
 }
 
 impl<'a, V> AbilityModifiers for Handle<'a, V> {}
@@ -56,6 +56,18 @@ pub trait AccessibilityModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets whether the content controlled by the view, such as the content of a collapsible
+    /// section or the items of a tree node, is expanded.
+    fn expanded<U: Into<bool>>(mut self, expanded: impl Res<U>) -> Self {
+        let entity = self.entity();
+        expanded.set_or_bind(self.context(), entity, |cx, expanded| {
+            cx.style.expanded.insert(cx.current, expanded.into());
+            cx.style.needs_access_update(cx.current);
+        });
+
+        self
+    }
+
     /// Sets the accessibility numeric value for the view.
     fn numeric_value<U: Into<f64>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
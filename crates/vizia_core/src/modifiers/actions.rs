@@ -27,6 +27,165 @@ impl Model for TooltipModel {
     }
 }
 
+#[derive(Lens)]
+pub struct ContextMenuModel {
+    pub context_menu_open: bool,
+    #[lens(ignore)]
+    popup: Entity,
+    #[lens(ignore)]
+    restore_focus: Entity,
+}
+
+pub(crate) enum ContextMenuEvent {
+    SetPopup(Entity),
+    Close,
+}
+
+impl ContextMenuModel {
+    // Opens the context menu at the given window position.
+    fn open(&mut self, cx: &mut EventContext, x: f32, y: f32, focus_first_item: bool) {
+        let bounds = cx.bounds();
+        let scale = cx.scale_factor();
+        let popup = self.popup;
+        cx.with_current(popup, |cx| {
+            cx.set_left(Pixels((x - bounds.x) / scale));
+            cx.set_top(Pixels((y - bounds.y) / scale));
+        });
+
+        self.context_menu_open = true;
+        self.restore_focus = cx.focused();
+
+        // Focus is moved into the menu so that it receives keyboard input.
+        let first_item = menu_items(cx, popup).first().copied();
+        match first_item {
+            Some(item) if focus_first_item => {
+                cx.with_current(item, |cx| cx.focus_with_visibility(true))
+            }
+            _ => cx.with_current(popup, |cx| cx.focus_with_visibility(false)),
+        }
+    }
+
+    // Closes the context menu and returns focus to the view which was focused when it was opened.
+    fn close(&mut self, cx: &mut EventContext) {
+        if !self.context_menu_open {
+            return;
+        }
+
+        self.context_menu_open = false;
+
+        if cx.focused().is_descendant_of(cx.tree, self.popup) {
+            let restore_focus = if cx.views.contains_key(&self.restore_focus) {
+                self.restore_focus
+            } else {
+                cx.current()
+            };
+            cx.with_current(restore_focus, |cx| cx.focus());
+        }
+    }
+
+    // Moves focus between the items of the menu in response to a navigation key.
+    fn navigate(&mut self, cx: &mut EventContext, code: Code) {
+        let items = menu_items(cx, self.popup);
+        let focused = cx.focused();
+        if items.is_empty() || (focused != self.popup && !items.contains(&focused)) {
+            return;
+        }
+
+        let len = items.len();
+        let current = items.iter().position(|item| *item == focused);
+        let index = match (code, current) {
+            (Code::ArrowDown, Some(index)) => (index + 1) % len,
+            (Code::ArrowUp, Some(index)) => (index + len - 1) % len,
+            (Code::ArrowDown, None) | (Code::Home, _) => 0,
+            (Code::ArrowUp, None) | (Code::End, _) => len - 1,
+            _ => return,
+        };
+
+        cx.with_current(items[index], |cx| cx.focus_with_visibility(true));
+    }
+}
+
+// Returns the enabled menu items at the top level of a context menu.
+fn menu_items(cx: &EventContext, popup: Entity) -> Vec<Entity> {
+    popup
+        .child_iter(cx.tree)
+        .filter(|item| {
+            !cx.style.disabled.get(*item).copied().unwrap_or_default()
+                && matches!(
+                    cx.views.get(item).and_then(|view| view.element()),
+                    Some("menubutton") | Some("submenu")
+                )
+        })
+        .collect()
+}
+
+impl Model for ContextMenuModel {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        if let Some(context_menu_event) = event.take() {
+            match context_menu_event {
+                ContextMenuEvent::SetPopup(popup) => {
+                    self.popup = popup;
+                }
+
+                ContextMenuEvent::Close => {
+                    self.close(cx);
+                }
+            }
+        }
+
+        event.map(|menu_event, _| match menu_event {
+            MenuEvent::CloseAll => {
+                self.close(cx);
+            }
+
+            _ => {}
+        });
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Right) => {
+                if !self.context_menu_open
+                    && !cx.is_disabled()
+                    && !cx.hovered().is_descendant_of(cx.tree, self.popup)
+                {
+                    let (x, y) = (cx.mouse().cursorx, cx.mouse().cursory);
+                    self.open(cx, x, y, false);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyDown(code, _) => {
+                if self.context_menu_open {
+                    if meta.target.is_descendant_of(cx.tree, self.popup) {
+                        if *code == Code::Escape {
+                            self.close(cx);
+                        } else {
+                            self.navigate(cx, *code);
+                        }
+
+                        // Prevent keyboard input within the menu from reaching the view.
+                        meta.consume();
+                    }
+                } else if (*code == Code::ContextMenu
+                    || (*code == Code::F10 && cx.modifiers().contains(Modifiers::SHIFT)))
+                    && !cx.is_disabled()
+                {
+                    let bounds = cx.bounds();
+                    self.open(cx, bounds.x, bounds.bottom(), true);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyUp(_, _) | WindowEvent::CharInput(_) => {
+                if self.context_menu_open && meta.target.is_descendant_of(cx.tree, self.popup) {
+                    meta.consume();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
 pub(crate) struct ActionsModel {
     pub(crate) on_press: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_press_down: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
@@ -43,6 +202,11 @@ pub(crate) struct ActionsModel {
     pub(crate) on_geo_changed: Option<Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>>,
     pub(crate) on_drag_start: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_drop: Option<Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>>,
+    pub(crate) on_drag_enter:
+        Option<Box<dyn Fn(&mut EventContext, &DropData) -> bool + Send + Sync>>,
+    pub(crate) on_drag_over: Option<Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>>,
+    pub(crate) on_drag_leave: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    pub(crate) drag_preview: Option<Entity>,
 }
 
 impl ActionsModel {
@@ -63,6 +227,10 @@ impl ActionsModel {
             on_geo_changed: None,
             on_drag_start: None,
             on_drop: None,
+            on_drag_enter: None,
+            on_drag_over: None,
+            on_drag_leave: None,
+            drag_preview: None,
         }
     }
 }
@@ -132,6 +300,22 @@ impl Model for ActionsModel {
                 ActionsEvent::OnDrop(on_drop) => {
                     self.on_drop = Some(on_drop);
                 }
+
+                ActionsEvent::OnDragEnter(on_drag_enter) => {
+                    self.on_drag_enter = Some(on_drag_enter);
+                }
+
+                ActionsEvent::OnDragOver(on_drag_over) => {
+                    self.on_drag_over = Some(on_drag_over);
+                }
+
+                ActionsEvent::OnDragLeave(on_drag_leave) => {
+                    self.on_drag_leave = Some(on_drag_leave);
+                }
+
+                ActionsEvent::SetDragPreview(drag_preview) => {
+                    self.drag_preview = Some(drag_preview);
+                }
             }
         }
 
@@ -163,6 +347,12 @@ impl Model for ActionsModel {
                     if let Some(action) = &self.on_drag_start {
                         (action)(cx);
                     }
+
+                    if let Some(drag_preview) = self.drag_preview {
+                        if cx.has_drop_data() {
+                            cx.set_drag_preview(drag_preview);
+                        }
+                    }
                 }
             }
 
@@ -208,13 +398,6 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_mouse_move {
                     (action)(cx, *x, *y);
                 }
-                if cx.mouse.left.state == MouseButtonState::Released {
-                    if let Some(drop_data) = cx.drop_data.take() {
-                        if let Some(action) = &self.on_drop {
-                            (action)(cx, drop_data);
-                        }
-                    }
-                }
             }
 
             WindowEvent::MouseDown(mouse_button) => {
@@ -227,10 +410,48 @@ impl Model for ActionsModel {
                 if let Some(action) = &self.on_mouse_up {
                     (action)(cx, *mouse_button);
                 }
-                if let Some(drop_data) = cx.drop_data.take() {
+            }
+
+            WindowEvent::DragEnter => {
+                // Views with a drop callback accept the drop unless rejected by `on_drag_enter`.
+                if self.on_drop.is_some() && !cx.is_disabled() {
+                    let accepted = match (&self.on_drag_enter, cx.drop_data.clone()) {
+                        (Some(action), Some(drop_data)) => (action)(cx, &drop_data),
+                        (None, Some(_)) => true,
+                        _ => false,
+                    };
+
+                    if accepted {
+                        cx.accept_drop();
+                        meta.consume();
+                    }
+                }
+            }
+
+            WindowEvent::DragOver(x, y) => {
+                if meta.target == cx.current() {
+                    if let Some(action) = &self.on_drag_over {
+                        (action)(cx, *x, *y);
+                    }
+                }
+            }
+
+            WindowEvent::DragLeave => {
+                if meta.target == cx.current() {
+                    if let Some(action) = &self.on_drag_leave {
+                        (action)(cx);
+                    }
+                }
+            }
+
+            WindowEvent::Drop(drop_data) => {
+                // Drops onto an accepting view originate from that view. Files dropped from the
+                // operating system are left for the handlers of the window.
+                if meta.target == cx.current() && meta.origin == cx.current() {
                     if let Some(action) = &self.on_drop {
-                        (action)(cx, drop_data);
+                        (action)(cx, drop_data.clone());
                     }
+                    meta.consume();
                 }
             }
 
@@ -275,6 +496,10 @@ pub(crate) enum ActionsEvent {
     OnGeoChanged(Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>),
     OnDragStart(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnDrop(Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>),
+    OnDragEnter(Box<dyn Fn(&mut EventContext, &DropData) -> bool + Send + Sync>),
+    OnDragOver(Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>),
+    OnDragLeave(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    SetDragPreview(Entity),
 }
 
 /// Modifiers which add an action callback to a view.
@@ -451,9 +676,102 @@ pub trait ActionModifiers<V> {
     where
         F: 'static + Fn(&mut EventContext) + Send + Sync;
 
+    /// Adds a callback which is performed when a drag-and-drop operation is dropped onto the view.
+    ///
+    /// The view accepts any dragged data unless a callback added with `on_drag_enter` rejects it.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_drop(|_, data| println!("Dropped: {:?}", data));
+    /// ```
     fn on_drop<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventContext, DropData) + Send + Sync;
+
+    /// Adds a callback which is performed when a drag-and-drop operation moves over the view.
+    /// The callback returns whether the view accepts the dragged data.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_drag_enter(|_, data| data.with_value(|_: &String| ()).is_some())
+    ///     .on_drop(|_, data| {
+    ///         data.with_value(|text: &String| println!("Dropped: {}", text));
+    ///     });
+    /// ```
+    fn on_drag_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &DropData) -> bool + Send + Sync;
+
+    /// Adds a callback which is performed when an accepted drag-and-drop operation moves over the view.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_drag_over(|_, x, y| println!("Dragging over: {} {}", x, y))
+    ///     .on_drop(|_, _| {});
+    /// ```
+    fn on_drag_over<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32) + Send + Sync;
+
+    /// Adds a callback which is performed when an accepted drag-and-drop operation leaves the view
+    /// or ends.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_drag_leave(|_| println!("Drag left the view!"))
+    ///     .on_drop(|_, _| {});
+    /// ```
+    fn on_drag_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
+    /// Sets the content of a preview which follows the cursor while the view is dragged.
+    ///
+    /// The preview is shown when a callback added with `on_drag` sets drop data, and can be styled
+    /// with the `drag-preview` class.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .on_drag(|cx| cx.set_drop_data(DropData::value(String::from("Hello"))))
+    ///     .drag_preview(|cx| {
+    ///         Label::new(cx, "Hello");
+    ///     });
+    /// ```
+    fn drag_preview<C: FnOnce(&mut Context)>(self, content: C) -> Self;
+
+    /// Adds a context menu to the view, which is opened at the cursor when the view is
+    /// right-clicked, or below the view when the menu key is pressed while it has focus.
+    ///
+    /// The items of the menu can be navigated with the arrow keys. The menu is closed when an item
+    /// is pressed, when the user clicks outside of the menu, or when the escape key is pressed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).context_menu(|cx| {
+    ///     MenuButton::new(cx, |_| println!("Rename"), |cx| Label::new(cx, "Rename"));
+    ///     MenuDivider::new(cx);
+    ///     MenuButton::new(cx, |_| println!("Delete"), |cx| Label::new(cx, "Delete"));
+    /// });
+    /// ```
+    fn context_menu<C>(self, content: C) -> Self
+    where
+        C: 'static + Fn(&mut Context);
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -483,6 +801,26 @@ fn build_tooltip_model(cx: &mut Context, entity: Entity) {
     }
 }
 
+fn build_context_menu_model(cx: &mut Context, entity: Entity) {
+    if cx
+        .data
+        .get(entity)
+        .and_then(|model_data_store| {
+            model_data_store.models.get(&TypeId::of::<ContextMenuModel>())
+        })
+        .is_none()
+    {
+        cx.with_current(entity, |cx| {
+            ContextMenuModel {
+                context_menu_open: false,
+                popup: Entity::null(),
+                restore_focus: Entity::null(),
+            }
+            .build(cx);
+        });
+    }
+}
+
 impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
     fn tooltip<C: FnOnce(&mut Context)>(self, content: C) -> Self {
         let entity = self.entity();
@@ -728,4 +1066,94 @@ impl<'a, V: View> ActionModifiers<V> for Handle<'a, V> {
 
         self
     }
+
+    fn on_drag_enter<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &DropData) -> bool + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragEnter(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_over<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragOver(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_drag_leave<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnDragLeave(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn drag_preview<C: FnOnce(&mut Context)>(self, content: C) -> Self {
+        let entity = self.entity();
+
+        build_action_model(self.cx, entity);
+
+        let preview = self.cx.with_current(entity, |cx| {
+            VStack::new(cx, content)
+                .class("drag-preview")
+                .position_type(PositionType::SelfDirected)
+                .size(Auto)
+                .display(Display::None)
+                .hoverable(false)
+                .z_index(100)
+                .entity()
+        });
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::SetDragPreview(preview)).target(entity).origin(entity),
+        );
+
+        self
+    }
+
+    fn context_menu<C>(self, content: C) -> Self
+    where
+        C: 'static + Fn(&mut Context),
+    {
+        let entity = self.entity();
+
+        build_context_menu_model(self.cx, entity);
+
+        let popup = self.cx.with_current(entity, |cx| {
+            MenuPopup::new(cx, ContextMenuModel::context_menu_open, false, content)
+                .class("context-menu")
+                .on_blur(|cx| cx.emit(ContextMenuEvent::Close))
+                .entity()
+        });
+
+        self.cx.emit_custom(
+            Event::new(ContextMenuEvent::SetPopup(popup)).target(entity).origin(entity),
+        );
+
+        self
+    }
 }
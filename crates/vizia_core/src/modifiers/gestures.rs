@@ -0,0 +1,794 @@
+use crate::prelude::*;
+use std::any::TypeId;
+
+/// The default distance, in logical pixels, that a pointer must move before a drag or pan is recognized.
+pub const DEFAULT_DRAG_THRESHOLD: f32 = 4.0;
+
+/// The default duration that a pointer must be held down, without moving, before a long-press is recognized.
+pub const DEFAULT_LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// Identifies the pointer which is driving a gesture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerId {
+    /// A mouse pointer with the given button held down.
+    Mouse(MouseButton),
+    /// A touch point with the given id.
+    Touch(u64),
+}
+
+/// Describes the state of a drag gesture.
+///
+/// All positions are in physical window coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragGesture {
+    /// The pointer driving the drag.
+    pub pointer: PointerId,
+    /// The position at which the pointer was first pressed.
+    pub start: (f32, f32),
+    /// The current position of the pointer.
+    pub position: (f32, f32),
+    /// The distance moved since the previous drag callback.
+    pub delta: (f32, f32),
+}
+
+impl DragGesture {
+    /// Returns the total distance moved since the pointer was first pressed.
+    pub fn offset(&self) -> (f32, f32) {
+        (self.position.0 - self.start.0, self.position.1 - self.start.1)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pointer {
+    id: PointerId,
+    start: (f32, f32),
+    position: (f32, f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GestureState {
+    // No pointers are down.
+    Idle,
+    // One or more pointers are down but no gesture has been recognized yet.
+    Pending,
+    // A long-press has been recognized and the pointer is still down.
+    LongPressed,
+    // A single pointer is being dragged.
+    Dragging(PointerId),
+    // The middle mouse button is being dragged.
+    Panning,
+    // Two touch points are moving together.
+    Pinching { distance: f32, centroid: (f32, f32) },
+}
+
+pub(crate) struct GestureModel {
+    drag_threshold: f32,
+    long_press_duration: Duration,
+    on_long_press: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
+    on_drag_start: Option<Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>>,
+    on_drag_update: Option<Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>>,
+    on_drag_end: Option<Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>>,
+    on_pinch: Option<Box<dyn Fn(&mut EventContext, f32) + Send + Sync>>,
+    on_pan: Option<Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>>,
+
+    pointers: Vec<Pointer>,
+    state: GestureState,
+    // The timer of the pending long-press.
+    long_press_timer: Option<TimerId>,
+}
+
+impl GestureModel {
+    pub(crate) fn new() -> Self {
+        Self {
+            drag_threshold: DEFAULT_DRAG_THRESHOLD,
+            long_press_duration: DEFAULT_LONG_PRESS_DURATION,
+            on_long_press: None,
+            on_drag_start: None,
+            on_drag_update: None,
+            on_drag_end: None,
+            on_pinch: None,
+            on_pan: None,
+            pointers: Vec::new(),
+            state: GestureState::Idle,
+            long_press_timer: None,
+        }
+    }
+
+    fn has_drag(&self) -> bool {
+        self.on_drag_start.is_some() || self.on_drag_update.is_some() || self.on_drag_end.is_some()
+    }
+
+    fn has_pinch_or_pan(&self) -> bool {
+        self.on_pinch.is_some() || self.on_pan.is_some()
+    }
+
+    // Attempts to claim the pointer for this recognizer by capturing mouse input.
+    // Fails if another view has already captured the input, which resolves conflicts between
+    // nested recognizers in favour of the innermost one, as pointer events propagate up the tree.
+    fn claim(cx: &mut EventContext) -> bool {
+        if *cx.captured == Entity::null() || *cx.captured == cx.current() {
+            cx.capture();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn claimed_elsewhere(cx: &EventContext) -> bool {
+        *cx.captured != Entity::null() && *cx.captured != cx.current()
+    }
+
+    fn reset(&mut self, cx: &mut EventContext) {
+        self.pointers.clear();
+        self.state = GestureState::Idle;
+        self.cancel_long_press(cx);
+        cx.release();
+    }
+
+    fn cancel_long_press(&mut self, cx: &mut EventContext) {
+        if let Some(timer) = self.long_press_timer.take() {
+            cx.cancel_scheduled(timer);
+        }
+    }
+
+    // Returns the distance between, and the centroid of, the first two pointers.
+    fn span(&self) -> (f32, (f32, f32)) {
+        let (a, b) = (self.pointers[0].position, self.pointers[1].position);
+        let distance = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+        (distance, ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0))
+    }
+
+    fn start_span(&self) -> (f32, (f32, f32)) {
+        let (a, b) = (self.pointers[0].start, self.pointers[1].start);
+        let distance = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+        (distance, ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0))
+    }
+
+    fn pointer_down(&mut self, cx: &mut EventContext, id: PointerId, position: (f32, f32)) {
+        // A stale pointer can be left behind if it was released outside of the view.
+        self.pointers.retain(|pointer| pointer.id != id);
+        if self.pointers.is_empty() || self.state == GestureState::Idle {
+            self.pointers.clear();
+            self.state = GestureState::Pending;
+        }
+
+        self.pointers.push(Pointer { id, start: position, position });
+        self.cancel_long_press(cx);
+
+        if self.pointers.len() == 1
+            && self.on_long_press.is_some()
+            && matches!(id, PointerId::Mouse(MouseButton::Left) | PointerId::Touch(_))
+        {
+            self.long_press_timer =
+                Some(cx.schedule_emit(self.long_press_duration, GestureEvent::LongPressTimeout));
+        }
+    }
+
+    fn pointer_move(&mut self, cx: &mut EventContext, id: PointerId, position: (f32, f32)) {
+        let index = match self.pointers.iter().position(|pointer| pointer.id == id) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let previous = self.pointers[index].position;
+        self.pointers[index].position = position;
+        let pointer = self.pointers[index];
+        let threshold = self.drag_threshold * cx.scale_factor();
+
+        match self.state {
+            GestureState::Pending | GestureState::LongPressed => {
+                if self.state == GestureState::Pending && Self::claimed_elsewhere(cx) {
+                    self.reset(cx);
+                    return;
+                }
+
+                if self.pointers.len() >= 2 {
+                    if !self.has_pinch_or_pan() {
+                        return;
+                    }
+
+                    let (start_distance, start_centroid) = self.start_span();
+                    let (distance, centroid) = self.span();
+                    let moved = (distance - start_distance)
+                        .abs()
+                        .max((centroid.0 - start_centroid.0).hypot(centroid.1 - start_centroid.1));
+                    if moved > threshold && Self::claim(cx) {
+                        self.cancel_long_press(cx);
+                        self.state = GestureState::Pinching {
+                            distance: start_distance,
+                            centroid: start_centroid,
+                        };
+                        self.update_pinch(cx);
+                    }
+                } else {
+                    let (dx, dy) = (position.0 - pointer.start.0, position.1 - pointer.start.1);
+                    if dx.hypot(dy) <= threshold {
+                        return;
+                    }
+
+                    // Moving beyond the threshold cancels any pending long-press.
+                    self.cancel_long_press(cx);
+
+                    match id {
+                        PointerId::Mouse(MouseButton::Middle) => {
+                            if self.on_pan.is_some() && Self::claim(cx) {
+                                self.state = GestureState::Panning;
+                                if let Some(action) = &self.on_pan {
+                                    (action)(cx, dx, dy);
+                                }
+                            }
+                        }
+
+                        _ => {
+                            if self.has_drag() && Self::claim(cx) {
+                                self.state = GestureState::Dragging(id);
+                                let gesture = DragGesture {
+                                    pointer: id,
+                                    start: pointer.start,
+                                    position,
+                                    delta: (dx, dy),
+                                };
+                                if let Some(action) = &self.on_drag_start {
+                                    (action)(cx, gesture);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            GestureState::Dragging(dragging) => {
+                if dragging == id {
+                    let gesture = DragGesture {
+                        pointer: id,
+                        start: pointer.start,
+                        position,
+                        delta: (position.0 - previous.0, position.1 - previous.1),
+                    };
+                    if let Some(action) = &self.on_drag_update {
+                        (action)(cx, gesture);
+                    }
+                }
+            }
+
+            GestureState::Panning => {
+                if let Some(action) = &self.on_pan {
+                    (action)(cx, position.0 - previous.0, position.1 - previous.1);
+                }
+            }
+
+            GestureState::Pinching { .. } => {
+                if index < 2 {
+                    self.update_pinch(cx);
+                }
+            }
+
+            GestureState::Idle => {}
+        }
+    }
+
+    fn update_pinch(&mut self, cx: &mut EventContext) {
+        if let GestureState::Pinching { distance, centroid } = self.state {
+            let (new_distance, new_centroid) = self.span();
+
+            if distance > 0.0 && new_distance != distance {
+                if let Some(action) = &self.on_pinch {
+                    (action)(cx, new_distance / distance);
+                }
+            }
+
+            if new_centroid != centroid {
+                if let Some(action) = &self.on_pan {
+                    (action)(cx, new_centroid.0 - centroid.0, new_centroid.1 - centroid.1);
+                }
+            }
+
+            self.state = GestureState::Pinching { distance: new_distance, centroid: new_centroid };
+        }
+    }
+
+    fn pointer_up(&mut self, cx: &mut EventContext, id: PointerId) {
+        let index = match self.pointers.iter().position(|pointer| pointer.id == id) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let pointer = self.pointers.remove(index);
+
+        match self.state {
+            GestureState::Dragging(dragging) if dragging == id => {
+                let gesture = DragGesture {
+                    pointer: id,
+                    start: pointer.start,
+                    position: pointer.position,
+                    delta: (0.0, 0.0),
+                };
+                if let Some(action) = &self.on_drag_end {
+                    (action)(cx, gesture);
+                }
+                self.reset(cx);
+            }
+
+            GestureState::Panning if id == PointerId::Mouse(MouseButton::Middle) => {
+                self.reset(cx);
+            }
+
+            // Lifting either finger ends a pinch, the remaining finger does not start a drag.
+            GestureState::Pinching { .. } => {
+                self.reset(cx);
+            }
+
+            _ => {
+                if self.pointers.is_empty() {
+                    self.reset(cx);
+                }
+            }
+        }
+    }
+
+    fn long_press(&mut self, cx: &mut EventContext) {
+        if self.long_press_timer.take().is_none()
+            || self.state != GestureState::Pending
+            || self.pointers.len() != 1
+        {
+            return;
+        }
+
+        if Self::claim(cx) {
+            self.state = GestureState::LongPressed;
+            if let Some(action) = &self.on_long_press {
+                (action)(cx);
+            }
+        } else {
+            self.reset(cx);
+        }
+    }
+
+    fn mouse_pointer(button: MouseButton) -> Option<PointerId> {
+        match button {
+            MouseButton::Left | MouseButton::Middle => Some(PointerId::Mouse(button)),
+            _ => None,
+        }
+    }
+}
+
+impl Model for GestureModel {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        if let Some(gesture_event) = event.take() {
+            match gesture_event {
+                GestureEvent::OnLongPress(on_long_press) => {
+                    self.on_long_press = Some(on_long_press);
+                }
+
+                GestureEvent::OnDragStart(on_drag_start) => {
+                    self.on_drag_start = Some(on_drag_start);
+                }
+
+                GestureEvent::OnDragUpdate(on_drag_update) => {
+                    self.on_drag_update = Some(on_drag_update);
+                }
+
+                GestureEvent::OnDragEnd(on_drag_end) => {
+                    self.on_drag_end = Some(on_drag_end);
+                }
+
+                GestureEvent::OnPinch(on_pinch) => {
+                    self.on_pinch = Some(on_pinch);
+                }
+
+                GestureEvent::OnPan(on_pan) => {
+                    self.on_pan = Some(on_pan);
+                }
+
+                GestureEvent::DragThreshold(threshold) => {
+                    self.drag_threshold = threshold;
+                }
+
+                GestureEvent::LongPressDuration(duration) => {
+                    self.long_press_duration = duration;
+                }
+
+                GestureEvent::LongPressTimeout => {
+                    self.long_press(cx);
+                }
+            }
+        }
+
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(button) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                if let Some(id) = Self::mouse_pointer(*button) {
+                    let position = (cx.mouse.cursorx, cx.mouse.cursory);
+                    self.pointer_down(cx, id, position);
+                }
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                for button in [MouseButton::Left, MouseButton::Middle] {
+                    self.pointer_move(cx, PointerId::Mouse(button), (*x, *y));
+                }
+            }
+
+            WindowEvent::MouseUp(button) => {
+                if let Some(id) = Self::mouse_pointer(*button) {
+                    self.pointer_up(cx, id);
+                }
+            }
+
+            WindowEvent::MouseScroll(_, y) => {
+                // Ctrl + scroll wheel is treated as a pinch when using a mouse.
+                if self.on_pinch.is_some() && cx.modifiers.contains(Modifiers::CTRL) {
+                    if let Some(action) = &self.on_pinch {
+                        (action)(cx, 1.1f32.powf(*y));
+                    }
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::TouchStart(id, x, y) => {
+                if !cx.is_disabled() {
+                    self.pointer_down(cx, PointerId::Touch(*id), (*x, *y));
+                }
+            }
+
+            WindowEvent::TouchMove(id, x, y) => {
+                self.pointer_move(cx, PointerId::Touch(*id), (*x, *y));
+            }
+
+            WindowEvent::TouchEnd(id, _, _) => {
+                self.pointer_up(cx, PointerId::Touch(*id));
+            }
+
+            WindowEvent::TouchCancel(id) => {
+                if self.pointers.iter().any(|pointer| pointer.id == PointerId::Touch(*id)) {
+                    self.reset(cx);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+pub(crate) enum GestureEvent {
+    OnLongPress(Box<dyn Fn(&mut EventContext) + Send + Sync>),
+    OnDragStart(Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>),
+    OnDragUpdate(Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>),
+    OnDragEnd(Box<dyn Fn(&mut EventContext, DragGesture) + Send + Sync>),
+    OnPinch(Box<dyn Fn(&mut EventContext, f32) + Send + Sync>),
+    OnPan(Box<dyn Fn(&mut EventContext, f32, f32) + Send + Sync>),
+    DragThreshold(f32),
+    LongPressDuration(Duration),
+    LongPressTimeout,
+}
+
+/// Modifiers which add gesture recognizers to a view.
+///
+/// Gesture recognizers work with both mouse and touch input. Once a gesture has been recognized the
+/// view captures the pointer until the gesture ends. When recognizers are nested, the innermost view
+/// which handles a gesture claims it, and any ancestor recognizers waiting on the same pointer are cancelled.
+pub trait GestureModifiers<V> {
+    /// Adds a callback which is performed when the primary pointer is held down on the view, without
+    /// moving beyond the drag threshold, for the long-press duration.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_long_press(|_| println!("View was long-pressed!"));
+    /// ```
+    fn on_long_press<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync;
+
+    /// Adds a callback which is performed when the primary pointer is pressed on the view and then
+    /// moved beyond the drag threshold.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_drag_start(|_, drag| println!("Drag started at {:?}", drag.start));
+    /// ```
+    fn on_drag_start<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync;
+
+    /// Adds a callback which is performed when the pointer moves during a drag.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_drag_update(|_, drag| println!("Dragged by {:?}", drag.offset()));
+    /// ```
+    fn on_drag_update<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync;
+
+    /// Adds a callback which is performed when the pointer is released at the end of a drag.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_drag_end(|_, drag| println!("Drag ended at {:?}", drag.position));
+    /// ```
+    fn on_drag_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync;
+
+    /// Adds a callback which is performed when two touch points move apart or together, or when the
+    /// mouse wheel is scrolled while the control key is held.
+    ///
+    /// The callback receives the scale factor relative to the previous pinch callback.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_pinch(|_, scale| println!("Pinched by {}", scale));
+    /// ```
+    fn on_pinch<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32) + Send + Sync;
+
+    /// Adds a callback which is performed when two touch points move together, or when the view is
+    /// dragged with the middle mouse button.
+    ///
+    /// The callback receives the distance moved, in physical pixels, since the previous pan callback.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_pan(|_, dx, dy| println!("Panned by {} {}", dx, dy));
+    /// ```
+    fn on_pan<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32) + Send + Sync;
+
+    /// Sets the distance, in logical pixels, that a pointer must move before a drag or pan is recognized.
+    ///
+    /// Defaults to [`DEFAULT_DRAG_THRESHOLD`].
+    fn drag_threshold(self, threshold: f32) -> Self;
+
+    /// Sets the duration that a pointer must be held down for before a long-press is recognized.
+    ///
+    /// Defaults to [`DEFAULT_LONG_PRESS_DURATION`].
+    fn long_press_duration(self, duration: Duration) -> Self;
+}
+
+// If the entity doesn't have a `GestureModel` then add one to the entity
+fn build_gesture_model(cx: &mut Context, entity: Entity) {
+    if cx
+        .data
+        .get(entity)
+        .and_then(|model_data_store| model_data_store.models.get(&TypeId::of::<GestureModel>()))
+        .is_none()
+    {
+        cx.with_current(entity, |cx| {
+            GestureModel::new().build(cx);
+        });
+    }
+}
+
+impl<'a, V: View> Handle<'a, V> {
+    fn emit_gesture_event(self, event: GestureEvent) -> Self {
+        build_gesture_model(self.cx, self.entity);
+
+        self.cx.emit_custom(Event::new(event).target(self.entity).origin(self.entity));
+
+        self
+    }
+}
+
+impl<'a, V: View> GestureModifiers<V> for Handle<'a, V> {
+    fn on_long_press<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnLongPress(Box::new(action)))
+    }
+
+    fn on_drag_start<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnDragStart(Box::new(action)))
+    }
+
+    fn on_drag_update<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnDragUpdate(Box::new(action)))
+    }
+
+    fn on_drag_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, DragGesture) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnDragEnd(Box::new(action)))
+    }
+
+    fn on_pinch<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnPinch(Box::new(action)))
+    }
+
+    fn on_pan<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32, f32) + Send + Sync,
+    {
+        self.emit_gesture_event(GestureEvent::OnPan(Box::new(action)))
+    }
+
+    fn drag_threshold(self, threshold: f32) -> Self {
+        self.emit_gesture_event(GestureEvent::DragThreshold(threshold))
+    }
+
+    fn long_press_duration(self, duration: Duration) -> Self {
+        self.emit_gesture_event(GestureEvent::LongPressDuration(duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::backend::BackendContext;
+    use std::sync::{Arc, Mutex};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    fn context() -> Context {
+        let mut cx = Context::default();
+        cx.style.dpi_factor = 1.0;
+        cx
+    }
+
+    fn process(cx: &mut Context) {
+        let mut cx = BackendContext::new_with_event_manager(cx);
+        cx.process_timers();
+        cx.process_events();
+    }
+
+    fn send(cx: &mut Context, entity: Entity, event: WindowEvent) {
+        cx.emit_custom(Event::new(event).target(entity).origin(entity));
+        process(cx);
+    }
+
+    fn recognizer(cx: &mut Context, log: &Log, name: &'static str) -> Entity {
+        let (press, start, update, end, pinch, pan) =
+            (log.clone(), log.clone(), log.clone(), log.clone(), log.clone(), log.clone());
+        let entity = Element::new(cx)
+            .long_press_duration(Duration::from_secs(60))
+            .on_long_press(move |_| press.lock().unwrap().push(format!("{name} long press")))
+            .on_drag_start(move |_, drag| {
+                start.lock().unwrap().push(format!("{name} start {:?}", drag.delta))
+            })
+            .on_drag_update(move |_, drag| {
+                update.lock().unwrap().push(format!("{name} update {:?}", drag.delta))
+            })
+            .on_drag_end(move |_, drag| {
+                end.lock().unwrap().push(format!("{name} end {:?}", drag.offset()))
+            })
+            .on_pinch(move |_, scale| pinch.lock().unwrap().push(format!("{name} pinch {scale}")))
+            .on_pan(move |_, dx, dy| pan.lock().unwrap().push(format!("{name} pan {dx} {dy}")))
+            .entity();
+        process(cx);
+        entity
+    }
+
+    fn take(log: &Log) -> Vec<String> {
+        std::mem::take(&mut *log.lock().unwrap())
+    }
+
+    #[test]
+    fn long_press_after_timeout() {
+        let mut cx = context();
+        let log = Log::default();
+        let view = recognizer(&mut cx, &log, "view");
+        cx.emit_custom(Event::new(GestureEvent::LongPressDuration(Duration::ZERO)).target(view));
+        process(&mut cx);
+
+        send(&mut cx, view, WindowEvent::TouchStart(0, 10.0, 10.0));
+        process(&mut cx);
+        assert_eq!(take(&log), ["view long press"]);
+        assert_eq!(cx.captured, view);
+
+        send(&mut cx, view, WindowEvent::TouchEnd(0, 10.0, 10.0));
+        assert_eq!(cx.captured, Entity::null());
+        assert!(take(&log).is_empty());
+    }
+
+    #[test]
+    fn long_press_is_cancelled() {
+        let mut cx = context();
+        let log = Log::default();
+        let view = recognizer(&mut cx, &log, "view");
+
+        // Releasing the pointer cancels the timer.
+        send(&mut cx, view, WindowEvent::TouchStart(0, 10.0, 10.0));
+        assert!(cx.timers.next_time().is_some());
+        send(&mut cx, view, WindowEvent::TouchEnd(0, 10.0, 10.0));
+        assert!(cx.timers.next_time().is_none());
+
+        // Moving beyond the drag threshold cancels the timer.
+        send(&mut cx, view, WindowEvent::TouchStart(0, 10.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchMove(0, 20.0, 10.0));
+        assert!(cx.timers.next_time().is_none());
+        assert_eq!(take(&log), ["view start (10.0, 0.0)"]);
+    }
+
+    #[test]
+    fn drag_after_threshold() {
+        let mut cx = context();
+        let log = Log::default();
+        let view = recognizer(&mut cx, &log, "view");
+
+        send(&mut cx, view, WindowEvent::TouchStart(0, 10.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchMove(0, 13.0, 10.0));
+        assert!(take(&log).is_empty());
+        assert_eq!(cx.captured, Entity::null());
+
+        send(&mut cx, view, WindowEvent::TouchMove(0, 16.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchMove(0, 16.0, 12.0));
+        assert_eq!(cx.captured, view);
+
+        send(&mut cx, view, WindowEvent::TouchEnd(0, 16.0, 12.0));
+        assert_eq!(
+            take(&log),
+            ["view start (6.0, 0.0)", "view update (0.0, 2.0)", "view end (6.0, 2.0)"]
+        );
+        assert_eq!(cx.captured, Entity::null());
+    }
+
+    #[test]
+    fn pinch_and_pan() {
+        let mut cx = context();
+        let log = Log::default();
+        let view = recognizer(&mut cx, &log, "view");
+
+        // Two touch points moving apart are a pinch, and lifting one of them ends it.
+        send(&mut cx, view, WindowEvent::TouchStart(0, 10.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchStart(1, 20.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchMove(1, 30.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchEnd(0, 10.0, 10.0));
+        send(&mut cx, view, WindowEvent::TouchMove(1, 50.0, 10.0));
+        assert_eq!(take(&log), ["view pinch 2", "view pan 5 0"]);
+        assert_eq!(cx.captured, Entity::null());
+
+        // Dragging with the middle mouse button is a pan.
+        send(&mut cx, view, WindowEvent::MouseDown(MouseButton::Middle));
+        send(&mut cx, view, WindowEvent::MouseMove(0.0, 5.0));
+        send(&mut cx, view, WindowEvent::MouseMove(2.0, 5.0));
+        send(&mut cx, view, WindowEvent::MouseUp(MouseButton::Middle));
+        assert_eq!(take(&log), ["view pan 0 5", "view pan 2 0"]);
+        assert_eq!(cx.captured, Entity::null());
+    }
+
+    #[test]
+    fn innermost_recognizer_wins() {
+        let mut cx = context();
+        let log = Log::default();
+        let parent = recognizer(&mut cx, &log, "parent");
+        let child = cx.with_current(parent, |cx| recognizer(cx, &log, "child"));
+
+        // Pointer events propagate from the child to the parent.
+        send(&mut cx, child, WindowEvent::TouchStart(0, 10.0, 10.0));
+        send(&mut cx, child, WindowEvent::TouchMove(0, 20.0, 10.0));
+        send(&mut cx, child, WindowEvent::TouchMove(0, 30.0, 10.0));
+        send(&mut cx, child, WindowEvent::TouchEnd(0, 30.0, 10.0));
+        assert_eq!(
+            take(&log),
+            ["child start (10.0, 0.0)", "child update (10.0, 0.0)", "child end (20.0, 0.0)"]
+        );
+        assert!(cx.timers.next_time().is_none());
+    }
+}
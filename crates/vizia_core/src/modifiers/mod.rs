@@ -84,6 +84,9 @@ pub use accessibility::*;
 mod actions;
 pub use actions::*;
 
+mod gestures;
+pub use gestures::*;
+
 mod layout;
 pub use layout::*;
 
@@ -5,6 +5,7 @@ use vizia_style::{
 use super::internal;
 use crate::prelude::*;
 use crate::style::{Abilities, ImageOrGradient, PseudoClassFlags, SystemFlags};
+use std::collections::HashMap;
 
 /// Modifiers for changing the style properties of a view.
 pub trait StyleModifiers: internal::Modifiable {
@@ -31,7 +32,7 @@ pub trait StyleModifiers: internal::Modifiable {
         let id = id.into();
         let entity = self.entity();
         self.context().style.ids.insert(entity, id.clone());
-        self.context().needs_restyle();
+        self.context().style.needs_restyle(entity);
 
         self.context().entity_identifiers.insert(id, entity);
 
@@ -60,7 +61,7 @@ pub trait StyleModifiers: internal::Modifiable {
             class_list.insert(name.to_string());
         }
 
-        self.context().needs_restyle();
+        self.context().style.needs_restyle(entity);
 
         self
     }
@@ -84,6 +85,66 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets an attribute of the view with the given name and value.
+    ///
+    /// Attributes can be referenced by CSS attribute selectors, and are suited to enumerated
+    /// state, such as a size or a variant, which would otherwise need a class for each value.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).attr("size", "small");
+    /// ```
+    /// css
+    /// ```css
+    /// [size="small"] {
+    ///     width: 20px;
+    /// }
+    ///```
+    fn attr<U: ToString>(mut self, name: &str, value: impl Res<U>) -> Self {
+        let name = name.to_owned();
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, move |cx, value| {
+            let value = value.to_string();
+            if let Some(attributes) = cx.style.attributes.get_mut(cx.current) {
+                attributes.insert(name.clone(), value);
+            } else {
+                let mut attributes = HashMap::new();
+                attributes.insert(name.clone(), value);
+                cx.style.attributes.insert(cx.current, attributes);
+            }
+
+            cx.needs_restyle();
+        });
+
+        self
+    }
+
+    /// Sets the text direction of the view, which is inherited by its descendants.
+    ///
+    /// The direction can be referenced by the `:dir()` CSS pseudo-class.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).direction(Direction::Rtl);
+    /// ```
+    /// css
+    /// ```css
+    /// element:dir(rtl) {
+    ///     child-left: 1s;
+    /// }
+    ///```
+    fn direction<U: Into<Direction>>(mut self, direction: impl Res<U>) -> Self {
+        let entity = self.entity();
+        direction.set_or_bind(self.context(), entity, |cx, direction| {
+            cx.style.direction.insert(cx.current, direction.into());
+            cx.needs_restyle();
+        });
+
+        self
+    }
+
     // PseudoClassFlags
     // TODO: Should these have their own modifiers trait?
 
@@ -187,6 +248,21 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets whether the view is a portal.
+    ///
+    /// A portal remains a child of its parent for events, bindings, styling and layout, but is
+    /// drawn and hit tested in an overlay layer above all other views of its window. This allows
+    /// popups and tooltips to escape the clipping of their ancestors, such as a scrollview, without
+    /// needing a large z-index. Portals are kept within the bounds of their window.
+    fn portal(mut self, flag: bool) -> Self {
+        let entity = self.entity();
+        self.context().tree.set_portal(entity, flag);
+        self.context().needs_relayout();
+        self.context().needs_redraw();
+
+        self
+    }
+
     /// Sets the clip path for the the view.
     fn clip_path<U: Into<ClipPath>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -231,8 +307,31 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
-    /// Sets the backdrop filter for the view.
-    fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
+    /// Sets the filters applied to the rendered content of the view and its children.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).filter(vec![
+    ///     Filter::Grayscale(PercentageOrNumber::Number(1.0)),
+    ///     Filter::Opacity(PercentageOrNumber::Percentage(50.0)),
+    /// ]);
+    /// ```
+    fn filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, v| {
+            let value = v.into();
+            cx.style.filter.insert(cx.current, value);
+
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    /// Sets the filters applied to the content behind the view.
+    fn backdrop_filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         value.set_or_bind(self.context(), entity, |cx, v| {
             let value = v.into();
@@ -312,20 +411,150 @@ pub trait StyleModifiers: internal::Modifiable {
     }
 
     // Border Properties
+    /// Sets the border width of all four sides of the view.
+    fn border_width<U: Into<LengthOrPercentage>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, v| {
+            let value = v.into();
+            cx.style.border_top_width.insert(cx.current, value.clone());
+            cx.style.border_right_width.insert(cx.current, value.clone());
+            cx.style.border_bottom_width.insert(cx.current, value.clone());
+            cx.style.border_left_width.insert(cx.current, value);
+
+            cx.needs_relayout();
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
     modifier!(
-        /// Sets the border width of the view.
-        border_width,
+        /// Sets the width of the top border of the view.
+        border_top_width,
         LengthOrPercentage,
         SystemFlags::RELAYOUT | SystemFlags::REDRAW
     );
 
     modifier!(
-        /// Sets the border color of the view.
-        border_color,
+        /// Sets the width of the right border of the view.
+        border_right_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the bottom border of the view.
+        border_bottom_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the left border of the view.
+        border_left_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    /// Sets the border color of all four sides of the view.
+    fn border_color<U: Into<BorderColor>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, v| {
+            let value = v.into();
+            cx.style.border_top_color.insert(cx.current, value.top);
+            cx.style.border_right_color.insert(cx.current, value.right);
+            cx.style.border_bottom_color.insert(cx.current, value.bottom);
+            cx.style.border_left_color.insert(cx.current, value.left);
+
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the color of the top border of the view.
+        border_top_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the right border of the view.
+        border_right_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the bottom border of the view.
+        border_bottom_color,
         Color,
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets the color of the left border of the view.
+        border_left_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the border style of all four sides of the view.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx)
+    ///     .border_width(Pixels(2.0))
+    ///     .border_color(Color::gray())
+    ///     .border_style(BorderStyleKeyword::Dashed);
+    /// ```
+    fn border_style<U: Into<BorderStyle>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        value.set_or_bind(self.context(), entity, |cx, v| {
+            let value = v.into();
+            cx.style.border_top_style.insert(cx.current, value.top);
+            cx.style.border_right_style.insert(cx.current, value.right);
+            cx.style.border_bottom_style.insert(cx.current, value.bottom);
+            cx.style.border_left_style.insert(cx.current, value.left);
+
+            cx.needs_relayout();
+            cx.needs_redraw();
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the style of the top border of the view.
+        border_top_style,
+        BorderStyleKeyword,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the right border of the view.
+        border_right_style,
+        BorderStyleKeyword,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the bottom border of the view.
+        border_bottom_style,
+        BorderStyleKeyword,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the left border of the view.
+        border_left_style,
+        BorderStyleKeyword,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
     modifier!(
         /// Sets the border radius for the top-left corner of the view.
         border_top_left_radius,
@@ -435,6 +664,13 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    modifier!(
+        /// Sets the outline style of the view.
+        outline_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
     modifier!(
         /// Sets the outline offset of the view.
         outline_offset,
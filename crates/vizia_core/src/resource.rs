@@ -1,40 +1,91 @@
 //! Resource management for fonts, themes, images, and translations.
 
-use crate::context::ResourceContext;
+use crate::context::{EventContext, ResourceContext};
 use crate::entity::Entity;
-use crate::prelude::IntoCssStr;
+#[cfg(feature = "hot_reload")]
+use crate::hot_reload::StyleWatcher;
+use crate::prelude::{IntoCssStr, StyleDiagnostic};
 use crate::view::Canvas;
+use cosmic_text::fontdb::Database;
 use fluent_bundle::{FluentBundle, FluentResource};
 use image::GenericImageView;
-use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use unic_langid::LanguageIdentifier;
 
 pub(crate) struct StoredImage {
-    pub image: ImageOrId,
+    pub image: image::DynamicImage,
+    pub flags: femtovg::ImageFlags,
+    /// The id of the image in the canvas of each window it has been uploaded to.
+    pub ids: HashMap<Entity, femtovg::ImageId>,
     pub retention_policy: ImageRetentionPolicy,
     pub used: bool,
     pub dirty: bool,
     pub observers: HashSet<Entity>,
 }
 
-pub(crate) enum ImageOrId {
-    Image(image::DynamicImage, femtovg::ImageFlags),
-    Id(femtovg::ImageId, (u32, u32)),
-}
+impl StoredImage {
+    pub fn new(image: image::DynamicImage, retention_policy: ImageRetentionPolicy) -> Self {
+        StoredImage {
+            image,
+            flags: femtovg::ImageFlags::REPEAT_X | femtovg::ImageFlags::REPEAT_Y,
+            ids: HashMap::new(),
+            retention_policy,
+            used: true,
+            dirty: false,
+            observers: HashSet::new(),
+        }
+    }
+
+    /// Returns the id of the image in the canvas of a window, uploading it the first time it is
+    /// used in that window. Each window has its own rendering context, so an image id can only be
+    /// drawn to the canvas which created it.
+    pub fn id(&mut self, window: Entity, canvas: &mut Canvas) -> Option<femtovg::ImageId> {
+        if let Some(id) = self.ids.get(&window) {
+            return Some(*id);
+        }
+
+        let source = femtovg::ImageSource::try_from(&self.image).ok()?;
+        let id = canvas.create_image(source, self.flags).ok()?;
+        self.ids.insert(window, id);
+        Some(id)
+    }
 
-impl ImageOrId {
-    pub fn id(&mut self, canvas: &mut Canvas) -> femtovg::ImageId {
-        match self {
-            ImageOrId::Image(image, flags) => {
-                let image_ref: &image::DynamicImage = image.borrow();
-                let res = canvas
-                    .create_image(femtovg::ImageSource::try_from(image_ref).unwrap(), *flags)
-                    .unwrap();
-                *self = ImageOrId::Id(res, image.dimensions());
-                res
+    /// Replaces the image, deleting any copies of the previous image which have been uploaded.
+    pub fn replace(&mut self, image: image::DynamicImage, canvases: &mut HashMap<Entity, Canvas>) {
+        for (window, id) in self.ids.drain() {
+            if let Some(canvas) = canvases.get_mut(&window) {
+                canvas.delete_image(id);
             }
-            ImageOrId::Id(i, _) => *i,
+        }
+
+        self.image = image;
+        self.dirty = true;
+    }
+
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.image.dimensions()
+    }
+}
+
+/// A stylesheet added to the application, along with what is kept of it between reloads.
+pub(crate) struct StyleSource {
+    pub style: Box<dyn IntoCssStr>,
+    /// The last version of the stylesheet which parsed without errors.
+    pub source: Option<String>,
+    /// The last version of each of the files imported by the stylesheet which parsed without errors.
+    pub imports: HashMap<PathBuf, String>,
+    /// The diagnostics from the last time the stylesheet was parsed.
+    pub diagnostics: Vec<StyleDiagnostic>,
+}
+
+impl StyleSource {
+    pub fn new(style: Box<dyn IntoCssStr>, source: String) -> Self {
+        StyleSource {
+            style,
+            source: Some(source),
+            imports: HashMap::new(),
+            diagnostics: Vec::new(),
         }
     }
 }
@@ -50,7 +101,13 @@ pub enum ImageRetentionPolicy {
 #[derive(Default)]
 pub struct ResourceManager {
     pub themes: Vec<String>, // Themes are the string content stylesheets
-    pub styles: Vec<Box<dyn IntoCssStr>>,
+    pub(crate) styles: Vec<StyleSource>,
+    /// The font files which have been loaded by `@font-face` rules, along with the families of
+    /// their fonts.
+    pub(crate) font_faces: HashMap<PathBuf, Vec<String>>,
+    pub(crate) style_diagnostic_handler: Option<Box<dyn Fn(&mut EventContext, &StyleDiagnostic)>>,
+    #[cfg(feature = "hot_reload")]
+    pub(crate) style_watcher: Option<StyleWatcher>,
     pub(crate) images: HashMap<String, StoredImage>,
     pub translations: HashMap<LanguageIdentifier, FluentBundle<FluentResource>>,
 
@@ -97,6 +154,10 @@ impl ResourceManager {
             themes: Vec::new(),
             images: HashMap::new(),
             styles: Vec::new(),
+            font_faces: HashMap::new(),
+            style_diagnostic_handler: None,
+            #[cfg(feature = "hot_reload")]
+            style_watcher: None,
 
             translations: HashMap::from([(
                 LanguageIdentifier::default(),
@@ -163,4 +224,36 @@ impl ResourceManager {
             ImageRetentionPolicy::Forever => true,
         });
     }
+
+    /// Loads a font file used by a `@font-face` rule, returning the families of its fonts. Each
+    /// file is only read the first time it is used.
+    pub(crate) fn load_font_face(
+        &mut self,
+        db: &mut Database,
+        path: &Path,
+    ) -> Result<&[String], std::io::Error> {
+        if !self.font_faces.contains_key(path) {
+            let data = std::fs::read(path)?;
+
+            let loaded = db.faces().map(|face| face.id).collect::<Vec<_>>();
+            db.load_font_data(data);
+
+            let families = db
+                .faces()
+                .filter(|face| !loaded.contains(&face.id))
+                .filter_map(|face| face.families.first().map(|(family, _)| family.clone()))
+                .collect::<Vec<_>>();
+
+            if families.is_empty() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "not a TrueType or OpenType font",
+                ));
+            }
+
+            self.font_faces.insert(path.to_owned(), families);
+        }
+
+        Ok(&self.font_faces[path])
+    }
 }
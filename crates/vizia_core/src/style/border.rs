@@ -0,0 +1,468 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::prelude::*;
+use crate::vg::{Paint, Path, Solidity};
+
+/// One of the four sides of a border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub const ALL: [Side; 4] = [Side::Top, Side::Right, Side::Bottom, Side::Left];
+}
+
+/// The geometry of a border or outline in physical pixels, used to draw sides with their own
+/// width, color and style.
+///
+/// The border fills the band between the edge of `bounds` and the same rectangle inset by the
+/// width of each side. Each side is separated from its neighbours at the middle of the corner
+/// between them, which for square corners is the diagonal from the outer to the inner corner.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BorderShape {
+    pub bounds: BoundingBox,
+    /// The widths of the top, right, bottom and left sides.
+    pub widths: [f32; 4],
+    /// The radii of the top-left, top-right, bottom-right and bottom-left corners, measured at
+    /// the center line of the border like the path used to draw the background.
+    pub radii: [f32; 4],
+    /// The shapes of the top-left, top-right, bottom-right and bottom-left corners.
+    pub shapes: [BorderCornerShape; 4],
+}
+
+impl BorderShape {
+    /// Draws each side of the border in its style and color.
+    pub fn draw(&self, canvas: &mut Canvas, styles: [BorderStyleKeyword; 4], colors: [Color; 4]) {
+        if self.widths.iter().all(|width| *width <= 0.0) {
+            return;
+        }
+
+        // A solid border of a single color is filled in one go to avoid seams between the sides.
+        if styles.iter().all(|style| *style == BorderStyleKeyword::Solid)
+            && colors.iter().all(|color| *color == colors[0])
+        {
+            let mut path = Path::new();
+            self.contour(&mut path, 0.0);
+            self.contour(&mut path, 1.0);
+            path.solidity(Solidity::Hole);
+            canvas.fill_path(&path, &Paint::color(colors[0].into()));
+            return;
+        }
+
+        for side in Side::ALL {
+            let width = self.widths[side as usize];
+            let color = colors[side as usize];
+            if width <= 0.0 || color.a() == 0 {
+                continue;
+            }
+
+            // Sides facing the top-left of the view are in shadow for the 3D styles.
+            let in_shadow = matches!(side, Side::Top | Side::Left);
+            let dark = shade(color, 0.0);
+            let light = shade(color, 255.0);
+
+            match styles[side as usize] {
+                BorderStyleKeyword::None | BorderStyleKeyword::Hidden => {}
+
+                BorderStyleKeyword::Solid => self.fill_band(canvas, side, 0.0, 1.0, color),
+
+                BorderStyleKeyword::Double => {
+                    if width < 3.0 {
+                        self.fill_band(canvas, side, 0.0, 1.0, color);
+                    } else {
+                        self.fill_band(canvas, side, 0.0, 1.0 / 3.0, color);
+                        self.fill_band(canvas, side, 2.0 / 3.0, 1.0, color);
+                    }
+                }
+
+                BorderStyleKeyword::Groove | BorderStyleKeyword::Ridge => {
+                    let groove = styles[side as usize] == BorderStyleKeyword::Groove;
+                    let (outer, inner) =
+                        if groove == in_shadow { (dark, light) } else { (light, dark) };
+                    self.fill_band(canvas, side, 0.0, 0.5, outer);
+                    self.fill_band(canvas, side, 0.5, 1.0, inner);
+                }
+
+                BorderStyleKeyword::Inset | BorderStyleKeyword::Outset => {
+                    let inset = styles[side as usize] == BorderStyleKeyword::Inset;
+                    let color = if inset == in_shadow { dark } else { light };
+                    self.fill_band(canvas, side, 0.0, 1.0, color);
+                }
+
+                BorderStyleKeyword::Dashed => self.draw_dashes(canvas, side, color),
+
+                BorderStyleKeyword::Dotted => self.draw_dots(canvas, side, color),
+            }
+        }
+    }
+
+    // Fills the part of a side between two depths through the border, where a depth of 0 is the
+    // outer edge and 1 is the inner edge.
+    fn fill_band(&self, canvas: &mut Canvas, side: Side, from: f32, to: f32, color: Color) {
+        let outer = self.side_points(side, from);
+        let inner = self.side_points(side, to);
+
+        let mut path = Path::new();
+        polygon(&mut path, &outer, &inner);
+        canvas.fill_path(&path, &Paint::color(color.into()));
+    }
+
+    // Fills dashes about twice as long as the border is wide along a side. The side begins and
+    // ends with half a dash, which joins the dash of the neighbouring side around the corner.
+    fn draw_dashes(&self, canvas: &mut Canvas, side: Side, color: Color) {
+        let width = self.widths[side as usize];
+        let outer = self.side_points(side, 0.0);
+        let inner = self.side_points(side, 1.0);
+        let distances = center_distances(&outer, &inner);
+        let length = distances[distances.len() - 1];
+
+        let count = ((length / (3.0 * width)).round() as usize).max(1);
+        let period = length / count as f32;
+
+        let mut path = Path::new();
+        for index in 0..=count {
+            let middle = index as f32 * period;
+            let start = (middle - period / 3.0).max(0.0);
+            let end = (middle + period / 3.0).min(length);
+            if end <= start {
+                continue;
+            }
+
+            let (mut dash_outer, mut dash_inner) = (Vec::new(), Vec::new());
+            let (o, i) = point_at(&outer, &inner, &distances, start);
+            dash_outer.push(o);
+            dash_inner.push(i);
+            for (point, distance) in distances.iter().enumerate() {
+                if *distance > start && *distance < end {
+                    dash_outer.push(outer[point]);
+                    dash_inner.push(inner[point]);
+                }
+            }
+            let (o, i) = point_at(&outer, &inner, &distances, end);
+            dash_outer.push(o);
+            dash_inner.push(i);
+
+            polygon(&mut path, &dash_outer, &dash_inner);
+        }
+
+        canvas.fill_path(&path, &Paint::color(color.into()));
+    }
+
+    // Fills round dots as wide as the border along a side, with a gap of about the same size
+    // between them. The dot at the end of the side is drawn by the next side.
+    fn draw_dots(&self, canvas: &mut Canvas, side: Side, color: Color) {
+        let width = self.widths[side as usize];
+        let outer = self.side_points(side, 0.0);
+        let inner = self.side_points(side, 1.0);
+        let distances = center_distances(&outer, &inner);
+        let length = distances[distances.len() - 1];
+
+        let count = ((length / (2.0 * width)).round() as usize).max(1);
+        let period = length / count as f32;
+
+        let mut path = Path::new();
+        for index in 0..count {
+            let (o, i) = point_at(&outer, &inner, &distances, index as f32 * period);
+            path.circle((o.0 + i.0) / 2.0, (o.1 + i.1) / 2.0, width / 2.0);
+        }
+
+        canvas.fill_path(&path, &Paint::color(color.into()));
+    }
+
+    // Adds the closed contour at a depth through the border to a path.
+    fn contour(&self, path: &mut Path, depth: f32) {
+        for corner in 0..4 {
+            let segments = self.segments(corner);
+            for step in 0..=segments {
+                let (x, y) = self.corner_point(corner, depth, step as f32 / segments as f32);
+                if corner == 0 && step == 0 {
+                    path.move_to(x, y);
+                } else {
+                    path.line_to(x, y);
+                }
+            }
+        }
+        path.close();
+    }
+
+    // Returns the points of a side at a depth through the border, from the middle of the corner
+    // before it to the middle of the corner after it, in clockwise order. Points at the same
+    // index correspond for every depth.
+    fn side_points(&self, side: Side, depth: f32) -> Vec<(f32, f32)> {
+        let start = side as usize;
+        let end = (start + 1) % 4;
+
+        let mut points = Vec::new();
+        let segments = self.segments(start);
+        for step in segments / 2..=segments {
+            points.push(self.corner_point(start, depth, step as f32 / segments as f32));
+        }
+
+        let segments = self.segments(end);
+        for step in 0..=segments / 2 {
+            points.push(self.corner_point(end, depth, step as f32 / segments as f32));
+        }
+
+        points
+    }
+
+    // Returns the (even) number of line segments used to approximate a corner.
+    fn segments(&self, corner: usize) -> usize {
+        let (rx, ry) = self.corner_radii(corner, 0.0);
+        if self.shapes[corner] == BorderCornerShape::Bevel {
+            return 2;
+        }
+
+        ((rx.max(ry) / 4.0).ceil() as usize).clamp(1, 16) * 2
+    }
+
+    // Returns the horizontal and vertical radii of a corner at a depth through the border.
+    fn corner_radii(&self, corner: usize, depth: f32) -> (f32, f32) {
+        let [top, right, bottom, left] = self.widths;
+        let b = self.bounds;
+
+        // The widths of the sides next to the corner, which change its horizontal and vertical
+        // radius through the border.
+        let (horizontal, vertical) = match corner {
+            0 => (left, top),
+            1 => (right, top),
+            2 => (right, bottom),
+            _ => (left, bottom),
+        };
+
+        let half_w = ((b.w - (left + right) / 2.0) / 2.0).max(0.0);
+        let half_h = ((b.h - (top + bottom) / 2.0) / 2.0).max(0.0);
+        let radius = self.radii[corner].max(0.0);
+
+        let radius_at = |radius: f32, half: f32, width: f32, size: f32| {
+            let radius = radius.min(half);
+            if radius > 0.0 {
+                (radius + width * (0.5 - depth)).clamp(0.0, (size / 2.0).max(0.0))
+            } else {
+                0.0
+            }
+        };
+
+        let w = b.w - depth * (left + right);
+        let h = b.h - depth * (top + bottom);
+        (radius_at(radius, half_w, horizontal, w), radius_at(radius, half_h, vertical, h))
+    }
+
+    // Returns the point at `t` along a corner at a depth through the border, where the corner
+    // runs clockwise from `t = 0` to `t = 1`.
+    fn corner_point(&self, corner: usize, depth: f32, t: f32) -> (f32, f32) {
+        let [top, right, bottom, left] = self.widths;
+        let b = self.bounds;
+
+        let x0 = b.x + depth * left;
+        let y0 = b.y + depth * top;
+        let x1 = b.x + b.w - depth * right;
+        let y1 = b.y + b.h - depth * bottom;
+
+        let (rx, ry) = self.corner_radii(corner, depth);
+        let (cx, cy) = match corner {
+            0 => (x0 + rx, y0 + ry),
+            1 => (x1 - rx, y0 + ry),
+            2 => (x1 - rx, y1 - ry),
+            _ => (x0 + rx, y1 - ry),
+        };
+
+        let start = PI + corner as f32 * FRAC_PI_2;
+        if self.shapes[corner] == BorderCornerShape::Bevel {
+            let (sx, sy) = (cx + rx * start.cos(), cy + ry * start.sin());
+            let end = start + FRAC_PI_2;
+            let (ex, ey) = (cx + rx * end.cos(), cy + ry * end.sin());
+            (sx + (ex - sx) * t, sy + (ey - sy) * t)
+        } else {
+            let angle = start + t * FRAC_PI_2;
+            (cx + rx * angle.cos(), cy + ry * angle.sin())
+        }
+    }
+}
+
+// Adds a closed polygon running along the outer points and back along the inner points.
+fn polygon(path: &mut Path, outer: &[(f32, f32)], inner: &[(f32, f32)]) {
+    path.move_to(outer[0].0, outer[0].1);
+    for (x, y) in outer.iter().skip(1).chain(inner.iter().rev()) {
+        path.line_to(*x, *y);
+    }
+    path.close();
+}
+
+// Returns the distance along the center line between corresponding outer and inner points to
+// each of them.
+fn center_distances(outer: &[(f32, f32)], inner: &[(f32, f32)]) -> Vec<f32> {
+    let center = outer
+        .iter()
+        .zip(inner)
+        .map(|(o, i)| ((o.0 + i.0) / 2.0, (o.1 + i.1) / 2.0))
+        .collect::<Vec<_>>();
+
+    let mut distances = vec![0.0];
+    for pair in center.windows(2) {
+        let step = (pair[1].0 - pair[0].0).hypot(pair[1].1 - pair[0].1);
+        distances.push(distances[distances.len() - 1] + step);
+    }
+
+    distances
+}
+
+// Returns the outer and inner points at a distance along the center line.
+fn point_at(
+    outer: &[(f32, f32)],
+    inner: &[(f32, f32)],
+    distances: &[f32],
+    distance: f32,
+) -> ((f32, f32), (f32, f32)) {
+    let last = distances.len() - 1;
+    let index = distances.windows(2).position(|pair| distance <= pair[1]).unwrap_or(last - 1);
+    let next = (index + 1).min(last);
+
+    let span = distances[next] - distances[index];
+    let t = if span > 0.0 { ((distance - distances[index]) / span).clamp(0.0, 1.0) } else { 0.0 };
+    let lerp = |a: (f32, f32), b: (f32, f32)| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t);
+
+    (lerp(outer[index], outer[next]), lerp(inner[index], inner[next]))
+}
+
+// Mixes a third of black or white into a color, to shade the sides of the 3D border styles.
+fn shade(color: Color, towards: f32) -> Color {
+    let mix = |channel: u8| (channel as f32 + (towards - channel as f32) / 3.0).round() as u8;
+    Color::rgba(mix(color.r()), mix(color.g()), mix(color.b()), color.a())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(widths: [f32; 4], radii: [f32; 4], corner: BorderCornerShape) -> BorderShape {
+        BorderShape {
+            bounds: BoundingBox { x: 0.0, y: 0.0, w: 100.0, h: 50.0 },
+            widths,
+            radii,
+            shapes: [corner; 4],
+        }
+    }
+
+    fn assert_points(actual: &[(f32, f32)], expected: &[(f32, f32)]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                (a.0 - e.0).abs() < 1e-3 && (a.1 - e.1).abs() < 1e-3,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn square_corners() {
+        let border = shape([10.0; 4], [0.0; 4], BorderCornerShape::Round);
+
+        assert_eq!(border.segments(0), 2);
+        assert_eq!(border.corner_radii(0, 0.0), (0.0, 0.0));
+        assert_points(&[border.corner_point(0, 0.0, 0.5)], &[(0.0, 0.0)]);
+        assert_points(&[border.corner_point(2, 1.0, 0.5)], &[(90.0, 40.0)]);
+
+        // The top side runs between the diagonals of its corners.
+        let outer = border.side_points(Side::Top, 0.0);
+        let inner = border.side_points(Side::Top, 1.0);
+        assert_points(&outer, &[(0.0, 0.0), (0.0, 0.0), (100.0, 0.0), (100.0, 0.0)]);
+        assert_points(&inner, &[(10.0, 10.0), (10.0, 10.0), (90.0, 10.0), (90.0, 10.0)]);
+
+        let left = border.side_points(Side::Left, 1.0);
+        assert_points(&left, &[(10.0, 40.0), (10.0, 40.0), (10.0, 10.0), (10.0, 10.0)]);
+    }
+
+    #[test]
+    fn rounded_corners() {
+        let border = shape([10.0; 4], [20.0; 4], BorderCornerShape::Round);
+
+        // The radius is measured at the center line and grows towards the outer edge.
+        assert_eq!(border.corner_radii(0, 0.0), (25.0, 25.0));
+        assert_eq!(border.corner_radii(0, 0.5), (20.0, 20.0));
+        assert_eq!(border.corner_radii(0, 1.0), (15.0, 15.0));
+        assert_eq!(border.segments(0), 14);
+
+        // Corners run clockwise, so the top-left corner starts on the left edge.
+        let diagonal = 25.0 * (1.0 - 0.5f32.sqrt());
+        assert_points(
+            &[
+                border.corner_point(0, 0.0, 0.0),
+                border.corner_point(0, 0.0, 0.5),
+                border.corner_point(0, 0.0, 1.0),
+            ],
+            &[(0.0, 25.0), (diagonal, diagonal), (25.0, 0.0)],
+        );
+        assert_points(
+            &[border.corner_point(2, 0.0, 0.0), border.corner_point(2, 0.0, 1.0)],
+            &[(100.0, 25.0), (75.0, 50.0)],
+        );
+
+        // The inner edge shares the center of the outer edge.
+        let inner = 25.0 - 15.0 * 0.5f32.sqrt();
+        assert_points(&[border.corner_point(0, 1.0, 0.5)], &[(inner, inner)]);
+
+        // Radii larger than the view are limited to half its size along each axis.
+        let border = shape([10.0; 4], [100.0; 4], BorderCornerShape::Round);
+        assert_eq!(border.corner_radii(1, 0.0), (50.0, 25.0));
+        assert_eq!(border.corner_radii(1, 1.0), (40.0, 15.0));
+    }
+
+    #[test]
+    fn bevelled_corners() {
+        let border = shape([10.0; 4], [20.0; 4], BorderCornerShape::Bevel);
+
+        assert_eq!(border.segments(0), 2);
+        assert_eq!(border.corner_radii(0, 0.0), (25.0, 25.0));
+        assert_points(&[border.corner_point(0, 0.0, 0.5)], &[(12.5, 12.5)]);
+
+        let outer = border.side_points(Side::Top, 0.0);
+        assert_points(&outer, &[(12.5, 12.5), (25.0, 0.0), (75.0, 0.0), (87.5, 12.5)]);
+    }
+
+    #[test]
+    fn zero_width_sides() {
+        let border = shape([0.0, 10.0, 10.0, 10.0], [0.0; 4], BorderCornerShape::Round);
+
+        // A side without width collapses onto the outer edge.
+        let outer = border.side_points(Side::Top, 0.0);
+        let inner = border.side_points(Side::Top, 1.0);
+        assert_points(&outer, &[(0.0, 0.0), (0.0, 0.0), (100.0, 0.0), (100.0, 0.0)]);
+        assert_points(&inner, &[(10.0, 0.0), (10.0, 0.0), (90.0, 0.0), (90.0, 0.0)]);
+
+        // Only the radius along the side with width changes through the border.
+        let border = shape([0.0, 10.0, 10.0, 10.0], [20.0; 4], BorderCornerShape::Round);
+        assert_eq!(border.corner_radii(0, 0.0), (25.0, 20.0));
+        assert_eq!(border.corner_radii(0, 1.0), (15.0, 20.0));
+        assert_points(&[border.corner_point(0, 1.0, 1.0)], &[(25.0, 0.0)]);
+    }
+
+    #[test]
+    fn distances_along_side() {
+        let border = shape([10.0; 4], [0.0; 4], BorderCornerShape::Round);
+        let outer = border.side_points(Side::Top, 0.0);
+        let inner = border.side_points(Side::Top, 1.0);
+
+        let distances = center_distances(&outer, &inner);
+        assert_eq!(distances, vec![0.0, 0.0, 90.0, 90.0]);
+
+        let (o, i) = point_at(&outer, &inner, &distances, 45.0);
+        assert_points(&[o, i], &[(50.0, 0.0), (50.0, 10.0)]);
+        let (o, i) = point_at(&outer, &inner, &distances, 0.0);
+        assert_points(&[o, i], &[(0.0, 0.0), (10.0, 10.0)]);
+        // Distances past the end of the side are clamped to its last point.
+        let (o, i) = point_at(&outer, &inner, &distances, 120.0);
+        assert_points(&[o, i], &[(100.0, 0.0), (90.0, 10.0)]);
+    }
+
+    #[test]
+    fn shades() {
+        let color = Color::rgba(90, 150, 30, 200);
+        assert_eq!(shade(color, 0.0), Color::rgba(60, 100, 20, 200));
+        assert_eq!(shade(color, 255.0), Color::rgba(145, 185, 105, 200));
+    }
+}
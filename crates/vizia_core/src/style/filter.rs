@@ -0,0 +1,248 @@
+use femtovg::rgb::RGBA8;
+use vizia_style::Filter;
+
+/// A 4x5 matrix which transforms the color of each pixel, used to apply the color filter
+/// functions.
+///
+/// Each row computes one of the red, green, blue and alpha channels from the unpremultiplied
+/// channels of a pixel, followed by a constant offset. The matrices for each filter function are
+/// the ones given by the Filter Effects specification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ColorMatrix([f32; 20]);
+
+impl ColorMatrix {
+    pub const IDENTITY: Self = ColorMatrix([
+        1.0, 0.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0, 0.0, //
+    ]);
+
+    /// Returns the color matrix of a filter function, or `None` if the filter is not a color
+    /// filter.
+    pub fn from_filter(filter: &Filter) -> Option<Self> {
+        let matrix = match filter {
+            Filter::Brightness(amount) => {
+                let a = amount.to_factor().max(0.0);
+                Self::linear(a, 0.0)
+            }
+
+            Filter::Contrast(amount) => {
+                let a = amount.to_factor().max(0.0);
+                Self::linear(a, 0.5 - 0.5 * a)
+            }
+
+            Filter::Invert(amount) => {
+                let a = amount.to_factor().clamp(0.0, 1.0);
+                Self::linear(1.0 - 2.0 * a, a)
+            }
+
+            Filter::Grayscale(amount) => {
+                let s = 1.0 - amount.to_factor().clamp(0.0, 1.0);
+                Self::rgb([
+                    [0.2126 + 0.7874 * s, 0.7152 - 0.7152 * s, 0.0722 - 0.0722 * s],
+                    [0.2126 - 0.2126 * s, 0.7152 + 0.2848 * s, 0.0722 - 0.0722 * s],
+                    [0.2126 - 0.2126 * s, 0.7152 - 0.7152 * s, 0.0722 + 0.9278 * s],
+                ])
+            }
+
+            Filter::Saturate(amount) => {
+                let s = amount.to_factor().max(0.0);
+                Self::rgb([
+                    [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                    [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+                ])
+            }
+
+            Filter::HueRotate(angle) => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                Self::rgb([
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                    ],
+                    [
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                    ],
+                    [
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                ])
+            }
+
+            Filter::Opacity(amount) => {
+                let mut matrix = Self::IDENTITY;
+                matrix.0[18] = amount.to_factor().clamp(0.0, 1.0);
+                matrix
+            }
+
+            Filter::Blur(_) | Filter::DropShadow(_) => return None,
+        };
+
+        Some(matrix)
+    }
+
+    // Scales the color channels and adds an offset to them, leaving the alpha channel unchanged.
+    fn linear(slope: f32, intercept: f32) -> Self {
+        let mut matrix = Self::IDENTITY;
+        for row in 0..3 {
+            matrix.0[row * 5 + row] = slope;
+            matrix.0[row * 5 + 4] = intercept;
+        }
+        matrix
+    }
+
+    // Mixes the color channels, leaving the alpha channel unchanged.
+    fn rgb(rows: [[f32; 3]; 3]) -> Self {
+        let mut matrix = Self::IDENTITY;
+        for (row, values) in rows.iter().enumerate() {
+            matrix.0[row * 5..row * 5 + 3].copy_from_slice(values);
+        }
+        matrix
+    }
+
+    /// Returns a matrix which applies this matrix followed by `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        let mut result = [0.0; 20];
+        for row in 0..4 {
+            for col in 0..5 {
+                let mut value: f32 =
+                    (0..4).map(|k| other.0[row * 5 + k] * self.0[k * 5 + col]).sum();
+                if col == 4 {
+                    value += other.0[row * 5 + 4];
+                }
+                result[row * 5 + col] = value;
+            }
+        }
+
+        ColorMatrix(result)
+    }
+
+    /// Returns the alpha factor if the matrix only scales the alpha channel, as with a lone
+    /// `opacity()` filter, which can be applied when compositing instead.
+    pub fn as_opacity(&self) -> Option<f32> {
+        let mut matrix = Self::IDENTITY;
+        matrix.0[18] = self.0[18];
+        (matrix == *self).then_some(self.0[18])
+    }
+
+    /// Applies the matrix to a premultiplied pixel.
+    pub fn apply(&self, pixel: &mut RGBA8) {
+        if pixel.a == 0 {
+            return;
+        }
+
+        let alpha = pixel.a as f32 / 255.0;
+        let color = [
+            pixel.r as f32 / 255.0 / alpha,
+            pixel.g as f32 / 255.0 / alpha,
+            pixel.b as f32 / 255.0 / alpha,
+            alpha,
+        ];
+
+        let m = &self.0;
+        let channel = |row: usize| {
+            let value = m[row * 5] * color[0]
+                + m[row * 5 + 1] * color[1]
+                + m[row * 5 + 2] * color[2]
+                + m[row * 5 + 3] * color[3]
+                + m[row * 5 + 4];
+            value.clamp(0.0, 1.0)
+        };
+
+        let alpha = channel(3);
+        pixel.r = (channel(0) * alpha * 255.0).round() as u8;
+        pixel.g = (channel(1) * alpha * 255.0).round() as u8;
+        pixel.b = (channel(2) * alpha * 255.0).round() as u8;
+        pixel.a = (alpha * 255.0).round() as u8;
+    }
+}
+
+/// Returns the number of pixels by which a list of filters extends the content it is applied to,
+/// through blurs and drop shadows.
+pub(crate) fn filter_outset(filters: &[Filter], scale_factor: f32) -> f32 {
+    filters.iter().fold(0.0, |outset, filter| match filter {
+        // A gaussian blur is negligible beyond three standard deviations, which is 1.5 times the
+        // blur radius.
+        Filter::Blur(radius) => outset + radius.to_px().unwrap_or_default() * 1.5 * scale_factor,
+
+        Filter::DropShadow(shadow) => {
+            let x = shadow.x_offset.to_px().unwrap_or_default().abs();
+            let y = shadow.y_offset.to_px().unwrap_or_default().abs();
+            let blur = shadow.blur_radius.as_ref().and_then(|r| r.to_px()).unwrap_or_default();
+            outset + (x.max(y) + blur * 1.5) * scale_factor
+        }
+
+        _ => outset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_style::{DropShadow, Length, PercentageOrNumber};
+
+    fn apply(matrix: &ColorMatrix, [r, g, b, a]: [u8; 4]) -> [u8; 4] {
+        let mut pixel = RGBA8::new(r, g, b, a);
+        matrix.apply(&mut pixel);
+        [pixel.r, pixel.g, pixel.b, pixel.a]
+    }
+
+    fn matrix(filter: Filter) -> ColorMatrix {
+        ColorMatrix::from_filter(&filter).unwrap()
+    }
+
+    #[test]
+    fn invert() {
+        let invert = matrix(Filter::Invert(PercentageOrNumber::Number(1.0)));
+        assert_eq!(apply(&invert, [255, 0, 51, 255]), [0, 255, 204, 255]);
+        // Pixels are unpremultiplied before the matrix is applied.
+        assert_eq!(apply(&invert, [128, 0, 0, 128]), [0, 128, 128, 128]);
+        assert_eq!(apply(&invert, [0, 0, 0, 0]), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn grayscale() {
+        let grayscale = matrix(Filter::Grayscale(PercentageOrNumber::Percentage(100.0)));
+        assert_eq!(apply(&grayscale, [255, 0, 0, 255]), [54, 54, 54, 255]);
+        assert_eq!(apply(&grayscale, [255, 255, 255, 255]), [255, 255, 255, 255]);
+
+        let none = matrix(Filter::Grayscale(PercentageOrNumber::Number(0.0)));
+        assert_eq!(none, ColorMatrix::IDENTITY);
+    }
+
+    #[test]
+    fn then() {
+        let invert = matrix(Filter::Invert(PercentageOrNumber::Number(1.0)));
+        let grayscale = matrix(Filter::Grayscale(PercentageOrNumber::Number(1.0)));
+        let opacity = matrix(Filter::Opacity(PercentageOrNumber::Number(0.5)));
+
+        assert_eq!(apply(&invert.then(&grayscale), [255, 0, 51, 255]), [197, 197, 197, 255]);
+        assert_eq!(apply(&grayscale.then(&invert), [255, 0, 0, 255]), [201, 201, 201, 255]);
+        assert_eq!(ColorMatrix::IDENTITY.then(&invert), invert);
+
+        assert_eq!(opacity.as_opacity(), Some(0.5));
+        assert_eq!(invert.then(&opacity).as_opacity(), None);
+    }
+
+    #[test]
+    fn outset() {
+        let shadow = Filter::DropShadow(DropShadow {
+            x_offset: Length::px(3.0),
+            y_offset: Length::px(-4.0),
+            blur_radius: Some(Length::px(2.0)),
+            color: None,
+        });
+
+        assert_eq!(filter_outset(&[Filter::Invert(PercentageOrNumber::Number(1.0))], 1.0), 0.0);
+        assert_eq!(filter_outset(&[Filter::Blur(Length::px(2.0))], 2.0), 6.0);
+        assert_eq!(filter_outset(&[shadow.clone()], 1.0), 7.0);
+        assert_eq!(filter_outset(&[Filter::Blur(Length::px(2.0)), shadow], 1.0), 10.0);
+    }
+}
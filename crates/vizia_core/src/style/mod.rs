@@ -60,44 +60,59 @@
 //! Element::new(cx).class("foo");
 //! ```
 
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use instant::{Duration, Instant};
 use morphorm::{LayoutType, PositionType, Units};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 use vizia_id::GenerationalId;
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Angle, BackgroundImage, BackgroundSize, BorderCornerShape, BoxShadow, ClipPath, Color, CssRule,
-    CursorIcon, Display, Filter, FontFamily, FontSize, FontStretch, FontStyle, FontWeight,
-    FontWeightKeyword, GenericFontFamily, Gradient, HorizontalPosition, HorizontalPositionKeyword,
-    Length, LengthOrPercentage, LengthValue, LineDirection, LinearGradient, Matrix, Opacity,
-    Overflow, PointerEvents, Position, Scale, TextAlign, Transform, Transition, Translate,
-    VerticalPosition, VerticalPositionKeyword, Visibility, RGBA,
+    Angle, BackgroundImage, BackgroundSize, BorderColor, BorderCornerShape, BorderStyle,
+    BorderStyleKeyword, BoxShadow, ClipPath, Color, Content, CssRule, CursorIcon,
+    DiagnosticSeverity, Direction, Display, DropShadow, Filter, FontFamily, FontSize, FontStretch,
+    FontStyle, FontWeight, FontWeightKeyword, GenericFontFamily, Gradient, HorizontalPosition,
+    HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue, LineDirection,
+    LinearGradient, Matrix, Opacity, Overflow, PercentageOrNumber, PointerEvents, Position, Scale,
+    StyleDiagnostic, TextAlign, Transform, Transition, Translate, VerticalPosition,
+    VerticalPositionKeyword, Visibility, RGBA,
 };
 
 use vizia_style::{
-    EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors, StyleSheet,
+    selectors::parser::{Combinator, Component, Selector},
+    EasingFunction, ErrorLocation, KeyframeSelector, ParserOptions, Property, PseudoClass,
+    PseudoElement, SelectorList, Selectors, StyleSheet,
 };
 
 mod rule;
 pub(crate) use rule::Rule;
 
+mod rule_map;
+pub(crate) use rule_map::*;
+
 mod pseudoclass;
 pub(crate) use pseudoclass::*;
 
 mod transform;
 pub(crate) use transform::*;
 
+mod filter;
+pub(crate) use filter::*;
+
+mod border;
+pub(crate) use border::*;
+
 use crate::animation::{Animation, AnimationState, Interpolator, Keyframe, TimingFunction};
 use crate::storage::animatable_set::AnimatableSet;
 use crate::storage::style_set::StyleSet;
 use bitflags::bitflags;
-use cosmic_text::FamilyOwned;
+use cosmic_text::{Family, FamilyOwned};
 use vizia_id::IdManager;
-use vizia_storage::SparseSet;
+use vizia_storage::{LayoutChildIterator, SparseSet};
 
 bitflags! {
     /// Describes the capabilities of a view with respect to user interaction.
@@ -141,6 +156,79 @@ impl Default for SystemFlags {
     }
 }
 
+bitflags! {
+    /// Describes which other views can affect whether a view matches the selectors of the
+    /// stylesheet, and so which views must be restyled along with a view whose state has changed.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub(crate) struct SelectorDependencies: u8 {
+        // Selectors match views based on their ancestors, so the descendants of a restyled view
+        // must also be restyled.
+        const ANCESTORS = 1 << 0;
+        // Selectors match views based on their previous siblings, so the later siblings of a
+        // restyled view must also be restyled.
+        const SIBLINGS = 1 << 1;
+        // Selectors match views based on their position among their siblings or whether they have
+        // children, so the parent and siblings of an added view must also be restyled.
+        const STRUCTURE = 1 << 2;
+    }
+}
+
+/// Returns which other views can affect whether a view matches the given selector.
+fn selector_dependencies(selector: &Selector<Selectors>) -> SelectorDependencies {
+    let mut dependencies = SelectorDependencies::empty();
+
+    for component in selector.iter_raw_match_order() {
+        match component {
+            Component::Combinator(Combinator::Child | Combinator::Descendant) => {
+                dependencies |= SelectorDependencies::ANCESTORS;
+            }
+
+            Component::Combinator(Combinator::NextSibling | Combinator::LaterSibling) => {
+                dependencies |= SelectorDependencies::SIBLINGS;
+            }
+
+            Component::FirstChild
+            | Component::LastChild
+            | Component::OnlyChild
+            | Component::Empty
+            | Component::NthChild(..)
+            | Component::NthLastChild(..)
+            | Component::NthOfType(..)
+            | Component::NthLastOfType(..)
+            | Component::FirstOfType
+            | Component::LastOfType
+            | Component::OnlyOfType => {
+                dependencies |= SelectorDependencies::STRUCTURE;
+            }
+
+            // The direction of a view is inherited from its ancestors.
+            Component::NonTSPseudoClass(PseudoClass::Dir(_)) => {
+                dependencies |= SelectorDependencies::ANCESTORS;
+            }
+
+            Component::Negation(selectors)
+            | Component::Is(selectors)
+            | Component::Where(selectors) => {
+                for selector in selectors.iter() {
+                    dependencies |= selector_dependencies(selector);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    dependencies
+}
+
+/// A font file loaded by a `@font-face` rule.
+pub(crate) struct FontFace {
+    pub path: PathBuf,
+    /// The family name declared by the rule, if any.
+    pub family: Option<String>,
+    pub location: ErrorLocation,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ImageOrGradient {
     Image(String),
@@ -160,15 +248,41 @@ pub struct Style {
 
     // List of rules
     pub(crate) rules: Vec<(Rule, SelectorList<Selectors>)>,
+    // The selectors of the rules, bucketed for matching.
+    pub(crate) rule_map: RuleMap,
+    // Which other views the selectors of the rules depend on.
+    pub(crate) selector_dependencies: SelectorDependencies,
+    // The pseudo-elements styled by the rules, so that views are only matched against those.
+    pub(crate) pseudo_elements: Vec<PseudoElement>,
+    // The files included by `@import` rules, in the order they were imported.
+    pub(crate) imports: Vec<PathBuf>,
+    // The font files loaded by `@font-face` rules.
+    pub(crate) font_faces: Vec<FontFace>,
+    // The family names declared by `@font-face` rules, mapped to the families of their fonts.
+    pub(crate) font_aliases: HashMap<String, String>,
+
+    // Views which need to be restyled, along with any views affected by them.
+    pub(crate) restyle: FnvHashSet<Entity>,
+    // Views which need to be redrawn since the last draw. The cached drawing of other views, such
+    // as the filtered contents of a view with filters, is kept.
+    pub(crate) redraw: FnvHashSet<Entity>,
+    // The rules matched by each view when it was last restyled, from the most to the least specific.
+    pub(crate) matched_rules: SparseSet<Vec<Rule>>,
 
     pub(crate) default_font: Vec<FamilyOwned>,
 
     // CSS Selector Properties
     pub(crate) ids: SparseSet<String>,
     pub(crate) classes: SparseSet<HashSet<String>>,
+    pub(crate) attributes: SparseSet<HashMap<String, String>>,
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
+    pub(crate) custom_pseudo_classes: SparseSet<HashSet<String>>,
+    pub(crate) direction: SparseSet<Direction>,
+    // The pseudo-element which a view generated for `::before` or `::after` content represents.
+    pub(crate) generated: SparseSet<PseudoElement>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
+    pub(crate) tab_index: SparseSet<i32>,
 
     pub(crate) accesskit_node_classes: accesskit::NodeClassSet,
 
@@ -179,6 +293,7 @@ pub struct Style {
     pub live: SparseSet<Live>,
     pub labelled_by: SparseSet<Entity>,
     pub hidden: SparseSet<bool>,
+    pub expanded: SparseSet<bool>,
     pub text_value: SparseSet<String>,
     pub numeric_value: SparseSet<f64>,
 
@@ -194,6 +309,9 @@ pub struct Style {
     // Z Order
     pub(crate) z_index: StyleSet<i32>,
 
+    // Generated content of the `::before` and `::after` pseudo-elements
+    pub(crate) content: StyleSet<Content>,
+
     // Clipping
     pub(crate) clip_path: AnimatableSet<ClipPath>,
 
@@ -202,7 +320,8 @@ pub struct Style {
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
-    pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) filter: AnimatableSet<Vec<Filter>>,
+    pub(crate) backdrop_filter: AnimatableSet<Vec<Filter>>,
 
     // Transform
     pub(crate) transform: AnimatableSet<Vec<Transform>>,
@@ -212,8 +331,20 @@ pub struct Style {
     pub(crate) scale: AnimatableSet<Scale>,
 
     // Border
-    pub(crate) border_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) border_color: AnimatableSet<Color>,
+    pub(crate) border_top_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_right_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_bottom_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_left_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_top_color: AnimatableSet<Color>,
+    pub(crate) border_right_color: AnimatableSet<Color>,
+    pub(crate) border_bottom_color: AnimatableSet<Color>,
+    pub(crate) border_left_color: AnimatableSet<Color>,
+
+    // Border Style
+    pub(crate) border_top_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_right_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_bottom_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_left_style: StyleSet<BorderStyleKeyword>,
 
     // Border Shape
     pub(crate) border_top_left_shape: StyleSet<BorderCornerShape>,
@@ -230,6 +361,7 @@ pub struct Style {
     // Outline
     pub(crate) outline_width: AnimatableSet<LengthOrPercentage>,
     pub(crate) outline_color: AnimatableSet<Color>,
+    pub(crate) outline_style: StyleSet<BorderStyleKeyword>,
     pub(crate) outline_offset: AnimatableSet<LengthOrPercentage>,
 
     // Background
@@ -251,6 +383,7 @@ pub struct Style {
     pub(crate) font_stretch: StyleSet<FontStretch>,
     pub(crate) caret_color: AnimatableSet<Color>,
     pub(crate) selection_color: AnimatableSet<Color>,
+    pub(crate) placeholder_color: StyleSet<Color>,
 
     // cursor Icon
     pub(crate) cursor: StyleSet<CursorIcon>,
@@ -326,9 +459,32 @@ impl Style {
         physical / self.dpi_factor as f32
     }
 
+    /// Returns the width of one side of the border of an entity, or `None` if the side has no
+    /// width or is not drawn because its border style is `none` or `hidden`.
+    pub(crate) fn border_width(&self, entity: Entity, side: Side) -> Option<&LengthOrPercentage> {
+        let (width, style) = match side {
+            Side::Top => (&self.border_top_width, &self.border_top_style),
+            Side::Right => (&self.border_right_width, &self.border_right_style),
+            Side::Bottom => (&self.border_bottom_width, &self.border_bottom_style),
+            Side::Left => (&self.border_left_width, &self.border_left_style),
+        };
+
+        match style.get(entity) {
+            Some(BorderStyleKeyword::None | BorderStyleKeyword::Hidden) => None,
+            _ => width.get(entity),
+        }
+    }
+
     pub(crate) fn remove_rules(&mut self) {
         self.rule_manager.reset();
         self.rules.clear();
+        self.rule_map.clear();
+        self.selector_dependencies = SelectorDependencies::empty();
+        self.pseudo_elements.clear();
+        self.matched_rules.clear();
+        self.imports.clear();
+        self.font_faces.clear();
+        self.font_aliases.clear();
     }
 
     pub(crate) fn get_animation(&self, name: &str) -> Option<&Animation> {
@@ -399,15 +555,93 @@ impl Style {
                 // BORDER
                 Property::BorderWidth(value) => {
                     insert_keyframe(
-                        &mut self.border_width,
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.top.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.right.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.bottom.0.clone(),
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_width,
                         animation_id,
                         time,
                         value.left.0.clone(),
                     );
                 }
 
+                Property::BorderTopWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderRightWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderBottomWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
+                Property::BorderLeftWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                    );
+                }
+
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(&mut self.border_top_color, animation_id, time, value.top);
+                    insert_keyframe(&mut self.border_right_color, animation_id, time, value.right);
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        value.bottom,
+                    );
+                    insert_keyframe(&mut self.border_left_color, animation_id, time, value.left);
+                }
+
+                Property::BorderTopColor(value) => {
+                    insert_keyframe(&mut self.border_top_color, animation_id, time, *value);
+                }
+
+                Property::BorderRightColor(value) => {
+                    insert_keyframe(&mut self.border_right_color, animation_id, time, *value);
+                }
+
+                Property::BorderBottomColor(value) => {
+                    insert_keyframe(&mut self.border_bottom_color, animation_id, time, *value);
+                }
+
+                Property::BorderLeftColor(value) => {
+                    insert_keyframe(&mut self.border_left_color, animation_id, time, *value);
                 }
 
                 Property::BorderTopLeftRadius(value) => {
@@ -494,6 +728,15 @@ impl Style {
                     insert_keyframe(&mut self.box_shadow, animation_id, time, value.clone());
                 }
 
+                // FILTERS
+                Property::Filter(value) => {
+                    insert_keyframe(&mut self.filter, animation_id, time, value.clone());
+                }
+
+                Property::BackdropFilter(value) => {
+                    insert_keyframe(&mut self.backdrop_filter, animation_id, time, value.clone());
+                }
+
                 // TEXT
                 Property::FontColor(value) => {
                     insert_keyframe(&mut self.font_color, animation_id, time, *value);
@@ -626,6 +869,23 @@ impl Style {
         animation_id
     }
 
+    /// Replaces the keyframes of an animation with an animation of the height of a view between
+    /// two values, for animating to heights which are only known once the view has been laid out.
+    pub(crate) fn set_height_animation(&mut self, animation: Animation, from: Units, to: Units) {
+        let keyframes = vec![
+            Keyframe { time: 0.0, value: from, timing_function: TimingFunction::ease_out() },
+            Keyframe { time: 1.0, value: to, timing_function: TimingFunction::ease_out() },
+        ];
+
+        if let Some(anim_state) = self.height.get_animation_mut(animation) {
+            anim_state.keyframes = keyframes;
+        } else {
+            let mut anim_state = AnimationState::new(animation);
+            anim_state.keyframes = keyframes;
+            self.height.insert_animation(animation, anim_state);
+        }
+    }
+
     pub(crate) fn enqueue_animation(
         &mut self,
         entity: Entity,
@@ -662,8 +922,14 @@ impl Style {
         self.rotate.play_animation(entity, animation, start_time, duration);
         self.scale.play_animation(entity, animation, start_time, duration);
 
-        self.border_width.play_animation(entity, animation, start_time, duration);
-        self.border_color.play_animation(entity, animation, start_time, duration);
+        self.border_top_width.play_animation(entity, animation, start_time, duration);
+        self.border_right_width.play_animation(entity, animation, start_time, duration);
+        self.border_bottom_width.play_animation(entity, animation, start_time, duration);
+        self.border_left_width.play_animation(entity, animation, start_time, duration);
+        self.border_top_color.play_animation(entity, animation, start_time, duration);
+        self.border_right_color.play_animation(entity, animation, start_time, duration);
+        self.border_bottom_color.play_animation(entity, animation, start_time, duration);
+        self.border_left_color.play_animation(entity, animation, start_time, duration);
 
         self.border_top_left_radius.play_animation(entity, animation, start_time, duration);
         self.border_top_right_radius.play_animation(entity, animation, start_time, duration);
@@ -680,6 +946,9 @@ impl Style {
 
         self.box_shadow.play_animation(entity, animation, start_time, duration);
 
+        self.filter.play_animation(entity, animation, start_time, duration);
+        self.backdrop_filter.play_animation(entity, animation, start_time, duration);
+
         self.font_color.play_animation(entity, animation, start_time, duration);
         self.font_size.play_animation(entity, animation, start_time, duration);
         self.caret_color.play_animation(entity, animation, start_time, duration);
@@ -724,8 +993,14 @@ impl Style {
             | self.translate.has_active_animation(entity, animation)
             | self.rotate.has_active_animation(entity, animation)
             | self.scale.has_active_animation(entity, animation)
-            | self.border_width.has_active_animation(entity, animation)
-            | self.border_color.has_active_animation(entity, animation)
+            | self.border_top_width.has_active_animation(entity, animation)
+            | self.border_right_width.has_active_animation(entity, animation)
+            | self.border_bottom_width.has_active_animation(entity, animation)
+            | self.border_left_width.has_active_animation(entity, animation)
+            | self.border_top_color.has_active_animation(entity, animation)
+            | self.border_right_color.has_active_animation(entity, animation)
+            | self.border_bottom_color.has_active_animation(entity, animation)
+            | self.border_left_color.has_active_animation(entity, animation)
             | self.border_top_left_radius.has_active_animation(entity, animation)
             | self.border_top_right_radius.has_active_animation(entity, animation)
             | self.border_bottom_left_radius.has_active_animation(entity, animation)
@@ -737,6 +1012,8 @@ impl Style {
             | self.background_image.has_active_animation(entity, animation)
             | self.background_size.has_active_animation(entity, animation)
             | self.box_shadow.has_active_animation(entity, animation)
+            | self.filter.has_active_animation(entity, animation)
+            | self.backdrop_filter.has_active_animation(entity, animation)
             | self.font_color.has_active_animation(entity, animation)
             | self.font_size.has_active_animation(entity, animation)
             | self.caret_color.has_active_animation(entity, animation)
@@ -767,9 +1044,49 @@ impl Style {
             | self.max_bottom.has_active_animation(entity, animation)
     }
 
-    pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
-        if let Ok(stylesheet) = StyleSheet::parse("test.css", stylesheet, ParserOptions::default())
-        {
+    /// Returns the font family to query for the given family, with the family names declared by
+    /// `@font-face` rules resolved to the families of their fonts.
+    pub(crate) fn resolve_font_family<'a>(&'a self, family: &'a FamilyOwned) -> Family<'a> {
+        match family {
+            FamilyOwned::Name(name) => {
+                Family::Name(self.font_aliases.get(name).unwrap_or(name).as_str())
+            }
+            _ => family.as_family(),
+        }
+    }
+
+    /// Parses a stylesheet without adding its rules, returning the problems found while parsing it.
+    pub(crate) fn check_stylesheet(filename: &str, stylesheet: &str) -> Vec<StyleDiagnostic> {
+        let diagnostics = Arc::new(RwLock::new(Vec::new()));
+        let options =
+            ParserOptions { diagnostics: Some(diagnostics.clone()), ..ParserOptions::default() };
+
+        StyleSheet::parse(filename, stylesheet, options).ok();
+
+        let mut diagnostics = diagnostics.write().unwrap();
+        std::mem::take(&mut *diagnostics)
+    }
+
+    /// Parses a stylesheet and adds its rules, returning the problems found while parsing it.
+    ///
+    /// The `@import` rules and the sources of the `@font-face` rules of the stylesheet are resolved
+    /// relative to the directory of its `path`, if it was read from a file. Imported stylesheets
+    /// are read with `read_import`.
+    pub(crate) fn parse_theme(
+        &mut self,
+        filename: &str,
+        path: Option<&Path>,
+        stylesheet: &str,
+        read_import: &mut dyn FnMut(&Path) -> Result<String, std::io::Error>,
+    ) -> Vec<StyleDiagnostic> {
+        let directory = path.and_then(|path| path.parent()).unwrap_or_else(|| Path::new(""));
+        let mut rule_diagnostics = Vec::new();
+
+        let diagnostics = Arc::new(RwLock::new(Vec::new()));
+        let options =
+            ParserOptions { diagnostics: Some(diagnostics.clone()), ..ParserOptions::default() };
+
+        if let Ok(stylesheet) = StyleSheet::parse(filename, stylesheet, options) {
             let rules = stylesheet.rules.0;
 
             for rule in rules {
@@ -779,17 +1096,58 @@ impl Style {
 
                         let selectors = style_rule.selectors;
 
+                        // The pseudo-element styled by every selector of the rule, if any.
+                        let pseudo_element = selectors
+                            .0
+                            .first()
+                            .and_then(|selector| selector.pseudo_element())
+                            .filter(|pseudo_element| {
+                                selectors.0.iter().all(|selector| {
+                                    selector.pseudo_element() == Some(*pseudo_element)
+                                })
+                            })
+                            .cloned();
+
+                        for (index, selector) in selectors.0.iter().enumerate() {
+                            self.selector_dependencies |= selector_dependencies(selector);
+                            if let Some(pseudo_element) = selector.pseudo_element() {
+                                if !self.pseudo_elements.contains(pseudo_element) {
+                                    self.pseudo_elements.push(pseudo_element.clone());
+                                }
+                            }
+                            self.rule_map.insert(
+                                rule_id,
+                                self.rules.len(),
+                                index,
+                                selector.clone(),
+                            );
+                        }
+
                         self.rules.push((rule_id, selectors));
 
                         for property in style_rule.declarations.declarations {
-                            match property {
-                                Property::Transition(transitions) => {
+                            match (&pseudo_element, property) {
+                                (_, Property::Transition(transitions)) => {
                                     for transition in transitions.iter() {
                                         self.insert_transition(rule_id, transition);
                                     }
                                 }
 
-                                _ => {
+                                // The `::selection` and `::placeholder` pseudo-elements are drawn
+                                // as part of the text of their originating view, so their colors
+                                // are stored as properties of that view.
+                                (
+                                    Some(PseudoElement::Selection),
+                                    Property::BackgroundColor(color),
+                                ) => {
+                                    self.selection_color.insert_rule(rule_id, color);
+                                }
+
+                                (Some(PseudoElement::Placeholder), Property::FontColor(color)) => {
+                                    self.placeholder_color.insert_rule(rule_id, color);
+                                }
+
+                                (_, property) => {
                                     self.insert_property(rule_id, property);
                                 }
                             }
@@ -822,10 +1180,80 @@ impl Style {
                         self.animations.insert(name, animation_id);
                     }
 
+                    CssRule::Import(import_rule) => {
+                        let import_path = directory.join(import_rule.url.as_ref());
+                        let location = ErrorLocation::new(import_rule.loc, filename.to_owned());
+
+                        // Each file is only imported once, which also breaks import cycles.
+                        if self.imports.contains(&import_path)
+                            || path.map_or(false, |path| path == import_path)
+                        {
+                            continue;
+                        }
+
+                        self.imports.push(import_path.clone());
+
+                        match read_import(&import_path) {
+                            Ok(source) => rule_diagnostics.extend(self.parse_theme(
+                                &import_path.display().to_string(),
+                                Some(&import_path),
+                                &source,
+                                read_import,
+                            )),
+
+                            Err(err) => rule_diagnostics.push(StyleDiagnostic {
+                                severity: DiagnosticSeverity::Error,
+                                line: location.line + 1,
+                                location,
+                                property: None,
+                                expected: None,
+                                message: format!(
+                                    "Failed to import `{}`: {}",
+                                    import_path.display(),
+                                    err
+                                ),
+                            }),
+                        }
+                    }
+
+                    CssRule::FontFace(font_face_rule) => {
+                        let location = ErrorLocation::new(font_face_rule.loc, filename.to_owned());
+
+                        let family = match font_face_rule.font_family() {
+                            Some(FontFamily::Named(name)) => Some(name.to_string()),
+                            _ => None,
+                        };
+
+                        match font_face_rule.font_file() {
+                            Some(url) => self.font_faces.push(FontFace {
+                                path: directory.join(url.url.as_ref()),
+                                family,
+                                location,
+                            }),
+
+                            None => rule_diagnostics.push(StyleDiagnostic {
+                                severity: DiagnosticSeverity::Warning,
+                                line: location.line + 1,
+                                location,
+                                property: Some(String::from("src")),
+                                expected: Some(String::from(
+                                    "url(<string>) format(truetype | opentype | collection)",
+                                )),
+                                message: String::from(
+                                    "No TrueType or OpenType font source in @font-face rule.",
+                                ),
+                            }),
+                        }
+                    }
+
                     _ => {}
                 }
             }
         }
+
+        let mut diagnostics = diagnostics.write().unwrap();
+        diagnostics.extend(rule_diagnostics);
+        std::mem::take(&mut *diagnostics)
     }
 
     fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) {
@@ -872,20 +1300,96 @@ impl Style {
             }
 
             "border" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-width" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+            }
+
+            "border-top-width" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+            }
+
+            "border-right-width" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-width" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+            }
+
+            "border-left-width" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
             }
 
             "border-color" => {
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
+            }
+
+            "border-top-color" => {
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+            }
+
+            "border-right-color" => {
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-color" => {
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+            }
+
+            "border-left-color" => {
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-radius" => {
@@ -969,6 +1473,16 @@ impl Style {
                 self.box_shadow.insert_transition(rule_id, animation);
             }
 
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(rule_id, animation);
+            }
+
+            "backdrop-filter" => {
+                self.backdrop_filter.insert_animation(animation, self.add_transition(transition));
+                self.backdrop_filter.insert_transition(rule_id, animation);
+            }
+
             "color" => {
                 self.font_color.insert_animation(animation, self.add_transition(transition));
                 self.font_color.insert_transition(rule_id, animation);
@@ -1136,6 +1650,10 @@ impl Style {
             }
 
             // Filters
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             Property::BackdropFilter(filter) => {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
@@ -1302,20 +1820,98 @@ impl Style {
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_top_color.insert_rule(rule_id, border_color);
+                    self.border_right_color.insert_rule(rule_id, border_color);
+                    self.border_bottom_color.insert_rule(rule_id, border_color);
+                    self.border_left_color.insert_rule(rule_id, border_color);
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    let border_width: LengthOrPercentage = border_width.into();
+                    self.border_top_width.insert_rule(rule_id, border_width.clone());
+                    self.border_right_width.insert_rule(rule_id, border_width.clone());
+                    self.border_bottom_width.insert_rule(rule_id, border_width.clone());
+                    self.border_left_width.insert_rule(rule_id, border_width);
+                }
+
+                if let Some(border_style) = border.style {
+                    self.border_top_style.insert_rule(rule_id, border_style.top);
+                    self.border_right_style.insert_rule(rule_id, border_style.right);
+                    self.border_bottom_style.insert_rule(rule_id, border_style.bottom);
+                    self.border_left_style.insert_rule(rule_id, border_style.left);
                 }
             }
 
-            // Border
+            // Border Width
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_top_width.insert_rule(rule_id, border_width.top.0);
+                self.border_right_width.insert_rule(rule_id, border_width.right.0);
+                self.border_bottom_width.insert_rule(rule_id, border_width.bottom.0);
+                self.border_left_width.insert_rule(rule_id, border_width.left.0);
+            }
+
+            Property::BorderTopWidth(border_width) => {
+                self.border_top_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderRightWidth(border_width) => {
+                self.border_right_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderBottomWidth(border_width) => {
+                self.border_bottom_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderLeftWidth(border_width) => {
+                self.border_left_width.insert_rule(rule_id, border_width.0);
+            }
+
+            // Border Color
+            Property::BorderColor(border_color) => {
+                self.border_top_color.insert_rule(rule_id, border_color.top);
+                self.border_right_color.insert_rule(rule_id, border_color.right);
+                self.border_bottom_color.insert_rule(rule_id, border_color.bottom);
+                self.border_left_color.insert_rule(rule_id, border_color.left);
+            }
+
+            Property::BorderTopColor(color) => {
+                self.border_top_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderRightColor(color) => {
+                self.border_right_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderBottomColor(color) => {
+                self.border_bottom_color.insert_rule(rule_id, color);
             }
-            Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+
+            Property::BorderLeftColor(color) => {
+                self.border_left_color.insert_rule(rule_id, color);
+            }
+
+            // Border Style
+            Property::BorderStyle(border_style) => {
+                self.border_top_style.insert_rule(rule_id, border_style.top);
+                self.border_right_style.insert_rule(rule_id, border_style.right);
+                self.border_bottom_style.insert_rule(rule_id, border_style.bottom);
+                self.border_left_style.insert_rule(rule_id, border_style.left);
+            }
+
+            Property::BorderTopStyle(border_style) => {
+                self.border_top_style.insert_rule(rule_id, border_style);
+            }
+
+            Property::BorderRightStyle(border_style) => {
+                self.border_right_style.insert_rule(rule_id, border_style);
+            }
+
+            Property::BorderBottomStyle(border_style) => {
+                self.border_bottom_style.insert_rule(rule_id, border_style);
+            }
+
+            Property::BorderLeftStyle(border_style) => {
+                self.border_left_style.insert_rule(rule_id, border_style);
             }
 
             // Border Radius
@@ -1461,6 +2057,9 @@ impl Style {
             // Z Index
             Property::ZIndex(z_index) => self.z_index.insert_rule(rule_id, z_index),
 
+            // Generated Content
+            Property::Content(content) => self.content.insert_rule(rule_id, content),
+
             // Outline
             Property::Outline(outline) => {
                 if let Some(outline_color) = outline.color {
@@ -1470,12 +2069,20 @@ impl Style {
                 if let Some(outline_width) = outline.width {
                     self.outline_width.insert_rule(rule_id, outline_width.into());
                 }
+
+                if let Some(outline_style) = outline.style {
+                    self.outline_style.insert_rule(rule_id, outline_style.top);
+                }
             }
 
             Property::OutlineColor(outline_color) => {
                 self.outline_color.insert_rule(rule_id, outline_color);
             }
 
+            Property::OutlineStyle(outline_style) => {
+                self.outline_style.insert_rule(rule_id, outline_style);
+            }
+
             Property::OutlineWidth(outline_width) => {
                 self.outline_width.insert_rule(rule_id, outline_width.left.0);
             }
@@ -1531,16 +2138,8 @@ impl Style {
                 self.pointer_events.insert_rule(rule_id, pointer_events);
             }
 
-            // Unparsed. TODO: Log the error.
-            Property::Unparsed(unparsed) => {
-                println!("Unparsed: {}", unparsed.name);
-            }
-
+            // Invalid values and unknown properties are reported as diagnostics by the parser.
             // TODO: Custom property support
-            Property::Custom(custom) => {
-                println!("Custom Property: {}", custom.name);
-            }
-
             _ => {}
         }
     }
@@ -1574,22 +2173,47 @@ impl Style {
         self.pseudo_classes.insert(entity, PseudoClassFlags::VALID);
         self.classes.insert(entity, HashSet::new());
         self.abilities.insert(entity, Abilities::default());
-        self.system_flags = SystemFlags::RESTYLE | SystemFlags::RELAYOUT;
+        self.needs_restyle(entity);
+        self.needs_relayout();
+    }
+
+    /// Marks the parent and siblings of an added view as needing to be restyled when the selectors
+    /// in use depend on the position of views among their siblings.
+    pub(crate) fn restyle_neighbours(&mut self, entity: Entity, tree: &Tree<Entity>) {
+        if !self.selector_dependencies.contains(SelectorDependencies::STRUCTURE) {
+            return;
+        }
+
+        if let Some(parent) = tree.get_layout_parent(entity) {
+            self.needs_restyle(parent);
+            for sibling in LayoutChildIterator::new(tree, parent) {
+                self.needs_restyle(sibling);
+            }
+        }
     }
 
     // Remove style data for the given entity.
     pub fn remove(&mut self, entity: Entity) {
+        self.restyle.remove(&entity);
+        self.redraw.remove(&entity);
         self.ids.remove(entity);
         self.classes.remove(entity);
+        self.attributes.remove(entity);
         self.pseudo_classes.remove(entity);
+        self.custom_pseudo_classes.remove(entity);
+        self.direction.remove(entity);
+        self.generated.remove(entity);
+        self.matched_rules.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
+        self.tab_index.remove(entity);
 
         self.role.remove(entity);
         self.default_action_verb.remove(entity);
         self.live.remove(entity);
         self.labelled_by.remove(entity);
         self.hidden.remove(entity);
+        self.expanded.remove(entity);
         self.text_value.remove(entity);
         self.numeric_value.remove(entity);
 
@@ -1601,10 +2225,12 @@ impl Style {
         self.opacity.remove(entity);
         // Z Order
         self.z_index.remove(entity);
+        self.content.remove(entity);
         // Clipping
         self.clip_path.remove(entity);
 
-        // Backdrop Filter
+        // Filters
+        self.filter.remove(entity);
         self.backdrop_filter.remove(entity);
 
         // Transform
@@ -1618,8 +2244,20 @@ impl Style {
         self.overflowy.remove(entity);
 
         // Border
-        self.border_width.remove(entity);
-        self.border_color.remove(entity);
+        self.border_top_width.remove(entity);
+        self.border_right_width.remove(entity);
+        self.border_bottom_width.remove(entity);
+        self.border_left_width.remove(entity);
+        self.border_top_color.remove(entity);
+        self.border_right_color.remove(entity);
+        self.border_bottom_color.remove(entity);
+        self.border_left_color.remove(entity);
+
+        // Border Style
+        self.border_top_style.remove(entity);
+        self.border_right_style.remove(entity);
+        self.border_bottom_style.remove(entity);
+        self.border_left_style.remove(entity);
 
         // Border Shape
         self.border_bottom_left_shape.remove(entity);
@@ -1636,6 +2274,7 @@ impl Style {
         // Outline
         self.outline_width.remove(entity);
         self.outline_color.remove(entity);
+        self.outline_style.remove(entity);
         self.outline_offset.remove(entity);
 
         // Background
@@ -1695,6 +2334,7 @@ impl Style {
         self.font_color.remove(entity);
         self.font_size.remove(entity);
         self.selection_color.remove(entity);
+        self.placeholder_color.remove(entity);
         self.caret_color.remove(entity);
 
         // Cursor
@@ -1708,10 +2348,46 @@ impl Style {
         self.needs_access_update.remove(entity);
     }
 
-    pub fn needs_restyle(&mut self) {
+    /// Marks a view as needing to be restyled, such as when its classes or pseudo-classes change.
+    pub fn needs_restyle(&mut self, entity: Entity) {
+        if entity.is_null() {
+            return;
+        }
+
+        self.restyle.insert(entity);
+    }
+
+    /// Marks every view as needing to be restyled, such as when the stylesheets change.
+    pub fn needs_full_restyle(&mut self) {
         self.system_flags.set(SystemFlags::RESTYLE, true);
     }
 
+    /// Returns the pseudo-classes of the given views, so that views whose pseudo-classes change
+    /// can be restyled with [`restyle_changed_pseudo_classes`](Self::restyle_changed_pseudo_classes).
+    pub(crate) fn snapshot_pseudo_classes(
+        &self,
+        entities: impl Iterator<Item = Entity>,
+    ) -> Vec<(Entity, PseudoClassFlags)> {
+        entities
+            .filter_map(|entity| {
+                self.pseudo_classes.get(entity).map(|pseudo_classes| (entity, *pseudo_classes))
+            })
+            .collect()
+    }
+
+    /// Marks the views whose pseudo-classes differ from the given snapshot as needing to be
+    /// restyled.
+    pub(crate) fn restyle_changed_pseudo_classes(
+        &mut self,
+        snapshot: Vec<(Entity, PseudoClassFlags)>,
+    ) {
+        for (entity, pseudo_classes) in snapshot {
+            if self.pseudo_classes.get(entity) != Some(&pseudo_classes) {
+                self.needs_restyle(entity);
+            }
+        }
+    }
+
     pub fn needs_relayout(&mut self) {
         self.system_flags.set(SystemFlags::RELAYOUT, true);
     }
@@ -1720,15 +2396,26 @@ impl Style {
         self.system_flags.set(SystemFlags::REDRAW, true);
     }
 
+    /// Marks a view as needing to be redrawn. Unlike [`needs_redraw`](Self::needs_redraw), which
+    /// may affect the drawing of any view, the cached drawing of unrelated views is kept.
+    pub(crate) fn needs_view_redraw(&mut self, entity: Entity) {
+        self.redraw.insert(entity);
+    }
+
     pub fn needs_access_update(&mut self, entity: Entity) {
         self.needs_access_update.insert(entity, true);
     }
 
     pub fn should_redraw<F: FnOnce()>(&mut self, f: F) {
         if self.system_flags.contains(SystemFlags::REDRAW) {
-            f();
+            // Changes which are not attributed to a view may affect the drawing of any view.
+            self.redraw.insert(Entity::root());
             self.system_flags.set(SystemFlags::REDRAW, false);
         }
+
+        if !self.redraw.is_empty() {
+            f();
+        }
     }
 
     // Remove all shared style data.
@@ -1742,11 +2429,13 @@ impl Style {
         self.opacity.clear_rules();
         // Z Order
         self.z_index.clear_rules();
+        self.content.clear_rules();
 
         // Clipping
         self.clip_path.clear_rules();
 
-        // Backdrop Filer
+        // Filters
+        self.filter.clear_rules();
         self.backdrop_filter.clear_rules();
 
         // Transform
@@ -1760,8 +2449,20 @@ impl Style {
         self.overflowy.clear_rules();
 
         // Border
-        self.border_width.clear_rules();
-        self.border_color.clear_rules();
+        self.border_top_width.clear_rules();
+        self.border_right_width.clear_rules();
+        self.border_bottom_width.clear_rules();
+        self.border_left_width.clear_rules();
+        self.border_top_color.clear_rules();
+        self.border_right_color.clear_rules();
+        self.border_bottom_color.clear_rules();
+        self.border_left_color.clear_rules();
+
+        // Border Style
+        self.border_top_style.clear_rules();
+        self.border_right_style.clear_rules();
+        self.border_bottom_style.clear_rules();
+        self.border_left_style.clear_rules();
 
         // Border Shape
         self.border_bottom_left_shape.clear_rules();
@@ -1778,6 +2479,7 @@ impl Style {
         // Outline
         self.outline_width.clear_rules();
         self.outline_color.clear_rules();
+        self.outline_style.clear_rules();
         self.outline_offset.clear_rules();
 
         // Background
@@ -1833,6 +2535,7 @@ impl Style {
         self.font_color.clear_rules();
         self.font_size.clear_rules();
         self.selection_color.clear_rules();
+        self.placeholder_color.clear_rules();
         self.caret_color.clear_rules();
 
         self.cursor.clear_rules();
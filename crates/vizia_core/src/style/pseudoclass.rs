@@ -6,7 +6,7 @@ use bitflags::bitflags;
 
 bitflags! {
     /// A bitflag of possible pseudoclasses.
-    #[derive(Debug, Clone, Copy)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct PseudoClassFlags: u32 {
         const HOVER = 1;
         const ACTIVE = 1 << 1;
@@ -29,6 +29,7 @@ bitflags! {
         const OPTIONAL = 1 << 18;
         const USER_VALID = 1 << 19;
         const USER_INVALID = 1 << 20;
+        const DROP_TARGET = 1 << 21;
     }
 }
 
@@ -62,6 +63,9 @@ impl std::fmt::Display for PseudoClassFlags {
         if self.contains(PseudoClassFlags::FOCUS_VISIBLE) {
             write!(f, ":focus-visible")?;
         }
+        if self.contains(PseudoClassFlags::DROP_TARGET) {
+            write!(f, ":drop-target")?;
+        }
 
         Ok(())
     }
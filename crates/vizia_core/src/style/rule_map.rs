@@ -0,0 +1,182 @@
+use fnv::FnvHashMap;
+use std::collections::HashSet;
+use vizia_style::{
+    selector_hash,
+    selectors::{
+        bloom::{BloomFilter, BLOOM_HASH_MASK},
+        parser::{AncestorHashes, Combinator, Component, Selector},
+    },
+    QuirksMode, Selectors,
+};
+
+use super::Rule;
+use crate::prelude::*;
+
+/// A selector of a style rule, along with the hashes used to fast-reject it with an
+/// [`AncestorFilter`].
+pub(crate) struct RuleSelector {
+    pub rule: Rule,
+    // The position of the rule in the stylesheet, used to order rules with the same specificity.
+    pub order: usize,
+    // The position of the selector in the selector list of the rule.
+    pub index: usize,
+    pub selector: Selector<Selectors>,
+    pub hashes: AncestorHashes,
+}
+
+/// The selectors of the style rules, bucketed by the id, class or element name of their rightmost
+/// compound selector, so that only the selectors which could match a view are tested against it.
+#[derive(Default)]
+pub(crate) struct RuleMap {
+    ids: FnvHashMap<String, Vec<RuleSelector>>,
+    classes: FnvHashMap<String, Vec<RuleSelector>>,
+    elements: FnvHashMap<String, Vec<RuleSelector>>,
+    universal: Vec<RuleSelector>,
+}
+
+// The bucket of the rule map which a selector is stored in.
+enum RuleKey {
+    Id(String),
+    Class(String),
+    Element(String),
+    Universal,
+}
+
+impl RuleMap {
+    /// Adds a selector of the rule at the given position in the stylesheet.
+    pub fn insert(
+        &mut self,
+        rule: Rule,
+        order: usize,
+        index: usize,
+        selector: Selector<Selectors>,
+    ) {
+        let hashes = AncestorHashes::new(&selector, QuirksMode::NoQuirks);
+        let key = rule_key(&selector);
+        let rule_selector = RuleSelector { rule, order, index, selector, hashes };
+
+        match key {
+            RuleKey::Id(id) => self.ids.entry(id).or_default().push(rule_selector),
+            RuleKey::Class(class) => self.classes.entry(class).or_default().push(rule_selector),
+            RuleKey::Element(element) => {
+                self.elements.entry(element).or_default().push(rule_selector)
+            }
+            RuleKey::Universal => self.universal.push(rule_selector),
+        }
+    }
+
+    /// Removes all of the selectors.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.classes.clear();
+        self.elements.clear();
+        self.universal.clear();
+    }
+
+    /// Returns the selectors which could match a view with the given element name, id and classes.
+    pub fn candidates<'a>(
+        &'a self,
+        element: Option<&'a str>,
+        id: Option<&'a String>,
+        classes: Option<&'a HashSet<String>>,
+    ) -> impl Iterator<Item = &'a RuleSelector> + 'a {
+        let ids = id.and_then(|id| self.ids.get(id)).into_iter().flatten();
+        let classes = classes
+            .into_iter()
+            .flatten()
+            .filter_map(move |class| self.classes.get(class))
+            .flatten();
+        let elements = element.and_then(|element| self.elements.get(element)).into_iter().flatten();
+
+        ids.chain(classes).chain(elements).chain(self.universal.iter())
+    }
+}
+
+// Returns the key of the bucket for a selector from its rightmost compound selector, preferring
+// the most specific of its id, classes and element name.
+fn rule_key(selector: &Selector<Selectors>) -> RuleKey {
+    let mut class = None;
+    let mut element = None;
+
+    let mut iter = selector.iter();
+    loop {
+        for component in &mut iter {
+            match component {
+                Component::ID(id) => return RuleKey::Id(id.0.clone()),
+                Component::Class(name) if class.is_none() => class = Some(name.0.clone()),
+                Component::LocalName(local_name) => element = Some(local_name.name.0.clone()),
+                _ => {}
+            }
+        }
+
+        // The compound selector to the left of a pseudo-element matches the originating view.
+        if iter.next_sequence() != Some(Combinator::PseudoElement) {
+            break;
+        }
+    }
+
+    class
+        .map(RuleKey::Class)
+        .or_else(|| element.map(RuleKey::Element))
+        .unwrap_or(RuleKey::Universal)
+}
+
+/// A bloom filter containing the element names, ids and classes of the ancestors of a view, used to
+/// fast-reject selectors with descendant and child combinators.
+///
+/// Views are usually matched in tree order, so the filter keeps the ancestors shared with the
+/// previously matched view and only updates the rest.
+#[derive(Default)]
+pub(crate) struct AncestorFilter {
+    filter: BloomFilter,
+    // The ancestors in the filter from the root down, along with the hashes inserted for each.
+    ancestors: Vec<(Entity, Vec<u32>)>,
+}
+
+impl AncestorFilter {
+    /// Updates the filter to contain the ancestors of the given view and returns it.
+    pub fn update(&mut self, cx: &Context, entity: Entity) -> &BloomFilter {
+        let mut ancestors = Vec::new();
+        let mut parent = cx.tree.get_parent(entity);
+        while let Some(ancestor) = parent {
+            ancestors.push(ancestor);
+            parent = cx.tree.get_parent(ancestor);
+        }
+        ancestors.reverse();
+
+        let shared = self
+            .ancestors
+            .iter()
+            .zip(ancestors.iter())
+            .take_while(|((previous, _), ancestor)| previous == *ancestor)
+            .count();
+
+        for (_, hashes) in self.ancestors.drain(shared..) {
+            for hash in hashes {
+                self.filter.remove_hash(hash);
+            }
+        }
+
+        for ancestor in ancestors.into_iter().skip(shared) {
+            let hashes = view_hashes(cx, ancestor);
+            for hash in hashes.iter() {
+                self.filter.insert_hash(*hash);
+            }
+            self.ancestors.push((ancestor, hashes));
+        }
+
+        &self.filter
+    }
+}
+
+// Returns the hashes of the element name, id and classes of a view.
+fn view_hashes(cx: &Context, entity: Entity) -> Vec<u32> {
+    cx.views
+        .get(&entity)
+        .and_then(|view| view.element())
+        .into_iter()
+        .chain(cx.style.ids.get(entity).map(|id| id.as_str()))
+        .chain(cx.style.classes.get(entity).into_iter().flatten().map(|class| class.as_str()))
+        .map(|name| selector_hash(name) & BLOOM_HASH_MASK)
+        .collect()
+}
@@ -133,6 +133,10 @@ pub(crate) fn get_access_node(
         }
     }
 
+    if let Some(expanded) = cx.style.expanded.get(entity) {
+        node_builder.set_expanded(*expanded);
+    }
+
     if let Some(live) = cx.style.live.get(entity) {
         node_builder.set_live(*live);
     }
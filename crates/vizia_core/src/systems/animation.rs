@@ -12,7 +12,10 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         // Opacity
         cx.style.opacity.tick(time)
         // Border Colour
-        | cx.style.border_color.tick(time)
+        | cx.style.border_top_color.tick(time)
+        | cx.style.border_right_color.tick(time)
+        | cx.style.border_bottom_color.tick(time)
+        | cx.style.border_left_color.tick(time)
         // Border Radius
         | cx.style.border_top_left_radius.tick(time)
         | cx.style.border_top_right_radius.tick(time)
@@ -24,6 +27,9 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         | cx.style.background_size.tick(time)
         // Box Shadow
         | cx.style.box_shadow.tick(time)
+        // Filters
+        | cx.style.filter.tick(time)
+        | cx.style.backdrop_filter.tick(time)
         // Font Color
         | cx.style.font_color.tick(time)
         // Transform
@@ -42,7 +48,10 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
     // Properties which affect layout
     let needs_relayout =
         // Border Width
-        cx.style.border_width.tick(time)
+        cx.style.border_top_width.tick(time)
+        | cx.style.border_right_width.tick(time)
+        | cx.style.border_bottom_width.tick(time)
+        | cx.style.border_left_width.tick(time)
         // Font Size
         | cx.style.font_size.tick(time)
         // Space
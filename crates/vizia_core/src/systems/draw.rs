@@ -1,24 +1,61 @@
+use crate::cache::FilterLayer;
 use crate::prelude::*;
+use crate::style::{filter_outset, ColorMatrix, SystemFlags};
+use crate::vg::{Paint, Path, RenderTarget};
+use femtovg::Transform2D;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use vizia_id::GenerationalId;
-use vizia_storage::LayoutChildIterator;
+use vizia_storage::{LayoutChildIterator, TreeIterator};
 
-pub(crate) fn draw_system(cx: &mut Context) {
-    let canvas = cx.canvases.get_mut(&Entity::root()).unwrap();
-    cx.resource_manager.mark_images_unused();
-    let window_width = cx.cache.get_width(Entity::root());
-    let window_height = cx.cache.get_height(Entity::root());
+pub(crate) fn draw_system(cx: &mut Context, window: Entity) {
+    invalidate_filter_layers(cx);
+
+    let ratio = if window == Entity::root() { 1.0 } else { cx.window_scale_ratio(window) };
+    let canvas = match cx.canvases.get_mut(&window) {
+        Some(canvas) => canvas,
+        None => return,
+    };
+    if window == Entity::root() {
+        cx.resource_manager.mark_images_unused();
+    }
+    let window_bounds = cx.cache.get_bounds(window);
+    let (width, height) = ((window_bounds.w * ratio) as u32, (window_bounds.h * ratio) as u32);
     let clear_color =
-        cx.style.background_color.get(Entity::root()).cloned().unwrap_or(RGBA::TRANSPARENT.into());
-    canvas.set_size(window_width as u32, window_height as u32, 1.0);
-    canvas.clear_rect(0, 0, window_width as u32, window_height as u32, clear_color.into());
+        cx.style.background_color.get(window).cloned().unwrap_or(RGBA::TRANSPARENT.into());
+    canvas.set_size(width, height, 1.0);
+    canvas.clear_rect(0, 0, width, height, clear_color.into());
+
+    // Secondary windows are laid out within the tree like any other view, so their contents are
+    // translated to the origin of their own canvas, and scaled if the display showing the window
+    // has a different scale factor to the main window. Glyphs are rasterized per canvas.
+    let window_transform = if window == Entity::root() {
+        Transform2D::identity()
+    } else {
+        if let Some(window_state) = cx.windows.get_mut(&window) {
+            cx.text_context.swap_glyph_cache(&mut window_state.glyph_cache);
+        }
+        let mut transform = Transform2D::new_translation(-window_bounds.x, -window_bounds.y);
+        let mut scale = Transform2D::identity();
+        scale.scale(ratio, ratio);
+        transform.multiply(&scale);
+        transform
+    };
 
     let mut queue = BinaryHeap::new();
-    queue.push(ZEntity { index: 0, entity: Entity::root(), opacity: 1.0, visible: true });
+    queue.push(ZEntity {
+        index: 0,
+        entity: window,
+        opacity: 1.0,
+        visible: true,
+        transform: Transform2D::identity(),
+    });
     while !queue.is_empty() {
         let zentity = queue.pop().unwrap();
         canvas.save();
+        // Views drawn out of tree order, such as portals, keep the transforms of their ancestors.
+        canvas.reset_transform();
+        canvas.set_transform(&zentity.transform);
         draw_entity(
             &mut DrawContext {
                 current: zentity.entity,
@@ -33,16 +70,46 @@ pub(crate) fn draw_system(cx: &mut Context) {
                 modifiers: &cx.modifiers,
                 mouse: &cx.mouse,
                 opacity: zentity.opacity,
+                render_target: RenderTarget::Screen,
             },
             canvas,
             zentity.index,
             &mut queue,
             zentity.visible,
+            &window_transform,
         );
         canvas.restore();
     }
 
     canvas.flush();
+
+    if window != Entity::root() {
+        if let Some(window_state) = cx.windows.get_mut(&window) {
+            cx.text_context.swap_glyph_cache(&mut window_state.glyph_cache);
+        }
+    }
+}
+
+// Discards the filtered contents of layers whose view, descendants or ancestors need to be redrawn.
+fn invalidate_filter_layers(cx: &mut Context) {
+    let redraw = std::mem::take(&mut cx.style.redraw);
+    let redraw_all =
+        cx.style.system_flags.contains(SystemFlags::REDRAW) || redraw.contains(&Entity::root());
+    if redraw.is_empty() && !redraw_all {
+        return;
+    }
+
+    let tree = &cx.tree;
+    for entry in cx.cache.filter_layer.dense.iter_mut() {
+        let entity = entry.key;
+        if redraw_all
+            || redraw.iter().any(|view| {
+                view.is_descendant_of(tree, entity) || entity.is_descendant_of(tree, *view)
+            })
+        {
+            entry.value.region = None;
+        }
+    }
 }
 
 fn draw_entity(
@@ -51,6 +118,7 @@ fn draw_entity(
     current_z: i32,
     queue: &mut BinaryHeap<ZEntity>,
     visible: bool,
+    window_transform: &Transform2D,
 ) {
     let current = cx.current;
 
@@ -59,17 +127,54 @@ fn draw_entity(
         return;
     }
 
-    // TODO: Looks like I'll need to keep track of the current transform manually instead of within femtovg
-    // because elements with a higher z-index aren't getting the transform of their parent.
+    // Views with a higher z-index, including portals, are drawn later without the clipping of their
+    // ancestors.
     let z_index = cx.tree.z_index(current);
     if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: current, opacity: cx.opacity, visible });
+        queue.push(ZEntity {
+            index: z_index,
+            entity: current,
+            opacity: cx.opacity,
+            visible,
+            transform: canvas.transform(),
+        });
         return;
     }
 
+    // Views with filters are drawn to an offscreen layer, which is filtered and then composited.
+    match cx.filter() {
+        Some(filters) if !filters.is_empty() => {
+            let filters = filters.clone();
+            draw_filter_layer(cx, canvas, &filters, current_z, queue, visible, window_transform);
+        }
+
+        _ => {
+            // The layer of a view is deleted once its filters are removed.
+            if let Some(layer) = cx.cache.filter_layer.remove(current) {
+                layer.delete(canvas);
+            }
+
+            draw_view(cx, canvas, current_z, queue, visible, window_transform);
+        }
+    }
+}
+
+// Draws the current view and its children.
+fn draw_view(
+    cx: &mut DrawContext,
+    canvas: &mut Canvas,
+    current_z: i32,
+    queue: &mut BinaryHeap<ZEntity>,
+    visible: bool,
+    window_transform: &Transform2D,
+) {
+    let current = cx.current;
+
     canvas.save();
 
-    canvas.set_transform(&cx.transform());
+    let mut transform = cx.transform();
+    transform.multiply(window_transform);
+    canvas.set_transform(&transform);
 
     let clip_region = cx.clip_region();
 
@@ -94,22 +199,337 @@ fn draw_entity(
     let parent_opacity = cx.opacity();
     // Draw its children
     for child in child_iter {
+        // Secondary windows are drawn to their own canvas.
+        if cx.tree.is_window(child) {
+            continue;
+        }
+
         cx.current = child;
         let opactiy = cx.style.opacity.get(child).copied().unwrap_or(Opacity(1.0)).0;
         cx.opacity = parent_opacity * opactiy;
         // TODO: Skip views with zero-sized bounding boxes here? Or let user decide if they want to skip?
-        draw_entity(cx, canvas, current_z, queue, is_visible);
+        draw_entity(cx, canvas, current_z, queue, is_visible, window_transform);
     }
 
     canvas.restore();
     cx.current = current;
 }
 
+// Draws the current view and its children to an offscreen layer, applies the filters to the layer
+// and then composites it into the current render target. The filtered layer is kept and composited
+// again until the view, its descendants or its ancestors need to be redrawn.
+fn draw_filter_layer(
+    cx: &mut DrawContext,
+    canvas: &mut Canvas,
+    filters: &[Filter],
+    current_z: i32,
+    queue: &mut BinaryHeap<ZEntity>,
+    visible: bool,
+    window_transform: &Transform2D,
+) {
+    let region = match layer_region(cx, canvas, filters, window_transform) {
+        Some(region) => region,
+        None => return,
+    };
+    let size = (region.w as usize, region.h as usize);
+
+    let layer = match cx.cache.filter_layer.remove(cx.current) {
+        Some(layer) if layer.size == size => Some(layer),
+        layer => {
+            if let Some(layer) = layer {
+                layer.delete(canvas);
+            }
+
+            FilterLayer::new(canvas, size)
+        }
+    };
+
+    // Without a layer the view is drawn unfiltered.
+    let mut layer = match layer {
+        Some(layer) => layer,
+        None => return draw_view(cx, canvas, current_z, queue, visible, window_transform),
+    };
+
+    if layer.region != Some(region) {
+        draw_filtered(cx, canvas, &mut layer, filters, region, queue, visible, window_transform);
+        layer.region = Some(region);
+    }
+
+    canvas.save();
+    canvas.reset_transform();
+    let mut path = Path::new();
+    path.rect(region.x, region.y, region.w, region.h);
+    canvas.fill_path(
+        &path,
+        &Paint::image(layer.images[0], region.x, region.y, region.w, region.h, 0.0, layer.alpha),
+    );
+    canvas.restore();
+
+    cx.cache.filter_layer.insert(cx.current, layer);
+}
+
+// Draws the current view and its children to the first image of a filter layer and applies the
+// filters to it.
+#[allow(clippy::too_many_arguments)]
+fn draw_filtered(
+    cx: &mut DrawContext,
+    canvas: &mut Canvas,
+    layer: &mut FilterLayer,
+    filters: &[Filter],
+    region: BoundingBox,
+    queue: &mut BinaryHeap<ZEntity>,
+    visible: bool,
+    window_transform: &Transform2D,
+) {
+    let parent_transform = canvas.transform();
+    let size = layer.size;
+
+    let render_target = cx.render_target;
+    cx.render_target = RenderTarget::Image(layer.images[0]);
+
+    canvas.save();
+    canvas.set_render_target(cx.render_target);
+    canvas.reset_scissor();
+    canvas.reset_transform();
+    canvas.clear_rect(0, 0, size.0 as u32, size.1 as u32, femtovg::Color::rgba(0, 0, 0, 0));
+
+    // The layer keeps the transforms of the ancestors, offset by the origin of the layer.
+    let mut transform = parent_transform;
+    transform.multiply(&Transform2D::new_translation(-region.x, -region.y));
+    canvas.set_transform(&transform);
+
+    // Descendants with a higher z-index are drawn within the layer so that they are filtered too.
+    draw_view(cx, canvas, i32::MAX, queue, visible, window_transform);
+    canvas.restore();
+
+    let scale_factor = cx.scale_factor();
+
+    // Successive color filters are combined into a single color matrix.
+    let mut color_matrix: Option<ColorMatrix> = None;
+    for filter in filters {
+        if let Some(matrix) = ColorMatrix::from_filter(filter) {
+            color_matrix =
+                Some(color_matrix.map_or(matrix, |color_matrix| color_matrix.then(&matrix)));
+            continue;
+        }
+
+        if let Some(matrix) = color_matrix.take() {
+            apply_color_matrix(canvas, layer, &matrix);
+        }
+
+        match filter {
+            Filter::Blur(radius) => {
+                let sigma = radius.to_px().unwrap_or_default() * scale_factor / 2.0;
+                if sigma > 0.0 {
+                    canvas.filter_image(
+                        layer.images[1],
+                        femtovg::ImageFilter::GaussianBlur { sigma },
+                        layer.images[0],
+                    );
+                    layer.images.swap(0, 1);
+                }
+            }
+
+            Filter::DropShadow(shadow) => {
+                let color = match shadow.color {
+                    Some(Color::CurrentColor) | None => {
+                        cx.style.font_color.get(cx.current).copied().unwrap_or(Color::black())
+                    }
+                    Some(color) => color,
+                };
+
+                draw_drop_shadow(canvas, layer, shadow, color, scale_factor);
+            }
+
+            _ => {}
+        }
+    }
+
+    // A trailing opacity filter is applied while compositing the layer.
+    layer.alpha = 1.0;
+    if let Some(matrix) = color_matrix {
+        match matrix.as_opacity() {
+            Some(opacity) => layer.alpha = opacity,
+            None => apply_color_matrix(canvas, layer, &matrix),
+        }
+    }
+
+    cx.render_target = render_target;
+    canvas.set_render_target(render_target);
+}
+
+// Returns the pixel-aligned region of the render target covered by the current view and its
+// descendants, expanded by the reach of the filters. Returns `None` if the region is empty.
+fn layer_region(
+    cx: &DrawContext,
+    canvas: &Canvas,
+    filters: &[Filter],
+    window_transform: &Transform2D,
+) -> Option<BoundingBox> {
+    // The transform of the view relative to the render target.
+    let mut transform = cx.transform();
+    transform.multiply(window_transform);
+    transform.multiply(&canvas.transform());
+
+    let bounds = TreeIterator::subtree(cx.tree, cx.current)
+        .filter_map(|entity| cx.cache.bounds.get(entity))
+        .fold(cx.bounds(), |region, bounds| {
+            BoundingBox::from_min_max(
+                region.left().min(bounds.left()),
+                region.top().min(bounds.top()),
+                region.right().max(bounds.right()),
+                region.bottom().max(bounds.bottom()),
+            )
+        });
+
+    let corners = [
+        transform.transform_point(bounds.left(), bounds.top()),
+        transform.transform_point(bounds.right(), bounds.top()),
+        transform.transform_point(bounds.left(), bounds.bottom()),
+        transform.transform_point(bounds.right(), bounds.bottom()),
+    ];
+
+    let target_size = match cx.render_target {
+        RenderTarget::Image(image) => canvas.image_size(image).unwrap_or_default(),
+        RenderTarget::Screen => {
+            let window_bounds = cx.cache.get_bounds(cx.tree.get_window(cx.current));
+            (window_bounds.w as usize, window_bounds.h as usize)
+        }
+    };
+
+    let outset = filter_outset(filters, cx.scale_factor());
+    let left = corners.iter().map(|corner| corner.0).fold(f32::INFINITY, f32::min) - outset;
+    let top = corners.iter().map(|corner| corner.1).fold(f32::INFINITY, f32::min) - outset;
+    let right = corners.iter().map(|corner| corner.0).fold(f32::NEG_INFINITY, f32::max) + outset;
+    let bottom = corners.iter().map(|corner| corner.1).fold(f32::NEG_INFINITY, f32::max) + outset;
+
+    let region = BoundingBox::from_min_max(
+        left.floor().max(0.0),
+        top.floor().max(0.0),
+        right.ceil().min(target_size.0 as f32),
+        bottom.ceil().min(target_size.1 as f32),
+    );
+
+    (region.w >= 1.0 && region.h >= 1.0).then(|| region)
+}
+
+// Applies a color matrix to the pixels of a filter layer. The layer is left unchanged if its pixels
+// cannot be read back or uploaded again.
+//
+// The pixels of the layer are read back from the GPU, which stalls the pipeline until the layer has
+// been drawn, and are then transformed on the CPU and uploaded again. This costs time in proportion
+// to the area of the layer, so consecutive color filters are combined into one matrix and applied
+// with a single readback, and the filtered layer is reused until the view is redrawn.
+fn apply_color_matrix(canvas: &mut Canvas, layer: &mut FilterLayer, matrix: &ColorMatrix) {
+    canvas.set_render_target(RenderTarget::Image(layer.images[0]));
+    let mut pixels = match canvas.screenshot() {
+        Ok(pixels) => pixels,
+        Err(_) => return,
+    };
+
+    for row in pixels.rows_mut() {
+        for pixel in row {
+            matrix.apply(pixel);
+        }
+    }
+
+    let size = (pixels.width(), pixels.height());
+    let image = match layer.pixels.take() {
+        Some(image)
+            if canvas.image_size(image).ok() == Some(size)
+                && canvas.update_image(image, pixels.as_ref(), 0, 0).is_ok() =>
+        {
+            image
+        }
+
+        image => {
+            if let Some(image) = image {
+                canvas.delete_image(image);
+            }
+
+            match canvas.create_image(pixels.as_ref(), femtovg::ImageFlags::PREMULTIPLIED) {
+                Ok(image) => image,
+                Err(_) => return,
+            }
+        }
+    };
+
+    layer.pixels = Some(image);
+
+    let (width, height) = (pixels.width() as f32, pixels.height() as f32);
+    canvas.save();
+    canvas.reset_scissor();
+    canvas.reset_transform();
+    canvas.clear_rect(
+        0,
+        0,
+        layer.size.0 as u32,
+        layer.size.1 as u32,
+        femtovg::Color::rgba(0, 0, 0, 0),
+    );
+    let mut path = Path::new();
+    path.rect(0.0, 0.0, layer.size.0 as f32, layer.size.1 as f32);
+    canvas.fill_path(&path, &Paint::image(image, 0.0, 0.0, width, height, 0.0, 1.0));
+    canvas.restore();
+}
+
+// Draws a blurred and offset silhouette of the contents of a filter layer beneath them.
+fn draw_drop_shadow(
+    canvas: &mut Canvas,
+    layer: &mut FilterLayer,
+    shadow: &DropShadow,
+    color: Color,
+    scale_factor: f32,
+) {
+    let (width, height) = (layer.size.0 as f32, layer.size.1 as f32);
+    let x_offset = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+    let y_offset = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+    let blur_radius = shadow.blur_radius.as_ref().and_then(|r| r.to_px()).unwrap_or_default();
+    let sigma = blur_radius * scale_factor / 2.0;
+
+    let mut path = Path::new();
+    path.rect(0.0, 0.0, width, height);
+
+    // Draw the offset contents, then fill them with the shadow color.
+    canvas.save();
+    canvas.set_render_target(RenderTarget::Image(layer.images[1]));
+    canvas.reset_scissor();
+    canvas.reset_transform();
+    canvas.clear_rect(0, 0, width as u32, height as u32, femtovg::Color::rgba(0, 0, 0, 0));
+    canvas.fill_path(
+        &path,
+        &Paint::image(layer.images[0], x_offset, y_offset, width, height, 0.0, 1.0),
+    );
+    canvas.global_composite_operation(femtovg::CompositeOperation::SourceIn);
+    canvas.fill_path(&path, &Paint::color(color.into()));
+    canvas.restore();
+
+    if sigma > 0.0 {
+        canvas.filter_image(
+            layer.images[2],
+            femtovg::ImageFilter::GaussianBlur { sigma },
+            layer.images[1],
+        );
+        layer.images.swap(1, 2);
+    }
+
+    // Draw the contents over the shadow.
+    canvas.save();
+    canvas.set_render_target(RenderTarget::Image(layer.images[1]));
+    canvas.reset_scissor();
+    canvas.reset_transform();
+    canvas.fill_path(&path, &Paint::image(layer.images[0], 0.0, 0.0, width, height, 0.0, 1.0));
+    canvas.restore();
+
+    layer.images.swap(0, 1);
+}
+
 struct ZEntity {
     pub index: i32,
     pub entity: Entity,
     pub opacity: f32,
     pub visible: bool,
+    pub transform: Transform2D,
 }
 
 impl Ord for ZEntity {
@@ -10,7 +10,9 @@ use vizia_storage::{LayoutChildIterator, LayoutParentIterator};
 
 // Determines the hovered entity based on the mouse cursor position.
 pub(crate) fn hover_system(cx: &mut Context) {
-    if let Some(pseudo_classes) = cx.style.pseudo_classes.get(Entity::root()) {
+    let window = cx.active_window;
+
+    if let Some(pseudo_classes) = cx.style.pseudo_classes.get(window) {
         if !pseudo_classes.contains(PseudoClassFlags::OVER) {
             return;
         }
@@ -18,10 +20,20 @@ pub(crate) fn hover_system(cx: &mut Context) {
 
     let mut queue = BinaryHeap::new();
     let pointer_events: bool =
-        cx.style.pointer_events.get(Entity::root()).copied().unwrap_or_default().into();
-    queue.push(ZEntity { index: 0, pointer_events, entity: Entity::root() });
-    let mut hovered = Entity::root();
-    let transform = Transform2D::identity();
+        cx.style.pointer_events.get(window).copied().unwrap_or_default().into();
+    queue.push(ZEntity {
+        index: 0,
+        pointer_events,
+        entity: window,
+        transform: Transform2D::identity(),
+    });
+    let mut hovered = window;
+
+    // Only the previously hovered view and its ancestors can lose their hover state. Views whose
+    // over state changes are marked while hit testing.
+    let previous = LayoutParentIterator::new(&cx.tree, Some(cx.hovered)).collect::<Vec<_>>();
+    let snapshot = cx.style.snapshot_pseudo_classes(previous.iter().copied());
+
     // let clip_bounds = cx.cache.get_bounds(Entity::root());
     let clip_bounds: BoundingBox =
         BoundingBox { x: -f32::MAX / 2.0, y: -f32::MAX / 2.0, w: f32::MAX, h: f32::MAX };
@@ -34,7 +46,7 @@ pub(crate) fn hover_system(cx: &mut Context) {
                 zentity.pointer_events,
                 &mut queue,
                 &mut hovered,
-                transform,
+                zentity.transform,
                 &clip_bounds,
             );
         });
@@ -48,11 +60,17 @@ pub(crate) fn hover_system(cx: &mut Context) {
                 && !pseudo_classes.contains(PseudoClassFlags::HOVER)
             {
                 pseudo_classes.set(PseudoClassFlags::HOVER, true);
-                cx.style.needs_restyle();
+
+                // Views which were not hovered before have gained their hover state.
+                if !previous.contains(&ancestor) {
+                    cx.style.needs_restyle(ancestor);
+                }
             }
         }
     }
 
+    cx.style.restyle_changed_pseudo_classes(snapshot);
+
     if hovered != cx.hovered {
         // Useful for debugging
         #[cfg(debug_assertions)]
@@ -70,7 +88,7 @@ pub(crate) fn hover_system(cx: &mut Context) {
         let cursor = cx.style.cursor.get(hovered).cloned().unwrap_or_default();
         // TODO: Decide if not changing the cursor when the view is disabled is the correct thing to do
         if !cx.cursor_icon_locked && !cx.style.disabled.get(hovered).cloned().unwrap_or_default() {
-            cx.emit(WindowEvent::SetCursor(cursor));
+            cx.emit_to(window, WindowEvent::SetCursor(cursor));
         }
 
         // Send mouse enter/leave events directly to entity.
@@ -82,8 +100,6 @@ pub(crate) fn hover_system(cx: &mut Context) {
         cx.event_queue.push_back(Event::new(WindowEvent::MouseOut).target(cx.hovered));
 
         cx.hovered = hovered;
-
-        cx.style.needs_restyle();
     }
 }
 
@@ -122,10 +138,16 @@ fn hover_entity(
         .map(|pointer_events| pointer_events.into())
         .unwrap_or(parent_pointer_events);
 
-    // Push to queue if the z-index is higher than the current z-index.
+    // Push to queue if the z-index is higher than the current z-index. Like when drawing, these
+    // views are hit tested without the clipping of their ancestors but keep their transforms.
     let z_index = cx.tree.z_index(cx.current);
     if z_index > current_z {
-        queue.push(ZEntity { index: z_index, entity: cx.current, pointer_events });
+        queue.push(ZEntity {
+            index: z_index,
+            entity: cx.current,
+            pointer_events,
+            transform: parent_transform,
+        });
         return;
     }
 
@@ -164,6 +186,7 @@ fn hover_entity(
             {
                 if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
                     pseudo_class.set(PseudoClassFlags::OVER, true);
+                    cx.style.needs_restyle(cx.current);
                 }
             }
         } else if cx
@@ -176,12 +199,18 @@ fn hover_entity(
         {
             if let Some(pseudo_class) = cx.style.pseudo_classes.get_mut(cx.current) {
                 pseudo_class.set(PseudoClassFlags::OVER, false);
+                cx.style.needs_restyle(cx.current);
             }
         }
     }
 
     let child_iter = LayoutChildIterator::new(cx.tree, cx.current);
     for child in child_iter {
+        // Secondary windows are hit tested separately.
+        if cx.tree.is_window(child) {
+            continue;
+        }
+
         cx.current = child;
         hover_entity(cx, current_z, pointer_events, queue, hovered, transform, &clipping);
     }
@@ -191,6 +220,7 @@ struct ZEntity {
     pub index: i32,
     pub pointer_events: bool,
     pub entity: Entity,
+    pub transform: Transform2D,
 }
 
 impl Ord for ZEntity {
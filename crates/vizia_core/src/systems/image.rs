@@ -1,8 +1,7 @@
 use crate::context::{Context, ResourceContext};
+use crate::prelude::*;
 use crate::resource::{ImageRetentionPolicy, StoredImage};
 use crate::style::ImageOrGradient;
-use crate::{prelude::*, resource::ImageOrId};
-use std::collections::HashSet;
 use vizia_id::GenerationalId;
 
 // Iterate the tree and load any images used by entities which aren't already loaded. Remove any images no longer being used.
@@ -46,23 +45,19 @@ fn load_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) {
 fn try_load_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) -> bool {
     // Check if the image is already loaded
     if let Some(image_store) = cx.resource_manager.images.get_mut(image_name) {
-        match &image_store.image {
-            // Image exists and is already loaded so just add this entity as an observer and mark image as used
-            ImageOrId::Id(_, _) => {
-                // TODO: check if the image is actually the same?
-                image_store.observers.insert(entity);
-                image_store.used = true;
-            }
+        // Add this entity as an observer and mark the image as used
+        image_store.observers.insert(entity);
+        image_store.used = true;
 
-            // Image exists but isn't loaded yet
-            ImageOrId::Image(_, _) => {
-                if let Some(canvas) = cx.canvases.get_mut(&Entity::root()) {
-                    // This loads the image and sets the image id
-                    image_store.image.id(canvas);
-                    image_store.used = true;
-                    cx.style.needs_relayout();
-                    cx.style.needs_redraw();
-                }
+        // Upload the image to the canvas of the window containing the entity if it hasn't been
+        // already. The canvas of a secondary window only exists once the backend has opened it.
+        let window = cx.tree.get_window(entity);
+        if !image_store.ids.contains_key(&window) {
+            if let Some(canvas) = cx.canvases.get_mut(&window) {
+                // This loads the image and sets the image id
+                image_store.id(window, canvas);
+                cx.style.needs_relayout();
+                cx.style.needs_redraw();
             }
         }
 
@@ -70,23 +65,16 @@ fn try_load_image(cx: &mut ResourceContext, entity: Entity, image_name: &str) ->
     } else {
         // Image doesn't exist yet so load and show placeholder image
         // TODO: Add way to configure the placeholder image
-        cx.resource_manager.images.insert(
-            image_name.to_owned(),
-            StoredImage {
-                image: ImageOrId::Image(
-                    image::load_from_memory_with_format(
-                        include_bytes!("../../resources/images/broken_image.png"),
-                        image::ImageFormat::Png,
-                    )
-                    .unwrap(),
-                    femtovg::ImageFlags::empty(),
-                ),
-                retention_policy: ImageRetentionPolicy::Forever,
-                used: true,
-                dirty: false,
-                observers: HashSet::new(),
-            },
+        let mut placeholder = StoredImage::new(
+            image::load_from_memory_with_format(
+                include_bytes!("../../resources/images/broken_image.png"),
+                image::ImageFormat::Png,
+            )
+            .unwrap(),
+            ImageRetentionPolicy::Forever,
         );
+        placeholder.flags = femtovg::ImageFlags::empty();
+        cx.resource_manager.images.insert(image_name.to_owned(), placeholder);
     }
 
     false
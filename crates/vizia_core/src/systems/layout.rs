@@ -3,7 +3,7 @@ use morphorm::Node;
 use crate::layout::cache::GeoChanged;
 use crate::layout::node::SubLayout;
 use crate::prelude::*;
-use crate::style::SystemFlags;
+use crate::style::{Side, SystemFlags};
 
 /// Determines the size and position of views.
 /// TODO: Currently relayout is done on an entire tree rather than incrementally.
@@ -52,14 +52,20 @@ pub(crate) fn layout_system(cx: &mut Context) {
                         .unwrap_or_default()
                         .to_px(width, 0.0)
                         * cx.scale_factor();
-                    let border_width = cx
+                    let border_left = cx
                         .style
-                        .border_width
-                        .get(entity)
+                        .border_width(entity, Side::Left)
                         .cloned()
                         .unwrap_or_default()
                         .to_pixels(width, cx.scale_factor());
-                    let width = width.ceil() - child_left - child_right - 2.0 * border_width;
+                    let border_right = cx
+                        .style
+                        .border_width(entity, Side::Right)
+                        .cloned()
+                        .unwrap_or_default()
+                        .to_pixels(width, cx.scale_factor());
+                    let width =
+                        width.ceil() - child_left - child_right - border_left - border_right;
                     cx.text_context.sync_styles(entity, cx.style);
                     let (text_width, text_height) =
                         cx.text_context.with_buffer(entity, |fs, buf| {
@@ -94,14 +100,20 @@ pub(crate) fn layout_system(cx: &mut Context) {
                         .unwrap_or_default()
                         .to_px(width, 0.0)
                         * cx.scale_factor();
-                    let border_width = cx
+                    let border_left = cx
                         .style
-                        .border_width
-                        .get(entity)
+                        .border_width(entity, Side::Left)
+                        .cloned()
+                        .unwrap_or_default()
+                        .to_pixels(width, cx.scale_factor());
+                    let border_right = cx
+                        .style
+                        .border_width(entity, Side::Right)
                         .cloned()
                         .unwrap_or_default()
                         .to_pixels(width, cx.scale_factor());
-                    let width = width.ceil() - child_left - child_right - 2.0 * border_width;
+                    let width =
+                        width.ceil() - child_left - child_right - border_left - border_right;
 
                     cx.text_context.with_buffer(entity, |fs, buffer| {
                         buffer.set_size(fs, width, f32::MAX);
@@ -120,6 +132,15 @@ pub(crate) fn layout_system(cx: &mut Context) {
                 }
             }
 
+            // Portals are kept within the bounds of their window.
+            if cx.tree.is_portal(entity) {
+                let window_bounds = cx.cache.get_bounds(cx.tree.get_window(entity));
+                if let Some(bounds) = cx.cache.bounds.get_mut(entity) {
+                    bounds.x = bounds.x.min(window_bounds.right() - bounds.w).max(window_bounds.x);
+                    bounds.y = bounds.y.min(window_bounds.bottom() - bounds.h).max(window_bounds.y);
+                }
+            }
+
             if let Some(geo) = cx.cache.geo_changed.get(entity).copied() {
                 // TODO: Use geo changed to determine whether an entity needs to be redrawn.
 
@@ -1,19 +1,26 @@
 use crate::{
     events::ViewHandler,
     prelude::*,
-    style::{PseudoClassFlags, Rule, Style, SystemFlags},
+    style::{
+        AncestorFilter, PseudoClassFlags, Rule, RuleSelector, SelectorDependencies, Style,
+        SystemFlags,
+    },
 };
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use vizia_id::GenerationalId;
 use vizia_storage::LayoutTreeIterator;
 use vizia_style::{
-    matches_selector_list,
     selectors::{
         attr::{AttrSelectorOperation, CaseSensitivity, NamespaceConstraint},
+        bloom::BloomFilter,
         SelectorImpl,
     },
-    selectors::{matching::ElementSelectorFlags, OpaqueElement},
-    Element, MatchingContext, MatchingMode, PseudoClass, QuirksMode, SelectorIdent, Selectors,
+    selectors::{
+        matching::{matches_selector, ElementSelectorFlags},
+        OpaqueElement,
+    },
+    Element, MatchingContext, MatchingMode, PseudoClass, PseudoElement, QuirksMode, SelectorIdent,
+    Selectors,
 };
 
 /// A node used for style matching.
@@ -23,6 +30,7 @@ pub(crate) struct Node<'s, 't, 'v> {
     store: &'s Style,
     tree: &'t Tree<Entity>,
     views: &'v FnvHashMap<Entity, Box<dyn ViewHandler>>,
+    locale: &'s LanguageIdentifier,
 }
 
 impl<'s, 't, 'v> std::fmt::Debug for Node<'s, 't, 'v> {
@@ -57,29 +65,44 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
             store: self.store,
             tree: self.tree,
             views: self.views,
+            locale: self.locale,
         })
     }
 
+    // Views generated for `::before` and `::after` content are not elements of their own, so they
+    // are skipped when matching structural selectors.
     fn prev_sibling_element(&self) -> Option<Self> {
-        self.tree.get_prev_sibling(self.entity).map(|parent| Node {
-            entity: parent,
+        let mut sibling = self.tree.get_prev_sibling(self.entity);
+        while let Some(entity) = sibling.filter(|entity| self.store.generated.contains(*entity)) {
+            sibling = self.tree.get_prev_sibling(entity);
+        }
+
+        sibling.map(|sibling| Node {
+            entity: sibling,
             store: self.store,
             tree: self.tree,
             views: self.views,
+            locale: self.locale,
         })
     }
 
     fn next_sibling_element(&self) -> Option<Self> {
-        self.tree.get_next_sibling(self.entity).map(|parent| Node {
-            entity: parent,
+        let mut sibling = self.tree.get_next_sibling(self.entity);
+        while let Some(entity) = sibling.filter(|entity| self.store.generated.contains(*entity)) {
+            sibling = self.tree.get_next_sibling(entity);
+        }
+
+        sibling.map(|sibling| Node {
+            entity: sibling,
             store: self.store,
             tree: self.tree,
             views: self.views,
+            locale: self.locale,
         })
     }
 
     fn is_empty(&self) -> bool {
-        !self.tree.has_children(self.entity)
+        !self.entity.child_iter(self.tree).any(|child| !self.store.generated.contains(child))
     }
 
     fn is_root(&self) -> bool {
@@ -160,12 +183,23 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
     fn attr_matches(
         &self,
         _ns: &NamespaceConstraint<&<Self::Impl as SelectorImpl>::NamespaceUrl>,
-        _local_name: &<Self::Impl as SelectorImpl>::LocalName,
-        _operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
+        local_name: &<Self::Impl as SelectorImpl>::LocalName,
+        operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
     ) -> bool {
+        if let Some(value) = self
+            .store
+            .attributes
+            .get(self.entity)
+            .and_then(|attributes| attributes.get(&local_name.0))
+        {
+            return operation.eval_str(value);
+        }
+
         false
     }
 
+    // Pseudo-elements are matched against their originating view with
+    // `MatchingMode::ForStatelessPseudoElement`, so a view is never a pseudo-element itself.
     fn match_pseudo_element(
         &self,
         _pe: &<Self::Impl as SelectorImpl>::PseudoElement,
@@ -224,12 +258,20 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
                 PseudoClass::UserInvalid => {
                     psudeo_class_flag.contains(PseudoClassFlags::USER_INVALID)
                 }
-                PseudoClass::Lang(_) => todo!(),
-                PseudoClass::Dir(_) => todo!(),
-                PseudoClass::Custom(name) => {
-                    println!("custom: {}", name);
-                    todo!()
+                PseudoClass::DropTarget => {
+                    psudeo_class_flag.contains(PseudoClassFlags::DROP_TARGET)
                 }
+                PseudoClass::Lang(langs) => {
+                    let locale = self.locale.to_string();
+                    langs.iter().any(|lang| lang_matches(&locale, lang))
+                }
+                PseudoClass::Dir(direction) => self.direction() == *direction,
+                PseudoClass::Custom(name) => self
+                    .store
+                    .custom_pseudo_classes
+                    .get(self.entity)
+                    .map(|pseudo_classes| pseudo_classes.contains(name))
+                    .unwrap_or_default(),
             }
         } else {
             false
@@ -237,6 +279,36 @@ impl<'s, 't, 'v> Element for Node<'s, 't, 'v> {
     }
 }
 
+impl<'s, 't, 'v> Node<'s, 't, 'v> {
+    /// Returns the text direction of the node, which is inherited from the nearest ancestor with
+    /// a direction if the node does not have one.
+    fn direction(&self) -> Direction {
+        let mut entity = self.entity;
+        loop {
+            if let Some(direction) = self.store.direction.get(entity) {
+                return *direction;
+            }
+
+            match self.tree.get_parent(entity) {
+                Some(parent) => entity = parent,
+                None => return Direction::default(),
+            }
+        }
+    }
+}
+
+/// Returns true if the locale matches the language range of a `:lang()` pseudo-class, which
+/// matches the language range itself or any locale starting with the range followed by a `-`.
+fn lang_matches(locale: &str, lang: &str) -> bool {
+    match locale.get(..lang.len()) {
+        Some(prefix) => {
+            prefix.eq_ignore_ascii_case(lang)
+                && matches!(locale.as_bytes().get(lang.len()), None | Some(b'-'))
+        }
+        None => false,
+    }
+}
+
 /// Link inheritable inline properties to their parent.
 pub(crate) fn inline_inheritance_system(cx: &mut Context) {
     for entity in cx.tree.into_iter() {
@@ -300,6 +372,10 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.backdrop_filter.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -402,12 +478,59 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
     }
 
     // Border
-    if style.border_width.link(entity, matched_rules) {
+    if style.border_top_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_right_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_bottom_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_left_width.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
     }
 
-    if style.border_color.link(entity, matched_rules) {
+    if style.border_top_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_top_style.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_right_style.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_bottom_style.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_left_style.link(entity, matched_rules) {
+        should_relayout = true;
         should_redraw = true;
     }
 
@@ -451,6 +574,10 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_redraw = true;
     }
 
+    if style.outline_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.outline_offset.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -513,10 +640,6 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
         should_relayout = true;
     }
 
-    if style.selection_color.link(entity, matched_rules) {
-        should_redraw = true;
-    }
-
     if style.caret_color.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -591,55 +714,440 @@ fn link_style_data(style: &mut Style, entity: Entity, matched_rules: &[Rule]) {
     }
 
     if should_redraw {
-        style.system_flags.set(SystemFlags::REDRAW, true);
+        style.needs_view_redraw(entity);
     }
 }
 
 /// Compute a list of matching style rules for a given entity.
+///
+/// The optional bloom filter contains the ancestors of the entity and is used to fast-reject
+/// selectors with descendant and child combinators.
 pub(crate) fn compute_matched_rules(
     cx: &Context,
     entity: Entity,
+    bloom_filter: Option<&BloomFilter>,
     matched_rules: &mut Vec<(Rule, u32)>,
 ) {
-    for (rule, selector_list) in cx.style.rules.iter() {
+    let node = Node {
+        entity,
+        store: &cx.style,
+        tree: &cx.tree,
+        views: &cx.views,
+        locale: &cx.environment().locale,
+    };
+
+    let mut matched_selectors = Vec::new();
+    for candidate in candidate_selectors(cx, entity) {
         let mut context =
-            MatchingContext::new(MatchingMode::Normal, None, None, QuirksMode::NoQuirks);
+            MatchingContext::new(MatchingMode::Normal, bloom_filter, None, QuirksMode::NoQuirks);
 
-        let (matches, specificity) = matches_selector_list(
-            selector_list,
-            &Node { entity, store: &cx.style, tree: &cx.tree, views: &cx.views },
+        if matches_selector(
+            &candidate.selector,
+            0,
+            Some(&candidate.hashes),
+            &node,
             &mut context,
+            &mut |_, _| {},
+        ) {
+            matched_selectors.push(candidate);
+        }
+    }
+
+    matched_rules.extend(sort_matched_selectors(matched_selectors));
+}
+
+/// Compute a list of style rules matching a pseudo-element of a given entity, ordered from the
+/// most to the least specific.
+pub(crate) fn compute_pseudo_element_rules(
+    cx: &Context,
+    entity: Entity,
+    bloom_filter: Option<&BloomFilter>,
+    pseudo_element: &PseudoElement,
+) -> Vec<Rule> {
+    // Skip matching when no rule styles the pseudo-element.
+    if !cx.style.pseudo_elements.contains(pseudo_element) {
+        return Vec::new();
+    }
+
+    let node = Node {
+        entity,
+        store: &cx.style,
+        tree: &cx.tree,
+        views: &cx.views,
+        locale: &cx.environment().locale,
+    };
+
+    let matches_pseudo_element = |pe: &PseudoElement| pe == pseudo_element;
+
+    let mut matched_selectors = Vec::new();
+    for candidate in candidate_selectors(cx, entity) {
+        if candidate.selector.pseudo_element() != Some(pseudo_element) {
+            continue;
+        }
+
+        let mut context = MatchingContext::new(
+            MatchingMode::ForStatelessPseudoElement,
+            bloom_filter,
+            None,
+            QuirksMode::NoQuirks,
         );
+        context.pseudo_element_matching_fn = Some(&matches_pseudo_element);
 
-        if matches {
-            matched_rules.push((*rule, specificity));
+        if matches_selector(
+            &candidate.selector,
+            0,
+            Some(&candidate.hashes),
+            &node,
+            &mut context,
+            &mut |_, _| {},
+        ) {
+            matched_selectors.push(candidate);
         }
     }
 
-    matched_rules.sort_by_cached_key(|(_, s)| *s);
-    matched_rules.reverse();
+    sort_matched_selectors(matched_selectors).map(|(rule, _)| rule).collect()
+}
+
+/// Returns the selectors of the style rules which could match the given entity.
+fn candidate_selectors(cx: &Context, entity: Entity) -> impl Iterator<Item = &RuleSelector> {
+    let element = cx.views.get(&entity).and_then(|view| view.element());
+    cx.style.rule_map.candidates(element, cx.style.ids.get(entity), cx.style.classes.get(entity))
+}
+
+/// Orders the rules of the matched selectors from the most to the least specific, with later rules
+/// first when their specificity is equal. A rule matches with the specificity of the first of its
+/// selectors which matches.
+fn sort_matched_selectors(
+    mut matched_selectors: Vec<&RuleSelector>,
+) -> impl Iterator<Item = (Rule, u32)> + '_ {
+    matched_selectors.sort_by_key(|matched| (matched.order, matched.index));
+    matched_selectors.dedup_by_key(|matched| matched.order);
+    matched_selectors.sort_by_key(|matched| (matched.selector.specificity(), matched.order));
+    matched_selectors
+        .into_iter()
+        .rev()
+        .map(|matched| (matched.rule, matched.selector.specificity()))
+}
+
+/// Creates, updates or removes the view generated for the `::before` or `::after` content of an
+/// entity, given the style rules matching the pseudo-element.
+fn update_generated_content(
+    cx: &mut Context,
+    entity: Entity,
+    pseudo_element: &PseudoElement,
+    rules: &[Rule],
+) {
+    let generated = entity
+        .child_iter(&cx.tree)
+        .find(|child| cx.style.generated.get(*child) == Some(pseudo_element));
+
+    let content = rules.iter().find_map(|rule| cx.style.content.get_shared(*rule)).cloned();
+
+    let text = match content {
+        Some(Content::String(text)) => text,
+        _ => {
+            if let Some(generated) = generated {
+                cx.remove(generated);
+            }
+
+            return;
+        }
+    };
+
+    // Generated content is skipped by selectors and styled here rather than by the style system,
+    // so moving it does not require any view to be restyled.
+    let created = generated.is_none();
+    let generated = generated.unwrap_or_else(|| {
+        let generated =
+            cx.with_current(entity, |cx| Element::new(cx).hoverable(false).hidden(true).entity());
+        cx.style.generated.insert(generated, pseudo_element.clone());
+        generated
+    });
+
+    // Content before the children of a view is its first child, and content after them its last.
+    let (in_place, index) = match pseudo_element {
+        PseudoElement::Before => (cx.tree.get_prev_sibling(generated).is_none(), 0),
+        _ => (cx.tree.get_next_sibling(generated).is_none(), usize::MAX),
+    };
+
+    if !in_place && cx.tree.reparent(generated, entity, index).is_ok() {
+        cx.style.needs_relayout();
+        cx.style.needs_redraw();
+    }
+
+    link_style_data(&mut cx.style, generated, rules);
+
+    if cx.style.content.link(generated, rules) || created {
+        cx.text_context.set_text(generated, &text);
+        cx.style.needs_text_layout.insert(generated, true);
+        cx.needs_relayout();
+        cx.needs_redraw();
+    }
 }
 
-// Iterates the tree and determines the matching style rules for each entity, then links the entity to the corresponding style rule data.
+// Iterates the tree and determines the matching style rules for each entity which needs to be
+// restyled, then links the entity to the corresponding style rule data.
 pub(crate) fn style_system(cx: &mut Context) {
-    if cx.style.system_flags.contains(SystemFlags::RESTYLE) {
-        let iterator = LayoutTreeIterator::full(&cx.tree);
-
-        // Restyle the entire application.
-        // TODO: Make this incremental.
-        for entity in iterator {
-            let mut matched_rules = Vec::with_capacity(5);
-            compute_matched_rules(cx, entity, &mut matched_rules);
-
-            if !matched_rules.is_empty() {
-                link_style_data(
-                    &mut cx.style,
-                    entity,
-                    &matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>(),
-                );
+    let full_restyle = cx.style.system_flags.contains(SystemFlags::RESTYLE);
+    if !full_restyle && cx.style.restyle.is_empty() {
+        return;
+    }
+
+    // Views marked during the restyle, such as generated content, are restyled on the next pass.
+    cx.style.system_flags.set(SystemFlags::RESTYLE, false);
+    let restyle = std::mem::take(&mut cx.style.restyle);
+    let dependencies = cx.style.selector_dependencies;
+
+    // Views which have been restyled, and parents whose later children must be restyled.
+    let mut restyled = FnvHashSet::default();
+    let mut restyled_siblings = FnvHashSet::default();
+
+    let mut generated_content = Vec::new();
+    let has_generated = !cx.style.generated.is_empty();
+
+    let mut ancestor_filter = AncestorFilter::default();
+
+    let iterator = LayoutTreeIterator::full(&cx.tree);
+    for entity in iterator {
+        // Views generated for `::before` and `::after` content are styled with the rules
+        // matching the pseudo-elements of their originating view.
+        if cx.style.generated.contains(entity) {
+            continue;
+        }
+
+        // Besides the views marked as needing a restyle, the selectors in use determine whether
+        // the descendants and later siblings of those views could match different rules.
+        let parent = cx.tree.get_layout_parent(entity);
+        let needs_restyle = full_restyle
+            || restyle.contains(&entity)
+            || parent.map_or(false, |parent| {
+                (dependencies.contains(SelectorDependencies::ANCESTORS)
+                    && restyled.contains(&parent))
+                    || restyled_siblings.contains(&parent)
+            });
+
+        if !needs_restyle {
+            continue;
+        }
+
+        restyled.insert(entity);
+        if let Some(parent) = parent {
+            if dependencies.contains(SelectorDependencies::SIBLINGS) {
+                restyled_siblings.insert(parent);
+            }
+        }
+
+        let bloom_filter = ancestor_filter.update(cx, entity);
+
+        let mut matched_rules = Vec::with_capacity(5);
+        compute_matched_rules(cx, entity, Some(bloom_filter), &mut matched_rules);
+        let matched_rules = matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>();
+
+        // The style data of a view only needs to be relinked if it matches different rules.
+        if cx.style.matched_rules.get(entity) != Some(&matched_rules) {
+            link_style_data(&mut cx.style, entity, &matched_rules);
+            cx.style.matched_rules.insert(entity, matched_rules.clone());
+        }
+
+        // The selection color can also be set with the `selection-color` property.
+        let mut selection_rules =
+            compute_pseudo_element_rules(cx, entity, Some(bloom_filter), &PseudoElement::Selection);
+        selection_rules.extend(matched_rules.iter().copied());
+        let placeholder_rules = compute_pseudo_element_rules(
+            cx,
+            entity,
+            Some(bloom_filter),
+            &PseudoElement::Placeholder,
+        );
+
+        if cx.style.selection_color.link(entity, &selection_rules)
+            | cx.style.placeholder_color.link(entity, &placeholder_rules)
+        {
+            cx.style.needs_view_redraw(entity);
+        }
+
+        // Content generated for a pseudo-element which is no longer styled is still removed.
+        for pseudo_element in [PseudoElement::Before, PseudoElement::After] {
+            if has_generated || cx.style.pseudo_elements.contains(&pseudo_element) {
+                let rules =
+                    compute_pseudo_element_rules(cx, entity, Some(bloom_filter), &pseudo_element);
+                generated_content.push((entity, pseudo_element, rules));
             }
         }
+    }
+
+    for (entity, pseudo_element, rules) in generated_content {
+        update_generated_content(cx, entity, &pseudo_element, &rules);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns whether a rule with the given selector matches the entity, by adding a stylesheet
+    // with the rule and comparing the number of rules matching the entity before and after.
+    fn matches(cx: &mut Context, entity: Entity, selector: &str) -> bool {
+        let mut before = Vec::new();
+        compute_matched_rules(cx, entity, None, &mut before);
+
+        let stylesheet = format!("{} {{ width: 1px; }}", selector);
+        cx.add_stylesheet(CSS::from_string(&stylesheet)).unwrap();
+
+        let mut after = Vec::new();
+        compute_matched_rules(cx, entity, None, &mut after);
+        after.len() > before.len()
+    }
+
+    #[test]
+    fn attribute_selectors() {
+        let cx = &mut Context::default();
+        let entity =
+            Element::new(cx).attr("variant", "primary").attr("data-tags", "small round").entity();
+
+        assert!(matches(cx, entity, "[variant]"));
+        assert!(matches(cx, entity, "[variant=primary]"));
+        assert!(!matches(cx, entity, "[variant=secondary]"));
+        assert!(matches(cx, entity, "[data-tags~=round]"));
+        assert!(!matches(cx, entity, "[data-tags~=roun]"));
+        assert!(matches(cx, entity, "[variant^=prim]"));
+        assert!(matches(cx, entity, "[variant$=ary]"));
+        assert!(matches(cx, entity, "[variant*=ima]"));
+        assert!(matches(cx, entity, "[data-tags*=\"l r\"]"));
+        assert!(!matches(cx, entity, "[variant*=prime]"));
+        assert!(!matches(cx, entity, "[size]"));
+    }
+
+    #[test]
+    fn attribute_changes() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).attr("variant", "primary").entity();
+
+        EventContext::new_with_current(cx, entity).set_attr("variant", "secondary");
+        assert!(matches(cx, entity, "[variant=secondary]"));
+        assert!(cx.style.restyle.contains(&entity));
+
+        EventContext::new_with_current(cx, entity).remove_attr("variant");
+        assert!(!matches(cx, entity, "[variant]"));
+    }
 
-        cx.style.system_flags.set(SystemFlags::RESTYLE, false);
+    #[test]
+    fn lang_pseudo_class() {
+        let cx = &mut Context::default();
+        cx.set_language("en-US".parse().unwrap());
+        let entity = Element::new(cx).entity();
+
+        assert!(matches(cx, entity, ":lang(en)"));
+        assert!(matches(cx, entity, ":lang(en-US)"));
+        assert!(matches(cx, entity, ":lang(fr, en)"));
+        assert!(!matches(cx, entity, ":lang(e)"));
+        assert!(!matches(cx, entity, ":lang(fr)"));
+    }
+
+    #[test]
+    fn dir_pseudo_class_is_inherited() {
+        let cx = &mut Context::default();
+        let parent = VStack::new(cx, |_| {}).direction(Direction::Rtl).entity();
+        let child = cx.with_current(parent, |cx| Element::new(cx).entity());
+        let other = Element::new(cx).entity();
+
+        assert!(matches(cx, child, ":dir(rtl)"));
+        assert!(!matches(cx, child, ":dir(ltr)"));
+        assert!(matches(cx, other, ":dir(ltr)"));
+
+        let nested =
+            cx.with_current(child, |cx| Element::new(cx).direction(Direction::Ltr).entity());
+        assert!(matches(cx, nested, ":dir(ltr)"));
+    }
+
+    #[test]
+    fn custom_pseudo_class() {
+        let cx = &mut Context::default();
+        let entity = Element::new(cx).entity();
+
+        assert!(!matches(cx, entity, "element:dragging"));
+
+        EventContext::new_with_current(cx, entity).set_pseudo_class("dragging", true);
+        assert!(matches(cx, entity, "element:dragging"));
+        assert!(!matches(cx, entity, "element:resizing"));
+
+        EventContext::new_with_current(cx, entity).set_pseudo_class("dragging", false);
+        assert!(!matches(cx, entity, "element:dragging"));
+    }
+
+    #[test]
+    fn generated_content_is_not_matched() {
+        let cx = &mut Context::default();
+        let parent = Element::new(cx).class("parent").entity();
+        let child = cx.with_current(parent, |cx| Element::new(cx).entity());
+        let empty = Element::new(cx).class("parent").entity();
+
+        cx.add_stylesheet(".parent::before { content: \"before\"; }").unwrap();
+        style_system(cx);
+
+        let generated = cx.tree.get_first_child(parent).unwrap();
+        assert_eq!(cx.style.generated.get(generated), Some(&PseudoElement::Before));
+        assert!(matches(cx, child, "element:first-child"));
+        assert!(cx.tree.has_children(empty));
+        assert!(matches(cx, empty, ":empty"));
+    }
+
+    #[test]
+    fn adding_view_restyles_only_new_view() {
+        let cx = &mut Context::default();
+        let parent = Element::new(cx).entity();
+        let child = cx.with_current(parent, |cx| Element::new(cx).entity());
+        cx.add_stylesheet("element { width: 5px; }").unwrap();
+        style_system(cx);
+
+        let added = cx.with_current(parent, |cx| Element::new(cx).entity());
+        assert!(!cx.style.system_flags.contains(SystemFlags::RESTYLE));
+        assert!(cx.style.restyle.contains(&added));
+        assert!(!cx.style.restyle.contains(&child));
+        assert!(!cx.style.restyle.contains(&parent));
+
+        style_system(cx);
+        assert!(cx.style.restyle.is_empty());
+        assert_eq!(cx.style.width.get(added), Some(&Units::Pixels(5.0)));
+    }
+
+    #[test]
+    fn adding_view_restyles_structural_neighbours() {
+        let cx = &mut Context::default();
+        let parent = Element::new(cx).entity();
+        let first = cx.with_current(parent, |cx| Element::new(cx).entity());
+        cx.add_stylesheet("element:last-child { width: 5px; }").unwrap();
+        style_system(cx);
+        assert_eq!(cx.style.width.get(first), Some(&Units::Pixels(5.0)));
+
+        let last = cx.with_current(parent, |cx| Element::new(cx).entity());
+        assert!(!cx.style.system_flags.contains(SystemFlags::RESTYLE));
+        assert!(cx.style.restyle.contains(&first));
+
+        style_system(cx);
+        assert_eq!(cx.style.width.get(first), None);
+        assert_eq!(cx.style.width.get(last), Some(&Units::Pixels(5.0)));
+    }
+
+    #[test]
+    fn removing_view_restyles_structural_neighbours() {
+        let cx = &mut Context::default();
+        let parent = Element::new(cx).entity();
+        let first = cx.with_current(parent, |cx| Element::new(cx).entity());
+        let last = cx.with_current(parent, |cx| Element::new(cx).entity());
+        let other = Element::new(cx).entity();
+        cx.add_stylesheet("element:last-child { width: 5px; }").unwrap();
+        style_system(cx);
+        assert_eq!(cx.style.width.get(first), None);
+
+        cx.remove(last);
+        assert!(!cx.style.system_flags.contains(SystemFlags::RESTYLE));
+        assert!(cx.style.restyle.contains(&parent));
+        assert!(cx.style.restyle.contains(&first));
+        assert!(!cx.style.restyle.contains(&last));
+        assert!(!cx.style.restyle.contains(&other));
+
+        style_system(cx);
+        assert_eq!(cx.style.width.get(first), Some(&Units::Pixels(5.0)));
     }
 }
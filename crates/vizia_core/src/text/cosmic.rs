@@ -1,7 +1,7 @@
 use crate::entity::Entity;
 use crate::layout::BoundingBox;
 use crate::prelude::Color;
-use crate::style::Style;
+use crate::style::{PseudoClassFlags, Style};
 use cosmic_text::fontdb::Query;
 use cosmic_text::{
     fontdb::Database, Attrs, AttrsList, Buffer, CacheKey, Color as FontColor, Edit, Editor,
@@ -56,6 +56,14 @@ impl TextContext {
         &mut self.font_system
     }
 
+    /// Swaps the cache of rasterized glyphs with the given cache.
+    ///
+    /// Glyph atlases are images owned by a particular canvas, so each window keeps its own cache.
+    pub(crate) fn swap_glyph_cache(&mut self, glyph_cache: &mut GlyphCache) {
+        std::mem::swap(&mut self.rendered_glyphs, &mut glyph_cache.rendered_glyphs);
+        std::mem::swap(&mut self.glyph_textures, &mut glyph_cache.glyph_textures);
+    }
+
     pub(crate) fn clear_buffer(&mut self, entity: Entity) {
         self.buffers.remove(&entity);
     }
@@ -106,7 +114,7 @@ impl TextContext {
                 .get(entity)
                 .unwrap_or(&style.default_font)
                 .iter()
-                .map(|x| x.as_family())
+                .map(|family| style.resolve_font_family(family))
                 .collect::<Vec<_>>();
             let query = Query {
                 families: families.as_slice(),
@@ -145,7 +153,18 @@ impl TextContext {
             (info.families.clone(), info.weight, info.style)
         };
 
-        let font_color = style.font_color.get(entity).copied().unwrap_or(Color::rgb(0, 0, 0));
+        let placeholder_shown = style
+            .pseudo_classes
+            .get(entity)
+            .map(|pseudo_classes| pseudo_classes.contains(PseudoClassFlags::PLACEHOLDER_SHOWN))
+            .unwrap_or_default();
+
+        let font_color = placeholder_shown
+            .then(|| style.placeholder_color.get(entity))
+            .flatten()
+            .or_else(|| style.font_color.get(entity))
+            .copied()
+            .unwrap_or(Color::rgb(0, 0, 0));
 
         let font_families =
             families.into_iter().map(|(name, _)| FamilyOwned::Name(name)).collect::<Vec<_>>();
@@ -524,6 +543,13 @@ impl TextContext {
     }
 }
 
+/// A cache of rasterized glyphs and the atlas textures they are stored in.
+#[derive(Default)]
+pub(crate) struct GlyphCache {
+    rendered_glyphs: FnvHashMap<CacheKey, Option<RenderedGlyph>>,
+    glyph_textures: Vec<FontTexture>,
+}
+
 pub(crate) struct FontTexture {
     atlas: Atlas,
     image_id: ImageId,
@@ -1,59 +1,119 @@
+use std::collections::HashSet;
+
 use crate::context::Context;
 use crate::entity::Entity;
-use crate::style::{Abilities, Display};
-use vizia_id::GenerationalId;
-use vizia_storage::{DoubleEndedTreeTour, TourDirection, TreeExt, TreeIterator, TreeTour};
+use crate::style::{Abilities, Display, Style, Visibility};
+use crate::tree::{focus_scope_of, is_focus_scope_member};
+use vizia_storage::{Tree, TreeExt, TreeIterator};
 
 /// Should the user be able to navigate to the entity with tab?
-pub(crate) fn is_navigatable(cx: &Context, node: Entity, lock_focus_to: Entity) -> bool {
-    // Skip invisible widgets
-    // if cx.cache.get_visibility(node) == Visibility::Hidden {
-    //     return false;
-    // }
-
+pub(crate) fn is_navigatable(
+    tree: &Tree<Entity>,
+    style: &Style,
+    node: Entity,
+    lock_focus_to: Entity,
+) -> bool {
     // Skip disabled widgets
-    if cx.style.disabled.get(node).cloned().unwrap_or_default() {
+    if style.disabled.get(node).cloned().unwrap_or_default() {
         return false;
     }
 
-    // Skip non-displayed widgets
-    if cx.style.display.get(node).copied().unwrap_or_default() == Display::None {
+    // Skip nodes outside of the subtree
+    if !node.is_descendant_of(tree, lock_focus_to) {
         return false;
     }
 
-    // Skip nodes outside of the subtree
-    if !node.is_descendant_of(&cx.tree, lock_focus_to) {
+    // Skip ignored widgets
+    if tree.is_ignored(node) {
         return false;
     }
 
-    // Skip ignored widgets
-    if cx.tree.is_ignored(node) {
+    // Skip views with a negative tab index, which can only be focused directly
+    if style.tab_index.get(node).copied().unwrap_or_default() < 0 {
         return false;
     }
 
-    cx.style
+    let navigable = style
         .abilities
         .get(node)
         .map(|abilities| abilities.contains(Abilities::NAVIGABLE))
-        .unwrap_or(false)
+        .unwrap_or(false);
+    if !navigable {
+        return false;
+    }
+
+    // Skip widgets which are not displayed or are invisible, either directly or because of an
+    // ancestor. Visibility is inherited from the closest ancestor which sets it.
+    let mut visibility = None;
+    for ancestor in node.parent_iter(tree) {
+        if style.display.get(ancestor).copied().unwrap_or_default() == Display::None {
+            return false;
+        }
+
+        if visibility.is_none() {
+            visibility = style.visibility.get(ancestor).copied();
+        }
+    }
+
+    visibility != Some(Visibility::Hidden)
+}
+
+/// Returns the entities within the subtree which can be reached with keyboard navigation, in the
+/// order in which they are visited.
+///
+/// Entities with a positive tab index are visited first, in ascending order, followed by the
+/// remaining entities in tree order. Only the active member of each focus scope is included.
+pub(crate) fn focus_order(cx: &Context, lock_focus_to: Entity) -> Vec<Entity> {
+    let mut visited_scopes = HashSet::new();
+    let mut order = TreeIterator::subtree(&cx.tree, lock_focus_to)
+        .filter(|node| is_navigatable(&cx.tree, &cx.style, *node, lock_focus_to))
+        .filter(|node| match focus_scope_of(&cx.tree, &cx.focus_scopes, *node) {
+            Some(scope) => {
+                let active = cx.focus_scopes[&scope].active;
+                if is_focus_scope_member(&cx.tree, &cx.style, &cx.focus_scopes, scope, active) {
+                    *node == active
+                } else {
+                    // Fall back to the first member of the scope.
+                    visited_scopes.insert(scope)
+                }
+            }
+
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    order.sort_by_key(|node| match cx.style.tab_index.get(*node).copied() {
+        Some(tab_index) if tab_index > 0 => tab_index,
+        _ => i32::MAX,
+    });
+
+    order
 }
 
 /// Get the next entity to be focused during forward keyboard navigation.
 pub(crate) fn focus_forward(cx: &Context, node: Entity, lock_focus_to: Entity) -> Option<Entity> {
-    TreeIterator::new(&cx.tree, DoubleEndedTreeTour::new(Some(node), Some(Entity::root())))
-        .skip(1)
-        .find(|node| is_navigatable(cx, *node, lock_focus_to))
+    let order = focus_order(cx, lock_focus_to);
+    if let Some(index) = order.iter().position(|entity| *entity == node) {
+        return order.get(index + 1).copied();
+    }
+
+    // The node cannot be navigated to, so continue from its position in the tree.
+    let tree_order = TreeIterator::subtree(&cx.tree, lock_focus_to).collect::<Vec<_>>();
+    let position = |entity: Entity| tree_order.iter().position(|e| *e == entity);
+    let node_position = position(node);
+    order.into_iter().find(|entity| position(*entity) > node_position)
 }
 
 /// Get the next entity to be focused during backward keybaord navigation.
 pub(crate) fn focus_backward(cx: &Context, node: Entity, lock_focus_to: Entity) -> Option<Entity> {
-    let mut iter = TreeIterator::new(
-        &cx.tree,
-        DoubleEndedTreeTour::new_raw(
-            TreeTour::new(Some(Entity::root())),
-            TreeTour::with_direction(Some(node), TourDirection::Leaving),
-        ),
-    );
-    iter.next_back();
-    iter.filter(|node| is_navigatable(cx, *node, lock_focus_to)).next_back()
+    let order = focus_order(cx, lock_focus_to);
+    if let Some(index) = order.iter().position(|entity| *entity == node) {
+        return index.checked_sub(1).map(|index| order[index]);
+    }
+
+    // The node cannot be navigated to, so continue from its position in the tree.
+    let tree_order = TreeIterator::subtree(&cx.tree, lock_focus_to).collect::<Vec<_>>();
+    let position = |entity: Entity| tree_order.iter().position(|e| *e == entity);
+    let node_position = position(node);
+    order.into_iter().rev().find(|entity| position(*entity) < node_position)
 }
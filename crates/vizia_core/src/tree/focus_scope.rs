@@ -0,0 +1,70 @@
+use fnv::FnvHashMap;
+
+use crate::entity::Entity;
+use crate::style::Style;
+use crate::tree::is_navigatable;
+use vizia_storage::{Tree, TreeExt, TreeIterator};
+
+/// A named group of views, such as the buttons of a toolbar, between which focus is moved with the
+/// arrow keys.
+///
+/// Only the active member of a scope, which is the most recently focused member, can be reached
+/// with tab navigation, so that tab moves focus out of the scope.
+pub(crate) struct FocusScope {
+    pub name: String,
+    pub active: Entity,
+}
+
+impl FocusScope {
+    pub fn new(name: String) -> Self {
+        Self { name, active: Entity::null() }
+    }
+}
+
+/// Returns the focus scope which directly contains the entity.
+pub(crate) fn focus_scope_of(
+    tree: &Tree<Entity>,
+    focus_scopes: &FnvHashMap<Entity, FocusScope>,
+    node: Entity,
+) -> Option<Entity> {
+    node.parent_iter(tree).skip(1).find(|ancestor| focus_scopes.contains_key(ancestor))
+}
+
+/// Returns true if the entity is a navigable member of the given focus scope.
+pub(crate) fn is_focus_scope_member(
+    tree: &Tree<Entity>,
+    style: &Style,
+    focus_scopes: &FnvHashMap<Entity, FocusScope>,
+    scope: Entity,
+    node: Entity,
+) -> bool {
+    node != Entity::null()
+        && is_navigatable(tree, style, node, tree.lock_focus_within(scope))
+        && focus_scope_of(tree, focus_scopes, node) == Some(scope)
+}
+
+/// Returns the navigable members of a focus scope in tree order.
+pub(crate) fn focus_scope_members(
+    tree: &Tree<Entity>,
+    style: &Style,
+    focus_scopes: &FnvHashMap<Entity, FocusScope>,
+    scope: Entity,
+) -> Vec<Entity> {
+    TreeIterator::subtree(tree, scope)
+        .skip(1)
+        .filter(|node| is_focus_scope_member(tree, style, focus_scopes, scope, *node))
+        .collect()
+}
+
+/// Records a newly focused entity as the active member of the focus scope which contains it.
+pub(crate) fn update_focus_scope(
+    tree: &Tree<Entity>,
+    focus_scopes: &mut FnvHashMap<Entity, FocusScope>,
+    focused: Entity,
+) {
+    if let Some(scope) = focus_scope_of(tree, focus_scopes, focused) {
+        if let Some(focus_scope) = focus_scopes.get_mut(&scope) {
+            focus_scope.active = focused;
+        }
+    }
+}
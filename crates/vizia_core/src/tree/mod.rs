@@ -1,5 +1,7 @@
 mod focus_iter;
 pub(crate) use focus_iter::*;
+mod focus_scope;
+pub(crate) use focus_scope::*;
 
 // Re-export tree
 pub use vizia_storage::{ChildIterator, ParentIterator, Tree, TreeExt};
@@ -2,6 +2,16 @@ use std::path::{Path, PathBuf};
 
 pub trait IntoCssStr: 'static {
     fn get_style(&self) -> Result<String, std::io::Error>;
+
+    /// The name of the stylesheet used in the locations of style diagnostics.
+    fn source_name(&self) -> String {
+        String::from("<style>")
+    }
+
+    /// The path of the file the stylesheet is read from, if any.
+    fn source_path(&self) -> Option<PathBuf> {
+        None
+    }
 }
 
 impl IntoCssStr for CSS {
@@ -12,6 +22,22 @@ impl IntoCssStr for CSS {
             CSS::String(style_string) => Ok(style_string.to_owned()),
         }
     }
+
+    fn source_name(&self) -> String {
+        match self {
+            CSS::Path(path) => path.display().to_string(),
+
+            CSS::String(_) => String::from("<style>"),
+        }
+    }
+
+    fn source_path(&self) -> Option<PathBuf> {
+        match self {
+            CSS::Path(path) => Some(path.clone()),
+
+            CSS::String(_) => None,
+        }
+    }
 }
 
 impl IntoCssStr for &'static str {
@@ -24,12 +50,28 @@ impl IntoCssStr for PathBuf {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn source_name(&self) -> String {
+        self.display().to_string()
+    }
+
+    fn source_path(&self) -> Option<PathBuf> {
+        Some(self.to_path_buf())
+    }
 }
 
 impl IntoCssStr for Path {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn source_name(&self) -> String {
+        self.display().to_string()
+    }
+
+    fn source_path(&self) -> Option<PathBuf> {
+        Some(self.to_path_buf())
+    }
 }
 
 pub enum CSS {
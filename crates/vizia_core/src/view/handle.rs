@@ -60,14 +60,17 @@ impl<'a, V> Handle<'a, V> {
     }
 
     /// Stop the user from tabbing out of a subtree, which is useful for modal dialogs.
+    ///
+    /// Focus is moved to the first navigable view within the subtree, and is returned to the
+    /// previously focused view when the subtree is removed or hidden.
     pub fn lock_focus_to_within(self) -> Self {
         self.cx.tree.set_lock_focus_within(self.entity, true);
-        self.cx.focus_stack.push(self.cx.focused);
         if !self.cx.focused.is_descendant_of(&self.cx.tree, self.entity) {
-            let new_focus = vizia_storage::TreeIterator::subtree(&self.cx.tree, self.entity)
-                .find(|node| crate::tree::is_navigatable(self.cx, *node, Entity::root()))
-                .unwrap_or(self.cx.focus_stack.pop().unwrap());
-            self.cx.with_current(new_focus, |cx| cx.focus());
+            self.cx.focus_stack.push((self.entity, self.cx.focused));
+            let new_focus = crate::tree::focus_order(self.cx, self.entity).first().copied();
+            if let Some(new_focus) = new_focus {
+                self.cx.with_current(new_focus, |cx| cx.focus());
+            }
         }
         self
     }
@@ -125,12 +128,12 @@ impl<'a, V> Handle<'a, V> {
 
     /// Marks the view as needing a restyle.
     pub fn needs_restyle(&mut self) {
-        self.cx.needs_restyle();
+        self.cx.style.needs_restyle(self.entity);
     }
 
     /// Marks the view as needing a redraw.
     pub fn needs_redraw(&mut self) {
-        self.cx.needs_redraw();
+        self.cx.style.needs_view_redraw(self.entity);
     }
 
     /// Returns the bounding box of the view.
@@ -0,0 +1,396 @@
+use crate::icons::ICON_CHEVRON_RIGHT;
+use crate::prelude::*;
+
+/// The duration of the animation of the height of the content when it is opened or closed.
+const COLLAPSIBLE_DURATION: Duration = Duration::from_millis(150);
+
+/// Events used by a [`Collapsible`] view.
+pub enum CollapsibleEvent {
+    /// Requests that the collapsible be toggled, triggering the `on_toggle` callback.
+    Toggle,
+    /// Opens or closes the content of the collapsible. Emitted when the bound open state changes.
+    SetOpen(bool),
+}
+
+/// A view with a header which can be pressed to show or hide its content.
+///
+/// The open state of the collapsible is bound to a lens, and pressing the header, or pressing
+/// Enter or Space while the header is focused, triggers the `on_toggle` callback. The content is
+/// built once and kept while the collapsible is closed, so its state is preserved, and its height
+/// is animated when it is opened or closed.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     open: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     ToggleOpen,
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { open: false }.build(cx);
+/// #
+/// Collapsible::new(
+///     cx,
+///     AppData::open,
+///     |cx| {
+///         Label::new(cx, "Advanced");
+///     },
+///     |cx| {
+///         Label::new(cx, "Advanced settings");
+///     },
+/// )
+/// .on_toggle(|cx| cx.emit(AppEvent::ToggleOpen));
+/// ```
+pub struct Collapsible {
+    // The last open state applied to the content, used to decide whether to animate.
+    is_open: bool,
+    animation: Animation,
+    // The view which is resized to show or hide the content.
+    content: Entity,
+    // The view containing the content, which is always laid out at its full height.
+    inner: Entity,
+    on_toggle: Option<Box<dyn Fn(&mut EventContext)>>,
+}
+
+impl Collapsible {
+    /// Creates a new collapsible with the given header and content.
+    pub fn new<L, H, C>(cx: &mut Context, open: L, header: H, content: C) -> Handle<Self>
+    where
+        L: Lens<Target = bool>,
+        H: FnOnce(&mut Context),
+        C: FnOnce(&mut Context),
+    {
+        let is_open = open.get(cx);
+        let animation = cx.add_animation(AnimationBuilder::new());
+
+        let mut content_entity = Entity::null();
+        let mut inner_entity = Entity::null();
+
+        Self { is_open, animation, content: Entity::null(), inner: Entity::null(), on_toggle: None }
+            .build(cx, |cx| {
+                CollapsibleHeader::new(cx, |cx| {
+                    Label::new(cx, ICON_CHEVRON_RIGHT).class("icon").class("collapsible-icon");
+                    HStack::new(cx, header).class("collapsible-title");
+                })
+                .expanded(open.clone());
+
+                content_entity = VStack::new(cx, |cx| {
+                    inner_entity = VStack::new(cx, content).class("collapsible-inner").entity();
+                })
+                .class("collapsible-content")
+                .height(if is_open { Auto } else { Pixels(0.0) })
+                .visibility(if is_open { Visibility::Visible } else { Visibility::Hidden })
+                .entity();
+            })
+            .modify(|collapsible| {
+                collapsible.content = content_entity;
+                collapsible.inner = inner_entity;
+            })
+            .toggle_class("open", open.clone())
+            .bind(open, |handle, open| {
+                let open = open.get(handle.cx);
+                let entity = handle.entity();
+                handle.cx.emit_to(entity, CollapsibleEvent::SetOpen(open));
+            })
+    }
+
+    fn set_open(&mut self, cx: &mut EventContext, open: bool) {
+        if open == self.is_open {
+            return;
+        }
+
+        self.is_open = open;
+
+        let scale = cx.scale_factor();
+        let from = cx.cache.get_height(self.content) / scale;
+        let to = if open { cx.cache.get_height(self.inner) / scale } else { 0.0 };
+
+        cx.style.set_height_animation(self.animation, Pixels(from), Pixels(to));
+
+        let animation = self.animation;
+        cx.with_current(self.content, |cx| {
+            // Hiding the closed content also removes it from keyboard navigation.
+            if open {
+                cx.set_height(Auto);
+                cx.set_visibility(Visibility::Visible);
+            } else {
+                cx.set_height(Pixels(0.0));
+                cx.set_visibility(Visibility::Hidden);
+            }
+
+            cx.play_animation(animation, COLLAPSIBLE_DURATION);
+        });
+    }
+}
+
+impl Handle<'_, Collapsible> {
+    /// Sets the callback triggered when the header of the collapsible is pressed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Lens)]
+    /// # struct AppData {
+    /// #     open: bool,
+    /// # }
+    /// #
+    /// # impl Model for AppData {}
+    /// #
+    /// # enum AppEvent {
+    /// #     ToggleOpen,
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// # AppData { open: false }.build(cx);
+    /// #
+    /// Collapsible::new(cx, AppData::open, |cx| {
+    ///     Label::new(cx, "Header");
+    /// }, |_| {})
+    /// .on_toggle(|cx| cx.emit(AppEvent::ToggleOpen));
+    /// ```
+    pub fn on_toggle<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|collapsible| collapsible.on_toggle = Some(Box::new(callback)))
+    }
+}
+
+impl View for Collapsible {
+    fn element(&self) -> Option<&'static str> {
+        Some("collapsible")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|collapsible_event, meta| match collapsible_event {
+            CollapsibleEvent::Toggle => {
+                if let Some(callback) = &self.on_toggle {
+                    (callback)(cx);
+                }
+                meta.consume();
+            }
+
+            CollapsibleEvent::SetOpen(open) => {
+                self.set_open(cx, *open);
+                meta.consume();
+            }
+        });
+    }
+}
+
+/// The header of a collapsible, which toggles the collapsible when pressed.
+struct CollapsibleHeader {}
+
+impl CollapsibleHeader {
+    fn new<F>(cx: &mut Context, content: F) -> Handle<Self>
+    where
+        F: FnOnce(&mut Context),
+    {
+        Self {}
+            .build(cx, content)
+            .class("collapsible-header")
+            .role(Role::Button)
+            .default_action_verb(DefaultActionVerb::Click)
+            .cursor(CursorIcon::Hand)
+            .navigable(true)
+    }
+}
+
+impl View for CollapsibleHeader {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::PressDown { mouse } => {
+                if *mouse {
+                    cx.focus();
+                }
+            }
+
+            // Presses on the contents of the header bubble up to it.
+            WindowEvent::Press { mouse } => {
+                let over = if *mouse { cx.mouse.left.pressed } else { cx.focused() };
+                let current = cx.current();
+                if (over == current || over.is_descendant_of(cx.tree, current)) && !cx.is_disabled()
+                {
+                    cx.emit(CollapsibleEvent::Toggle);
+                }
+            }
+
+            WindowEvent::ActionRequest(action) => {
+                if action.action == Action::Default {
+                    cx.emit(CollapsibleEvent::Toggle);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+/// Events used by an [`Accordion`] view.
+pub enum AccordionEvent {
+    /// Opens the section with the given index if it is closed, or closes it if it is open.
+    Toggle(usize),
+    /// Opens or closes the section with the given index.
+    SetOpen(usize, bool),
+}
+
+/// A section of an [`Accordion`], made up of a header and content.
+pub struct AccordionSection {
+    pub header: Box<dyn FnOnce(&mut Context)>,
+    pub content: Box<dyn FnOnce(&mut Context)>,
+    pub open: bool,
+}
+
+impl AccordionSection {
+    pub fn new<H, C>(header: H, content: C) -> Self
+    where
+        H: 'static + FnOnce(&mut Context),
+        C: 'static + FnOnce(&mut Context),
+    {
+        Self { header: Box::new(header), content: Box::new(content), open: false }
+    }
+
+    /// Sets whether the section is initially open.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+}
+
+/// A vertical list of [`Collapsible`] sections.
+///
+/// By default any number of sections can be open at once. With
+/// [`exclusive`](Handle::exclusive) opening a section closes the others.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Accordion::new(
+///     cx,
+///     vec![
+///         AccordionSection::new(
+///             |cx| {
+///                 Label::new(cx, "General");
+///             },
+///             |cx| {
+///                 Label::new(cx, "General settings");
+///             },
+///         )
+///         .open(true),
+///         AccordionSection::new(
+///             |cx| {
+///                 Label::new(cx, "Advanced");
+///             },
+///             |cx| {
+///                 Label::new(cx, "Advanced settings");
+///             },
+///         ),
+///     ],
+/// )
+/// .exclusive(true);
+/// ```
+#[derive(Lens)]
+pub struct Accordion {
+    open: Vec<bool>,
+    exclusive: bool,
+    #[lens(ignore)]
+    on_toggle: Option<Box<dyn Fn(&mut EventContext, usize, bool)>>,
+}
+
+impl Accordion {
+    /// Creates a new accordion with the given sections.
+    pub fn new(cx: &mut Context, sections: Vec<AccordionSection>) -> Handle<Self> {
+        let open = sections.iter().map(|section| section.open).collect();
+
+        Self { open, exclusive: false, on_toggle: None }.build(cx, |cx| {
+            for (index, section) in sections.into_iter().enumerate() {
+                Collapsible::new(
+                    cx,
+                    Accordion::open.map(move |open| open.get(index).copied().unwrap_or_default()),
+                    section.header,
+                    section.content,
+                )
+                .on_toggle(move |cx| cx.emit(AccordionEvent::Toggle(index)));
+            }
+        })
+    }
+
+    fn set_open(&mut self, cx: &mut EventContext, index: usize, open: bool) {
+        if index >= self.open.len() || self.open[index] == open {
+            return;
+        }
+
+        if open && self.exclusive {
+            self.open.iter_mut().for_each(|open| *open = false);
+        }
+
+        self.open[index] = open;
+
+        if let Some(callback) = &self.on_toggle {
+            (callback)(cx, index, open);
+        }
+    }
+}
+
+impl Handle<'_, Accordion> {
+    /// Sets whether opening a section closes the other sections. If several sections are
+    /// initially open, only the first of them is kept open.
+    pub fn exclusive(self, exclusive: bool) -> Self {
+        self.modify(|accordion| {
+            accordion.exclusive = exclusive;
+            if exclusive {
+                if let Some(first) = accordion.open.iter().position(|open| *open) {
+                    accordion.open.iter_mut().skip(first + 1).for_each(|open| *open = false);
+                }
+            }
+        })
+    }
+
+    /// Sets the callback triggered when a section is opened or closed, with the index of the
+    /// section and whether it is now open.
+    pub fn on_toggle<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, bool),
+    {
+        self.modify(|accordion| accordion.on_toggle = Some(Box::new(callback)))
+    }
+}
+
+impl View for Accordion {
+    fn element(&self) -> Option<&'static str> {
+        Some("accordion")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|accordion_event, meta| match accordion_event {
+            AccordionEvent::Toggle(index) => {
+                if let Some(open) = self.open.get(*index).copied() {
+                    self.set_open(cx, *index, !open);
+                }
+                meta.consume();
+            }
+
+            AccordionEvent::SetOpen(index, open) => {
+                self.set_open(cx, *index, *open);
+                meta.consume();
+            }
+        });
+    }
+}
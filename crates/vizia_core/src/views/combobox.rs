@@ -375,7 +375,7 @@ impl ComboPopup {
             .role(Role::Dialog)
             .checked(lens)
             .position_type(PositionType::SelfDirected)
-            .z_index(100)
+            .portal(true)
     }
 }
 
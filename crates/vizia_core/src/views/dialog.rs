@@ -0,0 +1,336 @@
+use std::sync::Arc;
+
+use morphorm::PositionType;
+
+use crate::prelude::*;
+
+/// The z-index of dialogs, which places them above popups and menus.
+const DIALOG_Z_INDEX: i32 = 1000;
+
+/// A modal dialog.
+///
+/// While open, the dialog covers its window with a dimming backdrop which blocks pointer input to
+/// the rest of the application, traps keyboard focus within its content, and prevents key presses
+/// from reaching views behind it. Pressing escape triggers the `on_close` callback, and focus
+/// returns to the previously focused view once the dialog closes.
+///
+/// # Examples
+///
+/// ## Dialog bound to data
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     show_dialog: bool,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     HideDialog,
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { show_dialog: false }.build(cx);
+/// #
+/// Dialog::new(cx, AppData::show_dialog, |cx| {
+///     Label::new(cx, "Hello");
+///     Button::new(cx, |cx| cx.emit(AppEvent::HideDialog), |cx| Label::new(cx, "Ok"));
+/// })
+/// .on_close(|cx| cx.emit(AppEvent::HideDialog));
+/// ```
+///
+/// ## Confirmation dialog
+///
+/// A confirmation dialog can be opened from an event handler and passes the choice of the user to
+/// a callback, which is called from the view which opened the dialog.
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # enum AppEvent {
+/// #     Delete,
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Button::new(
+///     cx,
+///     |cx| {
+///         Dialog::confirm(cx, "Delete the file?", |cx, ok| {
+///             if ok {
+///                 cx.emit(AppEvent::Delete);
+///             }
+///         })
+///     },
+///     |cx| Label::new(cx, "Delete"),
+/// );
+/// ```
+pub struct Dialog {
+    on_close: Option<Box<dyn Fn(&mut EventContext)>>,
+}
+
+impl Dialog {
+    /// Creates a new dialog which is open while the given lens is true.
+    pub fn new<L, F>(cx: &mut Context, is_open: L, content: F) -> Handle<Self>
+    where
+        L: Lens<Target = bool>,
+        F: 'static + Fn(&mut Context),
+    {
+        Self::backdrop(cx, move |cx| {
+            Binding::new(cx, is_open.clone(), move |cx, is_open| {
+                if is_open.get(cx) {
+                    Self::content(cx, &content);
+                }
+            });
+        })
+        .checked(is_open)
+    }
+
+    /// Opens a dialog which asks the user to confirm an action, and calls `on_result` with true if
+    /// the user confirmed it or false if the user cancelled it.
+    pub fn confirm<F>(cx: &mut EventContext, message: impl Into<String>, on_result: F)
+    where
+        F: 'static + Fn(&mut EventContext, bool) + Send + Sync,
+    {
+        let choices = vec![(String::from("Cancel"), false), (String::from("OK"), true)];
+        Self::choose(cx, message, choices, move |cx, result| {
+            (on_result)(cx, result.unwrap_or(false))
+        });
+    }
+
+    /// Opens a dialog which asks the user to pick one of the given choices, each of which is shown
+    /// as a button with the given label, and calls `on_result` with the value of the chosen button,
+    /// or with `None` if the dialog was dismissed with escape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// #
+    /// # #[derive(Clone)]
+    /// # enum Save {
+    /// #     Save,
+    /// #     Discard,
+    /// # }
+    /// #
+    /// # enum AppEvent {
+    /// #     Close(Save),
+    /// # }
+    /// #
+    /// # let cx = &mut Context::default();
+    /// #
+    /// Button::new(
+    ///     cx,
+    ///     |cx| {
+    ///         let choices = vec![
+    ///             (String::from("Discard"), Save::Discard),
+    ///             (String::from("Save"), Save::Save),
+    ///         ];
+    ///
+    ///         Dialog::choose(cx, "Save changes before closing?", choices, |cx, choice| {
+    ///             if let Some(choice) = choice {
+    ///                 cx.emit(AppEvent::Close(choice));
+    ///             }
+    ///         })
+    ///     },
+    ///     |cx| Label::new(cx, "Close"),
+    /// );
+    /// ```
+    pub fn choose<T, F>(
+        cx: &mut EventContext,
+        message: impl Into<String>,
+        choices: Vec<(String, T)>,
+        on_result: F,
+    ) where
+        T: 'static + Clone + Send + Sync,
+        F: 'static + Fn(&mut EventContext, Option<T>) + Send + Sync,
+    {
+        let (labels, values): (Vec<_>, Vec<_>) = choices.into_iter().unzip();
+        let request = DialogRequest {
+            id: 0,
+            opener: cx.current(),
+            message: message.into(),
+            choices: labels,
+            on_result: Arc::new(move |cx, index| {
+                (on_result)(cx, index.and_then(|index| values.get(index).cloned()))
+            }),
+        };
+
+        cx.emit_to(Entity::root(), DialogHostEvent::Open(request));
+    }
+
+    /// Builds the backdrop of a dialog, which covers the window.
+    fn backdrop(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        let handle = Self { on_close: None }.build(cx, content);
+
+        // Follow the size of the window, which does not otherwise affect the layout of the dialog.
+        handle.cx.with_current(handle.entity, |cx| {
+            cx.add_listener(|_: &mut Dialog, cx, event| {
+                event.map(|window_event, meta| {
+                    if let WindowEvent::GeometryChanged(_) = window_event {
+                        if meta.target == cx.tree.get_window(cx.current) {
+                            Self::cover_window(cx);
+                        }
+                    }
+                });
+            });
+        });
+
+        handle.position_type(PositionType::SelfDirected).z_index(DIALOG_Z_INDEX)
+    }
+
+    /// Builds the content of a dialog, within which focus is trapped.
+    fn content(cx: &mut Context, content: impl FnOnce(&mut Context)) {
+        VStack::new(cx, content).class("dialog-content").role(Role::Dialog).lock_focus_to_within();
+    }
+
+    /// Positions the backdrop so that it covers the window which contains it.
+    fn cover_window(cx: &mut EventContext) {
+        let window = cx.cache.get_bounds(cx.tree.get_window(cx.current));
+        let parent = cx.tree.get_layout_parent(cx.current).unwrap_or(Entity::root());
+        let parent = cx.cache.get_bounds(parent);
+        let scale = cx.scale_factor();
+
+        cx.set_left(Pixels((window.x - parent.x) / scale));
+        cx.set_top(Pixels((window.y - parent.y) / scale));
+        cx.set_width(Pixels(window.w / scale));
+        cx.set_height(Pixels(window.h / scale));
+    }
+}
+
+impl Handle<'_, Dialog> {
+    /// Sets the callback triggered when the user presses escape, usually with the intent of
+    /// closing the dialog.
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|dialog| dialog.on_close = Some(Box::new(callback)))
+    }
+}
+
+impl View for Dialog {
+    fn element(&self) -> Option<&'static str> {
+        Some("dialog")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::GeometryChanged(_) if meta.target == cx.current() => {
+                Self::cover_window(cx);
+            }
+
+            // Keep input within the dialog from reaching views behind it.
+            WindowEvent::KeyDown(code, _) => {
+                if *code == Code::Escape {
+                    if let Some(callback) = &self.on_close {
+                        (callback)(cx);
+                    }
+                }
+
+                meta.consume();
+            }
+
+            WindowEvent::KeyUp(_, _)
+            | WindowEvent::CharInput(_)
+            | WindowEvent::MouseDown(_)
+            | WindowEvent::MouseUp(_)
+            | WindowEvent::MouseScroll(_, _) => {
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+/// A dialog opened from an event handler, such as with [`Dialog::confirm`].
+#[derive(Clone)]
+pub(crate) struct DialogRequest {
+    id: usize,
+    opener: Entity,
+    message: String,
+    choices: Vec<String>,
+    on_result: Arc<dyn Fn(&mut EventContext, Option<usize>) + Send + Sync>,
+}
+
+impl Data for DialogRequest {
+    fn same(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+pub(crate) enum DialogHostEvent {
+    Open(DialogRequest),
+    Resolve(usize, Option<usize>),
+}
+
+/// The dialogs opened from event handlers, which are built at the root of the tree.
+#[derive(Default, Lens)]
+pub(crate) struct DialogHost {
+    requests: Vec<DialogRequest>,
+    #[lens(ignore)]
+    next_id: usize,
+}
+
+impl DialogHost {
+    /// Builds the dialog host into the root of the tree, unless it has already been built.
+    pub(crate) fn build_host(cx: &mut Context) {
+        cx.with_current(Entity::root(), |cx| {
+            if cx.data::<Self>().is_none() {
+                Self::default().build(cx);
+                Self::build_dialogs(cx);
+            }
+        });
+    }
+
+    fn build_dialogs(cx: &mut Context) {
+        Binding::new(cx, DialogHost::requests, |cx, requests| {
+            for request in requests.get(cx) {
+                let id = request.id;
+                Dialog::backdrop(cx, move |cx| {
+                    Dialog::content(cx, move |cx| {
+                        Label::new(cx, &request.message).class("dialog-message");
+                        HStack::new(cx, |cx| {
+                            for (index, choice) in request.choices.iter().enumerate() {
+                                Button::new(
+                                    cx,
+                                    move |cx| cx.emit(DialogHostEvent::Resolve(id, Some(index))),
+                                    |cx| Label::new(cx, choice),
+                                );
+                            }
+                        })
+                        .class("dialog-buttons");
+                    });
+                })
+                .checked(true)
+                .on_close(move |cx| cx.emit(DialogHostEvent::Resolve(id, None)));
+            }
+        });
+    }
+}
+
+impl Model for DialogHost {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        if let Some(event) = event.take() {
+            match event {
+                DialogHostEvent::Open(mut request) => {
+                    request.id = self.next_id;
+                    self.next_id += 1;
+                    self.requests.push(request);
+                }
+
+                DialogHostEvent::Resolve(id, choice) => {
+                    if let Some(index) = self.requests.iter().position(|request| request.id == id) {
+                        let request = self.requests.remove(index);
+                        cx.with_current(request.opener, |cx| (request.on_result)(cx, choice));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1109 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::icons::ICON_X;
+use crate::prelude::*;
+
+/// The distance in logical pixels which the cursor must move before a panel is dragged.
+const DOCK_DRAG_THRESHOLD: f32 = 4.0;
+
+/// The z-index of floating panels, which places them above docked panels.
+const FLOATING_Z_INDEX: i32 = 10;
+
+/// The smallest size in logical pixels to which a docking area can be resized.
+const MIN_AREA_SIZE: f32 = 48.0;
+
+/// The size in logical pixels of a panel when it is floated.
+const FLOATING_SIZE: (f32, f32) = (300.0, 200.0);
+
+/// An area of a [`DockView`] into which panels are docked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DockArea {
+    Left,
+    Right,
+    Bottom,
+    Center,
+}
+
+impl DockArea {
+    /// All of the docking areas.
+    pub const ALL: [DockArea; 4] =
+        [DockArea::Left, DockArea::Right, DockArea::Bottom, DockArea::Center];
+
+    fn name(&self) -> &'static str {
+        match self {
+            DockArea::Left => "left",
+            DockArea::Right => "right",
+            DockArea::Bottom => "bottom",
+            DockArea::Center => "center",
+        }
+    }
+}
+
+/// The panels docked into an area of a [`DockLayout`], which are shown as tabs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockStack {
+    /// The width of a side area, or the height of the bottom area, in logical pixels. The center
+    /// area fills the remaining space.
+    pub size: f32,
+    /// The ids of the docked panels, in tab order.
+    pub panels: Vec<String>,
+    /// The index of the selected panel.
+    pub selected: usize,
+}
+
+impl DockStack {
+    fn new(size: f32) -> Self {
+        Self { size, panels: Vec::new(), selected: 0 }
+    }
+
+    /// Returns the id of the selected panel, if the stack has any panels.
+    pub fn selected_panel(&self) -> Option<&str> {
+        self.panels.get(self.selected).map(String::as_str)
+    }
+
+    fn remove(&mut self, id: &str) -> bool {
+        if let Some(index) = self.panels.iter().position(|panel| panel == id) {
+            self.panels.remove(index);
+            if index < self.selected || (index == self.selected && index == self.panels.len()) {
+                self.selected = self.selected.saturating_sub(1);
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A panel floating over a [`DockView`], positioned in logical pixels relative to the dock view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatingPanel {
+    pub id: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// Where a panel is placed within a [`DockLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockPlacement {
+    /// The panel is docked into an area at the given tab index.
+    Docked(DockArea, usize),
+    /// The panel is floating, at the given index of the floating panels.
+    Floating(usize),
+}
+
+/// The arrangement of the panels of a [`DockView`].
+///
+/// Panels are identified by the id given to their [`DockPanel`]. A panel is either docked into one
+/// of the areas, floating, or closed if the layout does not contain it.
+///
+/// A layout can be converted to and from a string, so that the layout arranged by the user can be
+/// saved and restored. Each line lists the panels docked into an area, after the size of the area,
+/// with the selected panel marked by a `*`, and each floating panel is listed with its position and
+/// size. Panel ids should therefore not contain whitespace or `*`.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// let layout: DockLayout = "
+///     left 240 files* outline
+///     bottom 180 console
+///     center editor*
+///     float properties 600 80 300 200
+/// "
+/// .parse()
+/// .unwrap();
+///
+/// assert_eq!(layout.placement("outline"), Some(DockPlacement::Docked(DockArea::Left, 1)));
+/// assert_eq!(layout.to_string().parse::<DockLayout>().unwrap(), layout);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockLayout {
+    pub left: DockStack,
+    pub right: DockStack,
+    pub bottom: DockStack,
+    pub center: DockStack,
+    /// The floating panels, from back to front.
+    pub floating: Vec<FloatingPanel>,
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        Self {
+            left: DockStack::new(240.0),
+            right: DockStack::new(240.0),
+            bottom: DockStack::new(200.0),
+            center: DockStack::new(0.0),
+            floating: Vec::new(),
+        }
+    }
+}
+
+impl Data for DockLayout {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl DockLayout {
+    /// Creates an empty layout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Docks a panel into an area after the panels already docked into it.
+    pub fn with_panel(mut self, area: DockArea, id: impl Into<String>) -> Self {
+        self.dock(id, area, usize::MAX);
+        self
+    }
+
+    /// Returns the panels docked into an area.
+    pub fn stack(&self, area: DockArea) -> &DockStack {
+        match area {
+            DockArea::Left => &self.left,
+            DockArea::Right => &self.right,
+            DockArea::Bottom => &self.bottom,
+            DockArea::Center => &self.center,
+        }
+    }
+
+    /// Returns a mutable reference to the panels docked into an area.
+    pub fn stack_mut(&mut self, area: DockArea) -> &mut DockStack {
+        match area {
+            DockArea::Left => &mut self.left,
+            DockArea::Right => &mut self.right,
+            DockArea::Bottom => &mut self.bottom,
+            DockArea::Center => &mut self.center,
+        }
+    }
+
+    /// Returns where the panel with the given id is placed, or `None` if it is closed.
+    pub fn placement(&self, id: &str) -> Option<DockPlacement> {
+        DockArea::ALL
+            .into_iter()
+            .find_map(|area| {
+                let index = self.stack(area).panels.iter().position(|panel| panel == id)?;
+                Some(DockPlacement::Docked(area, index))
+            })
+            .or_else(|| {
+                self.floating.iter().position(|panel| panel.id == id).map(DockPlacement::Floating)
+            })
+    }
+
+    /// Returns whether the layout contains the panel with the given id.
+    pub fn contains(&self, id: &str) -> bool {
+        self.placement(id).is_some()
+    }
+
+    /// Returns the floating panel with the given id, if the panel is floating.
+    pub fn floating_panel(&self, id: &str) -> Option<&FloatingPanel> {
+        self.floating.iter().find(|panel| panel.id == id)
+    }
+
+    /// Docks a panel into an area at the given tab index and selects it, moving it from wherever
+    /// it was placed. An index past the last tab of the area appends the panel.
+    pub fn dock(&mut self, id: impl Into<String>, area: DockArea, index: usize) {
+        let id = id.into();
+        self.close(&id);
+
+        let stack = self.stack_mut(area);
+        let index = index.min(stack.panels.len());
+        stack.panels.insert(index, id);
+        stack.selected = index;
+    }
+
+    /// Floats a panel in front of the other floating panels, moving it from wherever it was placed.
+    pub fn float(&mut self, id: impl Into<String>, x: f32, y: f32, width: f32, height: f32) {
+        let id = id.into();
+        self.close(&id);
+        self.floating.push(FloatingPanel { id, x, y, width, height });
+    }
+
+    /// Removes a panel from the layout, returning whether the layout contained it.
+    pub fn close(&mut self, id: &str) -> bool {
+        let mut removed = false;
+        for area in DockArea::ALL {
+            removed |= self.stack_mut(area).remove(id);
+        }
+
+        let count = self.floating.len();
+        self.floating.retain(|panel| panel.id != id);
+
+        removed || self.floating.len() != count
+    }
+
+    /// Selects a docked panel within its area, or moves a floating panel in front of the other
+    /// floating panels.
+    pub fn select(&mut self, id: &str) {
+        match self.placement(id) {
+            Some(DockPlacement::Docked(area, index)) => self.stack_mut(area).selected = index,
+            Some(DockPlacement::Floating(index)) => {
+                let panel = self.floating.remove(index);
+                self.floating.push(panel);
+            }
+            None => {}
+        }
+    }
+
+    // Returns whether the panels are docked into the same areas and tab positions, and floating in
+    // the same order, in both layouts.
+    fn same_placement(&self, other: &DockLayout) -> bool {
+        DockArea::ALL.into_iter().all(|area| self.stack(area).panels == other.stack(area).panels)
+            && self
+                .floating
+                .iter()
+                .map(|panel| &panel.id)
+                .eq(other.floating.iter().map(|panel| &panel.id))
+    }
+
+    // Returns whether the panel is shown, which is the case if it is floating or selected.
+    fn is_shown(&self, id: &str) -> bool {
+        match self.placement(id) {
+            Some(DockPlacement::Docked(area, index)) => self.stack(area).selected == index,
+            Some(DockPlacement::Floating(_)) => true,
+            None => false,
+        }
+    }
+}
+
+impl fmt::Display for DockLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for area in DockArea::ALL {
+            let stack = self.stack(area);
+            write!(f, "{}", area.name())?;
+            if area != DockArea::Center {
+                write!(f, " {}", stack.size)?;
+            }
+
+            for (index, id) in stack.panels.iter().enumerate() {
+                let marker = if index == stack.selected { "*" } else { "" };
+                write!(f, " {}{}", id, marker)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        for panel in self.floating.iter() {
+            writeln!(
+                f,
+                "float {} {} {} {} {}",
+                panel.id, panel.x, panel.y, panel.width, panel.height
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DockLayout {
+    type Err = ParseDockLayoutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut layout = DockLayout::default();
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let mut tokens = line.split_whitespace();
+            let incomplete = || ParseDockLayoutError::Incomplete(line.to_owned());
+
+            let area = match tokens.next().ok_or_else(incomplete)? {
+                "left" => DockArea::Left,
+                "right" => DockArea::Right,
+                "bottom" => DockArea::Bottom,
+                "center" => DockArea::Center,
+                "float" => {
+                    let id = tokens.next().ok_or_else(incomplete)?;
+                    if layout.contains(id) {
+                        return Err(ParseDockLayoutError::DuplicatePanel(id.to_owned()));
+                    }
+
+                    let mut numbers = [0.0; 4];
+                    for number in numbers.iter_mut() {
+                        *number = parse_number(tokens.next().ok_or_else(incomplete)?)?;
+                    }
+
+                    let [x, y, width, height] = numbers;
+                    layout.float(id, x, y, width, height);
+                    continue;
+                }
+                keyword => return Err(ParseDockLayoutError::UnknownArea(keyword.to_owned())),
+            };
+
+            if area != DockArea::Center {
+                layout.stack_mut(area).size = parse_number(tokens.next().ok_or_else(incomplete)?)?;
+            }
+
+            for token in tokens {
+                let (id, selected) = match token.strip_suffix('*') {
+                    Some(id) => (id, true),
+                    None => (token, false),
+                };
+
+                if layout.contains(id) {
+                    return Err(ParseDockLayoutError::DuplicatePanel(id.to_owned()));
+                }
+
+                let stack = layout.stack_mut(area);
+                if selected {
+                    stack.selected = stack.panels.len();
+                }
+                stack.panels.push(id.to_owned());
+            }
+        }
+
+        Ok(layout)
+    }
+}
+
+fn parse_number(s: &str) -> Result<f32, ParseDockLayoutError> {
+    s.parse().map_err(|_| ParseDockLayoutError::InvalidNumber(s.to_owned()))
+}
+
+/// An error returned when parsing a [`DockLayout`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDockLayoutError {
+    /// A line does not start with the name of an area or `float`.
+    UnknownArea(String),
+    /// A size or position is not a number.
+    InvalidNumber(String),
+    /// A line is missing a size, id or position.
+    Incomplete(String),
+    /// A panel is placed more than once.
+    DuplicatePanel(String),
+}
+
+impl fmt::Display for ParseDockLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDockLayoutError::UnknownArea(name) => write!(f, "unknown area '{}'", name),
+            ParseDockLayoutError::InvalidNumber(value) => write!(f, "invalid number '{}'", value),
+            ParseDockLayoutError::Incomplete(line) => write!(f, "incomplete line '{}'", line),
+            ParseDockLayoutError::DuplicatePanel(id) => write!(f, "duplicate panel '{}'", id),
+        }
+    }
+}
+
+impl Error for ParseDockLayoutError {}
+
+/// A panel of a [`DockView`], with an id used to refer to it in a [`DockLayout`], a title shown in
+/// its tab, and its content.
+pub struct DockPanel {
+    id: String,
+    title: String,
+    content: Box<dyn FnOnce(&mut Context)>,
+}
+
+impl DockPanel {
+    pub fn new<C>(id: impl Into<String>, title: impl Into<String>, content: C) -> Self
+    where
+        C: 'static + FnOnce(&mut Context),
+    {
+        Self { id: id.into(), title: title.into(), content: Box::new(content) }
+    }
+}
+
+pub enum DockEvent {
+    /// Selects the panel with the given id within its area, or brings it to the front if it is
+    /// floating.
+    Select(String),
+    /// Docks the panel with the given id into an area at a tab index.
+    Dock(String, DockArea, usize),
+    /// Floats the panel with the given id over the dock view.
+    Float(String),
+    /// Closes the panel with the given id. Its content is kept so that it can be reopened.
+    Close(String),
+    /// Reopens the closed panel with the given id in the area it was last docked into.
+    Open(String),
+    /// Replaces the layout of the dock view.
+    SetLayout(DockLayout),
+}
+
+enum DockDragEvent {
+    /// A panel started being dragged from the given cursor position.
+    Start(String, f32, f32),
+    /// A dragged panel moved to the given cursor position.
+    Move(String, f32, f32),
+    /// A dragged panel was dropped at the given cursor position.
+    Drop(String, f32, f32),
+    /// The splitter of an area was dragged to the given cursor position.
+    Resize(DockArea, f32, f32),
+}
+
+// The entities making up a docking area.
+struct AreaEntities {
+    area: DockArea,
+    root: Entity,
+    tabs: Entity,
+    content: Entity,
+}
+
+/// A view which arranges panels into docking areas to the left, right and bottom of a center
+/// area, where the panels of each area are shown as tabs.
+///
+/// Panels are moved by dragging their tabs. A panel dropped onto the tabs of an area, or onto an
+/// empty area, is docked into it, while a panel dropped anywhere else floats over the dock view and
+/// can be moved by dragging its title. Panels are closed with the close button of their tab or a
+/// middle click, and reopened with [`DockEvent::Open`]. The areas are resized by dragging the
+/// splitters between them.
+///
+/// The content of each panel is built once and kept while the panel is moved or closed,
+/// preserving its state. The layout can be saved with the
+/// [`on_layout_change`](Handle::on_layout_change) callback and restored with
+/// [`DockEvent::SetLayout`].
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// let layout = DockLayout::new()
+///     .with_panel(DockArea::Left, "files")
+///     .with_panel(DockArea::Center, "editor");
+///
+/// DockView::new(
+///     cx,
+///     layout,
+///     vec![
+///         DockPanel::new("files", "Files", |cx| {
+///             Label::new(cx, "main.rs");
+///         }),
+///         DockPanel::new("editor", "Editor", |cx| {
+///             Label::new(cx, "fn main() {}");
+///         }),
+///     ],
+/// )
+/// .on_layout_change(|_, layout| println!("{}", layout));
+/// ```
+#[derive(Lens)]
+pub struct DockView {
+    layout: DockLayout,
+    dragging: bool,
+    #[lens(ignore)]
+    areas: Vec<AreaEntities>,
+    #[lens(ignore)]
+    panels: HashMap<String, Entity>,
+    // The container of closed panels, which is not displayed.
+    #[lens(ignore)]
+    closed: Entity,
+    // The area each panel was last docked into, so that closed panels can be reopened there.
+    #[lens(ignore)]
+    last_area: HashMap<String, DockArea>,
+    // The cursor position of the last drag event, while a panel is being dragged.
+    #[lens(ignore)]
+    drag_position: Option<(f32, f32)>,
+    // The area which is highlighted as the target of the dragged panel.
+    #[lens(ignore)]
+    drop_target: Option<Entity>,
+    #[lens(ignore)]
+    on_layout_change: Option<Box<dyn Fn(&mut EventContext, &DockLayout)>>,
+}
+
+impl DockView {
+    pub fn new(cx: &mut Context, layout: DockLayout, panels: Vec<DockPanel>) -> Handle<Self> {
+        let titles: HashMap<String, String> =
+            panels.iter().map(|panel| (panel.id.clone(), panel.title.clone())).collect();
+        let mut panels: HashMap<String, DockPanel> =
+            panels.into_iter().map(|panel| (panel.id.clone(), panel)).collect();
+
+        let mut areas = Vec::new();
+        let mut panel_entities = HashMap::new();
+        let mut closed = Entity::null();
+        let initial = layout.clone();
+
+        let handle = Self {
+            layout,
+            dragging: false,
+            areas: Vec::new(),
+            panels: HashMap::new(),
+            closed: Entity::null(),
+            last_area: HashMap::new(),
+            drag_position: None,
+            drop_target: None,
+            on_layout_change: None,
+        }
+        .build(cx, |cx| {
+            HStack::new(cx, |cx| {
+                areas.push(Self::build_area(cx, DockArea::Left, &titles));
+                DockSplitter::new(cx, DockArea::Left);
+                VStack::new(cx, |cx| {
+                    areas.push(Self::build_area(cx, DockArea::Center, &titles));
+                    DockSplitter::new(cx, DockArea::Bottom);
+                    areas.push(Self::build_area(cx, DockArea::Bottom, &titles));
+                })
+                .class("dock-middle");
+                DockSplitter::new(cx, DockArea::Right);
+                areas.push(Self::build_area(cx, DockArea::Right, &titles));
+            })
+            .class("dock-main");
+
+            closed = Element::new(cx).class("dock-closed").entity();
+
+            // Build the panels into the areas they are docked into, in tab order, followed by the
+            // floating panels and then the closed panels.
+            let mut placed = Vec::new();
+            for area in areas.iter() {
+                for id in initial.stack(area.area).panels.iter() {
+                    placed.push((id.clone(), area.content));
+                }
+            }
+
+            for panel in initial.floating.iter() {
+                placed.push((panel.id.clone(), cx.current()));
+            }
+
+            for (id, parent) in placed {
+                if let Some(panel) = panels.remove(&id) {
+                    let entity =
+                        cx.with_current(parent, |cx| DockPanelView::new(cx, panel).entity());
+                    panel_entities.insert(id, entity);
+                }
+            }
+
+            for (id, panel) in panels.drain() {
+                let entity = cx.with_current(closed, |cx| DockPanelView::new(cx, panel).entity());
+                panel_entities.insert(id, entity);
+            }
+        });
+
+        handle
+            .modify(|dock| {
+                dock.areas = areas;
+                dock.panels = panel_entities;
+                dock.closed = closed;
+                dock.record_areas();
+            })
+            .toggle_class("dragging", DockView::dragging)
+    }
+
+    // Builds a docking area, with a row of tabs for the panels docked into it.
+    fn build_area(
+        cx: &mut Context,
+        area: DockArea,
+        titles: &HashMap<String, String>,
+    ) -> AreaEntities {
+        let titles = titles.clone();
+        let mut tabs = Entity::null();
+        let mut content = Entity::null();
+
+        let handle = VStack::new(cx, |cx| {
+            tabs = HStack::new(cx, move |cx| {
+                let panels = DockView::layout.map(move |layout| layout.stack(area).panels.clone());
+                Binding::new(cx, panels, move |cx, panels| {
+                    for id in panels.get(cx) {
+                        if let Some(title) = titles.get(&id) {
+                            let selected = id.clone();
+                            DockTab::new(cx, id, title).checked(DockView::layout.map(
+                                move |layout| {
+                                    layout.stack(area).selected_panel() == Some(selected.as_str())
+                                },
+                            ));
+                        }
+                    }
+                });
+            })
+            .class("dock-tabs")
+            .entity();
+
+            content = VStack::new(cx, |_| {}).class("dock-area-content").entity();
+        })
+        .class("dock-area")
+        .class(area.name())
+        .toggle_class(
+            "empty",
+            DockView::layout.map(move |layout| layout.stack(area).panels.is_empty()),
+        );
+
+        let size = DockView::layout.map(move |layout| Pixels(layout.stack(area).size));
+        let root = match area {
+            DockArea::Left | DockArea::Right => handle.width(size).entity(),
+            DockArea::Bottom => handle.height(size).entity(),
+            DockArea::Center => handle.entity(),
+        };
+
+        AreaEntities { area, root, tabs, content }
+    }
+
+    // Records the area each docked panel is docked into.
+    fn record_areas(&mut self) {
+        for area in DockArea::ALL {
+            for id in self.layout.stack(area).panels.iter() {
+                self.last_area.insert(id.clone(), area);
+            }
+        }
+    }
+
+    // Moves the panels to the areas they are docked into after the layout has changed.
+    fn layout_changed(&mut self, cx: &mut EventContext) {
+        self.record_areas();
+
+        for area in self.areas.iter() {
+            for (index, id) in self.layout.stack(area.area).panels.iter().enumerate() {
+                if let Some(&entity) = self.panels.get(id) {
+                    cx.reparent(entity, area.content, index);
+                }
+            }
+        }
+
+        // Floating panels are kept after the docking areas, from back to front.
+        for panel in self.layout.floating.iter() {
+            if let Some(&entity) = self.panels.get(&panel.id) {
+                cx.reparent(entity, cx.current(), usize::MAX);
+            }
+        }
+
+        for (id, &entity) in self.panels.iter() {
+            if !self.layout.contains(id) {
+                cx.reparent(entity, self.closed, usize::MAX);
+            }
+        }
+
+        self.notify(cx);
+    }
+
+    fn notify(&self, cx: &mut EventContext) {
+        if let Some(callback) = &self.on_layout_change {
+            (callback)(cx, &self.layout);
+        }
+    }
+
+    // Returns the area and tab index at which a panel would be docked if it was dropped at the
+    // given cursor position, or `None` if it would float.
+    fn drop_target(
+        &self,
+        cx: &EventContext,
+        id: &str,
+        x: f32,
+        y: f32,
+    ) -> Option<(DockArea, usize)> {
+        let contains = |entity: Entity| {
+            let bounds = cx.cache.get_bounds(entity);
+            x >= bounds.left() && x < bounds.right() && y >= bounds.top() && y < bounds.bottom()
+        };
+
+        self.areas.iter().find_map(|area| {
+            if contains(area.tabs) {
+                // The panel is inserted after the other tabs to the left of the cursor.
+                let index = area
+                    .tabs
+                    .child_iter(cx.tree)
+                    .filter(|&tab| {
+                        cx.views
+                            .get(&tab)
+                            .and_then(|view| view.downcast_ref::<DockTab>())
+                            .map_or(false, |tab| tab.id != id)
+                            && cx.cache.get_bounds(tab).center().0 < x
+                    })
+                    .count();
+
+                Some((area.area, index))
+            } else if self.layout.stack(area.area).panels.is_empty() && contains(area.root) {
+                Some((area.area, 0))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn set_drop_target(&mut self, cx: &mut EventContext, target: Option<Entity>) {
+        if target != self.drop_target {
+            for (entity, applied) in [(self.drop_target, false), (target, true)] {
+                if let Some(entity) = entity {
+                    cx.with_current(entity, |cx| cx.toggle_class("drop-target", applied));
+                }
+            }
+
+            self.drop_target = target;
+        }
+    }
+}
+
+impl Handle<'_, DockView> {
+    /// Sets the callback triggered whenever the layout changes, such as when a panel is moved or
+    /// closed or an area is resized, with the new layout.
+    pub fn on_layout_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &DockLayout),
+    {
+        self.modify(|dock| dock.on_layout_change = Some(Box::new(callback)))
+    }
+}
+
+impl View for DockView {
+    fn element(&self) -> Option<&'static str> {
+        Some("dockview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|dock_event, meta| {
+            let previous = self.layout.clone();
+            match dock_event {
+                DockEvent::Select(id) => self.layout.select(id),
+
+                DockEvent::Dock(id, area, index) => {
+                    if self.panels.contains_key(id) {
+                        self.layout.dock(id.clone(), *area, *index);
+                    }
+                }
+
+                DockEvent::Float(id) => {
+                    if self.panels.contains_key(id) {
+                        let bounds = cx.bounds();
+                        let scale = cx.scale_factor();
+                        let (width, height) = FLOATING_SIZE;
+                        let x = ((bounds.width() / scale - width) / 2.0).max(0.0);
+                        let y = ((bounds.height() / scale - height) / 2.0).max(0.0);
+                        self.layout.float(id.clone(), x, y, width, height);
+                    }
+                }
+
+                DockEvent::Close(id) => {
+                    self.layout.close(id);
+                }
+
+                DockEvent::Open(id) => {
+                    if self.panels.contains_key(id) && !self.layout.contains(id) {
+                        let area = self.last_area.get(id).copied().unwrap_or(DockArea::Center);
+                        self.layout.dock(id.clone(), area, usize::MAX);
+                    }
+                }
+
+                DockEvent::SetLayout(layout) => self.layout = layout.clone(),
+            }
+
+            // Panels only need to be moved if their placement changed, and not when a docked panel
+            // is selected or an area is resized.
+            if !self.layout.same_placement(&previous) {
+                self.layout_changed(cx);
+            } else if self.layout != previous {
+                self.notify(cx);
+            }
+            meta.consume();
+        });
+
+        event.map(|drag_event, meta| {
+            match drag_event {
+                DockDragEvent::Start(_, x, y) => {
+                    self.dragging = true;
+                    self.drag_position = Some((*x, *y));
+                }
+
+                DockDragEvent::Move(id, x, y) => {
+                    // Floating panels follow the cursor while they are dragged.
+                    if let Some((px, py)) = self.drag_position.replace((*x, *y)) {
+                        let scale = cx.scale_factor();
+                        if let Some(panel) = self.layout.floating.iter_mut().find(|p| p.id == *id) {
+                            panel.x += (x - px) / scale;
+                            panel.y += (y - py) / scale;
+                        }
+                    }
+
+                    let target = self.drop_target(cx, id, *x, *y).and_then(|(area, _)| {
+                        self.areas.iter().find(|a| a.area == area).map(|a| a.root)
+                    });
+                    self.set_drop_target(cx, target);
+                }
+
+                DockDragEvent::Drop(id, x, y) => {
+                    self.dragging = false;
+                    self.drag_position = None;
+                    self.set_drop_target(cx, None);
+
+                    if let Some((area, index)) = self.drop_target(cx, id, *x, *y) {
+                        self.layout.dock(id.clone(), area, index);
+                    } else if self.layout.floating_panel(id).is_none() {
+                        // Float the panel with its title under the cursor.
+                        let bounds = cx.bounds();
+                        let scale = cx.scale_factor();
+                        let (width, height) = FLOATING_SIZE;
+                        let x = ((x - bounds.left()) / scale - 32.0).max(0.0);
+                        let y = ((y - bounds.top()) / scale - 16.0).max(0.0);
+                        self.layout.float(id.clone(), x, y, width, height);
+                    }
+
+                    self.layout_changed(cx);
+                }
+
+                DockDragEvent::Resize(area, x, y) => {
+                    let bounds = cx.bounds();
+                    let size = match area {
+                        DockArea::Left => x - bounds.left(),
+                        DockArea::Right => bounds.right() - x,
+                        DockArea::Bottom => bounds.bottom() - y,
+                        DockArea::Center => return,
+                    };
+
+                    self.layout.stack_mut(*area).size =
+                        (size / cx.scale_factor()).max(MIN_AREA_SIZE);
+                    self.notify(cx);
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+/// The container of a docked or floating panel, which is moved between areas without rebuilding
+/// its content.
+struct DockPanelView {}
+
+impl DockPanelView {
+    fn new(cx: &mut Context, panel: DockPanel) -> Handle<Self> {
+        let DockPanel { id, title, content } = panel;
+
+        let floating = {
+            let id = id.clone();
+            move |layout: &DockLayout| layout.floating_panel(&id).cloned()
+        };
+        let shown = {
+            let id = id.clone();
+            DockView::layout.map(move |layout| layout.is_shown(&id))
+        };
+
+        Self {}
+            .build(cx, |cx| {
+                DockTab::new(cx, id, &title).class("dock-title");
+                VStack::new(cx, content).class("dockpanel-content");
+            })
+            .display(shown)
+            .toggle_class(
+                "floating",
+                DockView::layout.map({
+                    let floating = floating.clone();
+                    move |layout| floating(layout).is_some()
+                }),
+            )
+            .z_index(DockView::layout.map({
+                let floating = floating.clone();
+                move |layout| if floating(layout).is_some() { FLOATING_Z_INDEX } else { 0 }
+            }))
+            .left(DockView::layout.map({
+                let floating = floating.clone();
+                move |layout| floating(layout).map_or(Auto, |panel| Pixels(panel.x))
+            }))
+            .top(DockView::layout.map({
+                let floating = floating.clone();
+                move |layout| floating(layout).map_or(Auto, |panel| Pixels(panel.y))
+            }))
+            .width(DockView::layout.map({
+                let floating = floating.clone();
+                move |layout| floating(layout).map_or(Stretch(1.0), |panel| Pixels(panel.width))
+            }))
+            .height(DockView::layout.map(move |layout| {
+                floating(layout).map_or(Stretch(1.0), |panel| Pixels(panel.height))
+            }))
+    }
+}
+
+impl View for DockPanelView {
+    fn element(&self) -> Option<&'static str> {
+        Some("dockpanel")
+    }
+}
+
+/// The tab of a docked panel, or the title of a floating panel, which is dragged to move the panel.
+struct DockTab {
+    id: String,
+    // The cursor position at which the tab was pressed, while it is pressed.
+    press_position: Option<(f32, f32)>,
+    dragging: bool,
+}
+
+impl DockTab {
+    fn new(cx: &mut Context, id: String, title: &str) -> Handle<Self> {
+        let close_id = id.clone();
+        Self { id, press_position: None, dragging: false }
+            .build(cx, |cx| {
+                Label::new(cx, title).hoverable(false);
+
+                Button::new(
+                    cx,
+                    move |cx| cx.emit(DockEvent::Close(close_id.clone())),
+                    |cx| Label::new(cx, ICON_X).class("icon"),
+                )
+                .class("icon")
+                .class("docktab-close")
+                .navigable(false);
+            })
+            .navigable(true)
+    }
+}
+
+impl View for DockTab {
+    fn element(&self) -> Option<&'static str> {
+        Some("docktab")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                cx.emit(DockEvent::Select(self.id.clone()));
+                self.press_position = Some((cx.mouse.cursorx, cx.mouse.cursory));
+                cx.capture();
+            }
+
+            WindowEvent::MouseDown(MouseButton::Middle) => {
+                cx.emit(DockEvent::Close(self.id.clone()));
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some((px, py)) = self.press_position {
+                    let threshold = DOCK_DRAG_THRESHOLD * cx.scale_factor();
+                    if !self.dragging && ((x - px).abs() > threshold || (y - py).abs() > threshold)
+                    {
+                        self.dragging = true;
+                        cx.toggle_class("dragging", true);
+                        cx.emit(DockDragEvent::Start(self.id.clone(), px, py));
+                    }
+
+                    if self.dragging {
+                        cx.emit(DockDragEvent::Move(self.id.clone(), *x, *y));
+                    }
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.press_position.take().is_some() {
+                    cx.release();
+                }
+
+                if self.dragging {
+                    self.dragging = false;
+                    cx.toggle_class("dragging", false);
+                    cx.emit(DockDragEvent::Drop(
+                        self.id.clone(),
+                        cx.mouse.cursorx,
+                        cx.mouse.cursory,
+                    ));
+                }
+            }
+
+            WindowEvent::FocusIn if meta.target == cx.current() => {
+                cx.emit(DockEvent::Select(self.id.clone()));
+            }
+
+            _ => {}
+        });
+    }
+}
+
+/// The splitter between a docking area and the center area, which is dragged to resize the area.
+struct DockSplitter {
+    area: DockArea,
+    dragging: bool,
+}
+
+impl DockSplitter {
+    fn new(cx: &mut Context, area: DockArea) -> Handle<Self> {
+        let orientation = if area == DockArea::Bottom { "horizontal" } else { "vertical" };
+        Self { area, dragging: false }
+            .build(cx, |_| {})
+            .class(orientation)
+            .display(DockView::layout.map(move |layout| !layout.stack(area).panels.is_empty()))
+    }
+}
+
+impl View for DockSplitter {
+    fn element(&self) -> Option<&'static str> {
+        Some("docksplitter")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                self.dragging = true;
+                cx.capture();
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if self.dragging {
+                    cx.emit(DockDragEvent::Resize(self.area, *x, *y));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.dragging {
+                    self.dragging = false;
+                    cx.release();
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(layout: &DockLayout) {
+        assert_eq!(layout.to_string().parse::<DockLayout>().as_ref(), Ok(layout));
+    }
+
+    #[test]
+    fn empty_layout_round_trips() {
+        round_trip(&DockLayout::new());
+    }
+
+    #[test]
+    fn layout_round_trips() {
+        let mut layout = DockLayout::new()
+            .with_panel(DockArea::Left, "files")
+            .with_panel(DockArea::Left, "outline")
+            .with_panel(DockArea::Right, "properties")
+            .with_panel(DockArea::Bottom, "console")
+            .with_panel(DockArea::Center, "editor");
+        layout.select("files");
+        layout.left.size = 180.5;
+        layout.float("search", 12.0, 40.25, 300.0, 200.0);
+        layout.float("history", 0.0, 0.0, 120.0, 80.0);
+
+        round_trip(&layout);
+        assert_eq!(layout.placement("files"), Some(DockPlacement::Docked(DockArea::Left, 0)));
+        assert_eq!(layout.left.selected_panel(), Some("files"));
+        assert_eq!(layout.placement("history"), Some(DockPlacement::Floating(1)));
+    }
+
+    #[test]
+    fn layout_round_trips_after_moving_panels() {
+        let mut layout = DockLayout::new()
+            .with_panel(DockArea::Center, "editor")
+            .with_panel(DockArea::Center, "preview")
+            .with_panel(DockArea::Bottom, "console");
+        layout.float("console", 10.0, 10.0, 200.0, 100.0);
+        layout.dock("preview", DockArea::Right, 0);
+        layout.close("editor");
+
+        round_trip(&layout);
+        assert!(!layout.contains("editor"));
+    }
+
+    #[test]
+    fn invalid_layouts() {
+        assert_eq!(
+            "top 100 files".parse::<DockLayout>(),
+            Err(ParseDockLayoutError::UnknownArea("top".to_owned()))
+        );
+        assert_eq!(
+            "left wide files".parse::<DockLayout>(),
+            Err(ParseDockLayoutError::InvalidNumber("wide".to_owned()))
+        );
+        assert_eq!(
+            "float search 10 10".parse::<DockLayout>(),
+            Err(ParseDockLayoutError::Incomplete("float search 10 10".to_owned()))
+        );
+        assert_eq!(
+            "left 100 files\ncenter files".parse::<DockLayout>(),
+            Err(ParseDockLayoutError::DuplicatePanel("files".to_owned()))
+        );
+    }
+}
@@ -267,7 +267,7 @@ where
             .role(Role::Dialog)
             .checked(lens.clone())
             .position_type(PositionType::SelfDirected)
-            .z_index(100)
+            .portal(true)
     }
 }
 
@@ -3,8 +3,11 @@
 mod button;
 mod checkbox;
 mod chip;
+mod collapsible;
 mod combobox;
 mod datepicker;
+mod dialog;
+mod dock;
 mod dropdown;
 mod element;
 mod image;
@@ -21,6 +24,7 @@ mod radio;
 mod rating;
 mod scrollbar;
 mod scrollview;
+mod shortcut_recorder;
 mod slider;
 mod spinbox;
 mod stack;
@@ -28,16 +32,27 @@ mod switch;
 mod tab;
 mod textbox;
 mod timepicker;
+mod toast;
 mod tooltip;
 mod virtual_list;
+mod window;
 
 pub use self::image::Image;
 pub use crate::binding::Binding;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use chip::Chip;
+pub use collapsible::{
+    Accordion, AccordionEvent, AccordionSection, Collapsible, CollapsibleEvent,
+};
 pub use combobox::*;
 pub use datepicker::Datepicker;
+pub use dialog::Dialog;
+pub(crate) use dialog::{DialogHost, DialogHostEvent};
+pub use dock::{
+    DockArea, DockEvent, DockLayout, DockPanel, DockPlacement, DockStack, DockView, FloatingPanel,
+    ParseDockLayoutError,
+};
 pub use dropdown::Dropdown;
 pub use element::Element;
 pub use knob::{ArcTrack, Knob, KnobMode, TickKnob, Ticks};
@@ -52,18 +67,22 @@ pub use radio::RadioButton;
 pub use rating::Rating;
 pub use scrollbar::Scrollbar;
 pub use scrollview::{ScrollData, ScrollEvent, ScrollView};
+pub use shortcut_recorder::ShortcutRecorder;
 pub use slider::{NamedSlider, Slider};
 pub use spinbox::{Spinbox, SpinboxEvent, SpinboxIcons, SpinboxKind};
 pub use stack::{HStack, VStack, ZStack};
 pub use switch::Switch;
-pub use tab::{TabPair, TabView};
+pub use tab::{TabEvent, TabPair, TabView};
 pub use textbox::{TextEvent, Textbox};
 pub use timepicker::{
     AMOrPM, AnalogTimepicker, AnalogTimepickerEvent, AnalogTimepickerPage, DayTime,
     DigitalTimepicker, DigitalTimepickerEvent, Timepicker,
 };
+pub use toast::{ToastAction, ToastEvent, ToastKind, DEFAULT_TOAST_DURATION};
+pub(crate) use toast::ToastHost;
 pub use tooltip::Tooltip;
 pub use virtual_list::*;
+pub use window::Window;
 
 use crate::prelude::*;
 
@@ -67,7 +67,7 @@ where
             .role(Role::Dialog)
             .checked(lens)
             .position_type(PositionType::SelfDirected)
-            .z_index(100)
+            .portal(true)
     }
 }
 
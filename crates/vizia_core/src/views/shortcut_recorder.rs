@@ -0,0 +1,176 @@
+use crate::prelude::*;
+
+/// A view which records a key chord, used to let the user choose a keyboard shortcut.
+///
+/// Pressing the recorder starts recording, after which the next key chord pressed by the user is
+/// passed to the `on_record` callback. Modifier keys on their own are not recorded, and pressing
+/// escape or moving focus elsewhere cancels recording. While not recording, pressing backspace or
+/// delete triggers the `on_clear` callback.
+///
+/// The recorder displays the bound key chord with the conventions of the current platform.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # #[derive(Lens)]
+/// # struct AppData {
+/// #     shortcut: Option<KeyChord>,
+/// # }
+/// #
+/// # impl Model for AppData {}
+/// #
+/// # enum AppEvent {
+/// #     SetShortcut(Option<KeyChord>),
+/// # }
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// # AppData { shortcut: None }.build(cx);
+/// #
+/// ShortcutRecorder::new(cx, AppData::shortcut)
+///     .on_record(|cx, chord| cx.emit(AppEvent::SetShortcut(Some(chord))))
+///     .on_clear(|cx| cx.emit(AppEvent::SetShortcut(None)));
+/// ```
+#[derive(Lens)]
+pub struct ShortcutRecorder {
+    recording: bool,
+
+    // Set when a recorded key would also press the recorder once it is released.
+    #[lens(ignore)]
+    ignore_press: bool,
+    #[lens(ignore)]
+    on_record: Option<Box<dyn Fn(&mut EventContext, KeyChord)>>,
+    #[lens(ignore)]
+    on_clear: Option<Box<dyn Fn(&mut EventContext)>>,
+}
+
+impl ShortcutRecorder {
+    /// Creates a new shortcut recorder which displays the given key chord.
+    pub fn new<L>(cx: &mut Context, lens: L) -> Handle<Self>
+    where
+        L: Lens<Target = Option<KeyChord>>,
+    {
+        Self { recording: false, ignore_press: false, on_record: None, on_clear: None }
+            .build(cx, move |cx| {
+                Binding::new(cx, ShortcutRecorder::recording, move |cx, recording| {
+                    if recording.get(cx) {
+                        Label::new(cx, "Press a shortcut...")
+                            .class("placeholder")
+                            .hoverable(false);
+                    } else {
+                        Label::new(
+                            cx,
+                            lens.clone().map(|chord| {
+                                chord.map(|chord| chord.label()).unwrap_or_default()
+                            }),
+                        )
+                        .hoverable(false);
+                    }
+                });
+            })
+            .checked(ShortcutRecorder::recording)
+            .role(Role::Button)
+            .default_action_verb(DefaultActionVerb::Click)
+            .cursor(CursorIcon::Hand)
+            .navigable(true)
+    }
+
+    fn start_recording(&mut self, cx: &mut EventContext) {
+        self.recording = true;
+        cx.focus_with_visibility(true);
+    }
+}
+
+impl Handle<'_, ShortcutRecorder> {
+    /// Sets the callback triggered when a key chord is recorded.
+    pub fn on_record<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, KeyChord),
+    {
+        self.modify(|recorder| recorder.on_record = Some(Box::new(callback)))
+    }
+
+    /// Sets the callback triggered when backspace or delete is pressed while not recording.
+    pub fn on_clear<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext),
+    {
+        self.modify(|recorder| recorder.on_clear = Some(Box::new(callback)))
+    }
+}
+
+impl View for ShortcutRecorder {
+    fn element(&self) -> Option<&'static str> {
+        Some("shortcut-recorder")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::Press { mouse } => {
+                if std::mem::take(&mut self.ignore_press) && !*mouse {
+                    return;
+                }
+
+                if meta.target == cx.current() && !cx.is_disabled() && !self.recording {
+                    self.start_recording(cx);
+                }
+            }
+
+            WindowEvent::ActionRequest(action) => match action.action {
+                Action::Default => {
+                    if !cx.is_disabled() && !self.recording {
+                        self.start_recording(cx);
+                    }
+                }
+
+                _ => {}
+            },
+
+            WindowEvent::KeyDown(code, _) if self.recording => {
+                meta.consume();
+
+                // Wait for a key to be pressed along with the modifiers.
+                if matches!(
+                    code,
+                    Code::ShiftLeft
+                        | Code::ShiftRight
+                        | Code::ControlLeft
+                        | Code::ControlRight
+                        | Code::AltLeft
+                        | Code::AltRight
+                        | Code::MetaLeft
+                        | Code::MetaRight
+                ) {
+                    return;
+                }
+
+                self.recording = false;
+                self.ignore_press = matches!(code, Code::Enter | Code::NumpadEnter | Code::Space);
+
+                if *code == Code::Escape && cx.modifiers().is_empty() {
+                    return;
+                }
+
+                if let Some(callback) = &self.on_record {
+                    (callback)(cx, KeyChord::new(*cx.modifiers, *code));
+                }
+            }
+
+            WindowEvent::KeyDown(Code::Backspace | Code::Delete, _) => {
+                if meta.target == cx.current() && !cx.is_disabled() {
+                    if let Some(callback) = &self.on_clear {
+                        (callback)(cx);
+                    }
+                }
+            }
+
+            WindowEvent::FocusOut => {
+                self.recording = false;
+            }
+
+            _ => {}
+        });
+    }
+}
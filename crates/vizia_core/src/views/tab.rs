@@ -1,12 +1,52 @@
+use crate::icons::{ICON_CHEVRON_DOWN, ICON_X};
 use crate::prelude::*;
 
+/// The distance in logical pixels which the cursor must move before a tab header is dragged.
+const TAB_DRAG_THRESHOLD: f32 = 4.0;
+
 pub enum TabEvent {
     SetSelected(usize),
+    /// Selects the next tab, wrapping around to the first.
+    SelectNext,
+    /// Selects the previous tab, wrapping around to the last.
+    SelectPrevious,
+    /// Requests that the tab with the given index be closed.
+    Close(usize),
+    /// Requests that the tab at the first index be moved to the second index.
+    Reorder(usize, usize),
+    /// Opens or closes the menu listing all of the tabs.
+    ToggleMenu,
+    CloseMenu,
 }
 
+/// A view which displays one of several tabs of content, selected with a row of tab headers.
+///
+/// Headers can optionally be closed with a close button or a middle click, and reordered by
+/// dragging them, with the [`on_close`](Handle::on_close) and [`on_reorder`](Handle::on_reorder)
+/// callbacks respectively. When the headers overflow they can be scrolled, and a menu listing all
+/// of the tabs is shown. Focus is moved between the headers with the arrow keys, while Ctrl+Tab
+/// and Ctrl+Shift+Tab cycle through the tabs from anywhere within the view.
+///
+/// By default the content of a tab is rebuilt when it is selected. With
+/// [`keep_inactive`](Handle::keep_inactive) the content of each tab is built the first time it is
+/// selected and kept while other tabs are selected, preserving its state. Closing or reordering
+/// tabs rebuilds the content of the tabs whose position changes.
 #[derive(Lens)]
 pub struct TabView {
     selected_index: usize,
+    closable: bool,
+    keep_inactive: bool,
+    // Which tabs have been selected at least once, so that their content has been built.
+    visited: Vec<bool>,
+    // Incremented for a tab when its position changes, so that content which is kept is rebuilt.
+    rebuilds: Vec<usize>,
+    menu_open: bool,
+    #[lens(ignore)]
+    tab_count: Box<dyn Fn(&EventContext) -> usize>,
+    #[lens(ignore)]
+    on_close: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+    #[lens(ignore)]
+    on_reorder: Option<Box<dyn Fn(&mut EventContext, usize, usize)>>,
 }
 
 impl TabView {
@@ -16,41 +56,228 @@ impl TabView {
         T: Clone + 'static,
         F: 'static + Clone + Fn(&mut Context, Then<L, Index<Vec<T>, T>>) -> TabPair,
     {
-        Self { selected_index: 0 }.build(cx, move |cx| {
+        let count_lens = lens.clone().map(|list| list.len());
+        Self {
+            selected_index: 0,
+            closable: false,
+            keep_inactive: false,
+            visited: vec![true],
+            rebuilds: Vec::new(),
+            menu_open: false,
+            tab_count: Box::new(move |cx| count_lens.get(cx)),
+            on_close: None,
+            on_reorder: None,
+        }
+        .build(cx, move |cx| {
             let lens2 = lens.clone();
+            let lens3 = lens.clone();
             let content2 = content.clone();
+            let content3 = content.clone();
+
             // Tab headers
-            VStack::new(cx, move |cx| {
-                Binding::new(cx, lens.clone().map(|list| list.len()), move |cx, list_length| {
-                    let list_length = list_length.get_fallible(cx).map_or(0, |d| d);
+            HStack::new(cx, move |cx| {
+                ScrollData {
+                    scroll_x: 0.0,
+                    scroll_y: 0.0,
+                    inner_width: 0.0,
+                    inner_height: 0.0,
+                    container_width: 0.0,
+                    container_height: 0.0,
+                    on_scroll: None,
+                }
+                .build(cx);
+
+                ScrollView::custom(cx, true, true, ScrollData::root, move |cx| {
+                    VStack::new(cx, move |cx| {
+                        Binding::new(
+                            cx,
+                            lens.clone().map(|list| list.len()),
+                            move |cx, list_length| {
+                                let list_length = list_length.get_fallible(cx).map_or(0, |d| d);
+                                for index in 0..list_length {
+                                    let l = lens.clone().index(index);
+                                    let builder = (content2)(cx, l).header;
+                                    TabHeader::new(cx, index, builder).bind(
+                                        TabView::selected_index,
+                                        move |handle, selected_index| {
+                                            let selected_index = selected_index.get(handle.cx);
+                                            handle.checked(selected_index == index);
+                                        },
+                                    );
+                                }
+                            },
+                        )
+                    })
+                    .class("tabview-tabheader-wrapper")
+                    .focus_scope("tabview");
+                });
+
+                // The menu of all tabs, which is shown when the headers overflow.
+                Button::new(
+                    cx,
+                    |cx| cx.emit(TabEvent::ToggleMenu),
+                    |cx| Label::new(cx, ICON_CHEVRON_DOWN).class("icon"),
+                )
+                .class("icon")
+                .class("tabview-more")
+                .display(ScrollData::root.map(|data| {
+                    data.inner_width > data.container_width
+                        || data.inner_height > data.container_height
+                }));
+
+                Popup::new(cx, TabView::menu_open, true, move |cx| {
+                    let list_length = lens3.clone().map(|list| list.len()).get(cx);
                     for index in 0..list_length {
-                        let l = lens.clone().index(index);
-                        let builder = (content2)(cx, l).header;
-                        TabHeader::new(cx, index, builder).bind(
-                            TabView::selected_index,
-                            move |handle, selected_index| {
-                                let selected_index = selected_index.get(handle.cx);
-                                handle.checked(selected_index == index);
+                        let builder = (content3)(cx, lens3.clone().index(index)).header;
+                        Button::new(
+                            cx,
+                            move |cx| {
+                                cx.emit(TabEvent::SetSelected(index));
+                                cx.emit(TabEvent::CloseMenu);
                             },
-                        );
+                            move |cx| HStack::new(cx, move |cx| (builder)(cx)),
+                        )
+                        .class("tabview-menu-item")
+                        .checked(TabView::selected_index.map(move |selected| *selected == index));
                     }
                 })
+                .on_blur(|cx| cx.emit(TabEvent::CloseMenu))
+                .class("tabview-menu");
             })
-            .class("tabview-tabheader-wrapper");
+            .class("tabview-tabheader-bar");
 
             Element::new(cx).class("tabview-divider");
 
             // Tab content
-            VStack::new(cx, |cx| {
-                Binding::new(cx, TabView::selected_index, move |cx, selected| {
-                    let selected = selected.get(cx);
-                    let l = lens2.clone().index(selected);
-                    ((content)(cx, l).content)(cx);
+            VStack::new(cx, move |cx| {
+                Binding::new(cx, TabView::keep_inactive, move |cx, keep_inactive| {
+                    let lens = lens2.clone();
+                    let content = content.clone();
+                    if keep_inactive.get(cx) {
+                        Self::build_kept_content(cx, lens, content);
+                    } else {
+                        Binding::new(cx, TabView::selected_index, move |cx, selected| {
+                            let selected = selected.get(cx);
+                            let l = lens.clone().index(selected);
+                            ((content)(cx, l).content)(cx);
+                        });
+                    }
                 });
             })
             .class("tabview-content-wrapper");
         })
     }
+
+    // Builds the content of each tab into its own container the first time the tab is selected,
+    // and only displays the container of the selected tab.
+    fn build_kept_content<L, T, F>(cx: &mut Context, lens: L, content: F)
+    where
+        L: Lens<Target = Vec<T>>,
+        T: Clone + 'static,
+        F: 'static + Clone + Fn(&mut Context, Then<L, Index<Vec<T>, T>>) -> TabPair,
+    {
+        Binding::new(cx, lens.clone().map(|list| !list.is_empty()), move |cx, has_tabs| {
+            if has_tabs.get(cx) {
+                Self::build_kept_tab(cx, lens.clone(), content.clone(), 0);
+            }
+        });
+    }
+
+    // Builds the container of the tab at the given index, followed by a binding which builds the
+    // container of the next tab while there is one. Adding or removing a tab therefore leaves the
+    // containers of the tabs before it untouched.
+    fn build_kept_tab<L, T, F>(cx: &mut Context, lens: L, content: F, index: usize)
+    where
+        L: Lens<Target = Vec<T>>,
+        T: Clone + 'static,
+        F: 'static + Clone + Fn(&mut Context, Then<L, Index<Vec<T>, T>>) -> TabPair,
+    {
+        let lens2 = lens.clone();
+        let content2 = content.clone();
+        VStack::new(cx, move |cx| {
+            let rebuilds = TabView::rebuilds
+                .map(move |rebuilds| rebuilds.get(index).copied().unwrap_or_default());
+            Binding::new(cx, rebuilds, move |cx, _| {
+                let lens = lens2.clone();
+                let content = content2.clone();
+                let visited = TabView::visited
+                    .map(move |visited| visited.get(index).copied().unwrap_or_default());
+                Binding::new(cx, visited, move |cx, visited| {
+                    if visited.get(cx) {
+                        ((content)(cx, lens.clone().index(index)).content)(cx);
+                    }
+                });
+            });
+        })
+        .class("tabview-tab-content")
+        .display(TabView::selected_index.map(move |selected| *selected == index));
+
+        Binding::new(cx, lens.clone().map(move |list| list.len() > index + 1), move |cx, more| {
+            if more.get(cx) {
+                Self::build_kept_tab(cx, lens.clone(), content.clone(), index + 1);
+            }
+        });
+    }
+
+    // Rebuilds the kept content of the tabs in the given range of positions.
+    fn rebuild(&mut self, range: std::ops::Range<usize>) {
+        if self.rebuilds.len() < range.end {
+            self.rebuilds.resize(range.end, 0);
+        }
+
+        for rebuild in self.rebuilds[range].iter_mut() {
+            *rebuild += 1;
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected_index = index;
+        if self.visited.len() <= index {
+            self.visited.resize(index + 1, false);
+        }
+
+        self.visited[index] = true;
+    }
+}
+
+impl Handle<'_, TabView> {
+    /// Shows a close button in each tab header and sets the callback triggered when it is pressed,
+    /// or when a header is middle clicked, with the index of the tab to close.
+    ///
+    /// The callback is expected to remove the tab from the list bound to the tab view. If the
+    /// selected tab is closed then the tab after it is selected, or the tab before it if it was the
+    /// last tab.
+    pub fn on_close<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|tabview| {
+            tabview.closable = true;
+            tabview.on_close = Some(Box::new(callback));
+        })
+        .class("closable")
+    }
+
+    /// Allows the tab headers to be reordered by dragging them, and sets the callback triggered
+    /// when a header is dropped, with the index the tab was dragged from and the index it was
+    /// dropped at.
+    ///
+    /// The callback is expected to move the tab within the list bound to the tab view. The
+    /// selection follows the moved tab.
+    pub fn on_reorder<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize, usize),
+    {
+        self.modify(|tabview| tabview.on_reorder = Some(Box::new(callback)))
+    }
+
+    /// Sets whether the content of inactive tabs is kept rather than rebuilt when they are
+    /// selected again. Content is built the first time a tab is selected. Closing or reordering
+    /// tabs rebuilds the content of the tabs whose position changes, since the content of a tab is
+    /// bound to its position in the list.
+    pub fn keep_inactive(self, flag: bool) -> Self {
+        self.modify(|tabview| tabview.keep_inactive = flag)
+    }
 }
 
 impl View for TabView {
@@ -58,13 +285,110 @@ impl View for TabView {
         Some("tabview")
     }
 
-    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
         event.map(|tab_event, meta| match tab_event {
             TabEvent::SetSelected(index) => {
-                self.selected_index = *index;
+                self.select(*index);
+                meta.consume();
+            }
+
+            TabEvent::SelectNext => {
+                let count = (self.tab_count)(cx);
+                if count > 0 {
+                    self.select((self.selected_index + 1) % count);
+                }
+                meta.consume();
+            }
+
+            TabEvent::SelectPrevious => {
+                let count = (self.tab_count)(cx);
+                if count > 0 {
+                    self.select((self.selected_index + count - 1) % count);
+                }
+                meta.consume();
+            }
+
+            TabEvent::Close(index) => {
+                if let Some(callback) = self.on_close.take() {
+                    let count = (self.tab_count)(cx);
+                    let index = *index;
+
+                    if index < self.selected_index
+                        || (index == self.selected_index && index + 1 == count)
+                    {
+                        self.selected_index = self.selected_index.saturating_sub(1);
+                    }
+
+                    if index < self.visited.len() {
+                        self.visited.remove(index);
+                    }
+                    self.visited.resize(self.visited.len().max(1), false);
+                    let selected = self.selected_index;
+                    self.select(selected);
+
+                    // The tabs after the closed tab move to the previous position.
+                    if self.keep_inactive {
+                        self.rebuild(index..count);
+                    }
+
+                    (callback)(cx, index);
+                    self.on_close = Some(callback);
+                }
+                meta.consume();
+            }
+
+            TabEvent::Reorder(from, to) => {
+                if let Some(callback) = self.on_reorder.take() {
+                    let (from, to) = (*from, *to);
+                    self.selected_index = reordered_index(self.selected_index, from, to);
+
+                    // Kept content is associated with the position of a tab, so rebuild the content
+                    // of the tabs between the two positions.
+                    if self.keep_inactive {
+                        if self.visited.len() <= from.max(to) {
+                            self.visited.resize(from.max(to) + 1, false);
+                        }
+                        let visited = self.visited.remove(from);
+                        self.visited.insert(to, visited);
+                        self.rebuild(from.min(to)..from.max(to) + 1);
+                    }
+                    let selected = self.selected_index;
+                    self.select(selected);
+
+                    (callback)(cx, from, to);
+                    self.on_reorder = Some(callback);
+                }
+                meta.consume();
+            }
+
+            TabEvent::ToggleMenu => {
+                self.menu_open ^= true;
+                meta.consume();
+            }
+
+            TabEvent::CloseMenu => {
+                self.menu_open = false;
                 meta.consume();
             }
         });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::KeyDown(code, _) if cx.modifiers().contains(Modifiers::CTRL) => {
+                match code {
+                    Code::Tab if cx.modifiers().contains(Modifiers::SHIFT) => {
+                        cx.emit(TabEvent::SelectPrevious);
+                    }
+
+                    Code::Tab | Code::PageDown => cx.emit(TabEvent::SelectNext),
+
+                    Code::PageUp => cx.emit(TabEvent::SelectPrevious),
+
+                    _ => {}
+                }
+            }
+
+            _ => {}
+        });
     }
 }
 
@@ -85,6 +409,10 @@ impl TabPair {
 
 pub struct TabHeader {
     index: usize,
+    // The cursor position at which the header was pressed, while it is pressed.
+    press_position: Option<(f32, f32)>,
+    // The index of the header under the cursor, while the header is being dragged.
+    drop_index: Option<usize>,
 }
 
 impl TabHeader {
@@ -92,7 +420,45 @@ impl TabHeader {
     where
         F: 'static + Fn(&mut Context),
     {
-        Self { index }.build(cx, |cx| (content)(cx))
+        Self { index, press_position: None, drop_index: None }
+            .build(cx, |cx| {
+                (content)(cx);
+
+                Button::new(
+                    cx,
+                    move |cx| cx.emit(TabEvent::Close(index)),
+                    |cx| Label::new(cx, ICON_X).class("icon"),
+                )
+                .class("icon")
+                .class("tabheader-close")
+                .navigable(false)
+                .display(TabView::closable);
+            })
+            .navigable(true)
+    }
+
+    // Returns the index of the header under the cursor, among the headers sharing this header's
+    // parent.
+    fn header_under_cursor(&self, cx: &EventContext) -> Option<usize> {
+        let parent = cx.tree.get_parent(cx.current())?;
+        let horizontal = cx
+            .tree
+            .get_layout_parent(cx.current())
+            .and_then(|wrapper| cx.style.layout_type.get(wrapper).copied())
+            .map_or(false, |layout_type| layout_type == LayoutType::Row);
+        let (cursorx, cursory) = (cx.mouse.cursorx, cx.mouse.cursory);
+
+        parent.child_iter(cx.tree).find_map(|child| {
+            let header = cx.views.get(&child).and_then(|view| view.downcast_ref::<TabHeader>())?;
+            let bounds = cx.cache.get_bounds(child);
+            let over = if horizontal {
+                cursorx >= bounds.left() && cursorx < bounds.right()
+            } else {
+                cursory >= bounds.top() && cursory < bounds.bottom()
+            };
+
+            over.then(|| header.index)
+        })
     }
 }
 
@@ -102,14 +468,227 @@ impl View for TabHeader {
     }
 
     fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _meta| match window_event {
-            WindowEvent::MouseDown(button) if *button == MouseButton::Left => {
-                //if meta.target == cx.current() {
+        event.map(|window_event, meta| match window_event {
+            // The close button handles its own presses.
+            WindowEvent::MouseDown(MouseButton::Left) if is_close_button(cx, meta.target) => {}
+
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                cx.emit(TabEvent::SetSelected(self.index));
+                self.press_position = Some((cx.mouse.cursorx, cx.mouse.cursory));
+                cx.capture();
+            }
+
+            WindowEvent::MouseDown(MouseButton::Middle) => {
+                cx.emit(TabEvent::Close(self.index));
+            }
+
+            WindowEvent::MouseMove(x, y) => {
+                if let Some((px, py)) = self.press_position {
+                    let threshold = TAB_DRAG_THRESHOLD * cx.scale_factor();
+                    if self.drop_index.is_none()
+                        && ((x - px).abs() > threshold || (y - py).abs() > threshold)
+                    {
+                        self.drop_index = Some(self.index);
+                        cx.toggle_class("dragging", true);
+                    }
+
+                    if self.drop_index.is_some() {
+                        if let Some(index) = self.header_under_cursor(cx) {
+                            self.drop_index = Some(index);
+                        }
+                    }
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.press_position.take().is_some() {
+                    cx.release();
+                }
+
+                if let Some(drop_index) = self.drop_index.take() {
+                    cx.toggle_class("dragging", false);
+                    if drop_index != self.index {
+                        cx.emit(TabEvent::Reorder(self.index, drop_index));
+                    }
+                }
+            }
+
+            WindowEvent::FocusIn if meta.target == cx.current() => {
                 cx.emit(TabEvent::SetSelected(self.index));
-                //}
             }
 
             _ => {}
         });
     }
 }
+
+// Returns the position of the tab at the given index after the tab at `from` is moved to `to`.
+fn reordered_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && to >= index {
+        index - 1
+    } else if from > index && to <= index {
+        index + 1
+    } else {
+        index
+    }
+}
+
+// Returns true if the entity is the close button of the current tab header or is within it.
+fn is_close_button(cx: &EventContext, entity: Entity) -> bool {
+    entity.parent_iter(cx.tree).take_while(|ancestor| *ancestor != cx.current()).any(|ancestor| {
+        cx.style.classes.get(ancestor).map_or(false, |classes| classes.contains("tabheader-close"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::backend::BackendContext;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static BUILT: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    }
+
+    #[derive(Lens)]
+    struct Tabs {
+        tabs: Vec<String>,
+    }
+
+    enum TabsEvent {
+        Close(usize),
+        Move(usize, usize),
+    }
+
+    impl Model for Tabs {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|tabs_event, _| match tabs_event {
+                TabsEvent::Close(index) => {
+                    self.tabs.remove(*index);
+                }
+
+                TabsEvent::Move(from, to) => {
+                    let tab = self.tabs.remove(*from);
+                    self.tabs.insert(*to, tab);
+                }
+            });
+        }
+    }
+
+    // Returns the names of the tabs whose content has been built since the last call, sorted.
+    fn take_built() -> Vec<String> {
+        let mut built = BUILT.with(|built| std::mem::take(&mut *built.borrow_mut()));
+        built.sort();
+        built
+    }
+
+    fn tabview(cx: &mut Context, keep_inactive: bool) -> Entity {
+        Tabs { tabs: vec![String::from("a"), String::from("b"), String::from("c")] }.build(cx);
+        let tabview = TabView::new(cx, Tabs::tabs, |_, tab| {
+            let label = tab.clone();
+            TabPair::new(
+                move |cx| {
+                    Label::new(cx, label.clone());
+                },
+                move |cx| {
+                    let name = tab.get(cx);
+                    BUILT.with(|built| built.borrow_mut().push(name));
+                },
+            )
+        })
+        .keep_inactive(keep_inactive)
+        .on_close(|cx, index| cx.emit(TabsEvent::Close(index)))
+        .on_reorder(|cx, from, to| cx.emit(TabsEvent::Move(from, to)))
+        .entity();
+        update(cx);
+        take_built();
+        tabview
+    }
+
+    fn update(cx: &mut Context) {
+        let mut cx = BackendContext::new_with_event_manager(cx);
+        while cx.has_queued_events() {
+            cx.process_events();
+        }
+        cx.process_data_updates();
+    }
+
+    fn send(cx: &mut Context, tabview: Entity, event: TabEvent) {
+        cx.emit_custom(Event::new(event).target(tabview).origin(tabview));
+        update(cx);
+    }
+
+    fn selected_index(cx: &Context, tabview: Entity) -> usize {
+        cx.views[&tabview].downcast_ref::<TabView>().unwrap().selected_index
+    }
+
+    #[test]
+    fn reordering_moves_the_tabs_in_between() {
+        // The moved tab takes the new position.
+        assert_eq!(reordered_index(1, 1, 3), 3);
+        assert_eq!(reordered_index(3, 3, 0), 0);
+
+        // The tabs it passes shift towards its old position.
+        assert_eq!(reordered_index(2, 1, 3), 1);
+        assert_eq!(reordered_index(3, 1, 3), 2);
+        assert_eq!(reordered_index(0, 3, 0), 1);
+        assert_eq!(reordered_index(2, 3, 0), 3);
+
+        // The other tabs stay in place.
+        assert_eq!(reordered_index(0, 1, 3), 0);
+        assert_eq!(reordered_index(4, 1, 3), 4);
+        assert_eq!(reordered_index(2, 2, 2), 2);
+    }
+
+    #[test]
+    fn content_is_rebuilt_on_selection() {
+        let cx = &mut Context::default();
+        let tabview = tabview(cx, false);
+
+        send(cx, tabview, TabEvent::SetSelected(1));
+        assert_eq!(take_built(), ["b"]);
+
+        send(cx, tabview, TabEvent::SetSelected(0));
+        assert_eq!(take_built(), ["a"]);
+    }
+
+    #[test]
+    fn inactive_content_is_kept() {
+        let cx = &mut Context::default();
+        let tabview = tabview(cx, true);
+
+        // Content is built the first time a tab is selected.
+        send(cx, tabview, TabEvent::SetSelected(1));
+        assert_eq!(take_built(), ["b"]);
+
+        send(cx, tabview, TabEvent::SetSelected(0));
+        send(cx, tabview, TabEvent::SelectNext);
+        assert!(take_built().is_empty());
+        assert_eq!(selected_index(cx, tabview), 1);
+    }
+
+    #[test]
+    fn kept_content_follows_reordered_and_closed_tabs() {
+        let cx = &mut Context::default();
+        let tabview = tabview(cx, true);
+        send(cx, tabview, TabEvent::SetSelected(1));
+        send(cx, tabview, TabEvent::SetSelected(0));
+        take_built();
+
+        // Moving `a` to the end shifts `b` and `c` forward, so the built content of `a` and `b`
+        // is rebuilt at their new positions, while `c` has still not been selected.
+        send(cx, tabview, TabEvent::Reorder(0, 2));
+        assert_eq!(take_built(), ["a", "b"]);
+        assert_eq!(selected_index(cx, tabview), 2);
+
+        // Closing `b` moves the selected tab `a` forward.
+        send(cx, tabview, TabEvent::Close(0));
+        assert_eq!(take_built(), ["a"]);
+        assert_eq!(selected_index(cx, tabview), 1);
+
+        send(cx, tabview, TabEvent::SetSelected(0));
+        assert_eq!(take_built(), ["c"]);
+    }
+}
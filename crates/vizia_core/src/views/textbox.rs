@@ -117,10 +117,13 @@ where
                                 |text| text.map(|x| x.to_string()).unwrap_or_else(|| "".to_owned()),
                             );
 
-                            if text_str.is_empty() {
+                            let placeholder_shown = text_str.is_empty();
+                            if placeholder_shown {
                                 text_str = placeholder.get(ex);
                             }
 
+                            ex.set_placeholder_shown(placeholder_shown && !text_str.is_empty());
+
                             ex.text_context.with_buffer(parent, |fs, buf| {
                                 buf.set_text(fs, &text_str, Attrs::new(), Shaping::Advanced);
                             });
@@ -139,6 +142,27 @@ where
         .text_value(text_lens)
         // .cursor(CursorIcon::Text)
         .default_action_verb(DefaultActionVerb::Focus)
+        .context_menu(|cx| {
+            MenuButton::new(cx, |cx| cx.emit(TextEvent::Cut), |cx| Label::new(cx, "Cut"));
+            MenuButton::new(cx, |cx| cx.emit(TextEvent::Copy), |cx| Label::new(cx, "Copy"));
+            MenuButton::new(
+                cx,
+                |cx| {
+                    cx.emit(TextEvent::StartEdit);
+                    cx.emit(TextEvent::Paste);
+                },
+                |cx| Label::new(cx, "Paste"),
+            );
+            MenuDivider::new(cx);
+            MenuButton::new(
+                cx,
+                |cx| {
+                    cx.emit(TextEvent::StartEdit);
+                    cx.emit(TextEvent::SelectAll);
+                },
+                |cx| Label::new(cx, "Select All"),
+            );
+        })
     }
 
     fn set_caret(&mut self, cx: &mut EventContext) {
@@ -658,7 +682,10 @@ where
             }
 
             WindowEvent::FocusOut => {
-                cx.emit(TextEvent::EndEdit);
+                // Keep editing while focus is within the context menu.
+                if !cx.focused().is_descendant_of(cx.tree, cx.current) {
+                    cx.emit(TextEvent::EndEdit);
+                }
             }
 
             WindowEvent::MouseDoubleClick(MouseButton::Left) => {
@@ -963,6 +990,7 @@ where
                     cx.focus_with_visibility(false);
                     // cx.capture();
                     cx.set_checked(true);
+                    cx.set_placeholder_shown(false);
 
                     if let Some(source) = cx.data::<L::Source>() {
                         let text = self.lens.view(source, |t| {
@@ -1000,10 +1028,13 @@ where
                         }
                     });
 
-                    if text.is_empty() {
+                    let placeholder_shown = text.is_empty();
+                    if placeholder_shown {
                         text = self.placeholder.clone();
                     };
 
+                    cx.set_placeholder_shown(placeholder_shown && !text.is_empty());
+
                     self.select_all(cx);
                     self.insert_text(cx, &text);
                     self.set_caret(cx);
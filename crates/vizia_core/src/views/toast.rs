@@ -0,0 +1,395 @@
+use std::sync::Arc;
+
+use morphorm::PositionType;
+
+use crate::icons::ICON_X;
+use crate::prelude::*;
+
+/// The z-index of the toast stack, which places toasts above dialogs.
+const TOAST_Z_INDEX: i32 = 1100;
+
+/// The duration of the animations played when a toast is shown and dismissed.
+const TOAST_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// The duration after which a toast is dismissed if no other duration is given.
+pub const DEFAULT_TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// The kind of a toast, which determines its styling and how urgently it is announced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Default for ToastKind {
+    fn default() -> Self {
+        ToastKind::Info
+    }
+}
+
+impl ToastKind {
+    fn class_name(&self) -> &'static str {
+        match self {
+            ToastKind::Info => "info",
+            ToastKind::Success => "success",
+            ToastKind::Warning => "warning",
+            ToastKind::Error => "error",
+        }
+    }
+}
+
+/// A button shown within a toast. Pressing the button triggers its callback and dismisses the
+/// toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    label: String,
+    callback: Arc<dyn Fn(&mut EventContext) + Send + Sync>,
+}
+
+impl ToastAction {
+    /// Creates a new toast action with the given label. The callback is called from the view which
+    /// showed the toast.
+    pub fn new<F>(label: impl ToString, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext) + Send + Sync,
+    {
+        Self { label: label.to_string(), callback: Arc::new(callback) }
+    }
+}
+
+/// Events for showing and dismissing toasts.
+///
+/// Toasts are stacked in the bottom right corner of the main window. A toast is dismissed after
+/// its duration, or when the user presses its close button or one of its actions. The countdown
+/// pauses while the cursor is over the toasts.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_core::prelude::*;
+/// #
+/// # let cx = &mut Context::default();
+/// #
+/// Button::new(
+///     cx,
+///     |cx| {
+///         cx.emit(ToastEvent::Show {
+///             title: String::from("File saved"),
+///             body: None,
+///             kind: ToastKind::Success,
+///             duration: Some(DEFAULT_TOAST_DURATION),
+///             actions: Vec::new(),
+///         })
+///     },
+///     |cx| Label::new(cx, "Save"),
+/// );
+/// ```
+#[derive(Clone)]
+pub enum ToastEvent {
+    /// Shows a toast. A toast without a duration stays open until it is dismissed by the user.
+    Show {
+        title: String,
+        body: Option<String>,
+        kind: ToastKind,
+        duration: Option<Duration>,
+        actions: Vec<ToastAction>,
+    },
+    /// Dismisses all of the open toasts.
+    DismissAll,
+}
+
+/// A toast which is open or being dismissed.
+#[derive(Clone)]
+pub(crate) struct ToastData {
+    id: usize,
+    origin: Entity,
+    title: String,
+    body: Option<String>,
+    kind: ToastKind,
+    actions: Vec<ToastAction>,
+    // Whether the toast has played its entrance animation.
+    entered: bool,
+    // Whether the toast has been dismissed and is playing its exit animation.
+    closing: bool,
+    // The time left before the toast is dismissed.
+    remaining: Option<Duration>,
+    // The time at which the countdown was last resumed, if it is running.
+    started: Option<Instant>,
+    // Incremented whenever the countdown stops, so that outdated timeouts are ignored.
+    generation: usize,
+}
+
+impl Data for ToastData {
+    fn same(&self, other: &Self) -> bool {
+        self.id == other.id && self.entered == other.entered && self.closing == other.closing
+    }
+}
+
+impl ToastData {
+    fn start_countdown(&mut self, cx: &mut EventContext) {
+        if let Some(remaining) = self.remaining {
+            self.started = Some(Instant::now());
+
+            let id = self.id;
+            let generation = self.generation;
+            cx.spawn(move |cx| {
+                std::thread::sleep(remaining);
+                let _ = cx.emit_to(Entity::root(), ToastHostEvent::Expire(id, generation));
+            });
+        }
+    }
+
+    fn stop_countdown(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.remaining =
+                self.remaining.map(|remaining| remaining.saturating_sub(started.elapsed()));
+        }
+
+        self.generation += 1;
+    }
+}
+
+pub(crate) enum ToastHostEvent {
+    Entered(usize),
+    Expire(usize, usize),
+    Dismiss(usize),
+    Remove(usize),
+    Pause,
+    Resume,
+}
+
+/// The toasts shown with [`ToastEvent`], which are built at the root of the tree.
+#[derive(Default, Lens)]
+pub(crate) struct ToastHost {
+    toasts: Vec<ToastData>,
+    #[lens(ignore)]
+    next_id: usize,
+    #[lens(ignore)]
+    paused: bool,
+}
+
+impl ToastHost {
+    /// Builds the toast host into the root of the tree, unless it has already been built.
+    pub(crate) fn build_host(cx: &mut Context) {
+        cx.with_current(Entity::root(), |cx| {
+            if cx.data::<Self>().is_none() {
+                Self::default().build(cx);
+                Self::build_toasts(cx);
+            }
+        });
+    }
+
+    fn build_toasts(cx: &mut Context) {
+        ToastStack {}
+            .build(cx, |cx| {
+                // Only rebuild the toasts when one is added or removed.
+                let ids = ToastHost::toasts
+                    .map(|toasts| toasts.iter().map(|toast| toast.id).collect::<Vec<_>>());
+                Binding::new(cx, ids, |cx, _| {
+                    for toast in ToastHost::toasts.get(cx) {
+                        Toast::new(cx, toast);
+                    }
+                });
+            })
+            .live(Live::Polite)
+            .position_type(PositionType::SelfDirected)
+            .z_index(TOAST_Z_INDEX);
+    }
+
+    fn dismiss(&mut self, cx: &mut EventContext, id: usize) {
+        if let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == id && !toast.closing) {
+            toast.stop_countdown();
+            toast.closing = true;
+
+            cx.spawn(move |cx| {
+                std::thread::sleep(TOAST_ANIMATION_DURATION);
+                let _ = cx.emit_to(Entity::root(), ToastHostEvent::Remove(id));
+            });
+        }
+    }
+}
+
+impl Model for ToastHost {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|toast_event, meta| match toast_event {
+            ToastEvent::Show { title, body, kind, duration, actions } => {
+                let mut toast = ToastData {
+                    id: self.next_id,
+                    origin: meta.origin,
+                    title: title.clone(),
+                    body: body.clone(),
+                    kind: *kind,
+                    actions: actions.clone(),
+                    entered: false,
+                    closing: false,
+                    remaining: *duration,
+                    started: None,
+                    generation: 0,
+                };
+                self.next_id += 1;
+
+                if !self.paused {
+                    toast.start_countdown(cx);
+                }
+
+                self.toasts.push(toast);
+                meta.consume();
+            }
+
+            ToastEvent::DismissAll => {
+                let ids = self.toasts.iter().map(|toast| toast.id).collect::<Vec<_>>();
+                for id in ids {
+                    self.dismiss(cx, id);
+                }
+            }
+        });
+
+        event.map(|toast_host_event, _| match toast_host_event {
+            ToastHostEvent::Entered(id) => {
+                if let Some(toast) = self.toasts.iter_mut().find(|toast| toast.id == *id) {
+                    toast.entered = true;
+                }
+            }
+
+            ToastHostEvent::Expire(id, generation) => {
+                if self
+                    .toasts
+                    .iter()
+                    .any(|toast| toast.id == *id && toast.generation == *generation)
+                {
+                    self.dismiss(cx, *id);
+                }
+            }
+
+            ToastHostEvent::Dismiss(id) => {
+                self.dismiss(cx, *id);
+            }
+
+            ToastHostEvent::Remove(id) => {
+                self.toasts.retain(|toast| toast.id != *id);
+            }
+
+            ToastHostEvent::Pause if !self.paused => {
+                self.paused = true;
+                for toast in self.toasts.iter_mut().filter(|toast| !toast.closing) {
+                    toast.stop_countdown();
+                }
+            }
+
+            ToastHostEvent::Resume if self.paused => {
+                self.paused = false;
+                for toast in self.toasts.iter_mut().filter(|toast| !toast.closing) {
+                    toast.start_countdown(cx);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+/// The container of the toasts, which pauses their countdown while the cursor is over it.
+struct ToastStack {}
+
+impl View for ToastStack {
+    fn element(&self) -> Option<&'static str> {
+        Some("toast-stack")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseOver => {
+                cx.emit(ToastHostEvent::Pause);
+            }
+
+            WindowEvent::MouseOut => {
+                let hovered = cx.hovered();
+                if hovered != cx.current() && !hovered.is_descendant_of(cx.tree, cx.current()) {
+                    cx.emit(ToastHostEvent::Resume);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+/// A single toast within the toast stack.
+struct Toast {}
+
+impl Toast {
+    fn new(cx: &mut Context, toast: ToastData) -> Handle<Self> {
+        let id = toast.id;
+        let origin = toast.origin;
+        let kind = toast.kind;
+        let entered = toast.entered;
+
+        let handle = Self {}
+            .build(cx, move |cx| {
+                VStack::new(cx, move |cx| {
+                    Label::new(cx, &toast.title).class("toast-title");
+
+                    if let Some(body) = &toast.body {
+                        Label::new(cx, body).class("toast-body");
+                    }
+
+                    if !toast.actions.is_empty() {
+                        HStack::new(cx, move |cx| {
+                            for action in toast.actions.iter() {
+                                let callback = action.callback.clone();
+                                Button::new(
+                                    cx,
+                                    move |cx| {
+                                        cx.with_current(origin, |cx| (callback)(cx));
+                                        cx.emit(ToastHostEvent::Dismiss(id));
+                                    },
+                                    |cx| Label::new(cx, &action.label),
+                                );
+                            }
+                        })
+                        .class("toast-actions");
+                    }
+                })
+                .class("toast-content");
+
+                Button::new(
+                    cx,
+                    move |cx| cx.emit(ToastHostEvent::Dismiss(id)),
+                    |cx| Label::new(cx, ICON_X).class("icon"),
+                )
+                .class("icon")
+                .class("toast-close");
+            })
+            .class(kind.class_name())
+            .toggle_class(
+                "closing",
+                ToastHost::toasts
+                    .map(move |toasts| toasts.iter().any(|toast| toast.id == id && toast.closing)),
+            );
+
+        let handle = if kind == ToastKind::Error {
+            handle.role(Role::Alert).live(Live::Assertive)
+        } else {
+            handle.role(Role::Status)
+        };
+
+        if !entered {
+            let entity = handle.entity;
+            if let Some(animation) = handle.cx.style.get_animation("toast-in").copied() {
+                handle.cx.style.enqueue_animation(entity, animation, TOAST_ANIMATION_DURATION);
+            }
+
+            handle.cx.emit_to(Entity::root(), ToastHostEvent::Entered(id));
+        }
+
+        handle
+    }
+}
+
+impl View for Toast {
+    fn element(&self) -> Option<&'static str> {
+        Some("toast")
+    }
+}
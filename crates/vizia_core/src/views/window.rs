@@ -0,0 +1,248 @@
+use crate::prelude::*;
+use crate::window::WindowState;
+use vizia_window::{Position, WindowDescription};
+
+/// A view which opens a secondary operating system window.
+///
+/// The window is opened when the view is built and closed when the view is removed from the tree,
+/// for example by building it within a [`Binding`]. Views within the window share the same context,
+/// models, and stylesheets as the rest of the application, while hover, focus, and window events are
+/// tracked separately for each window.
+///
+/// By default, closing the window with the window decorations hides it. Use [`on_close`](Handle::on_close)
+/// to update the application state so that the view is removed instead.
+///
+/// The contents of the window are laid out with the scale factor of the main window, and scaled
+/// when drawn if the window is shown on a display with a different scale factor.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// # let cx = &mut Context::default();
+/// # #[derive(Lens)]
+/// # pub struct AppData {
+/// #     show_window: bool,
+/// # }
+/// # impl Model for AppData {}
+/// # AppData { show_window: true }.build(cx);
+/// Binding::new(cx, AppData::show_window, |cx, show_window| {
+///     if show_window.get(cx) {
+///         Window::new(cx, |cx| {
+///             Label::new(cx, "Hello from a second window");
+///         })
+///         .title("Secondary Window")
+///         .inner_size((400, 200));
+///     }
+/// });
+/// ```
+pub struct Window {
+    on_close: Option<Box<dyn Fn(&mut EventContext)>>,
+    commands: Vec<WindowEvent>,
+}
+
+impl Window {
+    /// Creates a new secondary window with the given content.
+    pub fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        let window_description = WindowDescription::new();
+        let width = window_description.inner_size.width as f32;
+        let height = window_description.inner_size.height as f32;
+
+        let handle = Self { on_close: None, commands: Vec::new() }
+            .build(cx, content)
+            .role(Role::Window)
+            .position_type(PositionType::SelfDirected)
+            .width(Pixels(width))
+            .height(Pixels(height))
+            .lock_focus_to_within();
+
+        let entity = handle.entity;
+        handle.cx.tree.set_window(entity, true);
+        handle.cx.windows.insert(entity, WindowState::new(entity, window_description));
+        handle.cx.pending_windows.push(entity);
+
+        handle
+    }
+
+    /// Returns the window commands which have been queued since the last call and clears the queue.
+    pub(crate) fn take_commands(&mut self) -> Vec<WindowEvent> {
+        std::mem::take(&mut self.commands)
+    }
+}
+
+impl View for Window {
+    fn element(&self) -> Option<&'static str> {
+        Some("window")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::WindowClose => {
+                if let Some(callback) = &self.on_close {
+                    (callback)(cx);
+                } else {
+                    self.commands.push(WindowEvent::SetVisible(false));
+                }
+                meta.consume();
+            }
+
+            WindowEvent::SetCursor(_)
+            | WindowEvent::GrabCursor(_)
+            | WindowEvent::SetCursorPosition(_, _)
+            | WindowEvent::SetTitle(_)
+            | WindowEvent::SetSize(_)
+            | WindowEvent::SetPosition(_)
+            | WindowEvent::SetMaxSize(_)
+            | WindowEvent::SetMinSize(_)
+            | WindowEvent::SetResizable(_)
+            | WindowEvent::SetMinimized(_)
+            | WindowEvent::SetMaximized(_)
+            | WindowEvent::SetVisible(_)
+            | WindowEvent::SetDecorations(_)
+            | WindowEvent::SetAlwaysOnTop(_) => {
+                self.commands.push(window_event.clone());
+                meta.consume();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl<'a> Handle<'a, Window> {
+    /// Sets the callback which is triggered when the user requests to close the window.
+    pub fn on_close(self, callback: impl 'static + Fn(&mut EventContext)) -> Self {
+        self.modify(|window: &mut Window| window.on_close = Some(Box::new(callback)))
+    }
+
+    /// Updates the description of the window, or sends the given event to the window if it has
+    /// already been opened.
+    fn modify_description(
+        self,
+        f: impl FnOnce(&mut WindowDescription),
+        event: Option<WindowEvent>,
+    ) -> Self {
+        let entity = self.entity;
+        if let Some(window_state) = self.cx.windows.get_mut(&entity) {
+            f(&mut window_state.window_description);
+        }
+
+        if !self.cx.pending_windows.contains(&entity) {
+            if let Some(event) = event {
+                self.cx.emit_to(entity, event);
+            }
+        }
+
+        self
+    }
+}
+
+impl<'a> WindowModifiers for Handle<'a, Window> {
+    fn title<T: ToString>(self, title: T) -> Self {
+        let title = title.to_string();
+        self.modify_description(
+            |description| description.title = title.clone(),
+            Some(WindowEvent::SetTitle(title.clone())),
+        )
+    }
+
+    fn inner_size<S: Into<WindowSize>>(self, size: S) -> Self {
+        let size = size.into();
+        self.width(Pixels(size.width as f32)).height(Pixels(size.height as f32)).modify_description(
+            |description| description.inner_size = size,
+            Some(WindowEvent::SetSize(size)),
+        )
+    }
+
+    fn min_inner_size<S: Into<WindowSize>>(self, size: Option<S>) -> Self {
+        let size = size.map(|size| size.into());
+        self.modify_description(
+            |description| description.min_inner_size = size,
+            Some(WindowEvent::SetMinSize(size)),
+        )
+    }
+
+    fn max_inner_size<S: Into<WindowSize>>(self, size: Option<S>) -> Self {
+        let size = size.map(|size| size.into());
+        self.modify_description(
+            |description| description.max_inner_size = size,
+            Some(WindowEvent::SetMaxSize(size)),
+        )
+    }
+
+    fn position<P: Into<Position>>(self, position: P) -> Self {
+        let position = position.into();
+        self.modify_description(
+            |description| description.position = Some(position),
+            Some(WindowEvent::SetPosition(position)),
+        )
+    }
+
+    fn resizable(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.resizable = flag,
+            Some(WindowEvent::SetResizable(flag)),
+        )
+    }
+
+    fn minimized(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.minimized = flag,
+            Some(WindowEvent::SetMinimized(flag)),
+        )
+    }
+
+    fn maximized(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.maximized = flag,
+            Some(WindowEvent::SetMaximized(flag)),
+        )
+    }
+
+    fn visible(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.visible = flag,
+            Some(WindowEvent::SetVisible(flag)),
+        )
+    }
+
+    fn transparent(self, flag: bool) -> Self {
+        self.modify_description(|description| description.transparent = flag, None)
+    }
+
+    fn decorations(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.decorations = flag,
+            Some(WindowEvent::SetDecorations(flag)),
+        )
+    }
+
+    fn always_on_top(self, flag: bool) -> Self {
+        self.modify_description(
+            |description| description.always_on_top = flag,
+            Some(WindowEvent::SetAlwaysOnTop(flag)),
+        )
+    }
+
+    fn vsync(self, flag: bool) -> Self {
+        self.modify_description(|description| description.vsync = flag, None)
+    }
+
+    fn icon(self, image: Vec<u8>, width: u32, height: u32) -> Self {
+        self.modify_description(
+            |description| {
+                description.icon = Some(image);
+                description.icon_width = width;
+                description.icon_height = height;
+            },
+            None,
+        )
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn canvas(self, canvas: &str) -> Self {
+        self.modify_description(
+            |description| description.target_canvas = Some(canvas.to_owned()),
+            None,
+        )
+    }
+}
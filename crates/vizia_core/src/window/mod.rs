@@ -5,3 +5,6 @@ pub use window_modifiers::*;
 
 mod window_event;
 pub use window_event::*;
+
+mod window_state;
+pub(crate) use window_state::*;
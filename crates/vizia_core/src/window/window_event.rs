@@ -1,14 +1,89 @@
+use std::any::Any;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use crate::{entity::Entity, layout::cache::GeoChanged};
 use vizia_input::{Code, Key, MouseButton};
 use vizia_style::CursorIcon;
 use vizia_window::{Position, WindowSize};
 
-#[derive(Debug, Clone)]
+/// The payload of a drag-and-drop operation.
+#[derive(Clone)]
 pub enum DropData {
+    /// A single file dropped onto the window from the operating system.
     File(PathBuf),
+    /// Multiple files dropped onto the window from the operating system.
+    Files(Vec<PathBuf>),
+    /// The entity of a dragged view.
     Id(Entity),
+    /// A typed value dragged within the application. Use [`DropData::value`] to create this variant.
+    Value(Arc<Mutex<dyn Any + Send>>),
+    /// Bytes tagged with a MIME type, such as `text/plain`.
+    Mime { mime_type: String, data: Arc<[u8]> },
+}
+
+impl DropData {
+    /// Creates drop data holding a typed value.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// let data = DropData::value(42u32);
+    /// assert_eq!(data.with_value(|value: &u32| *value), Some(42));
+    /// ```
+    pub fn value<T: Any + Send>(value: T) -> Self {
+        DropData::Value(Arc::new(Mutex::new(value)))
+    }
+
+    /// Creates drop data holding bytes tagged with a MIME type.
+    pub fn mime(mime_type: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        DropData::Mime { mime_type: mime_type.into(), data: data.into().into() }
+    }
+
+    /// Calls the closure with a reference to the typed value if the drop data holds a value of
+    /// type `T`.
+    pub fn with_value<T: Any, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        match self {
+            DropData::Value(value) => {
+                let value = value.lock().unwrap();
+                value.downcast_ref::<T>().map(f)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the paths of any files held by the drop data.
+    pub fn files(&self) -> &[PathBuf] {
+        match self {
+            DropData::File(path) => std::slice::from_ref(path),
+            DropData::Files(paths) => paths,
+            _ => &[],
+        }
+    }
+
+    /// Returns the MIME type and bytes if the drop data holds MIME-tagged data.
+    pub fn mime_data(&self) -> Option<(&str, &[u8])> {
+        match self {
+            DropData::Mime { mime_type, data } => Some((mime_type.as_str(), &data[..])),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for DropData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DropData::File(path) => f.debug_tuple("File").field(path).finish(),
+            DropData::Files(paths) => f.debug_tuple("Files").field(paths).finish(),
+            DropData::Id(entity) => f.debug_tuple("Id").field(entity).finish(),
+            DropData::Value(_) => f.write_str("Value(..)"),
+            DropData::Mime { mime_type, data } => f
+                .debug_struct("Mime")
+                .field("mime_type", mime_type)
+                .field("len", &data.len())
+                .finish(),
+        }
+    }
 }
 
 impl From<Entity> for DropData {
@@ -23,14 +98,29 @@ impl From<PathBuf> for DropData {
     }
 }
 
+impl From<Vec<PathBuf>> for DropData {
+    fn from(value: Vec<PathBuf>) -> Self {
+        DropData::Files(value)
+    }
+}
+
 /// Events generated by the application in response to OS events as well as events that can be used
 /// to set properties of the window.
 #[derive(Debug, Clone)]
 pub enum WindowEvent {
     /// Emitted when a window is closed. Can also be emitted by a view or model to close the window.
     WindowClose,
-    /// Emitted when a file is dragged and then dropped onto the window.
+    /// Emitted when a file is dragged and then dropped onto the window, and sent to the view which
+    /// accepted a drag-and-drop operation when it is dropped.
     Drop(DropData),
+    /// Emitted when a drag-and-drop operation moves over a view. A view can accept the drop by calling
+    /// [`EventContext::accept_drop`](crate::context::EventContext::accept_drop).
+    DragEnter,
+    /// Emitted when a drag-and-drop operation moves to the given (x, y) position over the view which
+    /// accepted the drop.
+    DragOver(f32, f32),
+    /// Emitted when a drag-and-drop operation leaves the view which accepted the drop, or ends.
+    DragLeave,
     /// Emitted when a mouse button is double clicked.
     MouseDoubleClick(MouseButton),
     /// Emitted when a mouse button is triple clicked
@@ -51,6 +141,14 @@ pub enum WindowEvent {
     MouseMove(f32, f32),
     /// Emitted when the mouse scroll wheel is scrolled.
     MouseScroll(f32, f32),
+    /// Emitted when a touch point with the given id makes contact at the given (x, y) position.
+    TouchStart(u64, f32, f32),
+    /// Emitted when a touch point with the given id moves to the given (x, y) position.
+    TouchMove(u64, f32, f32),
+    /// Emitted when a touch point with the given id is lifted at the given (x, y) position.
+    TouchEnd(u64, f32, f32),
+    /// Emitted when a touch point with the given id is cancelled by the system.
+    TouchCancel(u64),
     /// Emitted when the mouse cursor enters the bounding box of an entity.
     MouseOver,
     /// Emitted when the mouse cursor leaves the bounding box of an entity.
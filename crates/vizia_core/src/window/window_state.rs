@@ -0,0 +1,36 @@
+use crate::entity::Entity;
+use crate::text::GlyphCache;
+use vizia_input::MouseState;
+use vizia_window::WindowDescription;
+
+/// Per-window state for a window which is not currently active.
+///
+/// Hover, focus and mouse state belong to a particular window. The state of the active window lives
+/// directly in the [`Context`](crate::prelude::Context), and is swapped with the stored state of another
+/// window when an event for that window is dispatched.
+pub(crate) struct WindowState {
+    pub window_description: WindowDescription,
+    /// The scale factor of the display showing the window, once the window has been opened.
+    pub scale_factor: Option<f64>,
+    pub hovered: Entity,
+    pub focused: Entity,
+    pub captured: Entity,
+    pub triggered: Entity,
+    pub mouse: MouseState<Entity>,
+    pub glyph_cache: GlyphCache,
+}
+
+impl WindowState {
+    pub fn new(window: Entity, window_description: WindowDescription) -> Self {
+        Self {
+            window_description,
+            scale_factor: None,
+            hovered: window,
+            focused: window,
+            captured: Entity::null(),
+            triggered: Entity::null(),
+            mouse: MouseState::default(),
+            glyph_cache: GlyphCache::default(),
+        }
+    }
+}
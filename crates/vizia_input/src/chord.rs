@@ -1,7 +1,15 @@
+use crate::names::{key_from_name, key_name, key_symbol, modifier_from_name};
 use crate::{Code, Modifiers};
+use std::error::Error;
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
 
 /// A key chord used inside of a `Keymap`.
+///
+/// Key chords can be formatted as and parsed from strings such as `Ctrl+Shift+S`, which is useful
+/// for storing them in configuration files. To show a key chord to the user, use
+/// [`label`](KeyChord::label), which follows the conventions of the current platform.
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct KeyChord {
     /// The modifiers that have to be pressed in order to active its associated actions.
@@ -23,4 +31,188 @@ impl KeyChord {
     pub fn new(modifiers: Modifiers, code: Code) -> Self {
         Self { modifiers, code }
     }
+
+    /// Returns a label for the key chord in the style of the current platform, such as `⇧⌘S` on
+    /// macOS and `Ctrl+Shift+S` elsewhere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_chord = KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyS);
+    /// println!("Save as... {}", key_chord.label());
+    /// ```
+    pub fn label(&self) -> String {
+        self.label_with(KeyLabelStyle::native())
+    }
+
+    /// Returns a label for the key chord in the given style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_chord = KeyChord::new(Modifiers::LOGO | Modifiers::SHIFT, Code::KeyS);
+    /// assert_eq!(key_chord.label_with(KeyLabelStyle::Symbols), "⇧⌘S");
+    /// ```
+    pub fn label_with(&self, style: KeyLabelStyle) -> String {
+        match style {
+            KeyLabelStyle::Symbols => {
+                // macOS lists modifiers in the order control, option, shift, command.
+                let mut label = String::new();
+                for (modifier, symbol) in [
+                    (Modifiers::CTRL, "⌃"),
+                    (Modifiers::ALT, "⌥"),
+                    (Modifiers::SHIFT, "⇧"),
+                    (Modifiers::LOGO, "⌘"),
+                ] {
+                    if self.modifiers.contains(modifier) {
+                        label.push_str(symbol);
+                    }
+                }
+
+                match key_symbol(self.code) {
+                    Some(symbol) => label.push_str(symbol),
+                    None => label.push_str(&code_name(self.code)),
+                }
+
+                label
+            }
+
+            KeyLabelStyle::Text => {
+                let logo = if cfg!(target_os = "windows") { "Win" } else { "Super" };
+                let mut parts = Vec::new();
+                for (modifier, name) in [
+                    (Modifiers::CTRL, "Ctrl"),
+                    (Modifiers::ALT, "Alt"),
+                    (Modifiers::SHIFT, "Shift"),
+                    (Modifiers::LOGO, logo),
+                ] {
+                    if self.modifiers.contains(modifier) {
+                        parts.push(name.to_owned());
+                    }
+                }
+
+                parts.push(code_name(self.code));
+                parts.join("+")
+            }
+        }
+    }
+}
+
+/// Returns the name of a key, falling back to the name of its code.
+fn code_name(code: Code) -> String {
+    key_name(code).map(str::to_owned).unwrap_or_else(|| format!("{:?}", code))
+}
+
+/// Formats the key chord as a string such as `Ctrl+Shift+S`, which can be parsed back into the
+/// same key chord.
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Logo"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        write!(f, "{}", code_name(self.code))
+    }
+}
+
+/// Parses a key chord from a string such as `Ctrl+Shift+S`.
+///
+/// Modifier and key names are not case sensitive. The modifier names `Control`, `Option`, `Cmd`,
+/// `Command`, `Super`, `Meta` and `Win` are accepted as alternatives.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_input::*;
+/// #
+/// let key_chord: KeyChord = "Ctrl+Shift+S".parse().unwrap();
+/// assert_eq!(key_chord, KeyChord::new(Modifiers::CTRL | Modifiers::SHIFT, Code::KeyS));
+/// ```
+impl FromStr for KeyChord {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop().filter(|key| !key.is_empty()).ok_or(ParseKeyChordError::MissingKey)?;
+
+        let mut modifiers = Modifiers::empty();
+        for part in parts {
+            modifiers |= modifier_from_name(part)
+                .ok_or_else(|| ParseKeyChordError::UnknownModifier(part.to_owned()))?;
+        }
+
+        let code =
+            key_from_name(key).ok_or_else(|| ParseKeyChordError::UnknownKey(key.to_owned()))?;
+
+        Ok(Self { modifiers, code })
+    }
+}
+
+/// The style of the labels returned by [`KeyChord::label_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyLabelStyle {
+    /// Modifiers and keys are shown as symbols, such as `⇧⌘S`, as is conventional on macOS.
+    Symbols,
+    /// Modifiers and keys are shown as names joined by `+`, such as `Ctrl+Shift+S`.
+    Text,
+}
+
+impl KeyLabelStyle {
+    /// Returns the style used by the current platform.
+    pub fn native() -> Self {
+        if cfg!(target_os = "macos") {
+            KeyLabelStyle::Symbols
+        } else {
+            KeyLabelStyle::Text
+        }
+    }
+}
+
+/// The error returned when a key chord or key sequence cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseKeyChordError {
+    /// The string does not end with a key.
+    MissingKey,
+    /// A modifier name was not recognized.
+    UnknownModifier(String),
+    /// A key name was not recognized.
+    UnknownKey(String),
+}
+
+impl fmt::Display for ParseKeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseKeyChordError::MissingKey => write!(f, "missing key"),
+            ParseKeyChordError::UnknownModifier(name) => write!(f, "unknown modifier '{}'", name),
+            ParseKeyChordError::UnknownKey(name) => write!(f, "unknown key '{}'", name),
+        }
+    }
+}
+
+impl Error for ParseKeyChordError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyChord {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeyChord {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
 }
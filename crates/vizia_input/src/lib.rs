@@ -1,10 +1,13 @@
 mod chord;
 mod modifiers;
 mod mouse;
+mod names;
+mod sequence;
 
 pub use chord::*;
 pub use modifiers::*;
 pub use mouse::*;
+pub use sequence::*;
 
 pub use keyboard_types::Modifiers as KeyboardModifiers;
 pub use keyboard_types::{Code, Key, KeyState};
@@ -0,0 +1,160 @@
+use crate::{Code, Modifiers};
+
+/// The names and macOS symbols of the keys which can be part of a key chord.
+///
+/// The name is used when formatting and parsing key chords, while the symbol, if there is one,
+/// replaces the name in labels shown on macOS.
+const KEYS: &[(Code, &str, Option<&str>)] = &[
+    (Code::KeyA, "A", None),
+    (Code::KeyB, "B", None),
+    (Code::KeyC, "C", None),
+    (Code::KeyD, "D", None),
+    (Code::KeyE, "E", None),
+    (Code::KeyF, "F", None),
+    (Code::KeyG, "G", None),
+    (Code::KeyH, "H", None),
+    (Code::KeyI, "I", None),
+    (Code::KeyJ, "J", None),
+    (Code::KeyK, "K", None),
+    (Code::KeyL, "L", None),
+    (Code::KeyM, "M", None),
+    (Code::KeyN, "N", None),
+    (Code::KeyO, "O", None),
+    (Code::KeyP, "P", None),
+    (Code::KeyQ, "Q", None),
+    (Code::KeyR, "R", None),
+    (Code::KeyS, "S", None),
+    (Code::KeyT, "T", None),
+    (Code::KeyU, "U", None),
+    (Code::KeyV, "V", None),
+    (Code::KeyW, "W", None),
+    (Code::KeyX, "X", None),
+    (Code::KeyY, "Y", None),
+    (Code::KeyZ, "Z", None),
+    (Code::Digit0, "0", None),
+    (Code::Digit1, "1", None),
+    (Code::Digit2, "2", None),
+    (Code::Digit3, "3", None),
+    (Code::Digit4, "4", None),
+    (Code::Digit5, "5", None),
+    (Code::Digit6, "6", None),
+    (Code::Digit7, "7", None),
+    (Code::Digit8, "8", None),
+    (Code::Digit9, "9", None),
+    (Code::F1, "F1", None),
+    (Code::F2, "F2", None),
+    (Code::F3, "F3", None),
+    (Code::F4, "F4", None),
+    (Code::F5, "F5", None),
+    (Code::F6, "F6", None),
+    (Code::F7, "F7", None),
+    (Code::F8, "F8", None),
+    (Code::F9, "F9", None),
+    (Code::F10, "F10", None),
+    (Code::F11, "F11", None),
+    (Code::F12, "F12", None),
+    (Code::F13, "F13", None),
+    (Code::F14, "F14", None),
+    (Code::F15, "F15", None),
+    (Code::F16, "F16", None),
+    (Code::F17, "F17", None),
+    (Code::F18, "F18", None),
+    (Code::F19, "F19", None),
+    (Code::F20, "F20", None),
+    (Code::F21, "F21", None),
+    (Code::F22, "F22", None),
+    (Code::F23, "F23", None),
+    (Code::F24, "F24", None),
+    (Code::Enter, "Enter", Some("↩")),
+    (Code::Escape, "Escape", Some("⎋")),
+    (Code::Backspace, "Backspace", Some("⌫")),
+    (Code::Tab, "Tab", Some("⇥")),
+    (Code::Space, "Space", None),
+    (Code::Delete, "Delete", Some("⌦")),
+    (Code::Insert, "Insert", None),
+    (Code::Home, "Home", Some("↖")),
+    (Code::End, "End", Some("↘")),
+    (Code::PageUp, "PageUp", Some("⇞")),
+    (Code::PageDown, "PageDown", Some("⇟")),
+    (Code::ArrowUp, "Up", Some("↑")),
+    (Code::ArrowDown, "Down", Some("↓")),
+    (Code::ArrowLeft, "Left", Some("←")),
+    (Code::ArrowRight, "Right", Some("→")),
+    (Code::Minus, "-", None),
+    (Code::Equal, "=", None),
+    (Code::BracketLeft, "[", None),
+    (Code::BracketRight, "]", None),
+    (Code::Backslash, "\\", None),
+    (Code::Semicolon, ";", None),
+    (Code::Quote, "'", None),
+    (Code::Backquote, "`", None),
+    (Code::Comma, "Comma", Some(",")),
+    (Code::Period, ".", None),
+    (Code::Slash, "/", None),
+    (Code::CapsLock, "CapsLock", Some("⇪")),
+    (Code::PrintScreen, "PrintScreen", None),
+    (Code::ScrollLock, "ScrollLock", None),
+    (Code::Pause, "Pause", None),
+    (Code::ContextMenu, "Menu", None),
+    (Code::NumLock, "NumLock", None),
+    (Code::Numpad0, "Numpad0", None),
+    (Code::Numpad1, "Numpad1", None),
+    (Code::Numpad2, "Numpad2", None),
+    (Code::Numpad3, "Numpad3", None),
+    (Code::Numpad4, "Numpad4", None),
+    (Code::Numpad5, "Numpad5", None),
+    (Code::Numpad6, "Numpad6", None),
+    (Code::Numpad7, "Numpad7", None),
+    (Code::Numpad8, "Numpad8", None),
+    (Code::Numpad9, "Numpad9", None),
+    (Code::NumpadAdd, "NumpadAdd", None),
+    (Code::NumpadSubtract, "NumpadSubtract", None),
+    (Code::NumpadMultiply, "NumpadMultiply", None),
+    (Code::NumpadDivide, "NumpadDivide", None),
+    (Code::NumpadDecimal, "NumpadDecimal", None),
+    (Code::NumpadEnter, "NumpadEnter", None),
+    (Code::AudioVolumeUp, "VolumeUp", None),
+    (Code::AudioVolumeDown, "VolumeDown", None),
+    (Code::AudioVolumeMute, "VolumeMute", None),
+    (Code::MediaPlayPause, "MediaPlayPause", None),
+    (Code::MediaStop, "MediaStop", None),
+    (Code::MediaTrackNext, "MediaTrackNext", None),
+    (Code::MediaTrackPrevious, "MediaTrackPrevious", None),
+];
+
+/// Returns the name of the key with the given code.
+pub(crate) fn key_name(code: Code) -> Option<&'static str> {
+    KEYS.iter().find(|(key, _, _)| *key == code).map(|(_, name, _)| *name)
+}
+
+/// Returns the macOS symbol of the key with the given code.
+pub(crate) fn key_symbol(code: Code) -> Option<&'static str> {
+    KEYS.iter().find(|(key, _, _)| *key == code).and_then(|(_, _, symbol)| *symbol)
+}
+
+/// Returns the code of the key with the given name, ignoring case, or the code with the given name
+/// for keys which have no name of their own.
+///
+/// The comma key is named `Comma`, so that it cannot be mistaken for the separator of a key
+/// sequence, but `,` is accepted as well.
+pub(crate) fn key_from_name(name: &str) -> Option<Code> {
+    if name == "," {
+        return Some(Code::Comma);
+    }
+
+    KEYS.iter()
+        .find(|(_, key_name, _)| key_name.eq_ignore_ascii_case(name))
+        .map(|(code, _, _)| *code)
+        .or_else(|| name.parse().ok())
+}
+
+/// Returns the modifier with the given name, ignoring case.
+pub(crate) fn modifier_from_name(name: &str) -> Option<Modifiers> {
+    match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Some(Modifiers::CTRL),
+        "shift" => Some(Modifiers::SHIFT),
+        "alt" | "option" | "opt" => Some(Modifiers::ALT),
+        "logo" | "super" | "meta" | "cmd" | "command" | "win" | "windows" => Some(Modifiers::LOGO),
+        _ => None,
+    }
+}
@@ -0,0 +1,445 @@
+use crate::{KeyChord, KeyLabelStyle, ParseKeyChordError};
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A sequence of key chords which have to be pressed one after another, such as `CTRL+K, CTRL+S`.
+///
+/// A single key chord converts into a sequence of length one. Like key chords, key sequences can be
+/// formatted as and parsed from strings, such as `Ctrl+K, Ctrl+S`.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeySequence {
+    chords: Vec<KeyChord>,
+}
+
+impl KeySequence {
+    /// Creates a new key sequence from the given key chords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_sequence = KeySequence::new(vec![
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyK),
+    ///     KeyChord::new(Modifiers::CTRL, Code::KeyS),
+    /// ]);
+    /// ```
+    pub fn new(chords: Vec<KeyChord>) -> Self {
+        Self { chords }
+    }
+
+    /// Returns the key chords of the sequence.
+    pub fn chords(&self) -> &[KeyChord] {
+        &self.chords
+    }
+
+    /// Returns the number of key chords in the sequence.
+    pub fn len(&self) -> usize {
+        self.chords.len()
+    }
+
+    /// Returns true if the sequence contains no key chords.
+    pub fn is_empty(&self) -> bool {
+        self.chords.is_empty()
+    }
+
+    /// Returns true if the sequence begins with the given key chords.
+    pub fn starts_with(&self, chords: &[KeyChord]) -> bool {
+        self.chords.starts_with(chords)
+    }
+
+    /// Returns a label for the key sequence in the style of the current platform.
+    pub fn label(&self) -> String {
+        self.label_with(KeyLabelStyle::native())
+    }
+
+    /// Returns a label for the key sequence in the given style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use vizia_input::*;
+    /// #
+    /// let key_sequence: KeySequence = "Logo+K, Logo+S".parse().unwrap();
+    /// assert_eq!(key_sequence.label_with(KeyLabelStyle::Symbols), "⌘K ⌘S");
+    /// ```
+    pub fn label_with(&self, style: KeyLabelStyle) -> String {
+        let separator = match style {
+            KeyLabelStyle::Symbols => " ",
+            KeyLabelStyle::Text => ", ",
+        };
+
+        self.chords.iter().map(|chord| chord.label_with(style)).collect::<Vec<_>>().join(separator)
+    }
+}
+
+/// Formats the key sequence as a string such as `Ctrl+K, Ctrl+S`.
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, chord) in self.chords.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", chord)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a key sequence from key chords separated by commas or whitespace, such as
+/// `Ctrl+K, Ctrl+S`.
+///
+/// A comma which follows a `+` or begins a key chord is the comma key rather than a separator, so
+/// that `Ctrl+,` parses as well as `Ctrl+Comma`.
+///
+/// # Examples
+///
+/// ```
+/// # use vizia_input::*;
+/// #
+/// let key_sequence: KeySequence = "Ctrl+K Ctrl+S".parse().unwrap();
+/// assert_eq!(key_sequence.to_string(), "Ctrl+K, Ctrl+S");
+/// ```
+impl FromStr for KeySequence {
+    type Err = ParseKeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chords =
+            split_chords(s).into_iter().map(str::parse).collect::<Result<Vec<KeyChord>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(ParseKeyChordError::MissingKey);
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+/// Splits a key sequence into its key chords, which are separated by commas or whitespace.
+fn split_chords(s: &str) -> Vec<&str> {
+    let mut chords = Vec::new();
+    let mut start = None;
+    // Whether the previous key chord has already been followed by a comma separator.
+    let mut separated = false;
+    for (index, c) in s.char_indices() {
+        match start {
+            None if c.is_whitespace() => {}
+            None if c == ',' && !chords.is_empty() && !separated => separated = true,
+            None => {
+                start = Some(index);
+                separated = false;
+            }
+            Some(begin) if c == ',' || c.is_whitespace() => {
+                // The key of the chord is still to come, so this is the comma key or spacing.
+                if s[begin..index].trim_end().ends_with('+') {
+                    continue;
+                }
+
+                chords.push(&s[begin..index]);
+                start = None;
+                separated = c == ',';
+            }
+            Some(_) => {}
+        }
+    }
+
+    if let Some(begin) = start {
+        chords.push(&s[begin..]);
+    }
+
+    chords
+}
+
+impl From<KeyChord> for KeySequence {
+    fn from(chord: KeyChord) -> Self {
+        Self { chords: vec![chord] }
+    }
+}
+
+impl From<Vec<KeyChord>> for KeySequence {
+    fn from(chords: Vec<KeyChord>) -> Self {
+        Self { chords }
+    }
+}
+
+impl<const N: usize> From<[KeyChord; N]> for KeySequence {
+    fn from(chords: [KeyChord; N]) -> Self {
+        Self { chords: chords.to_vec() }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeySequence {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeySequence {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Code, Modifiers};
+
+    const CODES: &[Code] = &[
+        Code::Backquote,
+        Code::Backslash,
+        Code::BracketLeft,
+        Code::BracketRight,
+        Code::Comma,
+        Code::Digit0,
+        Code::Digit1,
+        Code::Digit2,
+        Code::Digit3,
+        Code::Digit4,
+        Code::Digit5,
+        Code::Digit6,
+        Code::Digit7,
+        Code::Digit8,
+        Code::Digit9,
+        Code::Equal,
+        Code::IntlBackslash,
+        Code::IntlRo,
+        Code::IntlYen,
+        Code::KeyA,
+        Code::KeyB,
+        Code::KeyC,
+        Code::KeyD,
+        Code::KeyE,
+        Code::KeyF,
+        Code::KeyG,
+        Code::KeyH,
+        Code::KeyI,
+        Code::KeyJ,
+        Code::KeyK,
+        Code::KeyL,
+        Code::KeyM,
+        Code::KeyN,
+        Code::KeyO,
+        Code::KeyP,
+        Code::KeyQ,
+        Code::KeyR,
+        Code::KeyS,
+        Code::KeyT,
+        Code::KeyU,
+        Code::KeyV,
+        Code::KeyW,
+        Code::KeyX,
+        Code::KeyY,
+        Code::KeyZ,
+        Code::Minus,
+        Code::Period,
+        Code::Quote,
+        Code::Semicolon,
+        Code::Slash,
+        Code::AltLeft,
+        Code::AltRight,
+        Code::Backspace,
+        Code::CapsLock,
+        Code::ContextMenu,
+        Code::ControlLeft,
+        Code::ControlRight,
+        Code::Enter,
+        Code::MetaLeft,
+        Code::MetaRight,
+        Code::ShiftLeft,
+        Code::ShiftRight,
+        Code::Space,
+        Code::Tab,
+        Code::Convert,
+        Code::KanaMode,
+        Code::Lang1,
+        Code::Lang2,
+        Code::Lang3,
+        Code::Lang4,
+        Code::Lang5,
+        Code::NonConvert,
+        Code::Delete,
+        Code::End,
+        Code::Help,
+        Code::Home,
+        Code::Insert,
+        Code::PageDown,
+        Code::PageUp,
+        Code::ArrowDown,
+        Code::ArrowLeft,
+        Code::ArrowRight,
+        Code::ArrowUp,
+        Code::NumLock,
+        Code::Numpad0,
+        Code::Numpad1,
+        Code::Numpad2,
+        Code::Numpad3,
+        Code::Numpad4,
+        Code::Numpad5,
+        Code::Numpad6,
+        Code::Numpad7,
+        Code::Numpad8,
+        Code::Numpad9,
+        Code::NumpadAdd,
+        Code::NumpadBackspace,
+        Code::NumpadClear,
+        Code::NumpadClearEntry,
+        Code::NumpadComma,
+        Code::NumpadDecimal,
+        Code::NumpadDivide,
+        Code::NumpadEnter,
+        Code::NumpadEqual,
+        Code::NumpadHash,
+        Code::NumpadMemoryAdd,
+        Code::NumpadMemoryClear,
+        Code::NumpadMemoryRecall,
+        Code::NumpadMemoryStore,
+        Code::NumpadMemorySubtract,
+        Code::NumpadMultiply,
+        Code::NumpadParenLeft,
+        Code::NumpadParenRight,
+        Code::NumpadStar,
+        Code::NumpadSubtract,
+        Code::Escape,
+        Code::F1,
+        Code::F2,
+        Code::F3,
+        Code::F4,
+        Code::F5,
+        Code::F6,
+        Code::F7,
+        Code::F8,
+        Code::F9,
+        Code::F10,
+        Code::F11,
+        Code::F12,
+        Code::Fn,
+        Code::FnLock,
+        Code::PrintScreen,
+        Code::ScrollLock,
+        Code::Pause,
+        Code::BrowserBack,
+        Code::BrowserFavorites,
+        Code::BrowserForward,
+        Code::BrowserHome,
+        Code::BrowserRefresh,
+        Code::BrowserSearch,
+        Code::BrowserStop,
+        Code::Eject,
+        Code::LaunchApp1,
+        Code::LaunchApp2,
+        Code::LaunchMail,
+        Code::MediaPlayPause,
+        Code::MediaSelect,
+        Code::MediaStop,
+        Code::MediaTrackNext,
+        Code::MediaTrackPrevious,
+        Code::Power,
+        Code::Sleep,
+        Code::AudioVolumeDown,
+        Code::AudioVolumeMute,
+        Code::AudioVolumeUp,
+        Code::WakeUp,
+        Code::Hyper,
+        Code::Super,
+        Code::Turbo,
+        Code::Abort,
+        Code::Resume,
+        Code::Suspend,
+        Code::Again,
+        Code::Copy,
+        Code::Cut,
+        Code::Find,
+        Code::Open,
+        Code::Paste,
+        Code::Props,
+        Code::Select,
+        Code::Undo,
+        Code::Hiragana,
+        Code::Katakana,
+        Code::Unidentified,
+        Code::F13,
+        Code::F14,
+        Code::F15,
+        Code::F16,
+        Code::F17,
+        Code::F18,
+        Code::F19,
+        Code::F20,
+        Code::F21,
+        Code::F22,
+        Code::F23,
+        Code::F24,
+        Code::BrightnessDown,
+        Code::BrightnessUp,
+        Code::DisplayToggleIntExt,
+        Code::KeyboardLayoutSelect,
+        Code::LaunchAssistant,
+        Code::LaunchControlPanel,
+        Code::LaunchScreenSaver,
+        Code::MailForward,
+        Code::MailReply,
+        Code::MailSend,
+        Code::MediaFastForward,
+        Code::MediaPause,
+        Code::MediaPlay,
+        Code::MediaRecord,
+        Code::MediaRewind,
+        Code::MicrophoneMuteToggle,
+        Code::PrivacyScreenToggle,
+        Code::SelectTask,
+        Code::ShowAllWindows,
+        Code::ZoomToggle,
+    ];
+
+    #[test]
+    fn every_code_round_trips() {
+        for code in CODES {
+            for modifiers in [Modifiers::empty(), Modifiers::CTRL | Modifiers::SHIFT] {
+                let sequence = KeySequence::from([
+                    KeyChord::new(modifiers, *code),
+                    KeyChord::new(Modifiers::CTRL, *code),
+                ]);
+                assert_eq!(sequence.to_string().parse(), Ok(sequence));
+            }
+        }
+    }
+
+    #[test]
+    fn comma_key() {
+        let comma = KeyChord::new(Modifiers::CTRL, Code::Comma);
+        assert_eq!(comma.to_string(), "Ctrl+Comma");
+        assert_eq!("Ctrl+,".parse(), Ok(KeySequence::from(comma)));
+        assert_eq!(
+            "Ctrl+,, Ctrl+K".parse(),
+            Ok(KeySequence::from([comma, KeyChord::new(Modifiers::CTRL, Code::KeyK)]))
+        );
+        assert_eq!(
+            "Ctrl+K, ,".parse(),
+            Ok(KeySequence::from([
+                KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                KeyChord::new(Modifiers::empty(), Code::Comma),
+            ]))
+        );
+        assert_eq!(
+            "Ctrl+K , Ctrl+S".parse(),
+            Ok(KeySequence::from([
+                KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                KeyChord::new(Modifiers::CTRL, Code::KeyS),
+            ]))
+        );
+    }
+
+    #[test]
+    fn unnamed_code() {
+        let chord = KeyChord::new(Modifiers::empty(), Code::IntlBackslash);
+        assert_eq!(chord.to_string(), "IntlBackslash");
+        assert_eq!("IntlBackslash".parse(), Ok(KeySequence::from(chord)));
+    }
+}
@@ -136,4 +136,28 @@ mod tests {
             println!("{:?}", item.index());
         }
     }
+
+    #[test]
+    fn test_draw_iter_portal() {
+        let mut tree = Tree::new();
+        let mut mgr: IdManager<Entity> = IdManager::new();
+        let root = mgr.create();
+        let a = mgr.create();
+        let aa = mgr.create();
+        let aaa = mgr.create();
+        let ab = mgr.create();
+        let b = mgr.create();
+
+        tree.add(a, root).unwrap();
+        tree.add(aa, a).unwrap();
+        tree.add(aaa, aa).unwrap();
+        tree.add(ab, a).unwrap();
+        tree.add(b, root).unwrap();
+        tree.set_z_index(b, 10);
+        tree.set_portal(aa, true);
+
+        // The portal is drawn after views with a higher z-index than its parent.
+        let vec: Vec<Entity> = DrawIterator::full(&tree).collect();
+        assert_eq!(vec, vec![root, a, ab, b, aa, aaa]);
+    }
 }
@@ -13,10 +13,15 @@ where
     pub prev_sibling: Vec<Option<I>>,
     pub ignored: Vec<bool>,
     pub lock_focus_within: Vec<bool>,
+    pub window: Vec<bool>,
+    pub portal: Vec<bool>,
     pub changed: bool,
     pub z_index: Vec<i32>,
 }
 
+/// The z-index of the overlay layer in which portals are drawn.
+const PORTAL_Z_INDEX: i32 = i32::MAX / 2;
+
 impl<I> Tree<I>
 where
     I: GenerationalId,
@@ -30,6 +35,8 @@ where
             prev_sibling: vec![None],
             ignored: vec![false],
             lock_focus_within: vec![true],
+            window: vec![true],
+            portal: vec![false],
             changed: true,
             z_index: vec![0],
         }
@@ -105,10 +112,25 @@ where
         self.ignored.get(entity.index()).map_or_else(|| false, |ignored| *ignored)
     }
 
+    /// Returns the z-index of the entity.
+    ///
+    /// Portals are placed in the overlay layer of their window, above every other view, with
+    /// nested portals above the portals which contain them.
     pub fn z_index(&self, entity: I) -> i32 {
+        if self.is_portal(entity) {
+            let depth =
+                entity.parent_iter(self).filter(|&ancestor| self.is_portal(ancestor)).count();
+            return PORTAL_Z_INDEX + depth as i32;
+        }
+
         self.z_index.get(entity.index()).copied().unwrap_or_default()
     }
 
+    /// Returns true if the entity is drawn in the overlay layer of its window.
+    pub fn is_portal(&self, entity: I) -> bool {
+        self.portal.get(entity.index()).copied().unwrap_or_default()
+    }
+
     /// Returns the first ancestor to have the lock_focus_within flag set
     pub fn lock_focus_within(&self, entity: I) -> I {
         entity
@@ -117,6 +139,16 @@ where
             .unwrap_or(I::root())
     }
 
+    /// Returns true if the entity is the root of a window.
+    pub fn is_window(&self, entity: I) -> bool {
+        self.window.get(entity.index()).copied().unwrap_or_default()
+    }
+
+    /// Returns the root entity of the window which contains the entity.
+    pub fn get_window(&self, entity: I) -> I {
+        entity.parent_iter(self).find(|&entity| self.is_window(entity)).unwrap_or(I::root())
+    }
+
     /// Returns the first ancestor of an entity which is not ignored
     pub fn get_layout_parent(&self, entity: I) -> Option<I> {
         let mut i = self.get_parent(entity);
@@ -243,6 +275,8 @@ where
         self.parent[entity_index] = None;
         self.ignored[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.window[entity_index] = false;
+        self.portal[entity_index] = false;
 
         // Set the changed flag
         self.changed = true;
@@ -449,6 +483,79 @@ where
         self.changed = true;
     }
 
+    /// Moves an entity, along with its descendants, to be the child of `parent` at the given
+    /// index. An index past the last child of the parent appends the entity to its children.
+    ///
+    /// Returns an error if the parent is the entity itself or one of its descendants, since the
+    /// subtree would then be detached from the tree.
+    pub fn reparent(&mut self, entity: I, parent: I, index: usize) -> Result<(), TreeError> {
+        if entity == I::null() || parent == I::null() {
+            return Err(TreeError::NullEntity);
+        }
+
+        if entity.index() >= self.parent.len() || self.get_parent(entity).is_none() {
+            return Err(TreeError::NoEntity);
+        }
+
+        if parent.index() >= self.parent.len() {
+            return Err(TreeError::InvalidParent);
+        }
+
+        let mut ancestor = Some(parent);
+        while let Some(current) = ancestor {
+            if current == entity {
+                return Err(TreeError::InvalidParent);
+            }
+            ancestor = self.get_parent(current);
+        }
+
+        // Detach the entity from its current siblings.
+        // from:    [PS] <-> [E] <-> [NS]
+        // to:      [PS] <-> [NS]
+        let prev_sibling = self.get_prev_sibling(entity);
+        let next_sibling = self.get_next_sibling(entity);
+
+        if let Some(prev_sibling) = prev_sibling {
+            self.next_sibling[prev_sibling.index()] = next_sibling;
+        } else if let Some(old_parent) = self.get_parent(entity) {
+            self.first_child[old_parent.index()] = next_sibling;
+        }
+
+        if let Some(next_sibling) = next_sibling {
+            self.prev_sibling[next_sibling.index()] = prev_sibling;
+        }
+
+        // Find the siblings between which the entity is inserted.
+        let mut prev_sibling = None;
+        let mut next_sibling = self.get_first_child(parent);
+        for _ in 0..index {
+            if let Some(sibling) = next_sibling {
+                prev_sibling = Some(sibling);
+                next_sibling = self.get_next_sibling(sibling);
+            } else {
+                break;
+            }
+        }
+
+        if let Some(prev_sibling) = prev_sibling {
+            self.next_sibling[prev_sibling.index()] = Some(entity);
+        } else {
+            self.first_child[parent.index()] = Some(entity);
+        }
+
+        if let Some(next_sibling) = next_sibling {
+            self.prev_sibling[next_sibling.index()] = Some(entity);
+        }
+
+        self.prev_sibling[entity.index()] = prev_sibling;
+        self.next_sibling[entity.index()] = next_sibling;
+        self.parent[entity.index()] = Some(parent);
+
+        self.changed = true;
+
+        Ok(())
+    }
+
     pub fn set_ignored(&mut self, entity: I, flag: bool) {
         if let Some(ignored) = self.ignored.get_mut(entity.index()) {
             *ignored = flag;
@@ -467,6 +574,18 @@ where
         }
     }
 
+    pub fn set_window(&mut self, entity: I, flag: bool) {
+        if let Some(window) = self.window.get_mut(entity.index()) {
+            *window = flag;
+        }
+    }
+
+    pub fn set_portal(&mut self, entity: I, flag: bool) {
+        if let Some(portal) = self.portal.get_mut(entity.index()) {
+            *portal = flag;
+        }
+    }
+
     /// Adds an entity to the tree with the specified parent.
     pub fn add(&mut self, entity: I, parent: I) -> Result<(), TreeError> {
         if entity == I::null() || parent == I::null() {
@@ -488,6 +607,8 @@ where
             self.prev_sibling.resize(entity_index + 1, None);
             self.ignored.resize(entity_index + 1, false);
             self.lock_focus_within.resize(entity_index + 1, false);
+            self.window.resize(entity_index + 1, false);
+            self.portal.resize(entity_index + 1, false);
             self.z_index.resize(entity_index + 1, 0);
         }
 
@@ -497,6 +618,8 @@ where
         self.prev_sibling[entity_index] = None;
         self.ignored[entity_index] = false;
         self.lock_focus_within[entity_index] = false;
+        self.window[entity_index] = false;
+        self.portal[entity_index] = false;
         self.z_index[entity_index] = 0;
 
         // If the parent has no first child then this entity is the first child
@@ -534,3 +657,53 @@ where
         TreeIterator::full(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TreeExt;
+    use vizia_id::{
+        impl_generational_id, GenerationalId, GENERATIONAL_ID_GENERATION_MASK,
+        GENERATIONAL_ID_INDEX_BITS, GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Entity(u32);
+
+    impl_generational_id!(Entity);
+
+    #[test]
+    fn test_reparent() -> Result<(), TreeError> {
+        let mut tree = Tree::new();
+        let [a, aa, ab, b, ba] = [1, 2, 3, 4, 5].map(|i| Entity::new(i, 0));
+
+        tree.add(a, Entity::root())?;
+        tree.add(aa, a)?;
+        tree.add(ab, a)?;
+        tree.add(b, Entity::root())?;
+        tree.add(ba, b)?;
+
+        // Move a subtree into another parent.
+        tree.reparent(a, b, 0)?;
+        assert_eq!(Entity::root().child_iter(&tree).collect::<Vec<_>>(), vec![b]);
+        assert_eq!(b.child_iter(&tree).collect::<Vec<_>>(), vec![a, ba]);
+        assert_eq!(a.child_iter(&tree).collect::<Vec<_>>(), vec![aa, ab]);
+        assert_eq!(tree.get_prev_sibling(b), None);
+        assert_eq!(tree.get_prev_sibling(ba), Some(a));
+        assert!(ab.is_descendant_of(&tree, b));
+
+        // Reorder within the same parent, with an index past the last child appending.
+        tree.reparent(a, b, 10)?;
+        assert_eq!(b.child_iter(&tree).collect::<Vec<_>>(), vec![ba, a]);
+        assert_eq!(b.child_iter(&tree).rev().collect::<Vec<_>>(), vec![a, ba]);
+        tree.reparent(aa, a, 1)?;
+        assert_eq!(a.child_iter(&tree).collect::<Vec<_>>(), vec![ab, aa]);
+
+        // An entity cannot be moved into its own subtree.
+        assert!(tree.reparent(b, aa, 0).is_err());
+        assert!(tree.reparent(b, b, 0).is_err());
+        assert!(tree.reparent(Entity::root(), b, 0).is_err());
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{CustomParseError, ParserOptions, Property};
+use crate::{
+    CustomParseError, DiagnosticSeverity, ErrorLocation, Location, ParserOptions, Property,
+    StyleDiagnostic,
+};
 
 use cssparser::*;
 
@@ -57,11 +60,43 @@ pub(crate) fn parse_declaration<'i>(
     input: &mut cssparser::Parser<'i, '_>,
     declarations: &mut DeclarationList<'i>,
     important_declarations: &mut DeclarationList<'i>,
-    _options: &ParserOptions,
+    options: &ParserOptions,
 ) -> Result<(), ParseError<'i, CustomParseError<'i>>> {
+    input.skip_whitespace();
+    let location = input.current_source_location();
     let property =
         input.parse_until_before(Delimiter::Bang, |input| Property::parse_value(name, input))?;
 
+    match &property {
+        Property::Unparsed(unparsed) => options.report(StyleDiagnostic {
+            severity: DiagnosticSeverity::Error,
+            location: ErrorLocation::new(
+                Location { line: location.line, column: location.column },
+                options.filename.clone(),
+            ),
+            line: location.line + 1,
+            property: Some(unparsed.name.to_string()),
+            expected: Property::expected_syntax(&unparsed.name).map(String::from),
+            message: format!("Invalid value for property `{}`.", unparsed.name),
+        }),
+
+        Property::Custom(custom) if !custom.name.starts_with("--") => {
+            options.report(StyleDiagnostic {
+                severity: DiagnosticSeverity::Warning,
+                location: ErrorLocation::new(
+                    Location { line: location.line, column: location.column },
+                    options.filename.clone(),
+                ),
+                line: location.line + 1,
+                property: Some(custom.name.to_string()),
+                expected: None,
+                message: format!("Unknown property `{}`.", custom.name),
+            })
+        }
+
+        _ => {}
+    }
+
     let important = input
         .try_parse(|input| {
             input.expect_delim('!')?;
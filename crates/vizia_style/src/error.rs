@@ -64,6 +64,32 @@ pub enum CustomParseError<'i> {
     AtRuleInvalid(CowRcStr<'i>),
     AtRuleBodyInvalid,
     QualifiedRuleInvalid,
+    UnexpectedImportRule,
+}
+
+impl<'i> CustomParseError<'i> {
+    /// Returns a human readable description of the error.
+    pub fn reason(&self) -> String {
+        use CustomParseError::*;
+        match self {
+            InvalidValue => "Invalid value.".into(),
+            InvalidDeclaration => "Invalid declaration.".into(),
+            InvalidNesting => "Declarations cannot come after nested rules.".into(),
+            SelectorError(err) => err.reason(),
+            EndOfInput => "Unexpected end of input.".into(),
+            UnexpectedToken(token) => format!("Unexpected token: {:?}", token),
+            AtRuleInvalid(name) => format!("Unknown at-rule: @{}", name),
+            AtRuleBodyInvalid => "Invalid at-rule body.".into(),
+            QualifiedRuleInvalid => "Invalid rule.".into(),
+            UnexpectedImportRule => "@import rules must come before all other rules.".into(),
+        }
+    }
+}
+
+impl<'i> fmt::Display for CustomParseError<'i> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason())
+    }
 }
 
 impl<'i> From<SelectorParseErrorKind<'i>> for CustomParseError<'i> {
@@ -157,7 +183,7 @@ impl<'i> From<SelectorParseErrorKind<'i>> for SelectorError<'i> {
 }
 
 impl<'i> SelectorError<'i> {
-    fn _reason(&self) -> String {
+    fn reason(&self) -> String {
         use SelectorError::*;
         match self {
         NoQualifiedNameInAttributeSelector(token) => format!("No qualified name in attribute selector: {:?}.", token),
@@ -212,3 +238,70 @@ impl<'i> Error<CustomParseError<'i>> {
         }
     }
 }
+
+/// The severity of a [`StyleDiagnostic`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiagnosticSeverity {
+    /// The stylesheet contains something which is ignored, such as an unknown property.
+    Warning,
+    /// The stylesheet contains something which could not be parsed.
+    Error,
+}
+
+impl fmt::Display for DiagnosticSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticSeverity::Warning => f.write_str("warning"),
+            DiagnosticSeverity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// A problem found while parsing a stylesheet.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StyleDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// The location in the stylesheet of the rule or declaration the diagnostic refers to.
+    pub location: ErrorLocation,
+    /// The line number of the location as shown in editors, starting from 1.
+    pub line: u32,
+    /// The name of the property the diagnostic refers to, if any.
+    pub property: Option<String>,
+    /// The syntax expected by the property, if any.
+    pub expected: Option<String>,
+    pub message: String,
+}
+
+impl StyleDiagnostic {
+    /// Creates an error diagnostic from a rule which failed to parse.
+    pub fn from_error(err: Error<CustomParseError<'_>>) -> Self {
+        let location = err.location.unwrap_or_else(|| ErrorLocation {
+            filename: String::new(),
+            line: 0,
+            column: 1,
+        });
+
+        StyleDiagnostic {
+            severity: DiagnosticSeverity::Error,
+            line: location.line + 1,
+            location,
+            property: None,
+            expected: None,
+            message: err.kind.reason(),
+        }
+    }
+}
+
+impl fmt::Display for StyleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} at {}:{}:{}",
+            self.severity, self.message, self.location.filename, self.line, self.location.column
+        )?;
+        if let Some(expected) = &self.expected {
+            write!(f, " (expected {})", expected)?;
+        }
+        Ok(())
+    }
+}
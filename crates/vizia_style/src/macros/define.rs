@@ -48,7 +48,7 @@ macro_rules! define_property {
         $vis:vis enum $name:ident<'i> {
             $(
                 $(#[$meta: meta])*
-                $str: literal: $variant: ident($inner_ty: ty),
+                $str: literal: $variant: ident($inner_ty: ty) = $syntax: expr,
             )+
         }
     ) => {
@@ -86,6 +86,17 @@ macro_rules! define_property {
                 input.reset(&state);
                 return Ok(Property::Unparsed(UnparsedProperty::parse(name, input)?));
             }
+
+            /// Returns the value syntax of the property with the given name, or `None` if the
+            /// property is unknown.
+            pub fn expected_syntax(name: &str) -> Option<&'static str> {
+                match name {
+                    $(
+                        $str => Some($syntax),
+                    )+
+                    _ => None,
+                }
+            }
         }
     };
 }
@@ -56,6 +56,7 @@ mod test {
     pub struct Store {
         element: HashMap<Entity, String>,
         classes: HashMap<Entity, HashSet<String>>,
+        attributes: HashMap<Entity, HashMap<String, String>>,
         pseudo_class: HashMap<Entity, PseudoClass>,
     }
 
@@ -176,6 +177,15 @@ mod test {
                 &<Self::Impl as selectors::SelectorImpl>::AttrValue,
             >,
         ) -> bool {
+            if let Some(value) = self
+                .store
+                .attributes
+                .get(&self.entity)
+                .and_then(|attributes| attributes.get(&local_name.0))
+            {
+                return operation.eval_str(value);
+            }
+
             false
         }
 
@@ -236,6 +246,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -264,6 +275,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -298,6 +310,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -371,6 +384,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -401,4 +415,58 @@ mod test {
             assert_eq!(result, false);
         }
     }
+
+    #[test]
+    fn attribute_match() {
+        let mut store = Store {
+            element: HashMap::new(),
+            classes: HashMap::new(),
+            attributes: HashMap::new(),
+            pseudo_class: HashMap::new(),
+        };
+
+        let root = Entity(0);
+        let child = Entity(1);
+
+        store.attributes.insert(
+            root,
+            HashMap::from([
+                (String::from("variant"), String::from("primary")),
+                (String::from("data-tags"), String::from("small round")),
+            ]),
+        );
+
+        store
+            .attributes
+            .insert(child, HashMap::from([(String::from("variant"), String::from("secondary"))]));
+
+        let root_node = Node { entity: root, store: &store };
+
+        let child_node = Node { entity: child, store: &store };
+
+        let cases = [
+            ("[variant]", true, true),
+            ("[data-tags]", true, false),
+            ("[variant=primary]", true, false),
+            ("[variant=\"secondary\"]", false, true),
+            ("[data-tags~=round]", true, false),
+            ("[data-tags~=roun]", false, false),
+            ("[variant^=prim]", true, false),
+            ("[variant$=ary]", true, true),
+            ("[variant*=cond]", false, true),
+        ];
+
+        for (selector, root_result, child_result) in cases {
+            let selector_list = parse(selector).unwrap();
+
+            let mut context =
+                MatchingContext::new(MatchingMode::Normal, None, None, QuirksMode::NoQuirks);
+
+            let result = matches_selector_list(&selector_list, &root_node, &mut context).0;
+            assert_eq!(result, root_result, "{}", selector);
+
+            let result = matches_selector_list(&selector_list, &child_node, &mut context).0;
+            assert_eq!(result, child_result, "{}", selector);
+        }
+    }
 }
@@ -1,11 +1,28 @@
 use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+
+use crate::StyleDiagnostic;
 
 pub mod declaration;
 pub mod rule;
 
 #[derive(Debug, Default, Clone)]
 pub struct ParserOptions<'i> {
+    // The name of the stylesheet, used for the locations of diagnostics.
+    pub(crate) filename: String,
+    /// A list to collect diagnostics into while parsing. If set, rules which fail to parse are
+    /// reported here and skipped instead of failing the whole stylesheet.
+    pub diagnostics: Option<Arc<RwLock<Vec<StyleDiagnostic>>>>,
     nesting: bool,
     // TODO
     p: PhantomData<&'i Self>,
 }
+
+impl<'i> ParserOptions<'i> {
+    /// Adds a diagnostic to the list of diagnostics, if there is one.
+    pub(crate) fn report(&self, diagnostic: StyleDiagnostic) {
+        if let Some(diagnostics) = &self.diagnostics {
+            diagnostics.write().unwrap().push(diagnostic);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     parse_declaration, CssRule, CssRuleList, CustomParseError, DeclarationBlock, DeclarationList,
-    KeyframeListParser, KeyframesName, KeyframesRule, Location, Parse, ParserOptions,
-    SelectorParser, Selectors, StyleRule,
+    FontFaceDeclarationParser, FontFaceRule, ImportRule, KeyframeListParser, KeyframesName,
+    KeyframesRule, Location, Parse, ParserOptions, SelectorParser, Selectors, StyleRule,
 };
 use cssparser::*;
 use selectors::SelectorList;
@@ -10,7 +10,7 @@ use selectors::SelectorList;
 enum State {
     Start = 1,
     // Layers = 2,
-    // Imports = 3,
+    Imports = 3,
     // Namespaces = 4,
     Body = 5,
 }
@@ -35,6 +35,8 @@ impl<'a, 'o, 'b, 'i> TopLevelRuleParser<'a, 'o, 'i> {
 pub enum AtRulePrelude<'i> {
     // Property(DashedIdent<'i>),
     Keyframes(KeyframesName<'i>),
+    Import(CowRcStr<'i>),
+    FontFace,
 }
 
 impl<'a, 'o, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a, 'o, 'i> {
@@ -53,12 +55,41 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for TopLevelRuleParser<'a, 'o, 'i> {
             //     let name = DashedIdent::parse(input)?;
             //     return Ok(AtRulePrelude::Property(name));
             // },
+            "import" => {
+                if self.state > State::Imports {
+                    return Err(input.new_custom_error(CustomParseError::UnexpectedImportRule));
+                }
+
+                let url = input.expect_url_or_string()?;
+                return Ok(AtRulePrelude::Import(url));
+            },
             _=> {}
         }
 
         AtRuleParser::parse_prelude(&mut self.nested(), name, input)
     }
 
+    fn rule_without_block(
+        &mut self,
+        prelude: Self::Prelude,
+        start: &ParserState,
+    ) -> Result<Self::AtRule, ()> {
+        let loc = start.source_location();
+        let rule = match prelude {
+            AtRulePrelude::Import(url) => {
+                self.state = State::Imports;
+                CssRule::Import(ImportRule {
+                    url,
+                    loc: Location { line: loc.line, column: loc.column },
+                })
+            }
+
+            _ => return Err(()),
+        };
+
+        Ok((start.position(), rule))
+    }
+
     fn parse_block<'t>(
         &mut self,
         prelude: Self::Prelude,
@@ -141,6 +172,9 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
                 let name = input.try_parse(KeyframesName::parse)?;
                 Ok(AtRulePrelude::Keyframes(name))
             },
+            "font-face" => {
+                Ok(AtRulePrelude::FontFace)
+            },
             _=> Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
         }
     }
@@ -151,8 +185,18 @@ impl<'a, 'o, 'i> AtRuleParser<'i> for NestedRuleParser<'a, 'o, 'i> {
         start: &ParserState,
         input: &mut Parser<'i, 't>,
     ) -> Result<Self::AtRule, ParseError<'i, Self::Error>> {
-        let _loc = self.loc(start);
+        let loc = self.loc(start);
         match prelude {
+            AtRulePrelude::FontFace => {
+                let iter = DeclarationListParser::new(input, FontFaceDeclarationParser);
+                Ok(CssRule::FontFace(FontFaceRule {
+                    properties: iter.filter_map(Result::ok).collect(),
+                    loc,
+                }))
+            }
+            AtRulePrelude::Import(_) => {
+                Err(input.new_error(BasicParseErrorKind::AtRuleBodyInvalid))
+            }
             AtRulePrelude::Keyframes(name) => {
                 let iter = RuleListParser::new_for_nested_rule(input, KeyframeListParser);
                 Ok(CssRule::Keyframes(KeyframesRule {
@@ -1,118 +1,133 @@
 use crate::{
-    define_property, Angle, BackgroundImage, BackgroundSize, Border, BorderCornerShape,
-    BorderRadius, BorderWidth, BorderWidthValue, BoxShadow, ClipPath, Color, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontStretch,
-    FontStyle, FontWeight, LayoutType, LengthOrPercentage, Opacity, Outline, Overflow, Parse,
-    PointerEvents, Position, PositionType, Rect, Scale, TextAlign, Transform, Transition,
-    Translate, Units, UnparsedProperty, Visibility,
+    define_property, Angle, BackgroundImage, BackgroundSize, Border, BorderColor,
+    BorderCornerShape, BorderRadius, BorderStyle, BorderStyleKeyword, BorderWidth,
+    BorderWidthValue, BoxShadow, ClipPath, Color, Content, CursorIcon, CustomParseError,
+    CustomProperty, Display, Filter, FontFamily, FontSize, FontStretch, FontStyle, FontWeight,
+    LayoutType, LengthOrPercentage, Opacity, Outline, Overflow, Parse, PointerEvents, Position,
+    PositionType, Rect, Scale, TextAlign, Transform, Transition, Translate, Units,
+    UnparsedProperty, Visibility,
 };
 use cssparser::Parser;
 
+// The value syntax shared by the layout properties.
+const UNITS: &str = "auto | <length> | <percentage> | <stretch>";
+
+const FONT_WEIGHT: &str = "thin | extra-light | light | normal | medium | semi-bold | bold \
+    | extra-bold | black | lighter | bolder | <integer>";
+
+const FONT_STRETCH: &str = "ultra-condensed | extra-condensed | condensed | semi-condensed \
+    | normal | semi-expanded | expanded | extra-expanded | ultra-expanded | <percentage>";
+
+const CURSOR: &str = "default | arrow | none | cell | crosshair | text | vertical-text \
+    | context-menu | help | hand | progress | wait | alias | copy | move | no-drop | not-allowed \
+    | grab | grabbing | zoom-in | zoom-out | n-resize | e-resize | s-resize | w-resize | ne-resize \
+    | nw-resize | se-resize | sw-resize | ew-resize | ns-resize | nesw-resize | nwse-resize \
+    | all-scroll | col-resize | row-resize";
+
 define_property! {
     pub enum Property<'i> {
         // General
-        "display": Display(Display),
-        "visibility": Visibility(Visibility),
-        "overflow": Overflow(Overflow),
-        "overflow-x": OverflowX(Overflow),
-        "overflow-y": OverflowY(Overflow),
-        "clip-path": ClipPath(ClipPath),
-        "opacity": Opacity(Opacity),
-        "z-index": ZIndex(i32),
+        "display": Display(Display) = "none | flex",
+        "visibility": Visibility(Visibility) = "visible | hidden",
+        "overflow": Overflow(Overflow) = "visible | hidden",
+        "overflow-x": OverflowX(Overflow) = "visible | hidden",
+        "overflow-y": OverflowY(Overflow) = "visible | hidden",
+        "clip-path": ClipPath(ClipPath) = "auto | inset(<length-percentage>{1,4}) | rect(<length-percentage>{1,4})",
+        "opacity": Opacity(Opacity) = "<number> | <percentage>",
+        "z-index": ZIndex(i32) = "<integer>",
+        "content": Content(Content) = "none | normal | <string>",
 
         // Positioning
-        "layout-type": LayoutType(LayoutType),
-        "position-type": PositionType(PositionType),
+        "layout-type": LayoutType(LayoutType) = "row | column",
+        "position-type": PositionType(PositionType) = "self-directed | parent-directed",
 
         // Position and Size
-        "space": Space(Units),
-        "left": Left(Units),
-        "width": Width(Units),
-        "right": Right(Units),
-        "top": Top(Units),
-        "size": Size(Units),
-        "height": Height(Units),
-        "bottom": Bottom(Units),
+        "space": Space(Units) = UNITS,
+        "left": Left(Units) = UNITS,
+        "width": Width(Units) = UNITS,
+        "right": Right(Units) = UNITS,
+        "top": Top(Units) = UNITS,
+        "size": Size(Units) = UNITS,
+        "height": Height(Units) = UNITS,
+        "bottom": Bottom(Units) = UNITS,
 
         // Constraints
-        "min-space": MinSpace(Units),
-        "min-left": MinLeft(Units),
-        "min-right": MinRight(Units),
-        "min-top": MinTop(Units),
-        "min-bottom": MinBottom(Units),
-
-        "max-space": MaxSpace(Units),
-        "max-left": MaxLeft(Units),
-        "max-right": MaxRight(Units),
-        "max-top": MaxTop(Units),
-        "max-bottom": MaxBottom(Units),
-
-        "min-size": MinSize(Units),
-        "min-width": MinWidth(Units),
-        "min-height": MinHeight(Units),
-
-        "max-size": MaxSize(Units),
-        "max-width": MaxWidth(Units),
-        "max-height": MaxHeight(Units),
+        "min-space": MinSpace(Units) = UNITS,
+        "min-left": MinLeft(Units) = UNITS,
+        "min-right": MinRight(Units) = UNITS,
+        "min-top": MinTop(Units) = UNITS,
+        "min-bottom": MinBottom(Units) = UNITS,
+
+        "max-space": MaxSpace(Units) = UNITS,
+        "max-left": MaxLeft(Units) = UNITS,
+        "max-right": MaxRight(Units) = UNITS,
+        "max-top": MaxTop(Units) = UNITS,
+        "max-bottom": MaxBottom(Units) = UNITS,
+
+        "min-size": MinSize(Units) = UNITS,
+        "min-width": MinWidth(Units) = UNITS,
+        "min-height": MinHeight(Units) = UNITS,
+
+        "max-size": MaxSize(Units) = UNITS,
+        "max-width": MaxWidth(Units) = UNITS,
+        "max-height": MaxHeight(Units) = UNITS,
 
         // Child Spacing
-        "child-space": ChildSpace(Units),
-        "child-left": ChildLeft(Units),
-        "child-right": ChildRight(Units),
-        "child-top": ChildTop(Units),
-        "child-bottom": ChildBottom(Units),
-        "row-between": RowBetween(Units),
-        "col-between": ColBetween(Units),
+        "child-space": ChildSpace(Units) = UNITS,
+        "child-left": ChildLeft(Units) = UNITS,
+        "child-right": ChildRight(Units) = UNITS,
+        "child-top": ChildTop(Units) = UNITS,
+        "child-bottom": ChildBottom(Units) = UNITS,
+        "row-between": RowBetween(Units) = UNITS,
+        "col-between": ColBetween(Units) = UNITS,
         // ----- Border -----
 
         // Border Shorthand
-        "border": Border(Border),
+        "border": Border(Border) = "<line-width> || <line-style> || <color>",
 
         // Border Color
-        "border-color": BorderColor(Color),
-        // TODO: Support coloring individual borders.
-        // "border-top-color": BorderTopColor(Color),
-        // "border-right-color": BorderRightColor(Color),
-        // "border-bottom-color": BorderBottomColor(Color),
-        // "border-left-color": BorderLeftColor(Color),
+        "border-color": BorderColor(BorderColor) = "<color>{1,4}",
+        "border-top-color": BorderTopColor(Color) = "<color>",
+        "border-right-color": BorderRightColor(Color) = "<color>",
+        "border-bottom-color": BorderBottomColor(Color) = "<color>",
+        "border-left-color": BorderLeftColor(Color) = "<color>",
 
         // Border Corner Shape
-        "border-corner-shape": BorderCornerShape(Rect<BorderCornerShape>),
-        "border-top-left-shape": BorderTopLeftShape(BorderCornerShape),
-        "border-top-right-shape": BorderTopRightShape(BorderCornerShape),
-        "border-bottom-left-shape": BorderBottomLeftShape(BorderCornerShape),
-        "border-bottom-right-shape": BorderBottomRightShape(BorderCornerShape),
+        "border-corner-shape": BorderCornerShape(Rect<BorderCornerShape>) = "[round | bevel]{1,4}",
+        "border-top-left-shape": BorderTopLeftShape(BorderCornerShape) = "round | bevel",
+        "border-top-right-shape": BorderTopRightShape(BorderCornerShape) = "round | bevel",
+        "border-bottom-left-shape": BorderBottomLeftShape(BorderCornerShape) = "round | bevel",
+        "border-bottom-right-shape": BorderBottomRightShape(BorderCornerShape) = "round | bevel",
 
         // Border Radius
-        "border-radius": BorderRadius(BorderRadius),
-        "border-top-left-radius": BorderTopLeftRadius(LengthOrPercentage),
-        "border-top-right-radius": BorderTopRightRadius(LengthOrPercentage),
-        "border-bottom-left-radius": BorderBottomLeftRadius(LengthOrPercentage),
-        "border-bottom-right-radius": BorderBottomRightRadius(LengthOrPercentage),
+        "border-radius": BorderRadius(BorderRadius) = "<length-percentage>{1,4}",
+        "border-top-left-radius": BorderTopLeftRadius(LengthOrPercentage) = "<length-percentage>",
+        "border-top-right-radius": BorderTopRightRadius(LengthOrPercentage) = "<length-percentage>",
+        "border-bottom-left-radius": BorderBottomLeftRadius(LengthOrPercentage) = "<length-percentage>",
+        "border-bottom-right-radius": BorderBottomRightRadius(LengthOrPercentage) = "<length-percentage>",
 
         // Border Style
-        // TODO: Support styling borders.
-        // "border-style": BorderStyle(BorderStyle),
-        // "border-top-style": BorderTopStyle(BorderStyleKeyword),
-        // "border-right-style": BorderRightStyle(BorderStyleKeyword),
-        // "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
-        // "border-left-style": BorderLeftStyle(BorderStyleKeyword),
+        "border-style": BorderStyle(BorderStyle) = "<line-style>{1,4}",
+        "border-top-style": BorderTopStyle(BorderStyleKeyword) = "<line-style>",
+        "border-right-style": BorderRightStyle(BorderStyleKeyword) = "<line-style>",
+        "border-bottom-style": BorderBottomStyle(BorderStyleKeyword) = "<line-style>",
+        "border-left-style": BorderLeftStyle(BorderStyleKeyword) = "<line-style>",
 
         // Border Width
-        "border-width": BorderWidth(BorderWidth),
-        "border-top-width": BorderTopWidth(BorderWidthValue),
-        "border-right-width": BorderRightWidth(BorderWidthValue),
-        "border-bottom-width": BorderBottomWidth(BorderWidthValue),
-        "border-left-width": BorderLeftWidth(BorderWidthValue),
+        "border-width": BorderWidth(BorderWidth) = "<line-width>{1,4}",
+        "border-top-width": BorderTopWidth(BorderWidthValue) = "<line-width>",
+        "border-right-width": BorderRightWidth(BorderWidthValue) = "<line-width>",
+        "border-bottom-width": BorderBottomWidth(BorderWidthValue) = "<line-width>",
+        "border-left-width": BorderLeftWidth(BorderWidthValue) = "<line-width>",
 
 
         // ----- Outline -----
 
         // Outline Shorthand
-        "outline": Outline(Outline),
+        "outline": Outline(Outline) = "<line-width> || <line-style> || <color>",
 
         // Outline Color
-        "outline-color": OutlineColor(Color),
+        "outline-color": OutlineColor(Color) = "<color>",
         // TODO: Support coloring individual outlines.
         // "outline-top-color": OutlineTopColor(Color),
         // "outline-right-color": OutlineRightColor(Color),
@@ -120,57 +135,53 @@ define_property! {
         // "outline-left-color": OutlineLeftColor(Color),
 
         // Outline Style
-        // TODO: Support styling outlines.
-        // "outline-style": OutlineStyle(BorderStyle),
-        // "outline-top-style": OutlineTopStyle(BorderStyleKeyword),
-        // "outline-right-style": OutlineRightStyle(BorderStyleKeyword),
-        // "outline-bottom-style": OutlineBottomStyle(BorderStyleKeyword),
-        // "outline-left-style": OutlineLeftStyle(BorderStyleKeyword),
+        "outline-style": OutlineStyle(BorderStyleKeyword) = "<line-style>",
 
         // Outline Width
-        "outline-width": OutlineWidth(BorderWidth),
+        "outline-width": OutlineWidth(BorderWidth) = "<line-width>{1,4}",
         // "outline-top-width": OutlineTopWidth(BorderWidthValue),
         // "outline-right-width": OutlineRightWidth(BorderWidthValue),
         // "outline-bottom-width": OutlineBottomWidth(BorderWidthValue),
         // "outline-left-width": OutlineLeftWidth(BorderWidthValue),
-        "outline-offset": OutlineOffset(LengthOrPercentage),
+        "outline-offset": OutlineOffset(LengthOrPercentage) = "<length-percentage>",
 
         // Background
-        "background-color": BackgroundColor(Color),
-        "background-image": BackgroundImage(Vec<BackgroundImage<'i>>),
-        "background-size": BackgroundSize(Vec<BackgroundSize>),
+        "background-color": BackgroundColor(Color) = "<color>",
+        "background-image": BackgroundImage(Vec<BackgroundImage<'i>>) = "[none | <url> | <gradient>]#",
+        "background-size": BackgroundSize(Vec<BackgroundSize>) = "[[<length-percentage> | auto]{1,2} | cover | contain]#",
 
         // Font
-        "font-size": FontSize(FontSize),
-        "color": FontColor(Color),
-        "font-family": FontFamily(Vec<FontFamily<'i>>),
-        "font-weight": FontWeight(FontWeight),
-        "font-style": FontStyle(FontStyle),
-        "font-stretch": FontStretch(FontStretch),
-        "selection-color": SelectionColor(Color), // TODO: Remove this once we have the pseudoselector version.
-        "caret-color": CaretColor(Color),
-        "text-wrap": TextWrap(bool),
-        "text-align": TextAlign(TextAlign),
+        "font-size": FontSize(FontSize) = "<absolute-size> | <number>",
+        "color": FontColor(Color) = "<color>",
+        "font-family": FontFamily(Vec<FontFamily<'i>>) = "[<family-name> | <generic-family>]#",
+        "font-weight": FontWeight(FontWeight) = FONT_WEIGHT,
+        "font-style": FontStyle(FontStyle) = "normal | italic | oblique",
+        "font-stretch": FontStretch(FontStretch) = FONT_STRETCH,
+        "selection-color": SelectionColor(Color) = "<color>", // TODO: Remove this in favour of the `::selection` pseudo-element.
+        "caret-color": CaretColor(Color) = "<color>",
+        "text-wrap": TextWrap(bool) = "true | false | on | off | yes | no",
+        "text-align": TextAlign(TextAlign) = "start | end | left | right | center | justify",
 
         // Box Shadow
-        "box-shadow": BoxShadow(Vec<BoxShadow>),
+        "box-shadow": BoxShadow(Vec<BoxShadow>) = "[<length>{2,4} <color>? inset?]#",
 
-        // Backdrop Filter
-        "backdrop-filter": BackdropFilter(Filter),
+        // Filters
+        "filter": Filter(Vec<Filter>) = "none | <filter-function>+",
+        "backdrop-filter": BackdropFilter(Vec<Filter>) = "none | <filter-function>+",
 
         // Animations
-        "transition": Transition(Vec<Transition>),
+        "transition": Transition(Vec<Transition>) = "[<property-name> <time> <time>? <easing-function>?]#",
 
         // Transform
-        "transform": Transform(Vec<Transform>),
-        "transform-origin": TransformOrigin(Position),
-        "translate": Translate(Translate),
-        "rotate": Rotate(Angle),
-        "scale": Scale(Scale),
+        "transform": Transform(Vec<Transform>) = "<transform-function>+",
+        "transform-origin": TransformOrigin(Position) = "<position>",
+        "translate": Translate(Translate) = "<length-percentage>{1,2}",
+        "rotate": Rotate(Angle) = "<angle>",
+        "scale": Scale(Scale) = "[<number> | <percentage>]{1,2}",
 
         // Cursor
-        "cursor": Cursor(CursorIcon),
-        "pointer-events": PointerEvents(PointerEvents),
+        "cursor": Cursor(CursorIcon) = CURSOR,
+        "pointer-events": PointerEvents(PointerEvents) = "auto | none",
     }
 }
 
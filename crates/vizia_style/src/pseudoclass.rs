@@ -28,6 +28,7 @@ pub enum PseudoClass {
     Optional,
     UserValid,
     UserInvalid,
+    DropTarget,
 
     Lang(Vec<String>),
     Dir(Direction),
@@ -63,6 +64,7 @@ impl ToCss for PseudoClass {
             PseudoClass::Optional => dest.write_str(":optional"),
             PseudoClass::UserValid => dest.write_str(":user-valid"),
             PseudoClass::UserInvalid => dest.write_str(":user-invalid"),
+            PseudoClass::DropTarget => dest.write_str(":drop-target"),
             PseudoClass::Lang(ref _lang) => dest.write_str(":lang()"),
             PseudoClass::Dir(_) => dest.write_str(":dir()"),
             PseudoClass::Custom(_) => dest.write_str(":custom"),
@@ -7,19 +7,24 @@ pub enum PseudoElement {
     After,
     Before,
     Selection,
+    Placeholder,
     Custom(String),
 }
 
 impl ToCss for PseudoElement {
-    fn to_css<W>(&self, _dest: &mut W) -> std::fmt::Result
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
     where
         W: std::fmt::Write,
     {
         match *self {
-            PseudoElement::After => todo!(),
-            PseudoElement::Before => todo!(),
-            PseudoElement::Selection => todo!(),
-            PseudoElement::Custom(_) => todo!(),
+            PseudoElement::After => dest.write_str("::after"),
+            PseudoElement::Before => dest.write_str("::before"),
+            PseudoElement::Selection => dest.write_str("::selection"),
+            PseudoElement::Placeholder => dest.write_str("::placeholder"),
+            PseudoElement::Custom(ref name) => {
+                dest.write_str("::")?;
+                dest.write_str(name)
+            }
         }
     }
 }
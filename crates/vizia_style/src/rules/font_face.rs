@@ -0,0 +1,170 @@
+use cssparser::*;
+
+use crate::{CustomParseError, CustomProperty, FontFamily, Location, Parse, Url};
+
+/// A `@font-face` rule, which loads a font to be used by the application.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FontFaceRule<'i> {
+    pub properties: Vec<FontFaceProperty<'i>>,
+    pub loc: Location,
+}
+
+impl<'i> FontFaceRule<'i> {
+    /// Returns the font family declared by the rule, if any.
+    pub fn font_family(&self) -> Option<&FontFamily<'i>> {
+        self.properties.iter().rev().find_map(|property| match property {
+            FontFaceProperty::FontFamily(family) => Some(family),
+            _ => None,
+        })
+    }
+
+    /// Returns the sources of the font declared by the rule.
+    pub fn sources(&self) -> &[FontFaceSource<'i>] {
+        self.properties
+            .iter()
+            .rev()
+            .find_map(|property| match property {
+                FontFaceProperty::Source(sources) => Some(sources.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the first source of the font which is a TrueType or OpenType font file, given by
+    /// its `format()` hint or otherwise by the extension of the file.
+    pub fn font_file(&self) -> Option<&Url<'i>> {
+        self.sources().iter().find_map(|source| match source {
+            FontFaceSource::Url(url, None) => {
+                let extension = url.url.rsplit('.').next().unwrap_or_default();
+                ["ttf", "otf", "ttc", "otc"]
+                    .iter()
+                    .any(|supported| extension.eq_ignore_ascii_case(supported))
+                    .then(|| url)
+            }
+            FontFaceSource::Url(url, Some(format))
+                if matches!(format.as_ref(), "truetype" | "opentype" | "collection") =>
+            {
+                Some(url)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// A descriptor of a `@font-face` rule.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceProperty<'i> {
+    Source(Vec<FontFaceSource<'i>>),
+    FontFamily(FontFamily<'i>),
+    Custom(CustomProperty<'i>),
+}
+
+/// A source of the `src` descriptor of a `@font-face` rule.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceSource<'i> {
+    /// A font file, along with the format hint given by `format()`, if any.
+    Url(Url<'i>, Option<CowRcStr<'i>>),
+    /// A font which is already installed, given by `local()`.
+    Local(FontFamily<'i>),
+}
+
+impl<'i> Parse<'i> for FontFaceSource<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_function_matching("local")).is_ok() {
+            let family = input.parse_nested_block(FontFamily::parse)?;
+            return Ok(FontFaceSource::Local(family));
+        }
+
+        let url = Url::parse(input)?;
+
+        let format = input
+            .try_parse(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>> {
+                input.expect_function_matching("format")?;
+                input.parse_nested_block(|input| {
+                    input.expect_ident_or_string().cloned().map_err(ParseError::from)
+                })
+            })
+            .ok();
+
+        Ok(FontFaceSource::Url(url, format))
+    }
+}
+
+pub(crate) struct FontFaceDeclarationParser;
+
+impl<'i> DeclarationParser<'i> for FontFaceDeclarationParser {
+    type Declaration = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+
+    fn parse_value<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self::Declaration, ParseError<'i, Self::Error>> {
+        let state = input.state();
+        match_ignore_ascii_case! { &name,
+            "src" => {
+                if let Ok(sources) = input.parse_comma_separated(FontFaceSource::parse) {
+                    return Ok(FontFaceProperty::Source(sources));
+                }
+            },
+            "font-family" => {
+                if let Ok(family) = FontFamily::parse(input) {
+                    if input.is_exhausted() {
+                        return Ok(FontFaceProperty::FontFamily(family));
+                    }
+                }
+            },
+            _ => {}
+        }
+
+        input.reset(&state);
+        Ok(FontFaceProperty::Custom(CustomProperty::parse(name, input)?))
+    }
+}
+
+impl<'i> AtRuleParser<'i> for FontFaceDeclarationParser {
+    type Prelude = ();
+    type AtRule = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_font_face_sources() {
+        let mut parser_input = ParserInput::new(
+            r#"url("fonts/Inter.ttf") format("truetype"), url(Inter.woff2), local("Inter")"#,
+        );
+        let mut parser = Parser::new(&mut parser_input);
+        let sources = parser.parse_comma_separated(FontFaceSource::parse).unwrap();
+
+        assert_eq!(
+            sources,
+            vec![
+                FontFaceSource::Url(
+                    Url { url: "fonts/Inter.ttf".into() },
+                    Some(CowRcStr::from("truetype"))
+                ),
+                FontFaceSource::Url(Url { url: "Inter.woff2".into() }, None),
+                FontFaceSource::Local(FontFamily::Named("Inter".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn font_file_skips_unsupported_sources() {
+        let rule = FontFaceRule {
+            properties: vec![FontFaceProperty::Source(vec![
+                FontFaceSource::Url(Url { url: "Inter.woff2".into() }, None),
+                FontFaceSource::Url(Url { url: "Inter.ttf".into() }, Some("woff2".into())),
+                FontFaceSource::Url(Url { url: "Inter.OTF".into() }, None),
+            ])],
+            loc: Location { line: 0, column: 1 },
+        };
+
+        assert_eq!(rule.font_file(), Some(&Url { url: "Inter.OTF".into() }));
+    }
+}
@@ -7,6 +7,12 @@ pub use keyframes::*;
 pub mod property;
 pub use property::*;
 
+pub mod import;
+pub use import::*;
+
+pub mod font_face;
+pub use font_face::*;
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct CssRuleList<'i>(pub Vec<CssRule<'i>>);
 
@@ -16,4 +22,6 @@ pub enum CssRule<'i> {
     Property(PropertyRule<'i>),
     Ignored,
     Keyframes(KeyframesRule<'i>),
+    Import(ImportRule<'i>),
+    FontFace(FontFaceRule<'i>),
 }
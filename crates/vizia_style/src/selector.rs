@@ -1,4 +1,5 @@
 use cssparser::*;
+use precomputed_hash::PrecomputedHash;
 use selectors::SelectorImpl;
 
 use crate::{CustomParseError, Direction, Parse, PseudoClass, PseudoElement};
@@ -21,6 +22,12 @@ impl std::convert::From<&str> for SelectorString {
     }
 }
 
+impl AsRef<str> for SelectorString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl ToCss for SelectorString {
     fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
     where
@@ -54,6 +61,20 @@ impl<'a> std::convert::From<CowRcStr<'a>> for SelectorIdent {
     }
 }
 
+// Used to fast-reject selectors with the ancestor bloom filter.
+impl PrecomputedHash for SelectorIdent {
+    fn precomputed_hash(&self) -> u32 {
+        selector_hash(&self.0)
+    }
+}
+
+/// Returns the hash of an element name, id or class, as inserted into the ancestor bloom filter
+/// used to fast-reject selectors during matching.
+pub fn selector_hash(name: &str) -> u32 {
+    // 32-bit FNV-1a.
+    name.bytes().fold(0x811c9dc5, |hash, byte| (hash ^ byte as u32).wrapping_mul(0x01000193))
+}
+
 impl SelectorImpl for Selectors {
     type AttrValue = SelectorString;
     type Identifier = SelectorIdent;
@@ -106,6 +127,7 @@ impl<'a, 'i> selectors::parser::Parser<'i> for SelectorParser<'a, 'i> {
             "optional" => Optional,
             "user-valid" => UserValid,
             "user-invalid" => UserInvalid,
+            "drop-target" => DropTarget,
 
             _ => Custom(name.to_string())
 
@@ -150,6 +172,7 @@ impl<'a, 'i> selectors::parser::Parser<'i> for SelectorParser<'a, 'i> {
             "before" => Before,
             "after" => After,
             "selection" => Selection,
+            "placeholder" => Placeholder,
             _=> Custom(name.to_string())
         };
 
@@ -160,7 +183,9 @@ impl<'a, 'i> selectors::parser::Parser<'i> for SelectorParser<'a, 'i> {
 #[cfg(test)]
 mod tests {
     use selectors::{
-        parser::{Component, LocalName, Selector},
+        bloom::BLOOM_HASH_MASK,
+        context::QuirksMode,
+        parser::{AncestorHashes, Component, LocalName, Selector},
         SelectorList,
     };
 
@@ -318,6 +343,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ancestor_hashes() {
+        let selector_list = parse("window #foo > .bar:hover label").unwrap();
+        let hashes = AncestorHashes::new(&selector_list.0[0], QuirksMode::NoQuirks);
+
+        assert_eq!(hashes.packed_hashes[0], selector_hash("bar") & BLOOM_HASH_MASK);
+        assert_eq!(hashes.packed_hashes[1], selector_hash("foo") & BLOOM_HASH_MASK);
+        assert_eq!(hashes.packed_hashes[2], selector_hash("window") & BLOOM_HASH_MASK);
+    }
+
     // TODO - Add more tests for selectors
     // TODO - Add tests for selector matching
     //   NOTE - Requires creating a dummy node for testing purposes (and also modification to selectors crate to allow properties from external store)
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::{CssRule, ParserOptions};
+use crate::{CssRule, ParserOptions, StyleDiagnostic};
 
 use crate::rule::TopLevelRuleParser;
 use crate::{CssRuleList, CustomParseError};
@@ -17,8 +17,10 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
     pub fn parse(
         filename: &str,
         code: &'i str,
-        options: ParserOptions<'o>,
+        mut options: ParserOptions<'o>,
     ) -> Result<Self, Error<CustomParseError<'i>>> {
+        options.filename = filename.to_owned();
+
         let mut input = ParserInput::new(code);
         let mut parser = Parser::new(&mut input);
         let rule_list_parser =
@@ -29,7 +31,15 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
             let rule = match rule {
                 Ok((_, CssRule::Ignored)) => continue,
                 Ok((_, rule)) => rule,
-                Err((e, _)) => return Err(Error::from(e, filename.to_owned())),
+                Err((e, _)) => {
+                    let error = Error::from(e, filename.to_owned());
+                    if options.diagnostics.is_some() {
+                        options.report(StyleDiagnostic::from_error(error));
+                        continue;
+                    }
+
+                    return Err(error);
+                }
             };
 
             rules.push(rule)
@@ -42,6 +52,8 @@ impl<'i, 'o> StyleSheet<'i, 'o> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{DiagnosticSeverity, ErrorLocation};
+    use std::sync::{Arc, RwLock};
 
     const CSS_EXAMPLE: &str = r#"
 button label {
@@ -134,6 +146,73 @@ test {
         let style_sheet = StyleSheet::parse("test.css", CSS_EXAMPLE, ParserOptions::default());
         println!("{:#?}", style_sheet);
     }
+
+    #[test]
+    fn parse_import_rules() {
+        let style_sheet = StyleSheet::parse(
+            "test.css",
+            "@import \"base.css\";\n@import url(theme.css);\nbutton {}\n",
+            ParserOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(style_sheet.rules.0.len(), 3);
+        assert!(matches!(
+            &style_sheet.rules.0[0],
+            CssRule::Import(import) if import.url.as_ref() == "base.css"
+        ));
+        assert!(matches!(
+            &style_sheet.rules.0[1],
+            CssRule::Import(import) if import.url.as_ref() == "theme.css"
+        ));
+
+        // Imports must come before all other rules.
+        assert!(StyleSheet::parse(
+            "test.css",
+            "button {}\n@import \"base.css\";\n",
+            ParserOptions::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_stylesheet_diagnostics() {
+        let diagnostics = Arc::new(RwLock::new(Vec::new()));
+        let options =
+            ParserOptions { diagnostics: Some(diagnostics.clone()), ..ParserOptions::default() };
+
+        let style_sheet = StyleSheet::parse(
+            "theme.css",
+            "button {\n    width: red;\n    colour: red;\n}\n\n#: {}\n\nlabel {\n    height: 5px;\n}\n",
+            options,
+        )
+        .unwrap();
+
+        assert_eq!(style_sheet.rules.0.len(), 2);
+
+        let diagnostics = diagnostics.read().unwrap();
+        assert_eq!(diagnostics.len(), 3);
+
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Error);
+        assert_eq!(
+            diagnostics[0].location,
+            ErrorLocation { filename: String::from("theme.css"), line: 1, column: 12 }
+        );
+        assert_eq!(diagnostics[0].line, 2);
+        assert_eq!(diagnostics[0].property.as_deref(), Some("width"));
+        assert_eq!(
+            diagnostics[0].expected.as_deref(),
+            Some("auto | <length> | <percentage> | <stretch>")
+        );
+
+        assert_eq!(diagnostics[1].severity, DiagnosticSeverity::Warning);
+        assert_eq!(diagnostics[1].line, 3);
+        assert_eq!(diagnostics[1].property.as_deref(), Some("colour"));
+
+        assert_eq!(diagnostics[2].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[2].line, 6);
+        assert_eq!(diagnostics[2].property, None);
+    }
 }
 
 // use cssparser::*;
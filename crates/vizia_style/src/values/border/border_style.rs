@@ -39,6 +39,12 @@ impl From<Rect<BorderStyleKeyword>> for BorderStyle {
     }
 }
 
+impl From<BorderStyleKeyword> for BorderStyle {
+    fn from(keyword: BorderStyleKeyword) -> Self {
+        Self::new(keyword, keyword, keyword, keyword)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
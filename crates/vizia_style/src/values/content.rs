@@ -0,0 +1,62 @@
+use crate::{CustomParseError, Parse};
+use cssparser::*;
+
+/// The content generated for a `::before` or `::after` pseudo-element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    /// No content is generated, so the pseudo-element is not created.
+    None,
+    /// A string of text, such as a label or an icon.
+    String(String),
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content::None
+    }
+}
+
+impl From<&str> for Content {
+    fn from(s: &str) -> Self {
+        Content::String(s.to_owned())
+    }
+}
+
+impl<'i> Parse<'i> for Content {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        match input.next()? {
+            Token::QuotedString(string) => Ok(Content::String(string.to_string())),
+            Token::Ident(ident) => match_ignore_ascii_case! { ident,
+                "none" | "normal" => Ok(Content::None),
+                _ => Err(location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+            },
+            token => {
+                let token = token.clone();
+                Err(location.new_unexpected_token_error(token))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Content, assert_content,
+
+        success {
+            "none" => Content::None,
+            "normal" => Content::None,
+            "\"Label\"" => Content::String(String::from("Label")),
+            "'\\ea61'" => Content::String(String::from("\u{ea61}")),
+        }
+
+        failure {
+            "12px",
+            "label",
+        }
+    }
+}
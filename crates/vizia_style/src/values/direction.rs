@@ -2,9 +2,9 @@ use crate::{macros::define_enum, Parse};
 
 define_enum! {
     pub enum Direction {
-        /// The entity will be rendered and acted on by the layout system.
+        /// Left-to-right text direction.
         "ltr": Ltr,
-        /// The entity will not be rendered and acted on by the layout system.
+        /// Right-to-left text direction.
         "rtl": Rtl,
     }
 }
@@ -0,0 +1,197 @@
+use crate::{Angle, Color, CustomParseError, Length, Parse, PercentageOrNumber};
+use cssparser::*;
+
+/// A filter function applied to the rendered content of an element, or to the content behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Blurs the content with a gaussian blur of the given radius.
+    Blur(Length),
+    /// Multiplies the color channels by the given amount.
+    Brightness(PercentageOrNumber),
+    /// Adjusts the contrast of the content by the given amount.
+    Contrast(PercentageOrNumber),
+    /// Converts the content to grayscale by the given amount.
+    Grayscale(PercentageOrNumber),
+    /// Saturates the content by the given amount.
+    Saturate(PercentageOrNumber),
+    /// Inverts the colors of the content by the given amount.
+    Invert(PercentageOrNumber),
+    /// Rotates the hue of the content by the given angle.
+    HueRotate(Angle),
+    /// Makes the content transparent by the given amount.
+    Opacity(PercentageOrNumber),
+    /// Draws a blurred, offset shadow of the content beneath it.
+    DropShadow(DropShadow),
+}
+
+impl Filter {
+    /// Returns true if the filter only transforms the color of each pixel, independently of the
+    /// surrounding pixels.
+    pub fn is_color_filter(&self) -> bool {
+        !matches!(self, Filter::Blur(_) | Filter::DropShadow(_))
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::Blur(Length::default())
+    }
+}
+
+impl<'i> Parse<'i> for Filter {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => Ok(Filter::Brightness(parse_amount(input))),
+                "contrast" => Ok(Filter::Contrast(parse_amount(input))),
+                "grayscale" => Ok(Filter::Grayscale(parse_amount(input))),
+                "saturate" => Ok(Filter::Saturate(parse_amount(input))),
+                "invert" => Ok(Filter::Invert(parse_amount(input))),
+                "opacity" => Ok(Filter::Opacity(parse_amount(input))),
+
+                "hue-rotate" => {
+                    Ok(Filter::HueRotate(input.try_parse(Angle::parse).unwrap_or(Angle::Deg(0.0))))
+                },
+
+                "drop-shadow" => Ok(Filter::DropShadow(DropShadow::parse(input)?)),
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+}
+
+// The amount of a color filter function, which is 1 (100%) when omitted.
+fn parse_amount(input: &mut Parser) -> PercentageOrNumber {
+    input.try_parse(PercentageOrNumber::parse).unwrap_or(PercentageOrNumber::Number(1.0))
+}
+
+impl<'i> Parse<'i> for Vec<Filter> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut filters = vec![Filter::parse(input)?];
+        while let Ok(filter) = input.try_parse(Filter::parse) {
+            filters.push(filter);
+        }
+
+        Ok(filters)
+    }
+}
+
+impl From<Filter> for Vec<Filter> {
+    fn from(filter: Filter) -> Self {
+        vec![filter]
+    }
+}
+
+/// A shadow drawn beneath the rendered content of an element by the `drop-shadow()` filter.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DropShadow {
+    /// The horizontal offset of the shadow.
+    pub x_offset: Length,
+    /// The vertical offset of the shadow.
+    pub y_offset: Length,
+    /// The blur radius of the shadow.
+    pub blur_radius: Option<Length>,
+    /// The color of the shadow.
+    pub color: Option<Color>,
+}
+
+impl DropShadow {
+    /// Creates a new drop shadow.
+    pub fn new(
+        x_offset: impl Into<Length>,
+        y_offset: impl Into<Length>,
+        blur_radius: Option<Length>,
+        color: Option<Color>,
+    ) -> Self {
+        Self { x_offset: x_offset.into(), y_offset: y_offset.into(), blur_radius, color }
+    }
+}
+
+impl<'i> Parse<'i> for DropShadow {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut color = input.try_parse(Color::parse).ok();
+        let x_offset = Length::parse(input)?;
+        let y_offset = Length::parse(input)?;
+        let blur_radius = input.try_parse(Length::parse).ok();
+        if color.is_none() {
+            color = input.try_parse(Color::parse).ok();
+        }
+
+        Ok(DropShadow::new(x_offset, y_offset, blur_radius, color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Filter, parse_filter,
+
+        custom {
+            success {
+                "blur(10px)" => Filter::Blur(Length::px(10.0)),
+                "blur()" => Filter::Blur(Length::px(0.0)),
+                "brightness(150%)" => Filter::Brightness(PercentageOrNumber::Percentage(150.0)),
+                "contrast(2)" => Filter::Contrast(PercentageOrNumber::Number(2.0)),
+                "grayscale()" => Filter::Grayscale(PercentageOrNumber::Number(1.0)),
+                "saturate(0.5)" => Filter::Saturate(PercentageOrNumber::Number(0.5)),
+                "invert(100%)" => Filter::Invert(PercentageOrNumber::Percentage(100.0)),
+                "opacity(25%)" => Filter::Opacity(PercentageOrNumber::Percentage(25.0)),
+                "hue-rotate(90deg)" => Filter::HueRotate(Angle::Deg(90.0)),
+                "drop-shadow(2px 4px 6px red)" => Filter::DropShadow(DropShadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    Some(Length::px(6.0)),
+                    Some(Color::rgb(255, 0, 0)),
+                )),
+                "drop-shadow(blue 2px 4px)" => Filter::DropShadow(DropShadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    None,
+                    Some(Color::rgb(0, 0, 255)),
+                )),
+            }
+
+            failure {
+                "test",
+                "sepia(1)",
+                "drop-shadow(2px)",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<Filter>, parse_vec_filter,
+
+        custom {
+            success {
+                "none" => vec![],
+                "blur(4px) grayscale(100%)" => vec![
+                    Filter::Blur(Length::px(4.0)),
+                    Filter::Grayscale(PercentageOrNumber::Percentage(100.0)),
+                ],
+            }
+
+            failure {
+                "test",
+                "10px",
+            }
+        }
+    }
+}
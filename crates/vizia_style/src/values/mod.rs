@@ -1,6 +1,5 @@
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
 pub mod background_size;
 pub mod basic;
 pub mod border;
@@ -8,6 +7,7 @@ pub mod box_shadow;
 pub mod calc;
 pub mod clip;
 pub mod color;
+pub mod content;
 pub mod cursor_icon;
 pub mod custom;
 pub mod dimension_percentage;
@@ -15,6 +15,7 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod filter;
 pub mod font_family;
 pub mod font_size;
 pub mod font_size_keyword;
@@ -54,7 +55,6 @@ pub mod visibility;
 
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
 pub use background_size::*;
 pub use basic::*;
 pub use border::*;
@@ -62,6 +62,7 @@ pub use box_shadow::*;
 pub use calc::*;
 pub use clip::*;
 pub use color::*;
+pub use content::*;
 pub use cursor_icon::*;
 pub use custom::*;
 pub use dimension_percentage::*;
@@ -69,6 +70,7 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use filter::*;
 pub use font_family::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
@@ -7,6 +7,7 @@ use accesskit::{Action, NodeBuilder, TreeUpdate};
 #[cfg(not(target_arch = "wasm32"))]
 use accesskit_winit;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use vizia_core::backend::*;
 #[cfg(not(target_arch = "wasm32"))]
 use vizia_core::context::EventProxy;
@@ -223,14 +224,13 @@ impl Application {
         ))]
         unsafe {
             if let Some(display) = window.window().wayland_display() {
-                let (_, clipboard) =
-                    copypasta::wayland_clipboard::create_clipboards_from_external(display);
-                cx.set_clipboard_provider(Box::new(clipboard));
+                cx.set_clipboard_provider(Box::new(SystemClipboard::from_wayland_display(display)));
             }
         }
 
         let scale_factor = window.window().scale_factor() as f32;
         cx.add_main_window(&self.window_description, canvas, scale_factor);
+        let main_window_id = window.id;
         cx.add_window(window);
 
         cx.0.remove_user_themes();
@@ -255,9 +255,17 @@ impl Application {
 
         let mut cursor_moved = false;
         let mut cursor = (0.0f32, 0.0f32);
+        let mut cursor_window = Entity::root();
+
+        // Files dropped onto a window during a frame are sent as a single drop.
+        let mut dropped_files = Vec::new();
+        let mut dropped_files_window = Entity::root();
+
+        // Secondary windows created by `Window` views, keyed by the entity of the view.
+        let mut secondary_windows: HashMap<Entity, Window> = HashMap::new();
 
         let mut main_events = false;
-        event_loop.run(move |event, _, control_flow| {
+        event_loop.run(move |event, event_loop_target, control_flow| {
             let mut cx = BackendContext::new_with_event_manager(&mut context);
 
             match event {
@@ -297,10 +305,25 @@ impl Application {
                         if default_should_poll { ControlFlow::Poll } else { ControlFlow::Wait };
 
                     if cursor_moved {
-                        cx.emit_origin(WindowEvent::MouseMove(cursor.0, cursor.1));
+                        cx.emit_window_event(
+                            cursor_window,
+                            WindowEvent::MouseMove(cursor.0, cursor.1),
+                        );
                         cursor_moved = false;
                     }
 
+                    if !dropped_files.is_empty() {
+                        let drop_data = if dropped_files.len() == 1 {
+                            DropData::File(dropped_files.remove(0))
+                        } else {
+                            DropData::Files(std::mem::take(&mut dropped_files))
+                        };
+
+                        cx.emit_window_event(dropped_files_window, WindowEvent::Drop(drop_data));
+                    }
+
+                    cx.process_timers();
+
                     cx.process_events();
 
                     cx.process_data_updates();
@@ -317,6 +340,10 @@ impl Application {
                         cx.mutate_window(|_, window: &Window| {
                             window.window().request_redraw();
                         });
+
+                        for window in secondary_windows.values() {
+                            window.window().request_redraw();
+                        }
                     }
 
                     cx.process_visual_updates();
@@ -328,9 +355,39 @@ impl Application {
                         }
                     });
 
+                    // Close the windows of any removed `Window` views.
+                    for (entity, canvas) in cx.take_closed_windows() {
+                        if let Some(window) = secondary_windows.remove(&entity) {
+                            window.make_current();
+                            drop(canvas);
+                        }
+                    }
+
+                    // Open windows for any newly built `Window` views.
+                    for entity in cx.take_pending_windows() {
+                        if let Some(window_description) = cx.window_description(entity) {
+                            let (window, canvas) =
+                                Window::new(event_loop_target, window_description);
+                            window.window().set_visible(window_description.visible);
+                            let scale_factor = window.window().scale_factor();
+                            cx.add_secondary_window(entity, canvas, scale_factor);
+                            secondary_windows.insert(entity, window);
+                        }
+                    }
+
+                    for (entity, window) in secondary_windows.iter() {
+                        for command in cx.take_window_commands(*entity) {
+                            window.handle_window_event(&command);
+                        }
+                    }
+
                     cx.mutate_window(|cx, window: &Window| {
                         cx.style().should_redraw(|| {
                             window.window().request_redraw();
+
+                            for window in secondary_windows.values() {
+                                window.window().request_redraw();
+                            }
                         });
                     });
 
@@ -346,6 +403,14 @@ impl Application {
                             .expect("Failed to send event");
                     }
 
+                    // Wake up to send the next scheduled event.
+                    if let Some(time) = cx.next_timer() {
+                        let mut control_flow = stored_control_flow.borrow_mut();
+                        if *control_flow == ControlFlow::Wait {
+                            *control_flow = ControlFlow::WaitUntil(time);
+                        }
+                    }
+
                     cx.mutate_window(|_, window: &Window| {
                         if window.should_close {
                             *stored_control_flow.borrow_mut() = ControlFlow::Exit;
@@ -353,46 +418,78 @@ impl Application {
                     });
                 }
 
-                winit::event::Event::RedrawRequested(_) => {
+                winit::event::Event::RedrawRequested(window_id) => {
                     if main_events {
                         // Redraw
-                        cx.draw();
-                        cx.mutate_window(|_, window: &Window| {
+                        if window_id == main_window_id {
+                            cx.mutate_window(|_, window: &Window| {
+                                window.make_current();
+                            });
+                            cx.draw();
+                            cx.mutate_window(|_, window: &Window| {
+                                window.swap_buffers();
+                            });
+                        } else if let Some((entity, window)) =
+                            secondary_windows.iter().find(|(_, window)| window.id == window_id)
+                        {
+                            window.make_current();
+                            cx.draw_window(*entity);
                             window.swap_buffers();
-                        });
+                        }
                     }
                 }
 
-                winit::event::Event::WindowEvent { window_id: _, event } => {
+                winit::event::Event::WindowEvent { window_id, event } => {
+                    // The entity of the window which received the event.
+                    let window_entity = secondary_windows
+                        .iter()
+                        .find(|(_, window)| window.id == window_id)
+                        .map(|(entity, _)| *entity)
+                        .unwrap_or(Entity::root());
+
                     match event {
                         winit::event::WindowEvent::CloseRequested => {
-                            cx.emit_origin(WindowEvent::WindowClose);
+                            cx.emit_window_event(window_entity, WindowEvent::WindowClose);
                         }
 
                         winit::event::WindowEvent::Focused(is_focused) => {
                             cx.0.window_has_focus = is_focused;
                             #[cfg(not(target_arch = "wasm32"))]
-                            accesskit.update_if_active(|| TreeUpdate {
-                                nodes: vec![],
-                                tree: None,
-                                focus: is_focused.then_some(cx.focused().accesskit_id()),
-                            });
+                            if window_entity == Entity::root() {
+                                accesskit.update_if_active(|| TreeUpdate {
+                                    nodes: vec![],
+                                    tree: None,
+                                    focus: is_focused.then_some(cx.focused().accesskit_id()),
+                                });
+                            }
                         }
 
                         winit::event::WindowEvent::ScaleFactorChanged {
                             scale_factor,
                             new_inner_size,
                         } => {
-                            cx.set_scale_factor(scale_factor);
-                            cx.set_window_size(
-                                new_inner_size.width as f32,
-                                new_inner_size.height as f32,
-                            );
+                            // The scale factor of the main window is used for layout, while a
+                            // secondary window scales its contents when drawn.
+                            if window_entity == Entity::root() {
+                                cx.set_scale_factor(scale_factor);
+                                cx.set_window_size(
+                                    new_inner_size.width as f32,
+                                    new_inner_size.height as f32,
+                                );
+                            } else {
+                                cx.set_window_scale_factor(window_entity, scale_factor);
+                                cx.set_secondary_window_size(
+                                    window_entity,
+                                    new_inner_size.width as f32,
+                                    new_inner_size.height as f32,
+                                );
+                            }
                             cx.needs_refresh();
                         }
 
                         winit::event::WindowEvent::DroppedFile(path) => {
-                            cx.emit_origin(WindowEvent::Drop(DropData::File(path)));
+                            dropped_files.push(path);
+                            dropped_files_window = window_entity;
                         }
 
                         #[allow(deprecated)]
@@ -407,6 +504,7 @@ impl Application {
                                 cursor_moved = true;
                                 cursor.0 = position.x as f32;
                                 cursor.1 = position.y as f32;
+                                cursor_window = window_entity;
                             }
                         }
 
@@ -433,7 +531,27 @@ impl Application {
                                 }
                             };
 
-                            cx.emit_origin(event);
+                            cx.emit_window_event(window_entity, event);
+                        }
+
+                        winit::event::WindowEvent::Touch(touch) => {
+                            let (x, y) = (touch.location.x as f32, touch.location.y as f32);
+                            let event = match touch.phase {
+                                winit::event::TouchPhase::Started => {
+                                    WindowEvent::TouchStart(touch.id, x, y)
+                                }
+                                winit::event::TouchPhase::Moved => {
+                                    WindowEvent::TouchMove(touch.id, x, y)
+                                }
+                                winit::event::TouchPhase::Ended => {
+                                    WindowEvent::TouchEnd(touch.id, x, y)
+                                }
+                                winit::event::TouchPhase::Cancelled => {
+                                    WindowEvent::TouchCancel(touch.id)
+                                }
+                            };
+
+                            cx.emit_window_event(window_entity, event);
                         }
 
                         winit::event::WindowEvent::MouseWheel { delta, phase: _, .. } => {
@@ -449,7 +567,7 @@ impl Application {
                                 }
                             };
 
-                            cx.emit_origin(out_event);
+                            cx.emit_window_event(window_entity, out_event);
                         }
 
                         winit::event::WindowEvent::KeyboardInput {
@@ -477,22 +595,32 @@ impl Application {
                                 }
                             };
 
-                            cx.emit_origin(event);
+                            cx.emit_window_event(window_entity, event);
                         }
 
                         winit::event::WindowEvent::ReceivedCharacter(character) => {
-                            cx.emit_origin(WindowEvent::CharInput(character));
+                            cx.emit_window_event(window_entity, WindowEvent::CharInput(character));
                         }
 
                         winit::event::WindowEvent::Resized(physical_size) => {
-                            cx.mutate_window(|_, window: &Window| {
+                            if let Some(window) = secondary_windows.get(&window_entity) {
                                 window.resize(physical_size);
-                            });
 
-                            cx.set_window_size(
-                                physical_size.width as f32,
-                                physical_size.height as f32,
-                            );
+                                cx.set_secondary_window_size(
+                                    window_entity,
+                                    physical_size.width as f32,
+                                    physical_size.height as f32,
+                                );
+                            } else {
+                                cx.mutate_window(|_, window: &Window| {
+                                    window.resize(physical_size);
+                                });
+
+                                cx.set_window_size(
+                                    physical_size.width as f32,
+                                    physical_size.height as f32,
+                                );
+                            }
 
                             cx.needs_refresh();
                         }
@@ -505,11 +633,11 @@ impl Application {
                         }
 
                         winit::event::WindowEvent::CursorEntered { device_id: _ } => {
-                            cx.emit_origin(WindowEvent::MouseEnter);
+                            cx.emit_window_event(window_entity, WindowEvent::MouseEnter);
                         }
 
                         winit::event::WindowEvent::CursorLeft { device_id: _ } => {
-                            cx.emit_origin(WindowEvent::MouseLeave);
+                            cx.emit_window_event(window_entity, WindowEvent::MouseLeave);
                         }
 
                         _ => {}
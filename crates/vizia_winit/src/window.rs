@@ -23,7 +23,7 @@ use glutin::{
 
 use vizia_core::backend::*;
 use vizia_core::prelude::*;
-use winit::event_loop::EventLoop;
+use winit::event_loop::EventLoopWindowTarget;
 use winit::window::{CursorGrabMode, WindowBuilder, WindowLevel};
 use winit::{dpi::*, window::WindowId};
 
@@ -40,7 +40,7 @@ pub struct Window {
 #[cfg(target_arch = "wasm32")]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
     ) -> (Self, Canvas<OpenGl>) {
         let window_builder = WindowBuilder::new();
@@ -74,7 +74,7 @@ impl Window {
         let window_builder = apply_window_description(window_builder, &window_description);
 
         // Get the window handle. this is a winit::window::Window
-        let handle = window_builder.build(&events_loop).unwrap();
+        let handle = window_builder.build(events_loop).unwrap();
 
         // Build our window
         let window = Window { id: handle.id(), window: handle, should_close: false };
@@ -94,6 +94,10 @@ impl Window {
         // TODO?
     }
 
+    pub fn make_current(&self) {
+        // Intentional no-op
+    }
+
     pub fn swap_buffers(&self) {
         // Intentional no-op
     }
@@ -102,7 +106,7 @@ impl Window {
 #[cfg(not(target_arch = "wasm32"))]
 impl Window {
     pub fn new(
-        events_loop: &EventLoop<UserEvent>,
+        events_loop: &EventLoopWindowTarget<UserEvent>,
         window_description: &WindowDescription,
     ) -> (Self, Canvas<OpenGl>) {
         let window_builder = WindowBuilder::new();
@@ -209,14 +213,19 @@ impl Window {
         }
     }
 
+    pub fn make_current(&self) {
+        self.context.make_current(&self.surface).expect("Failed to make context current");
+    }
+
     pub fn swap_buffers(&self) {
         self.surface.swap_buffers(&self.context).expect("Failed to swap buffers");
     }
 }
 
-impl View for Window {
-    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
-        event.map(|window_event, _| match window_event {
+impl Window {
+    /// Applies a window event which sets a property of the window, such as the title or cursor.
+    pub fn handle_window_event(&self, window_event: &WindowEvent) {
+        match window_event {
             WindowEvent::GrabCursor(flag) => {
                 let grab_mode = if *flag { CursorGrabMode::Locked } else { CursorGrabMode::None };
                 self.window().set_cursor_grab(grab_mode).expect("Failed to set cursor grab");
@@ -281,15 +290,27 @@ impl View for Window {
                 self.window().set_decorations(*flag);
             }
 
-            WindowEvent::ReloadStyles => {
-                cx.reload_styles().unwrap();
+            WindowEvent::SetAlwaysOnTop(flag) => {
+                self.window().set_window_level(if *flag {
+                    WindowLevel::AlwaysOnTop
+                } else {
+                    WindowLevel::Normal
+                });
             }
 
+            _ => {}
+        }
+    }
+}
+
+impl View for Window {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
             WindowEvent::WindowClose => {
                 self.should_close = true;
             }
 
-            _ => {}
+            _ => self.handle_window_event(window_event),
         })
     }
 }
@@ -2,8 +2,40 @@ use vizia::prelude::*;
 mod helpers;
 use helpers::*;
 
+const STYLE: &str = r#"
+    .target:drop-target {
+        border-width: 2px;
+        border-color: #4871ae;
+    }
+
+    .drag-preview {
+        opacity: 0.7;
+    }
+"#;
+
+#[derive(Lens)]
+pub struct AppData {
+    dropped: String,
+}
+
+pub enum AppEvent {
+    SetDropped(String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetDropped(dropped) => self.dropped = dropped.clone(),
+        });
+    }
+}
+
 fn main() {
     Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        AppData { dropped: String::from("Drop something here") }.build(cx);
+
         ExamplePage::vertical(cx, |cx| {
             HStack::new(cx, |cx| {
                 Element::new(cx).size(Pixels(50.0)).background_color(Color::red()).on_drag(|ex| {
@@ -19,32 +51,61 @@ fn main() {
                 Element::new(cx).size(Pixels(50.0)).background_color(Color::blue()).on_drag(|ex| {
                     ex.set_drop_data(ex.current());
                 });
+
+                Label::new(cx, "Text")
+                    .size(Pixels(50.0))
+                    .child_space(Stretch(1.0))
+                    .background_color(Color::gray())
+                    .on_drag(|ex| {
+                        ex.set_drop_data(DropData::value(String::from("Hello from a label")));
+                    })
+                    .drag_preview(|cx| {
+                        Label::new(cx, "Hello from a label").background_color(Color::white());
+                    });
             })
             .height(Pixels(100.0))
             .width(Auto)
             .col_between(Pixels(20.0))
             .child_space(Stretch(1.0));
 
-            Element::new(cx)
-                .size(Pixels(100.0))
-                .background_color(Color::beige())
-                .on_drop(|ex, data| {
-                    if let DropData::Id(id) = data {
-                        let bg = ex.with_current(id, |ex| ex.background_color());
-                        ex.set_background_color(bg);
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default));
-                    }
-                    if let DropData::File(file) = data {
-                        println!("Dropped File: {:?}", file);
-                    }
-                })
-                .on_hover(|ex| {
-                    if ex.has_drop_data() {
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Copy));
-                    } else {
-                        ex.emit(WindowEvent::SetCursor(CursorIcon::Default));
-                    }
-                });
+            HStack::new(cx, |cx| {
+                // Accepts dragged views, typed values and files.
+                Element::new(cx)
+                    .size(Pixels(100.0))
+                    .class("target")
+                    .background_color(Color::beige())
+                    .on_drop(|ex, data| match data {
+                        DropData::Id(id) => {
+                            let bg = ex.with_current(id, |ex| ex.background_color());
+                            ex.set_background_color(bg);
+                        }
+                        DropData::Value(_) => {
+                            if let Some(text) = data.with_value(|text: &String| text.clone()) {
+                                ex.emit(AppEvent::SetDropped(text));
+                            }
+                        }
+                        _ => {
+                            for file in data.files() {
+                                println!("Dropped File: {:?}", file);
+                            }
+                        }
+                    });
+
+                // Only accepts typed string values.
+                Label::new(cx, AppData::dropped)
+                    .size(Pixels(100.0))
+                    .class("target")
+                    .background_color(Color::beige())
+                    .on_drag_enter(|_, data| data.with_value(|_: &String| ()).is_some())
+                    .on_drop(|ex, data| {
+                        if let Some(text) = data.with_value(|text: &String| text.clone()) {
+                            ex.emit(AppEvent::SetDropped(text));
+                        }
+                    });
+            })
+            .height(Auto)
+            .width(Auto)
+            .col_between(Pixels(20.0));
         });
     })
     .run();
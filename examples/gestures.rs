@@ -0,0 +1,65 @@
+use vizia::prelude::*;
+mod helpers;
+use helpers::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    x: f32,
+    y: f32,
+    zoom: f32,
+}
+
+pub enum AppEvent {
+    Move(f32, f32),
+    Zoom(f32),
+    Reset,
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Move(dx, dy) => {
+                self.x += cx.physical_to_logical(*dx);
+                self.y += cx.physical_to_logical(*dy);
+            }
+
+            AppEvent::Zoom(scale) => {
+                self.zoom = (self.zoom * scale).clamp(0.25, 4.0);
+            }
+
+            AppEvent::Reset => {
+                self.x = 0.0;
+                self.y = 0.0;
+                self.zoom = 1.0;
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { x: 0.0, y: 0.0, zoom: 1.0 }.build(cx);
+
+        ExamplePage::new(cx, |cx| {
+            // Panning and pinching the canvas moves and zooms the contents.
+            ZStack::new(cx, |cx| {
+                // Dragging the box moves it. Being the innermost recognizer, it wins over the canvas pan.
+                Element::new(cx)
+                    .size(Pixels(100.0))
+                    .background_color(Color::rgb(200, 100, 100))
+                    .left(AppData::x.map(|x| Pixels(*x)))
+                    .top(AppData::y.map(|y| Pixels(*y)))
+                    .scale(AppData::zoom.map(|zoom| Scale::new(*zoom, *zoom)))
+                    .on_drag_update(|cx, drag| cx.emit(AppEvent::Move(drag.delta.0, drag.delta.1)))
+                    .on_long_press(|cx| cx.emit(AppEvent::Reset));
+            })
+            .size(Stretch(1.0))
+            .background_color(Color::rgb(220, 220, 220))
+            .overflow(Overflow::Hidden)
+            .on_pan(|cx, dx, dy| cx.emit(AppEvent::Move(dx, dy)))
+            .on_pinch(|cx, scale| cx.emit(AppEvent::Zoom(scale)));
+        });
+    })
+    .title("Gestures")
+    .run();
+}
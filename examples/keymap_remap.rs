@@ -0,0 +1,65 @@
+//! This example showcases how to let the user remap the key chord of an action.
+//!
+//! Press the shortcut recorder and then a key chord to rebind `Action::Save`. Press backspace
+//! while the recorder is focused to unbind it.
+
+use vizia::prelude::*;
+
+#[derive(Lens)]
+struct AppData {
+    save: Option<KeyChord>,
+}
+
+enum AppEvent {
+    SetSaveShortcut(Option<KeyChord>),
+}
+
+impl Model for AppData {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetSaveShortcut(chord) => {
+                self.save = *chord;
+
+                let config = KeymapConfig {
+                    bindings: vec![KeyBinding {
+                        action: Action::Save,
+                        keys: chord.iter().copied().map(KeySequence::from).collect(),
+                    }],
+                };
+
+                println!("Save bound to: {:?}", config.keys(&Action::Save));
+                cx.emit(KeymapEvent::ApplyConfig(config));
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        let save = KeyChord::new(Modifiers::CTRL, Code::KeyS);
+
+        AppData { save: Some(save) }.build(cx);
+
+        Keymap::from(vec![(save, KeymapEntry::new(Action::Save, |_| println!("Action Save")))])
+            .build(cx);
+
+        HStack::new(cx, |cx| {
+            Label::new(cx, "Save");
+            ShortcutRecorder::new(cx, AppData::save)
+                .on_record(|cx, chord| cx.emit(AppEvent::SetSaveShortcut(Some(chord))))
+                .on_clear(|cx| cx.emit(AppEvent::SetSaveShortcut(None)));
+        })
+        .col_between(Pixels(10.0))
+        .child_top(Stretch(1.0))
+        .child_bottom(Stretch(1.0))
+        .space(Pixels(10.0));
+    })
+    .title("Keymap - Remap")
+    .run();
+}
+
+// The actions that are associated with the key chords.
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Action {
+    Save,
+}
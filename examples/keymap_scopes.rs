@@ -0,0 +1,88 @@
+//! This example showcases key sequences and keymaps which are scoped to a part of the application.
+//!
+//! Key sequences anywhere in the application:
+//! `CTRL+K, CTRL+S` => `Action::SaveAll`
+//! `CTRL+F`         => `Action::Find`
+//!
+//! Key chords while the editor panel has focus:
+//! `CTRL+F` => `Action::FindInEditor`, which shadows `Action::Find`
+//!
+//! Key chords while the terminal panel has focus:
+//! `CTRL+K` => `Action::ClearTerminal`, with a higher priority than `CTRL+K, CTRL+S`
+
+use vizia::prelude::*;
+
+fn main() {
+    Application::new(|cx| {
+        // Build the application-wide keymap.
+        Keymap::from(vec![
+            (
+                KeySequence::from([
+                    KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                    KeyChord::new(Modifiers::CTRL, Code::KeyS),
+                ]),
+                KeymapEntry::new(Action::SaveAll, |_| println!("Action SaveAll")),
+            ),
+            (
+                KeySequence::from(KeyChord::new(Modifiers::CTRL, Code::KeyF)),
+                KeymapEntry::new(Action::Find, |_| println!("Action Find")),
+            ),
+        ])
+        .build(cx);
+
+        HStack::new(cx, |cx| {
+            VStack::new(cx, |cx| {
+                // This keymap is only active while focus is within the editor panel.
+                Keymap::from(vec![(
+                    KeyChord::new(Modifiers::CTRL, Code::KeyF),
+                    KeymapEntry::new(Action::FindInEditor, |_| println!("Action FindInEditor")),
+                )])
+                .build(cx);
+
+                Button::new(cx, |_| {}, |cx| Label::new(cx, "Editor"));
+            });
+
+            VStack::new(cx, |cx| {
+                // This keymap is only active while focus is within the terminal panel.
+                Keymap::from(vec![(
+                    KeyChord::new(Modifiers::CTRL, Code::KeyK),
+                    KeymapEntry::new(Action::ClearTerminal, |_| println!("Action ClearTerminal"))
+                        .with_priority(1),
+                )])
+                .build(cx);
+
+                Button::new(cx, |_| {}, |cx| Label::new(cx, "Terminal"));
+            });
+
+            Button::new(
+                cx,
+                |cx| {
+                    for conflict in cx.keymap_conflicts() {
+                        println!(
+                            "{:?} {:?} conflicts with {:?} {:?}: {:?}",
+                            conflict.entity,
+                            conflict.sequence,
+                            conflict.other_entity,
+                            conflict.other_sequence,
+                            conflict.kind,
+                        );
+                    }
+                },
+                |cx| Label::new(cx, "Print Conflicts"),
+            );
+        })
+        .col_between(Pixels(10.0))
+        .child_space(Pixels(10.0));
+    })
+    .title("Keymap - Scopes")
+    .run();
+}
+
+// The actions that are associated with the key sequences.
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Action {
+    SaveAll,
+    Find,
+    FindInEditor,
+    ClearTerminal,
+}
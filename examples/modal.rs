@@ -2,19 +2,38 @@ use vizia::prelude::*;
 
 fn main() {
     Application::new(|cx| {
-        AppData { show_modal: false }.build(cx);
+        AppData { show_modal: false, deleted: false }.build(cx);
 
         VStack::new(cx, |cx| {
             Button::new(cx, |cx| cx.emit(AppEvent::ShowModal), |cx| Label::new(cx, "Show Modal"));
 
-            Popup::new(cx, AppData::show_modal, true, |cx| {
+            Button::new(
+                cx,
+                |cx| {
+                    Dialog::confirm(cx, "Delete the file?", |cx, ok| {
+                        if ok {
+                            cx.emit(AppEvent::Delete);
+                        }
+                    })
+                },
+                |cx| Label::new(cx, "Delete"),
+            );
+
+            Label::new(
+                cx,
+                AppData::deleted.map(|deleted| if *deleted { "Deleted" } else { "Not deleted" }),
+            );
+
+            Dialog::new(cx, AppData::show_modal, |cx| {
                 Label::new(cx, "Modal Title").class("title");
                 Label::new(cx, "This is a message");
-                Button::new(cx, |cx| cx.emit(AppEvent::HideModal), |cx| Label::new(cx, "Ok"))
-                    .class("accent");
+                HStack::new(cx, |cx| {
+                    Button::new(cx, |cx| cx.emit(AppEvent::HideModal), |cx| Label::new(cx, "Ok"))
+                        .class("accent");
+                })
+                .class("dialog-buttons");
             })
-            .on_blur(|cx| cx.emit(AppEvent::HideModal))
-            .class("modal");
+            .on_close(|cx| cx.emit(AppEvent::HideModal));
         })
         .class("container");
     })
@@ -26,11 +45,13 @@ fn main() {
 pub enum AppEvent {
     ShowModal,
     HideModal,
+    Delete,
 }
 
 #[derive(Lens)]
 pub struct AppData {
     show_modal: bool,
+    deleted: bool,
 }
 
 impl Model for AppData {
@@ -42,6 +63,9 @@ impl Model for AppData {
             AppEvent::HideModal => {
                 self.show_modal = false;
             }
+            AppEvent::Delete => {
+                self.deleted = true;
+            }
         });
     }
 }
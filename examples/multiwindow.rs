@@ -0,0 +1,69 @@
+use vizia::prelude::*;
+
+#[cfg(feature = "baseview")]
+fn main() {
+    panic!("This example is not supported on baseview");
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    show_window: bool,
+    count: i32,
+}
+
+pub enum AppEvent {
+    ToggleWindow,
+    CloseWindow,
+    Increment,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::ToggleWindow => self.show_window ^= true,
+            AppEvent::CloseWindow => self.show_window = false,
+            AppEvent::Increment => self.count += 1,
+        });
+    }
+}
+
+#[cfg(not(feature = "baseview"))]
+fn main() {
+    Application::new(|cx| {
+        AppData { show_window: false, count: 0 }.build(cx);
+
+        VStack::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| cx.emit(AppEvent::ToggleWindow),
+                |cx| Label::new(cx, "Toggle Window"),
+            );
+            Label::new(cx, AppData::count.map(|count| format!("Count: {}", count)));
+        })
+        .row_between(Pixels(10.0))
+        .child_space(Stretch(1.0));
+
+        Binding::new(cx, AppData::show_window, |cx, show_window| {
+            if show_window.get(cx) {
+                Window::new(cx, |cx| {
+                    VStack::new(cx, |cx| {
+                        Label::new(cx, AppData::count.map(|count| format!("Count: {}", count)));
+                        Button::new(
+                            cx,
+                            |cx| cx.emit(AppEvent::Increment),
+                            |cx| Label::new(cx, "Increment"),
+                        );
+                    })
+                    .row_between(Pixels(10.0))
+                    .child_space(Stretch(1.0));
+                })
+                .on_close(|cx| cx.emit(AppEvent::CloseWindow))
+                .title("Secondary Window")
+                .inner_size((300, 200));
+            }
+        });
+    })
+    .title("Multiple Windows")
+    .inner_size((400, 200))
+    .run();
+}
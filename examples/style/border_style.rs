@@ -0,0 +1,122 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+
+    .row {
+        child-space: 1s;
+        col-between: 20px;
+    }
+
+    element {
+        size: 100px;
+        background-color: rgb(230, 230, 230);
+        border: 6px rgb(60, 60, 60);
+    }
+
+    .dashed {
+        border-style: dashed;
+    }
+
+    .dotted {
+        border-style: dotted;
+    }
+
+    .double {
+        border-style: double;
+    }
+
+    .groove {
+        border-style: groove;
+        border-color: rgb(120, 140, 200);
+    }
+
+    .ridge {
+        border-style: ridge;
+        border-color: rgb(120, 140, 200);
+    }
+
+    .inset {
+        border-style: inset;
+        border-color: rgb(120, 140, 200);
+    }
+
+    .outset {
+        border-style: outset;
+        border-color: rgb(120, 140, 200);
+    }
+
+    .rounded {
+        border-radius: 20px;
+    }
+
+    .bevelled {
+        border-radius: 20px;
+        border-corner-shape: bevel;
+    }
+
+    .sides {
+        border-width: 2px 6px 10px 14px;
+        border-color: red green blue orange;
+        border-style: solid dashed dotted double;
+        border-radius: 16px;
+    }
+
+    .outline {
+        border-width: 0px;
+        outline: 2px dashed rgb(60, 60, 60);
+        outline-offset: 4px;
+    }
+
+    .drop-zone {
+        width: 320px;
+        height: 100px;
+        child-space: 1s;
+        background-color: rgb(245, 248, 255);
+        border: 2px dashed rgb(120, 140, 200);
+        border-radius: 12px;
+    }
+
+    .drop-zone:hover {
+        background-color: rgb(225, 233, 255);
+        border-color: rgb(60, 90, 200);
+        transition: border-color 100ms, background-color 100ms;
+    }
+"#;
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        HStack::new(cx, |cx| {
+            Element::new(cx).class("dashed");
+            Element::new(cx).class("dotted");
+            Element::new(cx).class("double");
+            Element::new(cx).class("groove");
+            Element::new(cx).class("ridge");
+            Element::new(cx).class("inset");
+            Element::new(cx).class("outset");
+        })
+        .class("row");
+
+        HStack::new(cx, |cx| {
+            Element::new(cx).class("dashed").class("rounded");
+            Element::new(cx).class("dotted").class("rounded");
+            Element::new(cx).class("double").class("bevelled");
+            Element::new(cx).class("sides");
+            Element::new(cx).class("outline").class("rounded");
+        })
+        .class("row");
+
+        HStack::new(cx, |cx| {
+            Label::new(cx, "Drop files here").class("drop-zone");
+
+            Element::new(cx)
+                .border_width(Pixels(4.0))
+                .border_color(Color::gray())
+                .border_style(BorderStyleKeyword::Dashed)
+                .border_radius(Pixels(50.0));
+        })
+        .class("row");
+    })
+    .run();
+}
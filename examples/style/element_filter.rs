@@ -0,0 +1,101 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+
+    :root {
+        child-space: 1s;
+    }
+
+    hstack {
+        child-space: 1s;
+        col-between: 40px;
+    }
+
+    element {
+        size: 100px;
+        background-color: rgb(200, 80, 80);
+        border-radius: 8px;
+    }
+
+    .blur:hover {
+        filter: blur(4px);
+        transition: filter 200ms;
+    }
+
+    .brightness:hover {
+        filter: brightness(150%);
+        transition: filter 200ms;
+    }
+
+    .contrast:hover {
+        filter: contrast(30%);
+        transition: filter 200ms;
+    }
+
+    .grayscale:hover {
+        filter: grayscale(100%);
+        transition: filter 200ms;
+    }
+
+    .saturate:hover {
+        filter: saturate(300%);
+        transition: filter 200ms;
+    }
+
+    .invert:hover {
+        filter: invert(100%);
+        transition: filter 200ms;
+    }
+
+    .hue-rotate:hover {
+        filter: hue-rotate(180deg);
+        transition: filter 200ms;
+    }
+
+    .opacity:hover {
+        filter: opacity(25%);
+        transition: filter 200ms;
+    }
+
+    .drop-shadow {
+        background-color: transparent;
+        border-width: 8px;
+        border-color: rgb(200, 80, 80);
+    }
+
+    .drop-shadow:hover {
+        filter: drop-shadow(6px 6px 4px black);
+        transition: filter 200ms;
+    }
+
+    .disabled:disabled {
+        filter: grayscale(100%) opacity(50%);
+    }
+"#;
+
+fn main() {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        HStack::new(cx, |cx| {
+            Element::new(cx).class("blur");
+            Element::new(cx).class("brightness");
+            Element::new(cx).class("contrast");
+            Element::new(cx).class("grayscale");
+            Element::new(cx).class("saturate");
+        });
+
+        HStack::new(cx, |cx| {
+            Element::new(cx).class("invert");
+            Element::new(cx).class("hue-rotate");
+            Element::new(cx).class("opacity");
+            Element::new(cx).class("drop-shadow");
+            Button::new(cx, |_| {}, |cx| Label::new(cx, "Disabled"))
+                .class("disabled")
+                .disabled(true);
+        });
+    })
+    .title("Filter")
+    .inner_size((1000, 600))
+    .run();
+}
@@ -0,0 +1,93 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Debug, Lens)]
+pub struct AppData {
+    pub open: bool,
+    pub notifications: bool,
+}
+
+#[derive(Debug)]
+pub enum AppEvent {
+    ToggleOpen,
+    ToggleNotifications,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::ToggleOpen => self.open ^= true,
+            AppEvent::ToggleNotifications => self.notifications ^= true,
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { open: false, notifications: true }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            Label::new(cx, "Collapsible").class("h2");
+
+            Collapsible::new(
+                cx,
+                AppData::open,
+                |cx| {
+                    Label::new(cx, "Notifications");
+                },
+                |cx| {
+                    HStack::new(cx, |cx| {
+                        Checkbox::new(cx, AppData::notifications)
+                            .on_toggle(|cx| cx.emit(AppEvent::ToggleNotifications))
+                            .id("notifications");
+                        Label::new(cx, "Show notifications").describing("notifications");
+                    })
+                    .size(Auto)
+                    .col_between(Pixels(5.0))
+                    .child_top(Stretch(1.0))
+                    .child_bottom(Stretch(1.0));
+                },
+            )
+            .on_toggle(|cx| cx.emit(AppEvent::ToggleOpen))
+            .width(Pixels(300.0));
+
+            Label::new(cx, "Accordion").class("h2");
+
+            Accordion::new(
+                cx,
+                vec![
+                    AccordionSection::new(
+                        |cx| {
+                            Label::new(cx, "General");
+                        },
+                        |cx| {
+                            Label::new(cx, "General settings");
+                        },
+                    )
+                    .open(true),
+                    AccordionSection::new(
+                        |cx| {
+                            Label::new(cx, "Appearance");
+                        },
+                        |cx| {
+                            Label::new(cx, "Theme settings");
+                        },
+                    ),
+                    AccordionSection::new(
+                        |cx| {
+                            Label::new(cx, "Advanced");
+                        },
+                        |cx| {
+                            Label::new(cx, "Advanced settings");
+                        },
+                    ),
+                ],
+            )
+            .exclusive(true)
+            .width(Pixels(300.0));
+        });
+    })
+    .title("Collapsible")
+    .run();
+}
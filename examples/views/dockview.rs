@@ -0,0 +1,82 @@
+use vizia::prelude::*;
+
+const DEFAULT_LAYOUT: &str = "
+    left 220 explorer* outline
+    right 260 layout*
+    bottom 160 console*
+    center editor*
+";
+
+const PANELS: [&str; 5] = ["explorer", "outline", "editor", "console", "layout"];
+
+#[derive(Lens)]
+pub struct AppData {
+    text: String,
+    layout: String,
+}
+
+pub enum AppEvent {
+    LayoutChanged(String),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::LayoutChanged(layout) => {
+                self.layout = layout.clone();
+            }
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { text: String::from("fn main() {}"), layout: String::from(DEFAULT_LAYOUT) }
+            .build(cx);
+
+        DockView::new(
+            cx,
+            DEFAULT_LAYOUT.parse().unwrap(),
+            vec![
+                DockPanel::new("explorer", "Explorer", |cx| {
+                    for name in ["main.rs", "lib.rs", "Cargo.toml"] {
+                        Label::new(cx, name);
+                    }
+                }),
+                DockPanel::new("outline", "Outline", |cx| {
+                    Label::new(cx, "fn main()");
+                }),
+                DockPanel::new("editor", "Editor", |cx| {
+                    // The text is kept while the panel is moved between areas.
+                    Textbox::new_multiline(cx, AppData::text, true).size(Stretch(1.0));
+                }),
+                DockPanel::new("console", "Console", |cx| {
+                    Label::new(cx, "Drag the tabs to move, float and dock the panels.");
+                }),
+                DockPanel::new("layout", "Layout", |cx| {
+                    Button::new(
+                        cx,
+                        |cx| cx.emit(DockEvent::SetLayout(DEFAULT_LAYOUT.parse().unwrap())),
+                        |cx| Label::new(cx, "Reset Layout"),
+                    );
+
+                    Button::new(
+                        cx,
+                        |cx| {
+                            for id in PANELS {
+                                cx.emit(DockEvent::Open(id.to_owned()));
+                            }
+                        },
+                        |cx| Label::new(cx, "Reopen Panels"),
+                    );
+
+                    Label::new(cx, AppData::layout).width(Stretch(1.0));
+                }),
+            ],
+        )
+        .on_layout_change(|cx, layout| cx.emit(AppEvent::LayoutChanged(layout.to_string())));
+    })
+    .title("Dock View")
+    .inner_size((1000, 700))
+    .run();
+}
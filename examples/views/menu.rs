@@ -151,6 +151,27 @@ fn main() {
             );
         });
 
+        Label::new(cx, "Right-click for a context menu")
+            .width(Pixels(300.0))
+            .height(Pixels(100.0))
+            .child_space(Stretch(1.0))
+            .space(Pixels(20.0))
+            .navigable(true)
+            .context_menu(|cx| {
+                MenuButton::new(cx, |_| println!("Rename"), |cx| Label::new(cx, "Rename"));
+                MenuButton::new(cx, |_| println!("Duplicate"), |cx| Label::new(cx, "Duplicate"));
+                Submenu::new(
+                    cx,
+                    |cx| Label::new(cx, "Share"),
+                    |cx| {
+                        MenuButton::new(cx, |_| println!("Email"), |cx| Label::new(cx, "Email"));
+                        MenuButton::new(cx, |_| println!("Link"), |cx| Label::new(cx, "Link"));
+                    },
+                );
+                MenuDivider::new(cx);
+                MenuButton::new(cx, |_| println!("Delete"), |cx| Label::new(cx, "Delete"));
+            });
+
         // Submenu::new(
         //     cx,
         //     |cx| Label::new(cx, "Options"),
@@ -4,39 +4,59 @@ use vizia::prelude::*;
 
 #[derive(Lens)]
 pub struct AppData {
-    list: Vec<&'static str>,
+    list: Vec<String>,
+    next: usize,
 }
 
-impl Model for AppData {}
+pub enum AppEvent {
+    AddTab,
+    CloseTab(usize),
+    MoveTab(usize, usize),
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::AddTab => {
+                self.next += 1;
+                self.list.push(format!("Document {}", self.next));
+            }
+
+            AppEvent::CloseTab(index) => {
+                self.list.remove(*index);
+            }
+
+            AppEvent::MoveTab(from, to) => {
+                let tab = self.list.remove(*from);
+                self.list.insert(*to, tab);
+            }
+        });
+    }
+}
 
 fn main() {
     Application::new(|cx| {
-        AppData { list: vec!["Tab1", "Tab2"] }.build(cx);
+        AppData { list: vec![String::from("Document 1"), String::from("Document 2")], next: 2 }
+            .build(cx);
 
         ExamplePage::new(cx, |cx| {
-            TabView::new(cx, AppData::list, |cx, item| match item.get(cx) {
-                "Tab1" => TabPair::new(
-                    move |cx| {
-                        Label::new(cx, item).hoverable(false);
-                        Element::new(cx).class("indicator");
-                    },
-                    |cx| {
-                        Element::new(cx).size(Pixels(200.0)).background_color(Color::red());
-                    },
-                ),
+            Button::new(cx, |cx| cx.emit(AppEvent::AddTab), |cx| Label::new(cx, "New Tab"));
 
-                "Tab2" => TabPair::new(
+            TabView::new(cx, AppData::list, |cx, item| {
+                TabPair::new(
                     move |cx| {
                         Label::new(cx, item).hoverable(false);
                         Element::new(cx).class("indicator");
                     },
-                    |cx| {
-                        Element::new(cx).size(Pixels(200.0)).background_color(Color::blue());
+                    move |cx| {
+                        // The text typed into each tab is kept while other tabs are selected.
+                        Textbox::new_multiline(cx, item, true).size(Stretch(1.0));
                     },
-                ),
-
-                _ => unreachable!(),
+                )
             })
+            .on_close(|cx, index| cx.emit(AppEvent::CloseTab(index)))
+            .on_reorder(|cx, from, to| cx.emit(AppEvent::MoveTab(from, to)))
+            .keep_inactive(true)
             .width(Pixels(500.0))
             .height(Pixels(300.0));
         });
@@ -0,0 +1,83 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    restored: usize,
+}
+
+pub enum AppEvent {
+    Restore,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::Restore => self.restored += 1,
+        });
+    }
+}
+
+fn main() {
+    Application::new(|cx| {
+        AppData { restored: 0 }.build(cx);
+
+        ExamplePage::new(cx, |cx| {
+            Button::new(
+                cx,
+                |cx| {
+                    cx.emit(ToastEvent::Show {
+                        title: String::from("File saved"),
+                        body: None,
+                        kind: ToastKind::Success,
+                        duration: Some(DEFAULT_TOAST_DURATION),
+                        actions: Vec::new(),
+                    })
+                },
+                |cx| Label::new(cx, "Success"),
+            );
+
+            Button::new(
+                cx,
+                |cx| {
+                    cx.emit(ToastEvent::Show {
+                        title: String::from("Item deleted"),
+                        body: Some(String::from("The item was moved to the trash.")),
+                        kind: ToastKind::Info,
+                        duration: Some(DEFAULT_TOAST_DURATION),
+                        actions: vec![ToastAction::new("Undo", |cx| cx.emit(AppEvent::Restore))],
+                    })
+                },
+                |cx| Label::new(cx, "With action"),
+            );
+
+            Button::new(
+                cx,
+                |cx| {
+                    cx.emit(ToastEvent::Show {
+                        title: String::from("Connection lost"),
+                        body: Some(String::from("This toast stays open until it is closed.")),
+                        kind: ToastKind::Error,
+                        duration: None,
+                        actions: Vec::new(),
+                    })
+                },
+                |cx| Label::new(cx, "Persistent error"),
+            );
+
+            Button::new(
+                cx,
+                |cx| cx.emit(ToastEvent::DismissAll),
+                |cx| Label::new(cx, "Dismiss all"),
+            );
+
+            Label::new(
+                cx,
+                AppData::restored.map(|restored| format!("Restored {} times", restored)),
+            );
+        });
+    })
+    .title("Toast")
+    .run();
+}